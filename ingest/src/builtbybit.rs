@@ -0,0 +1,113 @@
+use crate::HttpServer;
+use crate::dry_run::DryRun;
+use crate::retry::RetryPolicy;
+
+use anyhow::Result;
+use governor::{Quota, RateLimiter};
+use governor::clock::QuantaClock;
+use governor::state::{InMemoryState, NotKeyed};
+use nonzero_ext::*;
+use reqwest::Client;
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+mod member;
+mod resource;
+mod version;
+
+const BUILTBYBIT_BASE_URL: &str = "https://api.builtbybit.com/v1/";
+
+const BUILTBYBIT_USER_AGENT: &str = concat!("Frumple/mc-plugin-finder/", env!("CARGO_PKG_VERSION"), " (contact@mcpluginfinder.com)");
+const BUILTBYBIT_RATE_LIMIT_PER_SECOND: NonZeroU32 = nonzero!(2u32);
+
+#[derive(Debug)]
+pub struct BuiltByBitServer;
+
+impl HttpServer for BuiltByBitServer {
+    async fn new() -> Self {
+        Self
+    }
+
+    fn base_url(&self) -> Url {
+        Url::parse(BUILTBYBIT_BASE_URL)
+          .expect("BuiltByBit base URL could not be parsed")
+    }
+}
+
+#[derive(Debug)]
+pub struct BuiltByBitClient<T> {
+    api_client: Client,
+    rate_limiter: RateLimiter<NotKeyed, InMemoryState, QuantaClock>,
+    retry_policy: RetryPolicy,
+    dry_run: Option<Arc<DryRun>>,
+    http_server: T,
+
+    /// Resources only reference their author by member ID, so usernames are cached for the rest of the run.
+    author_names: Mutex<HashMap<i32, String>>
+}
+
+impl<T> BuiltByBitClient<T> {
+    /// Like CurseForge, the BuiltByBit API requires a private API token on every request.
+    pub fn new(http_server: T, api_token: &str) -> Result<BuiltByBitClient<T>> {
+        let mut authorization_value = HeaderValue::from_str(&["Private ", api_token].concat())?;
+        authorization_value.set_sensitive(true);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, authorization_value);
+
+        let api_client = reqwest::Client::builder()
+            .user_agent(BUILTBYBIT_USER_AGENT)
+            .default_headers(headers)
+            .build()?;
+
+        let quota = Quota::per_second(BUILTBYBIT_RATE_LIMIT_PER_SECOND);
+        let rate_limiter = RateLimiter::direct(quota);
+
+        Ok(Self { api_client, rate_limiter, retry_policy: RetryPolicy::default(), dry_run: None, http_server, author_names: Mutex::new(HashMap::new()) })
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: Option<Arc<DryRun>>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wiremock::MockServer;
+
+    pub const BUILTBYBIT_TEST_API_TOKEN: &str = "test-api-token";
+
+    #[derive(Debug)]
+    pub struct BuiltByBitTestServer {
+        mock_server: MockServer
+    }
+
+    impl BuiltByBitTestServer {
+        pub fn mock(&self) -> &MockServer {
+            &self.mock_server
+        }
+    }
+
+    impl HttpServer for BuiltByBitTestServer {
+        async fn new() -> Self {
+            Self {
+                mock_server: MockServer::start().await
+            }
+        }
+
+        fn base_url(&self) -> Url {
+            Url::parse(&self.mock_server.uri())
+                .expect("BuiltByBit mock server base URL could not be parsed")
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use crate::HttpServer;
+use crate::retry::send_with_retry;
+use crate::builtbybit::BuiltByBitClient;
+use crate::metrics::{time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_log::IngestLogRepository;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use thiserror::Error;
+use tracing::instrument;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetBuiltByBitMemberResponse {
+    data: IncomingBuiltByBitMember
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingBuiltByBitMember {
+    member_id: i32,
+    username: String
+}
+
+#[derive(Debug, Error)]
+enum GetBuiltByBitMemberError {
+    #[error("Member ID {member_id}: Member not found")]
+    MemberNotFound {
+        member_id: i32
+    },
+    #[error("Member ID {member_id}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        member_id: i32,
+        status_code: u16
+    }
+}
+
+impl<T> BuiltByBitClient<T> where T: HttpServer + Send + Sync {
+    /// Gets the username of the given member, using the cached username if the member has already been seen.
+    pub async fn get_builtbybit_author_name(&self, member_id: i32) -> Result<String> {
+        let cached_name = self.author_names
+            .lock()
+            .expect("BuiltByBit author name cache should not be poisoned")
+            .get(&member_id)
+            .cloned();
+
+        if let Some(name) = cached_name {
+            return Ok(name)
+        }
+
+        let name = self.get_member_from_api(member_id).await?.data.username;
+
+        self.author_names
+            .lock()
+            .expect("BuiltByBit author name cache should not be poisoned")
+            .insert(member_id, name.clone());
+
+        Ok(name)
+    }
+
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_member_from_api(&self, member_id: i32) -> Result<GetBuiltByBitMemberResponse> {
+        let path = &["members/", member_id.to_string().as_str()].concat();
+        let url = self.http_server.base_url().join(path)?;
+
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::BuiltByBit,
+            || time_rate_limiter_wait(IngestLogRepository::BuiltByBit, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let response: GetBuiltByBitMemberResponse = raw_response.json().await?;
+                Ok(response)
+            }
+            StatusCode::NOT_FOUND => {
+                Err(
+                    GetBuiltByBitMemberError::MemberNotFound {
+                        member_id
+                    }.into()
+                )
+            }
+            _ => {
+                Err(
+                    GetBuiltByBitMemberError::UnexpectedStatusCode {
+                        member_id,
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builtbybit::test::{BuiltByBitTestServer, BUILTBYBIT_TEST_API_TOKEN};
+
+    use speculoos::prelude::*;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{header, method, path};
+
+    #[tokio::test]
+    async fn should_get_member_from_api() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let expected_response = GetBuiltByBitMemberResponse {
+            data: IncomingBuiltByBitMember {
+                member_id: 1,
+                username: "alice".to_string()
+            }
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/members/1"))
+            .and(header("authorization", ["Private ", BUILTBYBIT_TEST_API_TOKEN].concat().as_str()))
+            .respond_with(response_template)
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let response = builtbybit_client.get_member_from_api(1).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_only_request_author_name_once() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let response = GetBuiltByBitMemberResponse {
+            data: IncomingBuiltByBitMember {
+                member_id: 1,
+                username: "alice".to_string()
+            }
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/members/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let first_name = builtbybit_client.get_builtbybit_author_name(1).await?;
+        let second_name = builtbybit_client.get_builtbybit_author_name(1).await?;
+
+        // Assert
+        assert_that(&first_name).is_equal_to("alice".to_string());
+        assert_that(&second_name).is_equal_to("alice".to_string());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_member_is_not_found() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        Mock::given(method("GET"))
+            .and(path("/members/1"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let result = builtbybit_client.get_builtbybit_author_name(1).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<GetBuiltByBitMemberError>().unwrap();
+
+        if let GetBuiltByBitMemberError::MemberNotFound{member_id} = downcast_error {
+            assert_that(member_id).is_equal_to(1);
+        } else {
+            panic!("expected error to be MemberNotFound, but was {}", downcast_error);
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,667 @@
+use crate::HttpServer;
+use crate::failure::{FailureKind, QuarantinedItems};
+use crate::retry::send_with_retry;
+use crate::builtbybit::BuiltByBitClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::builtbybit::resource::{BuiltByBitResource, upsert_builtbybit_resource, mark_unseen_builtbybit_resources_as_deleted};
+use mc_plugin_finder::database::ingest_failure::get_ingest_failures;
+use mc_plugin_finder::database::ingest_checkpoint::{IngestCheckpoint, upsert_ingest_checkpoint, get_ingest_checkpoint, delete_ingest_checkpoint, get_checkpoint_start_date};
+use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
+use mc_plugin_finder::database::source_repository::{SourceRepository, extract_source_repository_from_url};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use futures::future;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use page_turner::prelude::*;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use std::fmt::Debug;
+use std::sync::{Arc, LazyLock};
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::{info, warn, instrument};
+
+const BUILTBYBIT_RESOURCES_REQUESTS_AHEAD: usize = 2;
+const BUILTBYBIT_RESOURCES_CONCURRENT_FUTURES: usize = 10;
+
+// Matches a link whose text is "Source" (e.g. [URL='https://github.com/alice/foo']Source[/URL]),
+// or a link following a "Source:" label (e.g. [B]Source:[/B] https://github.com/alice/foo).
+static SOURCE_URL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(concat!(
+    r#"(?i)\[url=['"]?(https?://[^\s\[\]"'<>]+)['"]?\]\s*source(?:\s+code)?\s*\[/url\]"#,
+    r#"|\bsource(?:\s+code)?(?:\s*\[/?[a-z]+\])*\s*:(?:\s*\[/?[a-z]+\])*\s*(?:\[url=['"]?)?(https?://[^\s\[\]"'<>]+)"#
+)).unwrap());
+
+#[derive(Clone, Debug, Serialize)]
+struct GetBuiltByBitResourcesRequest {
+    page: u32,
+    count: u32,
+    sort: String,
+    order: String
+}
+
+impl GetBuiltByBitResourcesRequest {
+    fn create_populate_request() -> Self {
+        Self {
+            page: 1,
+            count: 20,
+            sort: "resource_id".to_string(),
+            order: "asc".to_string()
+        }
+    }
+
+    fn create_update_request() -> Self {
+        Self {
+            page: 1,
+            count: 20,
+            sort: "update_date".to_string(),
+            order: "desc".to_string()
+        }
+    }
+}
+
+impl RequestAhead for GetBuiltByBitResourcesRequest {
+    fn next_request(&self) -> Self {
+        Self {
+            page: self.page + 1,
+            count: self.count,
+            sort: self.sort.clone(),
+            order: self.order.clone()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetBuiltByBitResourcesResponse {
+    data: Vec<IncomingBuiltByBitResource>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetBuiltByBitResourceResponse {
+    data: IncomingBuiltByBitResource
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingBuiltByBitResource {
+    resource_id: i32,
+    author_id: i32,
+    title: String,
+    tag_line: String,
+    #[serde(default)]
+    description: String,
+    release_date: i64,
+    update_date: i64,
+    price: f64,
+    currency: Option<String>,
+    download_count: i32
+}
+
+#[derive(Debug, Error)]
+enum GetBuiltByBitResourcesError {
+    #[error("Could not get BuiltByBit resources {request:?}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        request: GetBuiltByBitResourcesRequest,
+        status_code: u16
+    },
+    #[error("Could not get BuiltByBit resource ID {resource_id}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCodeForResource {
+        resource_id: i32,
+        status_code: u16
+    }
+}
+
+impl<T> BuiltByBitClient<T> where T: HttpServer + Send + Sync {
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn populate_builtbybit_resources(&self, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+        info!("Populating BuiltByBit resources...");
+
+        let mut request = GetBuiltByBitResourcesRequest::create_populate_request();
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::BuiltByBit, IngestLogItem::Resource).await?;
+        let date_started = OffsetDateTime::now_utc();
+
+        let mut checkpoint_date_started = get_checkpoint_start_date(db_pool).await?;
+
+        if resume {
+            match get_ingest_checkpoint(db_pool, IngestLogRepository::BuiltByBit, IngestLogItem::Resource).await? {
+                Some(checkpoint) => {
+                    request.page = u32::try_from(checkpoint.position)? + 1;
+                    checkpoint_date_started = checkpoint.date_started;
+                    info!("Resuming from page: {}", request.page);
+                }
+                None => info!("No checkpoint found, starting from the beginning...")
+            }
+        }
+
+        let first_page = request.page;
+
+        let result = self
+            .pages_ahead(BUILTBYBIT_RESOURCES_REQUESTS_AHEAD, Limit::None, request)
+            .enumerate()
+            .map(|(index, page_result)| page_result.map(|incoming_resources| (first_page + index as u32, incoming_resources)))
+            .try_for_each(|(page, incoming_resources)| self.process_incoming_resource_page(page, incoming_resources, db_pool, &count, &quarantined, checkpoint_date_started))
+            .await;
+
+        // The run has completed, so there is nothing left to resume.
+        if result.is_ok() && self.dry_run.is_none() {
+            delete_ingest_checkpoint(db_pool, IngestLogRepository::BuiltByBit, IngestLogItem::Resource).await?;
+
+            // Anything that was not seen since the run started is no longer available upstream.
+            if reconcile {
+                let deleted = mark_unseen_builtbybit_resources_as_deleted(db_pool, checkpoint_date_started).await?;
+                info!("BuiltByBit resources marked as deleted: {}", deleted);
+            }
+        }
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::BuiltByBit,
+            item: IngestLogItem::Resource,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("BuiltByBit resources populated: {}", items_processed);
+
+        result
+    }
+
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn update_builtbybit_resources(&self, db_pool: &Pool, update_date_later_than: OffsetDateTime) -> Result<()> {
+        info!("Updating BuiltByBit resources since: {}", update_date_later_than);
+
+        let request = GetBuiltByBitResourcesRequest::create_update_request();
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::BuiltByBit, IngestLogItem::Resource).await?;
+        let date_started = OffsetDateTime::now_utc();
+
+        let result = self
+            .pages_ahead(BUILTBYBIT_RESOURCES_REQUESTS_AHEAD, Limit::None, request)
+            .items()
+            .try_take_while(|x| future::ready(Ok(x.update_date > update_date_later_than.unix_timestamp())))
+            .try_for_each_concurrent(BUILTBYBIT_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, &count, &quarantined, true))
+            .await;
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Update,
+            repository: IngestLogRepository::BuiltByBit,
+            item: IngestLogItem::Resource,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("BuiltByBit resources updated: {}", items_processed);
+
+        result
+    }
+
+    /// Re-fetches and processes each resource that previously failed, removing it from the ingest failures if it succeeds.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn retry_builtbybit_resource_failures(&self, db_pool: &Pool) -> Result<()> {
+        let failures = get_ingest_failures(db_pool, IngestLogRepository::BuiltByBit, IngestLogItem::Resource).await?;
+        info!("Retrying failed BuiltByBit resources: {}", failures.len());
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::new(IngestLogRepository::BuiltByBit, IngestLogItem::Resource, failures.iter().map(|x| x.upstream_id.clone()));
+
+        for failure in failures {
+            let resource_id: i32 = match failure.upstream_id.parse() {
+                Ok(resource_id) => resource_id,
+                Err(err) => {
+                    warn!("Skipping BuiltByBit resource failure {}: {}", failure.upstream_id, err);
+                    continue;
+                }
+            };
+
+            match self.get_resource_from_api(resource_id).await {
+                // The resource is removed from the ingest failures if it succeeds, or quarantined again if it still fails.
+                Ok(Some(incoming_resource)) => self.process_incoming_resource(incoming_resource, db_pool, &count, &quarantined, true).await?,
+                // The resource no longer exists upstream
+                Ok(None) => {
+                    if self.dry_run.is_none() {
+                        quarantined.unquarantine(db_pool, &failure.upstream_id).await;
+                    }
+                }
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        info!("BuiltByBit resources retried: {}", count.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_resources_from_api(&self, request: GetBuiltByBitResourcesRequest) -> Result<GetBuiltByBitResourcesResponse> {
+        let url = self.http_server.base_url().join("resources")?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::BuiltByBit,
+            || time_rate_limiter_wait(IngestLogRepository::BuiltByBit, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        if status == StatusCode::OK {
+            let response: GetBuiltByBitResourcesResponse = raw_response.json().await?;
+            Ok(response)
+        } else {
+            Err(
+                GetBuiltByBitResourcesError::UnexpectedStatusCode {
+                    request,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    /// Gets a single resource, or None if it no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_resource_from_api(&self, resource_id: i32) -> Result<Option<IncomingBuiltByBitResource>> {
+        let path = &["resources/", resource_id.to_string().as_str()].concat();
+        let url = self.http_server.base_url().join(path)?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::BuiltByBit,
+            || time_rate_limiter_wait(IngestLogRepository::BuiltByBit, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let response: GetBuiltByBitResourceResponse = raw_response.json().await?;
+                Ok(Some(response.data))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(
+                GetBuiltByBitResourcesError::UnexpectedStatusCodeForResource {
+                    resource_id,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    async fn process_incoming_resource_page(&self, page: u32, incoming_resources: Vec<IncomingBuiltByBitResource>, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        stream::iter(incoming_resources)
+            .map(Ok)
+            .try_for_each_concurrent(BUILTBYBIT_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, count, quarantined, false))
+            .await?;
+
+        let checkpoint = IngestCheckpoint {
+            repository: IngestLogRepository::BuiltByBit,
+            item: IngestLogItem::Resource,
+            position: page.try_into()?,
+            date_started,
+            date_updated: OffsetDateTime::now_utc()
+        };
+        if self.dry_run.is_none() {
+            upsert_ingest_checkpoint(db_pool, &checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_incoming_resource(&self, incoming_resource: IncomingBuiltByBitResource, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, get_version: bool) -> Result<()> {
+        let author_result = self.get_builtbybit_author_name(incoming_resource.author_id).await;
+
+        let author = match author_result {
+            Ok(author) => author,
+            Err(err) => {
+                warn!("Skipping resource ID {}: Could not get author: {}", incoming_resource.resource_id, err);
+                return Ok(())
+            }
+        };
+
+        let mut version_name = None;
+
+        if get_version {
+            let version_result = self.get_latest_builtbybit_resource_version_from_api(incoming_resource.resource_id).await;
+
+            match version_result {
+                Ok(retrieved_version_name) => version_name = Some(retrieved_version_name),
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        let resource_id = incoming_resource.resource_id;
+        let payload = incoming_resource.clone();
+        let convert_result = convert_incoming_resource(incoming_resource, author, version_name);
+
+        match convert_result {
+            Ok(resource) => {
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &resource).await,
+                    None => time_db_upsert("builtbybit_resource", upsert_builtbybit_resource(db_pool, &resource)).await
+                };
+
+                match db_result {
+                    Ok(_) => {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::BuiltByBit, IngestLogItem::Resource, ItemOutcome::Processed);
+                        if self.dry_run.is_none() {
+                            quarantined.unquarantine(db_pool, &resource_id.to_string()).await;
+                        }
+                    }
+                    Err(err) => {
+                        record_item(IngestLogRepository::BuiltByBit, IngestLogItem::Resource, ItemOutcome::Failed);
+                        warn!("{}", err);
+                        if self.dry_run.is_none() {
+                            quarantined.quarantine(db_pool, resource_id.to_string(), FailureKind::Database, &err, &payload).await;
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                record_item(IngestLogRepository::BuiltByBit, IngestLogItem::Resource, ItemOutcome::Skipped);
+                warn!("{}", err);
+                if self.dry_run.is_none() {
+                    quarantined.quarantine(db_pool, resource_id.to_string(), FailureKind::Conversion, &err, &payload).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> PageTurner<GetBuiltByBitResourcesRequest> for BuiltByBitClient<T> where T: HttpServer + Send + Sync {
+    type PageItems = Vec<IncomingBuiltByBitResource>;
+    type PageError = anyhow::Error;
+
+    async fn turn_page(&self, mut request: GetBuiltByBitResourcesRequest) -> TurnedPageResult<Self, GetBuiltByBitResourcesRequest> {
+        let response = self.get_resources_from_api(request.clone()).await?;
+
+        // BuiltByBit does not report the total number of resources, so a partial page is the last page.
+        if response.data.len() == request.count as usize {
+            request.page += 1;
+            Ok(TurnedPage::next(response.data, request))
+        } else {
+            Ok(TurnedPage::last(response.data))
+        }
+    }
+}
+
+fn convert_incoming_resource(incoming_resource: IncomingBuiltByBitResource, author: String, version_name: Option<String>) -> Result<BuiltByBitResource> {
+    // Free resources have a price of zero, and the currency is meaningless.
+    let currency = incoming_resource.currency
+        .filter(|currency| !currency.is_empty() && incoming_resource.price > 0.0);
+
+    // BuiltByBit has no dedicated source code field, so use the first source repository link labelled as the source in the description.
+    // Other links, such as to a library or to the author's other projects, are not assumed to be the source.
+    let source_url = find_source_url_in_description(&incoming_resource.description);
+
+    let mut resource = BuiltByBitResource {
+        id: incoming_resource.resource_id,
+        name: incoming_resource.title,
+        description: incoming_resource.tag_line,
+        author,
+        date_created: OffsetDateTime::from_unix_timestamp(incoming_resource.release_date)?,
+        date_updated: OffsetDateTime::from_unix_timestamp(incoming_resource.update_date)?,
+        downloads: incoming_resource.download_count,
+        price: incoming_resource.price,
+        currency,
+        version_name,
+        source_url: source_url.clone(),
+        source_repository: None
+    };
+
+    if let Some(url) = source_url {
+        let option_repo = extract_source_repository_from_url(url.as_str());
+
+        if let Some(repo) = option_repo {
+            resource.source_repository = Some(SourceRepository {
+                host: repo.host,
+                owner: repo.owner,
+                name: repo.name,
+                id: None
+            });
+        }
+    }
+
+    Ok(resource)
+}
+
+fn find_source_url_in_description(description: &str) -> Option<String> {
+    SOURCE_URL_REGEX.captures_iter(description)
+        .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+        .map(|x| x.as_str().trim_end_matches(['.', ',', ')', '/']))
+        .find(|url| extract_source_repository_from_url(url).is_some())
+        .map(|url| url.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::retry::test::create_test_retry_policy;
+    use crate::builtbybit::test::{BuiltByBitTestServer, BUILTBYBIT_TEST_API_TOKEN};
+
+    use rstest::*;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{method, path, query_param};
+
+    #[tokio::test]
+    async fn should_get_resources_from_api() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let request = GetBuiltByBitResourcesRequest::create_populate_request();
+
+        let expected_response = GetBuiltByBitResourcesResponse {
+            data: create_test_resources()
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .and(query_param("page", request.page.to_string().as_str()))
+            .and(query_param("count", request.count.to_string().as_str()))
+            .and(query_param("sort", request.sort.as_str()))
+            .and(query_param("order", request.order.as_str()))
+            .respond_with(response_template)
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let response = builtbybit_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_retry_getting_resources_from_api_after_transient_error() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let request = GetBuiltByBitResourcesRequest::create_populate_request();
+
+        let expected_response = GetBuiltByBitResourcesResponse {
+            data: create_test_resources()
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(builtbybit_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .respond_with(response_template)
+            .expect(1)
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?.with_retry_policy(create_test_retry_policy());
+        let response = builtbybit_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_get_resources_from_api_when_unauthorized() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let request = GetBuiltByBitResourcesRequest::create_populate_request();
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let response = builtbybit_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_incoming_resource() -> Result<()> {
+        // Arrange
+        let incoming_resource = create_test_resources()[0].clone();
+
+        // Act
+        let resource = convert_incoming_resource(incoming_resource, "alice".to_string(), Some("1.2.3".to_string()))?;
+
+        // Assert
+        let expected_resource = BuiltByBitResource {
+            id: 1,
+            name: "foo-builtbybit".to_string(),
+            description: "foo-builtbybit-description".to_string(),
+            author: "alice".to_string(),
+            date_created: datetime!(2017-01-01 0:00 UTC),
+            date_updated: datetime!(2017-02-03 0:00 UTC),
+            downloads: 100,
+            price: 0.0,
+            currency: None,
+            version_name: Some("1.2.3".to_string()),
+            source_url: Some("https://github.com/alice/foo".to_string()),
+            source_repository: Some(SourceRepository {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo".to_string(),
+                id: None
+            })
+        };
+
+        assert_that(&resource).is_equal_to(expected_resource);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_incoming_premium_resource() -> Result<()> {
+        // Arrange
+        let incoming_resource = create_test_resources()[1].clone();
+
+        // Act
+        let resource = convert_incoming_resource(incoming_resource, "bob".to_string(), None)?;
+
+        // Assert
+        assert_that(&resource.price).is_equal_to(9.99);
+        assert_that(&resource.currency).is_equal_to(Some("USD".to_string()));
+        assert_that(&resource.source_url).is_none();
+        assert_that(&resource.source_repository).is_none();
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::bbcode_link_text("[URL='https://github.com/alice/foo']Source[/URL]", Some("https://github.com/alice/foo"))]
+    #[case::bbcode_label("[B]Source:[/B] [URL='https://github.com/alice/foo']GitHub[/URL]", Some("https://github.com/alice/foo"))]
+    #[case::plain_label("Source code: https://gitlab.com/bob/bar.", Some("https://gitlab.com/bob/bar"))]
+    #[case::labelled_repository("Wiki: https://example.com/wiki, Source: https://codeberg.org/eve/baz", Some("https://codeberg.org/eve/baz"))]
+    #[case::unlabelled_repository("Uses [URL='https://github.com/bob/lib']bob's library[/URL]", None)]
+    #[case::other_label("Dependency: https://github.com/bob/lib", None)]
+    #[case::not_a_repository("Source: https://discord.gg/foo", None)]
+    #[case::empty("", None)]
+    fn should_find_source_url_in_description(#[case] description: &str, #[case] expected_url: Option<&str>) {
+        // Act
+        let url = find_source_url_in_description(description);
+
+        // Assert
+        assert_that(&url).is_equal_to(expected_url.map(|x| x.to_string()));
+    }
+
+    fn create_test_resources() -> Vec<IncomingBuiltByBitResource> {
+        vec![
+            IncomingBuiltByBitResource {
+                resource_id: 1,
+                author_id: 1,
+                title: "foo-builtbybit".to_string(),
+                tag_line: "foo-builtbybit-description".to_string(),
+                description: "[B]Source:[/B] [URL='https://github.com/alice/foo']GitHub[/URL]".to_string(),
+                release_date: 1483228800,
+                update_date: 1486080000,
+                price: 0.0,
+                currency: Some("USD".to_string()),
+                download_count: 100
+            },
+            IncomingBuiltByBitResource {
+                resource_id: 2,
+                author_id: 2,
+                title: "bar-builtbybit".to_string(),
+                tag_line: "bar-builtbybit-description".to_string(),
+                description: "Join our Discord: https://discord.gg/bar".to_string(),
+                release_date: 1483315200,
+                update_date: 1485993600,
+                price: 9.99,
+                currency: Some("USD".to_string()),
+                download_count: 300
+            }
+        ]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,231 @@
+use crate::HttpServer;
+use crate::retry::send_with_retry;
+use crate::builtbybit::BuiltByBitClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
+use mc_plugin_finder::database::builtbybit::resource::{BuiltByBitResource, upsert_builtbybit_resource, get_builtbybit_resources};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::{info, warn, instrument};
+
+const BUILTBYBIT_VERSIONS_CONCURRENT_FUTURES: usize = 10;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetBuiltByBitVersionResponse {
+    data: IncomingBuiltByBitVersion
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingBuiltByBitVersion {
+    version_id: i32,
+    name: String,
+    release_date: i64
+}
+
+#[derive(Debug, Error)]
+enum GetBuiltByBitVersionError {
+    #[error("Resource ID {resource_id}: Latest version not found")]
+    LatestVersionNotFound {
+        resource_id: i32
+    },
+    #[error("Resource ID {resource_id}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        resource_id: i32,
+        status_code: u16
+    }
+}
+
+impl<T> BuiltByBitClient<T> where T: HttpServer + Send + Sync {
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn populate_builtbybit_versions(&self, db_pool: &Pool) -> Result<()> {
+        info!("Populating BuiltByBit versions...");
+
+        let count = Arc::new(AtomicU32::new(0));
+        let date_started = OffsetDateTime::now_utc();
+
+        let resources = get_builtbybit_resources(db_pool).await?;
+
+        let resource_stream = stream::iter(resources);
+
+        let result = resource_stream
+            .map(Ok)
+            .try_for_each_concurrent(BUILTBYBIT_VERSIONS_CONCURRENT_FUTURES, |resource| self.process_builtbybit_resource(resource, db_pool, &count))
+            .await;
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::BuiltByBit,
+            item: IngestLogItem::Version,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("BuiltByBit versions populated: {}", items_processed);
+
+        result
+    }
+
+    async fn process_builtbybit_resource(&self, resource: BuiltByBitResource, db_pool: &Pool, count: &Arc<AtomicU32>) -> Result<()> {
+        let version_result = self.get_latest_builtbybit_resource_version_from_api(resource.id).await;
+
+        match version_result {
+            Ok(version_name) => {
+                let mut new_resource = resource.clone();
+                new_resource.version_name = Some(version_name);
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report_against(Some(&resource), &new_resource),
+                    None => time_db_upsert("builtbybit_resource", upsert_builtbybit_resource(db_pool, &new_resource)).await
+                };
+
+                match db_result {
+                    Ok(_) => {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::BuiltByBit, IngestLogItem::Version, ItemOutcome::Processed);
+                    },
+                    Err(err) => {
+                        record_item(IngestLogRepository::BuiltByBit, IngestLogItem::Version, ItemOutcome::Failed);
+                        warn!("{}", err)
+                    }
+                }
+            }
+            Err(err) => {
+                record_item(IngestLogRepository::BuiltByBit, IngestLogItem::Version, ItemOutcome::Failed);
+                warn!("{}", err)
+            }
+        }
+
+        Ok(())
+    }
+
+    #[instrument(
+        skip(self)
+    )]
+    pub async fn get_latest_builtbybit_resource_version_from_api(&self, resource_id: i32) -> Result<String> {
+        let path = &["resources/", resource_id.to_string().as_str(), "/versions/latest"].concat();
+        let url = self.http_server.base_url().join(path)?;
+
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::BuiltByBit,
+            || time_rate_limiter_wait(IngestLogRepository::BuiltByBit, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let response: GetBuiltByBitVersionResponse = raw_response.json().await?;
+                Ok(response.data.name)
+            }
+            StatusCode::NOT_FOUND => {
+                Err(
+                    GetBuiltByBitVersionError::LatestVersionNotFound {
+                        resource_id
+                    }.into()
+                )
+            }
+            _ => {
+                Err(
+                    GetBuiltByBitVersionError::UnexpectedStatusCode {
+                        resource_id,
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builtbybit::test::{BuiltByBitTestServer, BUILTBYBIT_TEST_API_TOKEN};
+
+    use speculoos::prelude::*;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{method, path};
+
+    #[tokio::test]
+    async fn should_get_latest_resource_version_name_from_api() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let expected_version_name = "v1.2.3";
+        let expected_response = GetBuiltByBitVersionResponse {
+            data: IncomingBuiltByBitVersion {
+                version_id: 10,
+                name: expected_version_name.to_string(),
+                release_date: 1483228800
+            }
+        };
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response);
+
+        let resource_id = 1;
+        let api_path = &["/resources/", resource_id.to_string().as_str(), "/versions/latest"].concat();
+        Mock::given(method("GET"))
+            .and(path(api_path))
+            .respond_with(response_template)
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let result = builtbybit_client.get_latest_builtbybit_resource_version_from_api(resource_id).await;
+
+        // Assert
+        assert_that(&result).is_ok().is_equal_to(expected_version_name.to_string());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_latest_resource_version_is_not_found() -> Result<()> {
+        // Arrange
+        let builtbybit_server = BuiltByBitTestServer::new().await;
+
+        let resource_id = 1;
+        let api_path = &["/resources/", resource_id.to_string().as_str(), "/versions/latest"].concat();
+        Mock::given(method("GET"))
+            .and(path(api_path))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(builtbybit_server.mock())
+            .await;
+
+        // Act
+        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, BUILTBYBIT_TEST_API_TOKEN)?;
+        let result = builtbybit_client.get_latest_builtbybit_resource_version_from_api(resource_id).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<GetBuiltByBitVersionError>().unwrap();
+
+        if let GetBuiltByBitVersionError::LatestVersionNotFound{resource_id} = downcast_error {
+            assert_that(&resource_id).is_equal_to(resource_id);
+        } else {
+            panic!("expected error to be LatestVersionNotFound, but was {}", downcast_error);
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
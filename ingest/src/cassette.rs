@@ -0,0 +1,332 @@
+use crate::metrics::UpstreamServer;
+use crate::retry::{RetryPolicy, send_with_retry_over_network};
+
+use anyhow::Result;
+use bytes::Bytes;
+use reqwest::{RequestBuilder, Response};
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use tracing::info;
+
+const INTERACTION_EXTENSION: &str = "json";
+const BODY_EXTENSION: &str = "body";
+
+static CASSETTE: OnceLock<Cassette> = OnceLock::new();
+
+/// Uses the cassette for every upstream request made by this process, instead of sending requests directly.
+pub fn use_cassette(cassette: Cassette) -> Result<()> {
+    CASSETTE.set(cassette).map_err(|_| CassetteError::AlreadyInUse)?;
+
+    Ok(())
+}
+
+pub fn get_cassette() -> Option<&'static Cassette> {
+    CASSETTE.get()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CassetteMode {
+    /// Requests are sent upstream as normal, and every response is saved to the cassette
+    Record,
+    /// Responses are served from the cassette, and nothing is sent upstream
+    Replay
+}
+
+/// Each interaction is saved as a JSON file, alongside a file with the raw response body.
+/// Both files are named after the order that the interaction was recorded in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct RecordedInteraction {
+    method: String,
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Bytes
+}
+
+impl RecordedResponse {
+    fn to_response(&self) -> Result<Response> {
+        let mut builder = http::Response::builder().status(self.status);
+
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+
+        Ok(builder.body(self.body.clone())?.into())
+    }
+}
+
+#[derive(Debug, Error)]
+enum CassetteError {
+    #[error("A cassette is already in use")]
+    AlreadyInUse,
+    #[error("No interaction was recorded for {method} {url}")]
+    InteractionNotFound {
+        method: String,
+        url: String
+    }
+}
+
+/// A directory of recorded upstream requests and responses, so that ingest runs can be reproduced offline.
+#[derive(Debug)]
+pub struct Cassette {
+    directory: PathBuf,
+    mode: CassetteMode,
+    sequence: AtomicU32,
+    /// Recorded responses for each request, in the order they were recorded
+    responses: Mutex<HashMap<String, VecDeque<RecordedResponse>>>
+}
+
+impl Cassette {
+    pub async fn record(directory: &Path) -> Result<Self> {
+        tokio::fs::create_dir_all(directory).await?;
+
+        info!("Recording upstream requests to cassette: {}", directory.display());
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            mode: CassetteMode::Record,
+            sequence: AtomicU32::new(0),
+            responses: Mutex::new(HashMap::new())
+        })
+    }
+
+    pub async fn replay(directory: &Path) -> Result<Self> {
+        let mut interaction_paths = Vec::new();
+
+        let mut entries = tokio::fs::read_dir(directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|x| x == INTERACTION_EXTENSION) {
+                interaction_paths.push(path);
+            }
+        }
+
+        // File names are zero-padded sequence numbers, so sorting them restores the recorded order.
+        interaction_paths.sort();
+
+        let mut responses: HashMap<String, VecDeque<RecordedResponse>> = HashMap::new();
+
+        for path in &interaction_paths {
+            let interaction: RecordedInteraction = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+            let body = tokio::fs::read(path.with_extension(BODY_EXTENSION)).await?;
+
+            responses.entry(interaction_key(&interaction.method, &interaction.url))
+                .or_default()
+                .push_back(RecordedResponse {
+                    status: interaction.status,
+                    headers: interaction.headers,
+                    body: body.into()
+                });
+        }
+
+        info!("Replaying {} upstream requests from cassette: {}", interaction_paths.len(), directory.display());
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            mode: CassetteMode::Replay,
+            sequence: AtomicU32::new(0),
+            responses: Mutex::new(responses)
+        })
+    }
+
+    pub async fn send<F, Fut>(&self, request: RequestBuilder, retry_policy: &RetryPolicy, server: UpstreamServer, until_ready: F) -> Result<Response> where F: Fn() -> Fut, Fut: Future<Output = ()> {
+        let built_request = request.try_clone()
+            .ok_or_else(|| anyhow::anyhow!("Request could not be recorded because it has a streaming body"))?
+            .build()?;
+        let method = built_request.method().to_string();
+        let url = built_request.url().to_string();
+
+        match self.mode {
+            CassetteMode::Record => {
+                let response = send_with_retry_over_network(request, retry_policy, server, until_ready).await?;
+                let recorded_response = RecordedResponse {
+                    status: response.status().as_u16(),
+                    headers: response.headers().iter()
+                        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).to_string()))
+                        .collect(),
+                    body: response.bytes().await?
+                };
+
+                self.save(&method, &url, &recorded_response).await?;
+                recorded_response.to_response()
+            },
+            CassetteMode::Replay => {
+                let recorded_response = self.next_response(&method, &url)?;
+                recorded_response.to_response()
+            }
+        }
+    }
+
+    async fn save(&self, method: &str, url: &str, recorded_response: &RecordedResponse) -> Result<()> {
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let path = self.directory.join(format!("{:08}.{}", sequence, INTERACTION_EXTENSION));
+
+        let interaction = RecordedInteraction {
+            method: method.to_string(),
+            url: url.to_string(),
+            status: recorded_response.status,
+            headers: recorded_response.headers.clone()
+        };
+
+        tokio::fs::write(&path, serde_json::to_vec_pretty(&interaction)?).await?;
+        tokio::fs::write(path.with_extension(BODY_EXTENSION), &recorded_response.body).await?;
+
+        Ok(())
+    }
+
+    /// Repeated requests are served in the order they were recorded, and the last response is served again once the others are used up.
+    fn next_response(&self, method: &str, url: &str) -> Result<RecordedResponse> {
+        let mut responses = self.responses.lock().expect("cassette responses lock should not be poisoned");
+
+        let queue = responses.get_mut(&interaction_key(method, url))
+            .filter(|x| !x.is_empty())
+            .ok_or_else(|| CassetteError::InteractionNotFound {
+                method: method.to_string(),
+                url: url.to_string()
+            })?;
+
+        let recorded_response = if queue.len() > 1 {
+            queue.pop_front().expect("queue should not be empty")
+        } else {
+            queue[0].clone()
+        };
+
+        Ok(recorded_response)
+    }
+}
+
+fn interaction_key(method: &str, url: &str) -> String {
+    format!("{} {}", method, url)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use speculoos::prelude::*;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use wiremock::matchers::{method, path};
+
+    #[tokio::test]
+    async fn should_replay_recorded_interaction_without_upstream() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_replay_recorded_interaction_without_upstream").await?;
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-page-count", "1").set_body_string("[]"))
+            .mount(&mock_server)
+            .await;
+
+        let url = [&mock_server.uri(), "/resources?page=1"].concat();
+        let client = reqwest::Client::new();
+
+        let recording_cassette = Cassette::record(&directory).await?;
+        recording_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+        drop(mock_server);
+
+        // Act
+        let replaying_cassette = Cassette::replay(&directory).await?;
+        let response = replaying_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+
+        // Assert
+        assert_that(&response.status().as_u16()).is_equal_to(200);
+        assert_that(&response.headers()["x-page-count"].to_str()?).is_equal_to("1");
+        assert_that(&response.text().await?).is_equal_to("[]".to_string());
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_replay_repeated_requests_in_recorded_order() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_replay_repeated_requests_in_recorded_order").await?;
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let url = [&mock_server.uri(), "/project/foo"].concat();
+        let client = reqwest::Client::new();
+
+        let recording_cassette = Cassette::record(&directory).await?;
+        recording_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+        recording_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+
+        // Act
+        let replaying_cassette = Cassette::replay(&directory).await?;
+        let first_response = replaying_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+        let second_response = replaying_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+        let third_response = replaying_cassette.send(client.get(&url), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await?;
+
+        // Assert
+        assert_that(&first_response.status().as_u16()).is_equal_to(404);
+        assert_that(&second_response.status().as_u16()).is_equal_to(200);
+        assert_that(&third_response.status().as_u16()).is_equal_to(200);
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_interaction_was_not_recorded() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_return_error_if_interaction_was_not_recorded").await?;
+        let client = reqwest::Client::new();
+
+        // Act
+        let replaying_cassette = Cassette::replay(&directory).await?;
+        let result = replaying_cassette.send(client.get("http://localhost/resources"), &RetryPolicy::default(), UpstreamServer::Spigot, || async {}).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<CassetteError>().unwrap();
+
+        if let CassetteError::InteractionNotFound{method, url} = downcast_error {
+            assert_that(method).is_equal_to("GET".to_string());
+            assert_that(url).is_equal_to("http://localhost/resources".to_string());
+        } else {
+            panic!("expected error to be InteractionNotFound, but was {}", downcast_error);
+        }
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    async fn create_test_directory(name: &str) -> Result<PathBuf> {
+        let directory = std::env::temp_dir().join("mcpf-cassette-test").join(name);
+
+        if tokio::fs::try_exists(&directory).await? {
+            tokio::fs::remove_dir_all(&directory).await?;
+        }
+        tokio::fs::create_dir_all(&directory).await?;
+
+        Ok(directory)
+    }
+}
\ No newline at end of file
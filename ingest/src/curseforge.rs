@@ -0,0 +1,108 @@
+use crate::HttpServer;
+use crate::dry_run::DryRun;
+use crate::retry::RetryPolicy;
+
+use anyhow::Result;
+use governor::{Quota, RateLimiter};
+use governor::clock::QuantaClock;
+use governor::state::{InMemoryState, NotKeyed};
+use nonzero_ext::*;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use url::Url;
+
+mod project;
+
+const CURSEFORGE_BASE_URL: &str = "https://api.curseforge.com/v1/";
+const CURSEFORGE_API_KEY_HEADER: &str = "x-api-key";
+
+const CURSEFORGE_USER_AGENT: &str = concat!("Frumple/mc-plugin-finder/", env!("CARGO_PKG_VERSION"), " (contact@mcpluginfinder.com)");
+const CURSEFORGE_RATE_LIMIT_PER_SECOND: NonZeroU32 = nonzero!(4u32);
+
+#[derive(Debug)]
+pub struct CurseForgeServer;
+
+impl HttpServer for CurseForgeServer {
+    async fn new() -> Self {
+        Self
+    }
+
+    fn base_url(&self) -> Url {
+        Url::parse(CURSEFORGE_BASE_URL)
+          .expect("CurseForge base URL could not be parsed")
+    }
+}
+
+#[derive(Debug)]
+pub struct CurseForgeClient<T> {
+    api_client: Client,
+    rate_limiter: RateLimiter<NotKeyed, InMemoryState, QuantaClock>,
+    retry_policy: RetryPolicy,
+    dry_run: Option<Arc<DryRun>>,
+    http_server: T
+}
+
+impl<T> CurseForgeClient<T> {
+    /// Unlike the other repositories, the CurseForge API requires an API key on every request.
+    pub fn new(http_server: T, api_key: &str) -> Result<CurseForgeClient<T>> {
+        let mut api_key_value = HeaderValue::from_str(api_key)?;
+        api_key_value.set_sensitive(true);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CURSEFORGE_API_KEY_HEADER, api_key_value);
+
+        let api_client = reqwest::Client::builder()
+            .user_agent(CURSEFORGE_USER_AGENT)
+            .default_headers(headers)
+            .build()?;
+
+        let quota = Quota::per_second(CURSEFORGE_RATE_LIMIT_PER_SECOND);
+        let rate_limiter = RateLimiter::direct(quota);
+
+        Ok(Self { api_client, rate_limiter, retry_policy: RetryPolicy::default(), dry_run: None, http_server })
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: Option<Arc<DryRun>>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wiremock::MockServer;
+
+    pub const CURSEFORGE_TEST_API_KEY: &str = "test-api-key";
+
+    #[derive(Debug)]
+    pub struct CurseForgeTestServer {
+        mock_server: MockServer
+    }
+
+    impl CurseForgeTestServer {
+        pub fn mock(&self) -> &MockServer {
+            &self.mock_server
+        }
+    }
+
+    impl HttpServer for CurseForgeTestServer {
+        async fn new() -> Self {
+            Self {
+                mock_server: MockServer::start().await
+            }
+        }
+
+        fn base_url(&self) -> Url {
+            Url::parse(&self.mock_server.uri())
+                .expect("CurseForge mock server base URL could not be parsed")
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,839 @@
+use crate::HttpServer;
+use crate::failure::{FailureKind, QuarantinedItems};
+use crate::retry::send_with_retry;
+use crate::curseforge::CurseForgeClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_failure::get_ingest_failures;
+use mc_plugin_finder::database::ingest_checkpoint::{IngestCheckpoint, upsert_ingest_checkpoint, get_ingest_checkpoint, delete_ingest_checkpoint, get_checkpoint_start_date};
+use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
+use mc_plugin_finder::database::curseforge::project::{CurseForgeProject, upsert_curseforge_project};
+use mc_plugin_finder::database::project_category::ProjectCategory;
+use mc_plugin_finder::database::server_platform::ServerPlatform;
+use mc_plugin_finder::database::source_repository::{SourceRepository, extract_source_repository_from_url};
+use mc_plugin_finder::minecraft_version::{MinecraftVersion, find_latest_minecraft_version};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use futures::future;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use page_turner::prelude::*;
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use time::OffsetDateTime;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use time::format_description::well_known::Rfc3339;
+use tracing::{info, warn, instrument};
+
+const CURSEFORGE_MINECRAFT_GAME_ID: u32 = 432;
+const CURSEFORGE_BUKKIT_PLUGINS_CLASS_ID: u32 = 5;
+const CURSEFORGE_LAST_UPDATED_SORT_FIELD: u32 = 3;
+
+// The search endpoint refuses any request where index + pageSize exceeds this number.
+const CURSEFORGE_SEARCH_WINDOW: u32 = 10000;
+
+const CURSEFORGE_PROJECTS_REQUESTS_AHEAD: usize = 2;
+const CURSEFORGE_PROJECTS_CONCURRENT_FUTURES: usize = 10;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetCurseForgeProjectsRequest {
+    game_id: u32,
+    class_id: u32,
+    sort_field: u32,
+    sort_order: String,
+    index: u32,
+    page_size: u32
+}
+
+impl GetCurseForgeProjectsRequest {
+    fn create_request(sort_order: &str) -> Self {
+        Self {
+            game_id: CURSEFORGE_MINECRAFT_GAME_ID,
+            class_id: CURSEFORGE_BUKKIT_PLUGINS_CLASS_ID,
+            sort_field: CURSEFORGE_LAST_UPDATED_SORT_FIELD,
+            sort_order: sort_order.to_string(),
+            index: 0,
+            page_size: 50
+        }
+    }
+
+    fn is_within_search_window(&self) -> bool {
+        self.index + self.page_size <= CURSEFORGE_SEARCH_WINDOW
+    }
+
+    /// The checkpoint position of the first project of the pass, as the ascending pass continues beyond the search window of the descending pass
+    fn base_position(&self) -> u32 {
+        if self.sort_order == "asc" {
+            CURSEFORGE_SEARCH_WINDOW
+        } else {
+            0
+        }
+    }
+}
+
+impl RequestAhead for GetCurseForgeProjectsRequest {
+    fn next_request(&self) -> Self {
+        Self {
+            index: self.index + self.page_size,
+            sort_order: self.sort_order.clone(),
+            ..*self
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetCurseForgeProjectsResponse {
+    data: Vec<IncomingCurseForgeProject>,
+    pagination: CurseForgeResponsePagination
+}
+
+impl GetCurseForgeProjectsResponse {
+    fn more_projects_available(&self) -> bool {
+        let next_index = self.pagination.index + self.pagination.page_size;
+        next_index < self.pagination.total_count && next_index + self.pagination.page_size <= CURSEFORGE_SEARCH_WINDOW
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetCurseForgeProjectResponse {
+    data: IncomingCurseForgeProject
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeResponsePagination {
+    pub index: u32,
+    pub page_size: u32,
+    pub result_count: u32,
+    pub total_count: u32
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingCurseForgeProject {
+    id: i32,
+    name: String,
+    slug: String,
+    summary: String,
+    links: IncomingCurseForgeProjectLinks,
+    download_count: i64,
+    thumbs_up_count: i32,
+    authors: Vec<IncomingCurseForgeProjectAuthor>,
+    logo: Option<IncomingCurseForgeProjectLogo>,
+    categories: Vec<IncomingCurseForgeProjectCategory>,
+    main_file_id: i32,
+    latest_files: Vec<IncomingCurseForgeProjectFile>,
+    latest_files_indexes: Vec<IncomingCurseForgeProjectFileIndex>,
+    date_created: String,
+    date_modified: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingCurseForgeProjectLinks {
+    website_url: Option<String>,
+    source_url: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingCurseForgeProjectAuthor {
+    id: i32,
+    name: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingCurseForgeProjectLogo {
+    thumbnail_url: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingCurseForgeProjectCategory {
+    id: i32,
+    name: String,
+    slug: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingCurseForgeProjectFile {
+    id: i32,
+    display_name: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingCurseForgeProjectFileIndex {
+    game_version: String,
+    file_id: i32
+}
+
+#[derive(Debug, Error)]
+enum GetCurseForgeProjectsError {
+    #[error("Could not get CurseForge projects {request:?}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        request: GetCurseForgeProjectsRequest,
+        status_code: u16
+    },
+    #[error("Could not get CurseForge project {id}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCodeForProject {
+        id: i32,
+        status_code: u16
+    }
+}
+
+#[derive(Debug, Error)]
+enum IncomingCurseForgeProjectError {
+    #[error("Skipping project {id}: No authors found")]
+    AuthorNotFound {
+        id: i32
+    }
+}
+
+impl<T> CurseForgeClient<T> where T: HttpServer + Send + Sync {
+    /// Populates all Bukkit plugins from CurseForge.
+    ///
+    /// The CurseForge search endpoint can only page through the first 10,000 results of any query.
+    /// To reach projects beyond this window, the projects are first retrieved from most to least recently updated,
+    /// and then any remaining projects are retrieved from least to most recently updated.
+    /// Since projects that are updated during the run move within both orders, the run cannot guarantee that it sees every project, so it does not support --reconcile.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn populate_curseforge_projects(&self, db_pool: &Pool, resume: bool) -> Result<()> {
+        info!("Populating CurseForge projects...");
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::CurseForge, IngestLogItem::Project).await?;
+        let date_started = OffsetDateTime::now_utc();
+
+        let mut checkpoint_date_started = get_checkpoint_start_date(db_pool).await?;
+
+        // Positions from 0 up to the search window belong to the descending pass, and positions after that belong to the ascending pass.
+        let mut next_position = 0;
+
+        if resume {
+            match get_ingest_checkpoint(db_pool, IngestLogRepository::CurseForge, IngestLogItem::Project).await? {
+                Some(checkpoint) => {
+                    next_position = u32::try_from(checkpoint.position)? + GetCurseForgeProjectsRequest::create_request("desc").page_size;
+                    checkpoint_date_started = checkpoint.date_started;
+                    info!("Resuming from position: {}", next_position);
+                }
+                None => info!("No checkpoint found, starting from the beginning...")
+            }
+        }
+
+        let result = self.populate_curseforge_project_passes(db_pool, next_position, &count, &quarantined, checkpoint_date_started).await;
+
+        // The run has completed, so there is nothing left to resume.
+        if result.is_ok() && self.dry_run.is_none() {
+            delete_ingest_checkpoint(db_pool, IngestLogRepository::CurseForge, IngestLogItem::Project).await?;
+        }
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::CurseForge,
+            item: IngestLogItem::Project,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("CurseForge projects populated: {}", items_processed);
+
+        result
+    }
+
+    async fn populate_curseforge_project_passes(&self, db_pool: &Pool, next_position: u32, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        if next_position < CURSEFORGE_SEARCH_WINDOW {
+            let mut request = GetCurseForgeProjectsRequest::create_request("desc");
+            request.index = next_position;
+
+            self.populate_curseforge_project_pass(db_pool, request, Limit::None, count, quarantined, date_started).await?;
+        }
+
+        let mut request = GetCurseForgeProjectsRequest::create_request("asc");
+        let total_count = self.get_projects_from_api(request.clone()).await?.pagination.total_count;
+        let remaining_count = total_count.saturating_sub(CURSEFORGE_SEARCH_WINDOW);
+
+        request.index = next_position.saturating_sub(CURSEFORGE_SEARCH_WINDOW);
+
+        if request.index < remaining_count {
+            info!("Retrieving {} remaining CurseForge projects beyond the search window...", remaining_count - request.index);
+
+            let pages = (remaining_count - request.index).div_ceil(request.page_size);
+            self.populate_curseforge_project_pass(db_pool, request, Limit::Pages(pages.try_into()?), count, quarantined, date_started).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn populate_curseforge_project_pass(&self, db_pool: &Pool, request: GetCurseForgeProjectsRequest, limit: Limit, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        let base_position = request.base_position();
+        let first_index = request.index;
+        let page_size = request.page_size;
+
+        self
+            .pages_ahead(CURSEFORGE_PROJECTS_REQUESTS_AHEAD, limit, request)
+            .enumerate()
+            .map(|(page, page_result)| page_result.map(|incoming_projects| (base_position + first_index + page as u32 * page_size, incoming_projects)))
+            .try_for_each(|(position, incoming_projects)| self.process_incoming_project_page(position, incoming_projects, db_pool, count, quarantined, date_started))
+            .await
+    }
+
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn update_curseforge_projects(&self, db_pool: &Pool, update_date_later_than: OffsetDateTime) -> Result<()> {
+        info!("Updating CurseForge projects since: {}", update_date_later_than);
+
+        let request = GetCurseForgeProjectsRequest::create_request("desc");
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::CurseForge, IngestLogItem::Project).await?;
+        let date_started = OffsetDateTime::now_utc();
+
+        let result = self
+            .pages_ahead(CURSEFORGE_PROJECTS_REQUESTS_AHEAD, Limit::None, request)
+            .items()
+            .try_take_while(|x| future::ready(
+                OffsetDateTime::parse(x.date_modified.as_str(), &Rfc3339)
+                    .map(|date_modified| date_modified > update_date_later_than)
+                    .map_err(|err| err.into())
+            ))
+            .try_for_each_concurrent(CURSEFORGE_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, &quarantined))
+            .await;
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Update,
+            repository: IngestLogRepository::CurseForge,
+            item: IngestLogItem::Project,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("CurseForge projects updated: {}", items_processed);
+
+        result
+    }
+
+    /// Re-fetches and processes each project that previously failed, removing it from the ingest failures if it succeeds.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn retry_curseforge_project_failures(&self, db_pool: &Pool) -> Result<()> {
+        let failures = get_ingest_failures(db_pool, IngestLogRepository::CurseForge, IngestLogItem::Project).await?;
+        info!("Retrying failed CurseForge projects: {}", failures.len());
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::new(IngestLogRepository::CurseForge, IngestLogItem::Project, failures.iter().map(|x| x.upstream_id.clone()));
+
+        for failure in failures {
+            let project_id: i32 = match failure.upstream_id.parse() {
+                Ok(project_id) => project_id,
+                Err(err) => {
+                    warn!("Skipping CurseForge project failure {}: {}", failure.upstream_id, err);
+                    continue;
+                }
+            };
+
+            match self.get_project_from_api(project_id).await {
+                // The project is removed from the ingest failures if it succeeds, or quarantined again if it still fails.
+                Ok(Some(incoming_project)) => self.process_incoming_project(incoming_project, db_pool, &count, &quarantined).await?,
+                // The project no longer exists upstream
+                Ok(None) => {
+                    if self.dry_run.is_none() {
+                        quarantined.unquarantine(db_pool, &failure.upstream_id).await;
+                    }
+                }
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        info!("CurseForge projects retried: {}", count.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    async fn process_incoming_project_page(&self, position: u32, incoming_projects: Vec<IncomingCurseForgeProject>, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        stream::iter(incoming_projects)
+            .map(Ok)
+            .try_for_each_concurrent(CURSEFORGE_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, count, quarantined))
+            .await?;
+
+        let checkpoint = IngestCheckpoint {
+            repository: IngestLogRepository::CurseForge,
+            item: IngestLogItem::Project,
+            position: position.try_into()?,
+            date_started,
+            date_updated: OffsetDateTime::now_utc()
+        };
+        if self.dry_run.is_none() {
+            upsert_ingest_checkpoint(db_pool, &checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_incoming_project(&self, incoming_project: IncomingCurseForgeProject, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems) -> Result<()> {
+        let project_id = incoming_project.id;
+        let payload = incoming_project.clone();
+        let convert_result = convert_incoming_project(incoming_project);
+
+        match convert_result {
+            Ok(project) => {
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &project).await,
+                    None => time_db_upsert("curseforge_project", upsert_curseforge_project(db_pool, &project)).await
+                };
+
+                match db_result {
+                    Ok(_) => {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::CurseForge, IngestLogItem::Project, ItemOutcome::Processed);
+                        if self.dry_run.is_none() {
+                            quarantined.unquarantine(db_pool, &project_id.to_string()).await;
+                        }
+                    },
+                    Err(err) => {
+                        record_item(IngestLogRepository::CurseForge, IngestLogItem::Project, ItemOutcome::Failed);
+                        warn!("{}", err);
+                        if self.dry_run.is_none() {
+                            quarantined.quarantine(db_pool, project_id.to_string(), FailureKind::Database, &err, &payload).await;
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                record_item(IngestLogRepository::CurseForge, IngestLogItem::Project, ItemOutcome::Skipped);
+                warn!("{}", err);
+                if self.dry_run.is_none() {
+                    quarantined.quarantine(db_pool, project_id.to_string(), FailureKind::Conversion, &err, &payload).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_projects_from_api(&self, request: GetCurseForgeProjectsRequest) -> Result<GetCurseForgeProjectsResponse> {
+        let url = self.http_server.base_url().join("mods/search")?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::CurseForge,
+            || time_rate_limiter_wait(IngestLogRepository::CurseForge, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        if status == StatusCode::OK {
+            let response: GetCurseForgeProjectsResponse = raw_response.json().await?;
+
+            Ok(response)
+        } else {
+            Err(
+                GetCurseForgeProjectsError::UnexpectedStatusCode {
+                    request,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    /// Gets a single project, or None if it no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_project_from_api(&self, id: i32) -> Result<Option<IncomingCurseForgeProject>> {
+        let path = &["mods/", id.to_string().as_str()].concat();
+        let url = self.http_server.base_url().join(path)?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::CurseForge,
+            || time_rate_limiter_wait(IngestLogRepository::CurseForge, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let response: GetCurseForgeProjectResponse = raw_response.json().await?;
+                Ok(Some(response.data))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(
+                GetCurseForgeProjectsError::UnexpectedStatusCodeForProject {
+                    id,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+}
+
+impl<T> PageTurner<GetCurseForgeProjectsRequest> for CurseForgeClient<T> where T: HttpServer + Send + Sync {
+    type PageItems = Vec<IncomingCurseForgeProject>;
+    type PageError = anyhow::Error;
+
+    async fn turn_page(&self, mut request: GetCurseForgeProjectsRequest) -> TurnedPageResult<Self, GetCurseForgeProjectsRequest> {
+        // Requests made ahead of time may fall outside of the search window, which the API would reject.
+        if !request.is_within_search_window() {
+            return Ok(TurnedPage::last(vec![]));
+        }
+
+        let response = self.get_projects_from_api(request.clone()).await?;
+
+        if response.more_projects_available() {
+            request.index += request.page_size;
+            Ok(TurnedPage::next(response.data, request))
+        } else {
+            Ok(TurnedPage::last(response.data))
+        }
+    }
+}
+
+fn convert_incoming_project(incoming_project: IncomingCurseForgeProject) -> Result<CurseForgeProject> {
+    let author = incoming_project.authors.first()
+        .ok_or(IncomingCurseForgeProjectError::AuthorNotFound { id: incoming_project.id })?
+        .name
+        .clone();
+
+    let version_name = incoming_project.latest_files.iter()
+        .find(|file| file.id == incoming_project.main_file_id)
+        .map(|file| file.display_name.clone());
+
+    let mut minecraft_versions: Vec<String> = incoming_project.latest_files_indexes.iter()
+        .map(|index| index.game_version.clone())
+        .filter(|game_version| game_version.parse::<MinecraftVersion>().is_ok())
+        .collect();
+    numeric_sort::sort(&mut minecraft_versions);
+    minecraft_versions.dedup();
+
+    let mut project = CurseForgeProject {
+        id: incoming_project.id,
+        slug: incoming_project.slug,
+        name: incoming_project.name,
+        description: incoming_project.summary,
+        author,
+        date_created: OffsetDateTime::parse(&incoming_project.date_created, &Rfc3339)?,
+        date_updated: OffsetDateTime::parse(&incoming_project.date_modified, &Rfc3339)?,
+        latest_minecraft_version: find_latest_minecraft_version(&minecraft_versions),
+        downloads: incoming_project.download_count.try_into()?,
+        likes: incoming_project.thumbs_up_count,
+        icon_url: incoming_project.logo.map(|logo| logo.thumbnail_url),
+        version_name,
+        source_url: incoming_project.links.source_url.clone(),
+        source_repository: None,
+        // Every project in the Bukkit plugins class is a Bukkit plugin.
+        platforms: vec![ServerPlatform::Bukkit],
+        categories: convert_curseforge_categories_to_categories(&incoming_project.categories),
+        minecraft_versions
+    };
+
+    if let Some(url) = incoming_project.links.source_url {
+        let option_repo = extract_source_repository_from_url(url.as_str());
+
+        if let Some(repo) = option_repo {
+            project.source_repository = Some(SourceRepository {
+                host: repo.host,
+                owner: repo.owner,
+                name: repo.name,
+                id: None
+            });
+        }
+    }
+
+    Ok(project)
+}
+
+fn convert_curseforge_categories_to_categories(curseforge_categories: &[IncomingCurseForgeProjectCategory]) -> Vec<ProjectCategory> {
+    let mut categories: Vec<ProjectCategory> = curseforge_categories.iter()
+        .filter_map(|category| match category.slug.as_str() {
+            "admin-tools" | "website-administration" => Some(ProjectCategory::AdminTools),
+            "anti-griefing-tools" => Some(ProjectCategory::Protection),
+            "chat-related" => Some(ProjectCategory::Chat),
+            "developer-tools" => Some(ProjectCategory::Library),
+            "economy" => Some(ProjectCategory::Economy),
+            "fixes" | "informational" => Some(ProjectCategory::Utility),
+            "fun" | "mechanics" => Some(ProjectCategory::Gameplay),
+            "general" | "miscellaneous" => Some(ProjectCategory::Miscellaneous),
+            "role-playing" => Some(ProjectCategory::RolePlaying),
+            "teleportation" => Some(ProjectCategory::Transportation),
+            "world-editing-and-management" | "world-generators" => Some(ProjectCategory::WorldManagement),
+            _ => None
+        })
+        .collect();
+
+    categories.sort();
+    categories.dedup();
+    categories
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curseforge::CURSEFORGE_API_KEY_HEADER;
+    use crate::curseforge::test::{CurseForgeTestServer, CURSEFORGE_TEST_API_KEY};
+
+    use rstest::*;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{header, method, path, query_param};
+
+    #[tokio::test]
+    async fn should_get_projects_from_api() -> Result<()> {
+        // Arrange
+        let curseforge_server = CurseForgeTestServer::new().await;
+
+        let request = GetCurseForgeProjectsRequest::create_request("desc");
+
+        let expected_response = GetCurseForgeProjectsResponse {
+            data: create_test_projects(),
+            pagination: CurseForgeResponsePagination {
+                index: 0,
+                page_size: 50,
+                result_count: 2,
+                total_count: 2
+            }
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/mods/search"))
+            .and(header(CURSEFORGE_API_KEY_HEADER, CURSEFORGE_TEST_API_KEY))
+            .and(query_param("gameId", request.game_id.to_string().as_str()))
+            .and(query_param("classId", request.class_id.to_string().as_str()))
+            .and(query_param("sortField", request.sort_field.to_string().as_str()))
+            .and(query_param("sortOrder", request.sort_order.as_str()))
+            .and(query_param("index", request.index.to_string().as_str()))
+            .and(query_param("pageSize", request.page_size.to_string().as_str()))
+            .respond_with(response_template)
+            .mount(curseforge_server.mock())
+            .await;
+
+        // Act
+        let curseforge_client = CurseForgeClient::new(curseforge_server, CURSEFORGE_TEST_API_KEY)?;
+        let response = curseforge_client.get_projects_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_request_projects_beyond_search_window() -> Result<()> {
+        // Arrange
+        let curseforge_server = CurseForgeTestServer::new().await;
+
+        let mut request = GetCurseForgeProjectsRequest::create_request("desc");
+        request.index = CURSEFORGE_SEARCH_WINDOW;
+
+        Mock::given(method("GET"))
+            .and(path("/mods/search"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(0)
+            .mount(curseforge_server.mock())
+            .await;
+
+        // Act
+        let curseforge_client = CurseForgeClient::new(curseforge_server, CURSEFORGE_TEST_API_KEY)?;
+        let incoming_projects: Vec<IncomingCurseForgeProject> = curseforge_client
+            .pages(request)
+            .items()
+            .try_collect()
+            .await?;
+
+        // Assert
+        assert_that(&incoming_projects).is_empty();
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_process_incoming_project() -> Result<()> {
+        // Arrange
+        let incoming_project = create_test_projects()[0].clone();
+
+        // Act
+        let project = convert_incoming_project(incoming_project)?;
+
+        // Assert
+        let expected_project = CurseForgeProject {
+            id: 1,
+            slug: "foo".to_string(),
+            name: "foo-curseforge".to_string(),
+            description: "foo-curseforge-description".to_string(),
+            author: "alice".to_string(),
+            date_created: datetime!(2019-01-01 0:00 UTC),
+            date_updated: datetime!(2019-02-03 0:00 UTC),
+            latest_minecraft_version: Some("1.21.4".to_string()),
+            downloads: 100,
+            likes: 200,
+            icon_url: Some("https://media.forgecdn.net/avatars/thumbnails/1/1/64/64/1.png".to_string()),
+            version_name: Some("foo-1.2.3.jar".to_string()),
+            source_url: Some("https://github.com/alice/foo".to_string()),
+            source_repository: Some(SourceRepository {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo".to_string(),
+                id: None
+            }),
+            platforms: vec![ServerPlatform::Bukkit],
+            categories: vec![ProjectCategory::Chat, ProjectCategory::Utility],
+            minecraft_versions: vec!["1.8".to_string(), "1.21.3".to_string(), "1.21.4".to_string()]
+        };
+
+        assert_that(&project).is_equal_to(expected_project);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::single(vec!["chat-related"], vec![ProjectCategory::Chat])]
+    #[case::multiple(vec!["economy", "fun"], vec![ProjectCategory::Economy, ProjectCategory::Gameplay])]
+    #[case::merged(vec!["world-editing-and-management", "world-generators"], vec![ProjectCategory::WorldManagement])]
+    #[case::unknown(vec!["unknown"], vec![])]
+    fn should_convert_curseforge_categories(#[case] slugs: Vec<&str>, #[case] expected_categories: Vec<ProjectCategory>) {
+        // Arrange
+        let curseforge_categories: Vec<IncomingCurseForgeProjectCategory> = slugs.into_iter()
+            .enumerate()
+            .map(|(id, slug)| create_test_category(id as i32, slug))
+            .collect();
+
+        // Act
+        let categories = convert_curseforge_categories_to_categories(&curseforge_categories);
+
+        // Assert
+        assert_that(&categories).is_equal_to(expected_categories);
+    }
+
+    fn create_test_category(id: i32, slug: &str) -> IncomingCurseForgeProjectCategory {
+        IncomingCurseForgeProjectCategory {
+            id,
+            name: slug.to_string(),
+            slug: slug.to_string()
+        }
+    }
+
+    fn create_test_projects() -> Vec<IncomingCurseForgeProject> {
+        vec![
+            IncomingCurseForgeProject {
+                id: 1,
+                name: "foo-curseforge".to_string(),
+                slug: "foo".to_string(),
+                summary: "foo-curseforge-description".to_string(),
+                links: IncomingCurseForgeProjectLinks {
+                    website_url: Some("https://www.curseforge.com/minecraft/bukkit-plugins/foo".to_string()),
+                    source_url: Some("https://github.com/alice/foo".to_string())
+                },
+                download_count: 100,
+                thumbs_up_count: 200,
+                authors: vec![
+                    IncomingCurseForgeProjectAuthor {
+                        id: 1,
+                        name: "alice".to_string()
+                    }
+                ],
+                logo: Some(IncomingCurseForgeProjectLogo {
+                    thumbnail_url: "https://media.forgecdn.net/avatars/thumbnails/1/1/64/64/1.png".to_string()
+                }),
+                categories: vec![create_test_category(117, "chat-related"), create_test_category(123, "informational")],
+                main_file_id: 12,
+                latest_files: vec![
+                    IncomingCurseForgeProjectFile {
+                        id: 11,
+                        display_name: "foo-1.2.2.jar".to_string()
+                    },
+                    IncomingCurseForgeProjectFile {
+                        id: 12,
+                        display_name: "foo-1.2.3.jar".to_string()
+                    }
+                ],
+                latest_files_indexes: vec![
+                    IncomingCurseForgeProjectFileIndex {
+                        game_version: "1.21.4".to_string(),
+                        file_id: 12
+                    },
+                    IncomingCurseForgeProjectFileIndex {
+                        game_version: "1.21.3".to_string(),
+                        file_id: 12
+                    },
+                    IncomingCurseForgeProjectFileIndex {
+                        game_version: "1.21.3".to_string(),
+                        file_id: 11
+                    },
+                    IncomingCurseForgeProjectFileIndex {
+                        game_version: "1.8".to_string(),
+                        file_id: 11
+                    },
+                    IncomingCurseForgeProjectFileIndex {
+                        game_version: "CB 1097".to_string(),
+                        file_id: 11
+                    }
+                ],
+                date_created: "2019-01-01T00:00:00Z".to_string(),
+                date_modified: "2019-02-03T00:00:00Z".to_string()
+            },
+            IncomingCurseForgeProject {
+                id: 2,
+                name: "bar-curseforge".to_string(),
+                slug: "bar".to_string(),
+                summary: "bar-curseforge-description".to_string(),
+                links: IncomingCurseForgeProjectLinks {
+                    website_url: Some("https://www.curseforge.com/minecraft/bukkit-plugins/bar".to_string()),
+                    source_url: None
+                },
+                download_count: 300,
+                thumbs_up_count: 100,
+                authors: vec![
+                    IncomingCurseForgeProjectAuthor {
+                        id: 2,
+                        name: "bob".to_string()
+                    }
+                ],
+                logo: None,
+                categories: vec![create_test_category(116, "anti-griefing-tools")],
+                main_file_id: 21,
+                latest_files: vec![
+                    IncomingCurseForgeProjectFile {
+                        id: 21,
+                        display_name: "bar-1.2.3.jar".to_string()
+                    }
+                ],
+                latest_files_indexes: vec![
+                    IncomingCurseForgeProjectFileIndex {
+                        game_version: "1.8".to_string(),
+                        file_id: 21
+                    }
+                ],
+                date_created: "2019-01-02T00:00:00Z".to_string(),
+                date_modified: "2019-02-02T00:00:00Z".to_string()
+            }
+        ]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,444 @@
+use crate::{HttpServer, cache_project_icons, fix_upstream_errors, refresh_common_projects, snapshot_project_stats};
+use crate::{populate_source_repository_metadata, populate_source_repository_releases, populate_spigot_authors};
+use crate::{update_builtbybit_resources, update_curseforge_projects, update_hangar_projects, update_modrinth_projects, update_polymart_resources, update_spigot_resources};
+use crate::builtbybit::{BuiltByBitClient, BuiltByBitServer};
+use crate::curseforge::{CurseForgeClient, CurseForgeServer};
+use crate::hangar::{HangarClient, HangarServer};
+use crate::modrinth::{ModrinthClient, ModrinthServer};
+use crate::polymart::{PolymartClient, PolymartServer};
+use crate::retry::RetryPolicy;
+use crate::source_repository::{ForgeServer, SourceRepositoryClient, SourceRepositoryServer};
+use crate::spigot::{SpigotClient, SpigotServer};
+
+use mc_plugin_finder::config::get_optional_config_string;
+use mc_plugin_finder::database::ingest_lock::try_acquire_ingest_lock;
+
+use anyhow::Result;
+use clap::Args;
+use deadpool_postgres::Pool;
+use std::fmt;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::{Instant, sleep_until};
+use tracing::{info, warn};
+
+const SECONDS_PER_MINUTE: u64 = 60;
+
+/// How long to wait before trying again when another ingest run holds the ingest lock, unless the interval is shorter.
+const LOCK_RETRY_DELAY: Duration = Duration::from_secs(5 * SECONDS_PER_MINUTE);
+
+/// How often each repository is updated while running as a daemon, in minutes: Set an interval to 0 to skip that repository
+#[derive(Args, Clone, Debug)]
+pub struct DaemonIntervals {
+    /// Minutes between updates of Spigot authors and resources
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    spigot_interval: u64,
+
+    /// Minutes between updates of Modrinth projects
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    modrinth_interval: u64,
+
+    /// Minutes between updates of Hangar projects
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    hangar_interval: u64,
+
+    /// Minutes between updates of CurseForge projects
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    curseforge_interval: u64,
+
+    /// Minutes between updates of Polymart resources
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    polymart_interval: u64,
+
+    /// Minutes between updates of BuiltByBit resources
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    builtbybit_interval: u64,
+
+    /// Minutes between updates of source repository releases and metadata
+    #[arg(long, value_name = "MINUTES", default_value_t = 1440)]
+    source_repository_interval: u64
+}
+
+impl DaemonIntervals {
+    fn to_durations(&self) -> Vec<(DaemonRepository, Duration)> {
+        vec![
+            (DaemonRepository::Spigot, self.spigot_interval),
+            (DaemonRepository::Modrinth, self.modrinth_interval),
+            (DaemonRepository::Hangar, self.hangar_interval),
+            (DaemonRepository::CurseForge, self.curseforge_interval),
+            (DaemonRepository::Polymart, self.polymart_interval),
+            (DaemonRepository::BuiltByBit, self.builtbybit_interval),
+            (DaemonRepository::SourceRepository, self.source_repository_interval)
+        ]
+        .into_iter()
+        .map(|(repository, minutes)| (repository, Duration::from_secs(minutes.saturating_mul(SECONDS_PER_MINUTE))))
+        .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DaemonRepository {
+    Spigot,
+    Modrinth,
+    Hangar,
+    CurseForge,
+    Polymart,
+    BuiltByBit,
+    SourceRepository
+}
+
+impl fmt::Display for DaemonRepository {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            DaemonRepository::Spigot => "Spigot",
+            DaemonRepository::Modrinth => "Modrinth",
+            DaemonRepository::Hangar => "Hangar",
+            DaemonRepository::CurseForge => "CurseForge",
+            DaemonRepository::Polymart => "Polymart",
+            DaemonRepository::BuiltByBit => "BuiltByBit",
+            DaemonRepository::SourceRepository => "Source repository"
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug)]
+struct DaemonScheduleEntry {
+    repository: DaemonRepository,
+    interval: Duration,
+    next_run: Instant
+}
+
+/// Keeps track of when each repository is next due to be updated.
+#[derive(Debug)]
+struct DaemonSchedule {
+    entries: Vec<DaemonScheduleEntry>
+}
+
+impl DaemonSchedule {
+    /// Every repository with a non-zero interval is due immediately.
+    fn new(intervals: Vec<(DaemonRepository, Duration)>, now: Instant) -> Self {
+        let entries = intervals.into_iter()
+            .filter(|(_, interval)| !interval.is_zero())
+            .map(|(repository, interval)| DaemonScheduleEntry {
+                repository,
+                interval,
+                next_run: now
+            })
+            .collect();
+
+        Self {
+            entries
+        }
+    }
+
+    fn next_run(&self) -> Option<Instant> {
+        self.entries.iter().map(|x| x.next_run).min()
+    }
+
+    /// Returns the repositories that are due.
+    fn due(&self, now: Instant) -> Vec<DaemonRepository> {
+        self.entries.iter()
+            .filter(|x| x.next_run <= now)
+            .map(|x| x.repository)
+            .collect()
+    }
+
+    /// Schedules the next run of the repositories one interval from now, after they have run.
+    fn reschedule(&mut self, repositories: &[DaemonRepository], now: Instant) {
+        for entry in self.entries.iter_mut().filter(|x| repositories.contains(&x.repository)) {
+            entry.next_run = now + entry.interval;
+        }
+    }
+
+    /// Schedules the repositories to be tried again shortly, after they could not run.
+    fn postpone(&mut self, repositories: &[DaemonRepository], now: Instant) {
+        for entry in self.entries.iter_mut().filter(|x| repositories.contains(&x.repository)) {
+            entry.next_run = now + entry.interval.min(LOCK_RETRY_DELAY);
+        }
+    }
+}
+
+/// Runs the update, fix, and refresh pipeline on a schedule until SIGTERM or Ctrl+C is received.
+/// The ingest lock is held during each run, so a run is postponed if another ingest is still in progress.
+pub async fn run_daemon(db_pool: &Pool, retry_policy: &RetryPolicy, intervals: &DaemonIntervals) -> Result<()> {
+    info!("Starting ingest daemon...");
+
+    let mut intervals = intervals.to_durations();
+
+    // CurseForge and BuiltByBit are skipped if no API key or token has been configured.
+    if get_optional_config_string("curseforge.api.key").is_none() {
+        warn!("Skipping CurseForge projects: MCPF_CURSEFORGE_API_KEY is not set.");
+        intervals.retain(|(repository, _)| *repository != DaemonRepository::CurseForge);
+    }
+    if get_optional_config_string("builtbybit.api.token").is_none() {
+        warn!("Skipping BuiltByBit resources: MCPF_BUILTBYBIT_API_TOKEN is not set.");
+        intervals.retain(|(repository, _)| *repository != DaemonRepository::BuiltByBit);
+    }
+
+    let mut schedule = DaemonSchedule::new(intervals, Instant::now());
+    let mut shutdown = spawn_shutdown_listener();
+
+    while let Some(next_run) = schedule.next_run() {
+        tokio::select! {
+            _ = sleep_until(next_run) => {},
+            _ = shutdown.changed() => {}
+        }
+
+        if *shutdown.borrow() {
+            break;
+        }
+
+        // Repositories are only rescheduled a full interval later once they have run.
+        // If the ingest lock is held or cannot be acquired, they are tried again shortly instead of stopping the daemon.
+        let now = Instant::now();
+        let due = schedule.due(now);
+        match run_scheduled_updates(db_pool, retry_policy, &due, &shutdown).await {
+            Ok(true) => schedule.reschedule(&due, now),
+            Ok(false) => schedule.postpone(&due, now),
+            Err(err) => {
+                warn!("Scheduled updates failed: {}", err);
+                schedule.postpone(&due, now);
+            }
+        }
+    }
+
+    info!("Ingest daemon stopped.");
+
+    Ok(())
+}
+
+/// Returns false without running any updates if another ingest run holds the ingest lock.
+async fn run_scheduled_updates(db_pool: &Pool, retry_policy: &RetryPolicy, repositories: &[DaemonRepository], shutdown: &watch::Receiver<bool>) -> Result<bool> {
+    let Some(ingest_lock) = try_acquire_ingest_lock(db_pool).await? else {
+        warn!("Postponing scheduled updates: Another ingest run is in progress.");
+        return Ok(false)
+    };
+
+    // Updates are not interrupted partway through, but no further updates are started once a shutdown is requested.
+    for repository in repositories {
+        if *shutdown.borrow() {
+            break;
+        }
+
+        if let Err(err) = update_repository(db_pool, retry_policy, *repository).await {
+            warn!("{} update failed: {}", repository, err);
+        }
+    }
+
+    if !*shutdown.borrow() {
+        if let Err(err) = snapshot_project_stats(db_pool).await {
+            warn!("Snapshotting project stats failed: {}", err);
+        }
+        if let Err(err) = cache_project_icons(db_pool, retry_policy).await {
+            warn!("Caching project icons failed: {}", err);
+        }
+        if let Err(err) = fix_upstream_errors(db_pool).await {
+            warn!("Fixing upstream errors failed: {}", err);
+        }
+        if let Err(err) = refresh_common_projects(db_pool).await {
+            warn!("Refreshing common projects failed: {}", err);
+        }
+    }
+
+    if let Err(err) = ingest_lock.release().await {
+        warn!("Releasing the ingest lock failed: {}", err);
+    }
+
+    Ok(true)
+}
+
+async fn update_repository(db_pool: &Pool, retry_policy: &RetryPolicy, repository: DaemonRepository) -> Result<()> {
+    match repository {
+        DaemonRepository::Spigot => {
+            let spigot_server = SpigotServer::new().await;
+            let spigot_client = SpigotClient::new(spigot_server)?.with_retry_policy(retry_policy.clone());
+
+            populate_spigot_authors(&spigot_client, db_pool).await?;
+            update_spigot_resources(&spigot_client, db_pool).await?;
+        },
+        DaemonRepository::Modrinth => {
+            let modrinth_server = ModrinthServer::new().await;
+            let modrinth_client = ModrinthClient::new(modrinth_server)?.with_retry_policy(retry_policy.clone());
+
+            update_modrinth_projects(&modrinth_client, db_pool).await?;
+        },
+        DaemonRepository::Hangar => {
+            let hangar_server = HangarServer::new().await;
+            let hangar_client = HangarClient::new(hangar_server)?.with_retry_policy(retry_policy.clone());
+
+            update_hangar_projects(&hangar_client, db_pool).await?;
+        },
+        DaemonRepository::CurseForge => {
+            let curseforge_server = CurseForgeServer::new().await;
+            let api_key = get_optional_config_string("curseforge.api.key").unwrap_or_default();
+            let curseforge_client = CurseForgeClient::new(curseforge_server, &api_key)?.with_retry_policy(retry_policy.clone());
+
+            update_curseforge_projects(&curseforge_client, db_pool).await?;
+        },
+        DaemonRepository::Polymart => {
+            let polymart_server = PolymartServer::new().await;
+            let polymart_client = PolymartClient::new(polymart_server)?.with_retry_policy(retry_policy.clone());
+
+            update_polymart_resources(&polymart_client, db_pool).await?;
+        },
+        DaemonRepository::BuiltByBit => {
+            let builtbybit_server = BuiltByBitServer::new().await;
+            let api_token = get_optional_config_string("builtbybit.api.token").unwrap_or_default();
+            let builtbybit_client = BuiltByBitClient::new(builtbybit_server, &api_token)?.with_retry_policy(retry_policy.clone());
+
+            update_builtbybit_resources(&builtbybit_client, db_pool).await?;
+        },
+        DaemonRepository::SourceRepository => {
+            let source_repository_server = SourceRepositoryServer::new().await;
+            let source_repository_client = SourceRepositoryClient::new(source_repository_server, get_optional_config_string("github.api.token").as_deref())?.with_retry_policy(retry_policy.clone());
+
+            populate_source_repository_releases(&source_repository_client, db_pool).await?;
+            populate_source_repository_metadata(&source_repository_client, db_pool).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns a receiver that changes to true once SIGTERM or Ctrl+C is received.
+fn spawn_shutdown_listener() -> watch::Receiver<bool> {
+    let (sender, receiver) = watch::channel(false);
+
+    tokio::spawn(async move {
+        wait_for_shutdown_signal().await;
+        info!("Shutdown requested, stopping once the current update has finished...");
+        let _ = sender.send(true);
+    });
+
+    receiver
+}
+
+#[cfg(unix)]
+async fn wait_for_shutdown_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut sigterm = signal(SignalKind::terminate()).expect("could not install SIGTERM handler");
+
+    tokio::select! {
+        _ = sigterm.recv() => {},
+        _ = tokio::signal::ctrl_c() => {}
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use speculoos::prelude::*;
+
+    const ONE_HOUR: Duration = Duration::from_secs(3600);
+    const ONE_DAY: Duration = Duration::from_secs(86400);
+
+    #[test]
+    fn should_schedule_all_repositories_immediately() {
+        // Arrange
+        let now = Instant::now();
+        let mut schedule = DaemonSchedule::new(vec![
+            (DaemonRepository::Spigot, ONE_HOUR),
+            (DaemonRepository::SourceRepository, ONE_DAY)
+        ], now);
+
+        // Act
+        let due = schedule.due(now);
+        schedule.reschedule(&due, now);
+
+        // Assert
+        assert_that(&due).is_equal_to(vec![DaemonRepository::Spigot, DaemonRepository::SourceRepository]);
+        assert_that(&schedule.next_run()).is_some().is_equal_to(now + ONE_HOUR);
+    }
+
+    #[test]
+    fn should_only_take_repositories_that_are_due() {
+        // Arrange
+        let now = Instant::now();
+        let mut schedule = DaemonSchedule::new(vec![
+            (DaemonRepository::Spigot, ONE_HOUR),
+            (DaemonRepository::SourceRepository, ONE_DAY)
+        ], now);
+        schedule.reschedule(&schedule.due(now), now);
+
+        // Act
+        let later = now + ONE_HOUR;
+        let due = schedule.due(later);
+        schedule.reschedule(&due, later);
+
+        // Assert
+        assert_that(&due).is_equal_to(vec![DaemonRepository::Spigot]);
+        assert_that(&schedule.next_run()).is_some().is_equal_to(later + ONE_HOUR);
+    }
+
+    #[test]
+    fn should_skip_repositories_with_zero_interval() {
+        // Arrange
+        let now = Instant::now();
+
+        // Act
+        let schedule = DaemonSchedule::new(vec![
+            (DaemonRepository::Modrinth, Duration::ZERO),
+            (DaemonRepository::Hangar, ONE_HOUR)
+        ], now);
+
+        // Assert
+        assert_that(&schedule.due(now)).is_equal_to(vec![DaemonRepository::Hangar]);
+    }
+
+    #[test]
+    fn should_retry_postponed_repositories_after_lock_retry_delay() {
+        // Arrange
+        let now = Instant::now();
+        let mut schedule = DaemonSchedule::new(vec![
+            (DaemonRepository::Spigot, ONE_HOUR),
+            (DaemonRepository::SourceRepository, ONE_DAY)
+        ], now);
+        let due = schedule.due(now);
+
+        // Act
+        schedule.postpone(&due, now);
+
+        // Assert
+        assert_that(&schedule.next_run()).is_some().is_equal_to(now + LOCK_RETRY_DELAY);
+        assert_that(&schedule.due(now)).is_empty();
+        assert_that(&schedule.due(now + LOCK_RETRY_DELAY)).is_equal_to(vec![DaemonRepository::Spigot, DaemonRepository::SourceRepository]);
+    }
+
+    #[test]
+    fn should_not_postpone_repositories_beyond_their_interval() {
+        // Arrange
+        let now = Instant::now();
+        let one_minute = Duration::from_secs(60);
+        let mut schedule = DaemonSchedule::new(vec![
+            (DaemonRepository::Modrinth, one_minute)
+        ], now);
+
+        // Act
+        schedule.postpone(&schedule.due(now), now);
+
+        // Assert
+        assert_that(&schedule.next_run()).is_some().is_equal_to(now + one_minute);
+    }
+
+    #[test]
+    fn should_have_no_next_run_if_all_repositories_are_skipped() {
+        // Arrange
+        let now = Instant::now();
+
+        // Act
+        let schedule = DaemonSchedule::new(vec![
+            (DaemonRepository::Modrinth, Duration::ZERO)
+        ], now);
+
+        // Assert
+        assert_that(&schedule.next_run()).is_none();
+    }
+}
\ No newline at end of file
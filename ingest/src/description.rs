@@ -0,0 +1,105 @@
+use anyhow::Result;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use pulldown_cmark::{Options, Parser, html};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+enum ConvertFullDescriptionError {
+    #[error("Invalid base64 data: {source}")]
+    InvalidBase64 {
+        source: base64::DecodeError
+    },
+    #[error("Invalid UTF-8 text: {source}")]
+    InvalidUtf8 {
+        source: std::string::FromUtf8Error
+    }
+}
+
+/// Converts a base64-encoded HTML description, as provided by the Spiget API, into sanitized HTML.
+/// Returns None if the description is empty.
+pub fn convert_base64_html_description(data: &str) -> Result<Option<String>> {
+    let bytes = STANDARD.decode(data.trim())
+        .map_err(|err| ConvertFullDescriptionError::InvalidBase64 { source: err })?;
+
+    let html = String::from_utf8(bytes)
+        .map_err(|err| ConvertFullDescriptionError::InvalidUtf8 { source: err })?;
+
+    Ok(sanitize_html(&html))
+}
+
+/// Renders a Markdown description, as provided by the Modrinth and Hangar APIs, into sanitized HTML.
+/// Returns None if the description is empty.
+pub fn convert_markdown_description(markdown: &str) -> Option<String> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let parser = Parser::new_ext(markdown, options);
+
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, parser);
+
+    sanitize_html(&unsafe_html)
+}
+
+// Descriptions are rendered directly on the web app, so anything that could run scripts or alter the page (such as script tags, event handlers, and inline styles) must be removed.
+fn sanitize_html(html: &str) -> Option<String> {
+    let sanitized = ammonia::clean(html);
+    let trimmed = sanitized.trim();
+
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_convert_base64_html_description() -> Result<()> {
+        // Arrange
+        let html = r#"<b>Foo</b> is a <a href="https://example.com" onclick="steal()">plugin</a>.<script>alert("xss")</script>"#;
+        let data = STANDARD.encode(html);
+
+        // Act
+        let description = convert_base64_html_description(&data)?;
+
+        // Assert
+        assert_that(&description).is_equal_to(Some(r#"<b>Foo</b> is a <a href="https://example.com" rel="noopener noreferrer">plugin</a>."#.to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_to_convert_invalid_base64_html_description() {
+        // Act
+        let result = convert_base64_html_description("not base64!");
+
+        // Assert
+        assert_that(&result).is_err();
+    }
+
+    #[test]
+    fn should_convert_markdown_description() {
+        // Arrange
+        let markdown = "# Foo\n\nA **chat** plugin.\n\n<img src=\"https://example.com/foo.png\" onerror=\"steal()\">\n\n<iframe src=\"https://example.com\"></iframe>";
+
+        // Act
+        let description = convert_markdown_description(markdown);
+
+        // Assert
+        assert_that(&description).is_equal_to(Some("<h1>Foo</h1>\n<p>A <strong>chat</strong> plugin.</p>\n<img src=\"https://example.com/foo.png\">".to_string()));
+    }
+
+    #[test]
+    fn should_not_convert_empty_description() {
+        // Act
+        let description = convert_markdown_description("  \n");
+
+        // Assert
+        assert_that(&description).is_none();
+    }
+}
\ No newline at end of file
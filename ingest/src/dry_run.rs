@@ -0,0 +1,346 @@
+use mc_plugin_finder::database::builtbybit::resource::{BuiltByBitResource, get_builtbybit_resource_by_id};
+use mc_plugin_finder::database::curseforge::project::{CurseForgeProject, get_curseforge_project_by_id};
+use mc_plugin_finder::database::hangar::project::{HangarProject, get_hangar_project_by_slug};
+use mc_plugin_finder::database::modrinth::project::{ModrinthProject, get_modrinth_project_by_id};
+use mc_plugin_finder::database::modrinth::version::{ModrinthVersion, get_modrinth_version_by_id};
+use mc_plugin_finder::database::polymart::resource::{PolymartResource, get_polymart_resource_by_id};
+use mc_plugin_finder::database::source_repository_metadata::{SourceRepositoryMetadata, get_source_repository_metadata_by_repository};
+use mc_plugin_finder::database::source_repository_release::{SourceRepositoryRelease, get_source_repository_release_by_repository};
+use mc_plugin_finder::database::spigot::author::{SpigotAuthor, get_spigot_author_by_id};
+use mc_plugin_finder::database::spigot::resource::{SpigotResource, get_spigot_resource_by_id};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::info;
+
+/// An item that is written to the database during a populate/update operation, and can therefore be compared during a dry run.
+pub trait DryRunItem: Serialize + Sized {
+    /// The kind of item, as shown in the diff
+    const KIND: &'static str;
+
+    /// Uniquely identifies the item within its kind
+    fn key(&self) -> String;
+
+    /// Gets the current row with the same key, which the item is compared against
+    #[allow(async_fn_in_trait)]
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>>;
+}
+
+impl DryRunItem for SpigotAuthor {
+    const KIND: &'static str = "spigot_author";
+
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_spigot_author_by_id(db_pool, self.id).await
+    }
+}
+
+impl DryRunItem for SpigotResource {
+    const KIND: &'static str = "spigot_resource";
+
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_spigot_resource_by_id(db_pool, self.id).await
+    }
+}
+
+impl DryRunItem for ModrinthProject {
+    const KIND: &'static str = "modrinth_project";
+
+    fn key(&self) -> String {
+        self.id.clone()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_modrinth_project_by_id(db_pool, &self.id).await
+    }
+}
+
+impl DryRunItem for ModrinthVersion {
+    const KIND: &'static str = "modrinth_version";
+
+    fn key(&self) -> String {
+        self.id.clone()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_modrinth_version_by_id(db_pool, &self.id).await
+    }
+}
+
+impl DryRunItem for HangarProject {
+    const KIND: &'static str = "hangar_project";
+
+    fn key(&self) -> String {
+        self.slug.clone()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_hangar_project_by_slug(db_pool, &self.slug).await
+    }
+}
+
+impl DryRunItem for CurseForgeProject {
+    const KIND: &'static str = "curseforge_project";
+
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_curseforge_project_by_id(db_pool, self.id).await
+    }
+}
+
+impl DryRunItem for PolymartResource {
+    const KIND: &'static str = "polymart_resource";
+
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_polymart_resource_by_id(db_pool, self.id).await
+    }
+}
+
+impl DryRunItem for BuiltByBitResource {
+    const KIND: &'static str = "builtbybit_resource";
+
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_builtbybit_resource_by_id(db_pool, self.id).await
+    }
+}
+
+impl DryRunItem for SourceRepositoryRelease {
+    const KIND: &'static str = "source_repository_release";
+
+    fn key(&self) -> String {
+        format!("{}/{}/{}", self.host, self.owner, self.name)
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_source_repository_release_by_repository(db_pool, &self.host, &self.owner, &self.name).await
+    }
+}
+
+impl DryRunItem for SourceRepositoryMetadata {
+    const KIND: &'static str = "source_repository_metadata";
+
+    fn key(&self) -> String {
+        format!("{}/{}/{}", self.host, self.owner, self.name)
+    }
+
+    async fn get_existing(&self, db_pool: &Pool) -> Result<Option<Self>> {
+        get_source_repository_metadata_by_repository(db_pool, &self.host, &self.owner, &self.name).await
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DryRunFieldChange {
+    pub old: Value,
+    pub new: Value
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum DryRunDiff {
+    New {
+        item: Value
+    },
+    Changed {
+        changes: BTreeMap<String, DryRunFieldChange>
+    },
+    Unchanged
+}
+
+/// A diff of a single item, emitted as one line of JSON.
+#[derive(Debug, Serialize)]
+struct DryRunEntry<'a> {
+    kind: &'static str,
+    key: String,
+    #[serde(flatten)]
+    diff: &'a DryRunDiff
+}
+
+/// When a client has a dry run, each converted item is compared against its current row and the diff is logged, instead of writing it to the database.
+/// Ingest logs, checkpoints, and deletions are not written either.
+#[derive(Debug, Default)]
+pub struct DryRun {
+    new: AtomicU32,
+    changed: AtomicU32,
+    unchanged: AtomicU32
+}
+
+impl DryRun {
+    /// Looks up the current row of the item, then reports the item against it.
+    pub async fn report<T: DryRunItem>(&self, db_pool: &Pool, item: &T) -> Result<()> {
+        let existing = item.get_existing(db_pool).await?;
+
+        self.report_against(existing.as_ref(), item)
+    }
+
+    /// Logs the item as JSON if it is new or differs from the existing row, and counts it for the summary.
+    /// This is used directly when the existing row has already been read, such as when updating the versions of every project.
+    pub fn report_against<T: DryRunItem>(&self, existing: Option<&T>, item: &T) -> Result<()> {
+        let existing = existing.map(serde_json::to_value).transpose()?;
+        let diff = diff_items(existing.as_ref(), serde_json::to_value(item)?);
+
+        let counter = match diff {
+            DryRunDiff::New { .. } => &self.new,
+            DryRunDiff::Changed { .. } => &self.changed,
+            DryRunDiff::Unchanged => &self.unchanged
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        if diff != DryRunDiff::Unchanged {
+            let entry = DryRunEntry {
+                kind: T::KIND,
+                key: item.key(),
+                diff: &diff
+            };
+            info!("{}", serde_json::to_string(&entry)?);
+        }
+
+        Ok(())
+    }
+
+    pub fn log_summary(&self) {
+        info!("Dry run finished, nothing was written: {} new, {} changed, {} unchanged",
+            self.new.load(Ordering::Relaxed),
+            self.changed.load(Ordering::Relaxed),
+            self.unchanged.load(Ordering::Relaxed));
+    }
+}
+
+fn diff_items(existing: Option<&Value>, incoming: Value) -> DryRunDiff {
+    let Some(existing) = existing else {
+        return DryRunDiff::New { item: incoming }
+    };
+
+    let changes: BTreeMap<String, DryRunFieldChange> = match (existing, incoming) {
+        (Value::Object(existing_fields), Value::Object(incoming_fields)) => {
+            incoming_fields.into_iter()
+                .filter_map(|(field, new)| {
+                    let old = existing_fields.get(&field).cloned().unwrap_or(Value::Null);
+                    (old != new).then_some((field, DryRunFieldChange { old, new }))
+                })
+                .collect()
+        },
+        (existing, incoming) => {
+            if *existing == incoming {
+                BTreeMap::new()
+            } else {
+                BTreeMap::from([("value".to_string(), DryRunFieldChange { old: existing.clone(), new: incoming })])
+            }
+        }
+    };
+
+    if changes.is_empty() {
+        DryRunDiff::Unchanged
+    } else {
+        DryRunDiff::Changed { changes }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde_json::json;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_report_new_item() {
+        // Arrange
+        let dry_run = DryRun::default();
+        let author = SpigotAuthor { id: 1, name: "alice".to_string() };
+
+        // Act
+        let result = dry_run.report_against(None, &author);
+
+        // Assert
+        assert_that(&result).is_ok();
+        assert_that(&dry_run.new.load(Ordering::Relaxed)).is_equal_to(1);
+    }
+
+    #[test]
+    fn should_report_changed_and_unchanged_items() -> Result<()> {
+        // Arrange
+        let dry_run = DryRun::default();
+        let alice = SpigotAuthor { id: 1, name: "alice".to_string() };
+        let bob = SpigotAuthor { id: 2, name: "bob".to_string() };
+
+        // Act
+        dry_run.report_against(Some(&alice), &SpigotAuthor { id: 1, name: "alice".to_string() })?;
+        dry_run.report_against(Some(&bob), &SpigotAuthor { id: 2, name: "robert".to_string() })?;
+
+        // Assert
+        assert_that(&dry_run.new.load(Ordering::Relaxed)).is_equal_to(0);
+        assert_that(&dry_run.changed.load(Ordering::Relaxed)).is_equal_to(1);
+        assert_that(&dry_run.unchanged.load(Ordering::Relaxed)).is_equal_to(1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_diff_only_changed_fields() {
+        // Arrange
+        let existing = json!({ "id": 1, "name": "foo", "downloads": 100 });
+        let incoming = json!({ "id": 1, "name": "foo", "downloads": 150 });
+
+        // Act
+        let diff = diff_items(Some(&existing), incoming);
+
+        // Assert
+        assert_that(&diff).is_equal_to(DryRunDiff::Changed {
+            changes: BTreeMap::from([
+                ("downloads".to_string(), DryRunFieldChange { old: json!(100), new: json!(150) })
+            ])
+        });
+    }
+
+    #[test]
+    fn should_serialize_diff_entry_as_json() -> Result<()> {
+        // Arrange
+        let diff = DryRunDiff::Changed {
+            changes: BTreeMap::from([
+                ("name".to_string(), DryRunFieldChange { old: json!("bob"), new: json!("robert") })
+            ])
+        };
+        let entry = DryRunEntry {
+            kind: SpigotAuthor::KIND,
+            key: "2".to_string(),
+            diff: &diff
+        };
+
+        // Act
+        let serialized = serde_json::to_value(&entry)?;
+
+        // Assert
+        assert_that(&serialized).is_equal_to(json!({
+            "kind": "spigot_author",
+            "key": "2",
+            "status": "changed",
+            "changes": {
+                "name": { "old": "bob", "new": "robert" }
+            }
+        }));
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,172 @@
+use mc_plugin_finder::database::ingest_failure::{IngestFailure, upsert_ingest_failure, delete_ingest_failure, get_ingest_failure_upstream_ids};
+use mc_plugin_finder::database::ingest_log::{IngestLogRepository, IngestLogItem};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Mutex;
+use time::OffsetDateTime;
+use tracing::warn;
+
+/// The step at which an upstream item failed, which is saved as the error kind of its ingest failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailureKind {
+    /// The item could not be retrieved from the upstream API
+    Fetch,
+    /// The item could not be converted into a database row
+    Conversion,
+    /// The item could not be written to the database
+    Database
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            FailureKind::Fetch => "Fetch",
+            FailureKind::Conversion => "Conversion",
+            FailureKind::Database => "Database"
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The upstream IDs of the quarantined items of an item type in a repository.
+/// These are loaded once per populate/update operation, so that written items are only removed from the ingest failures if they were quarantined.
+#[derive(Debug)]
+pub struct QuarantinedItems {
+    repository: IngestLogRepository,
+    item: IngestLogItem,
+    upstream_ids: Mutex<HashSet<String>>
+}
+
+impl QuarantinedItems {
+    pub fn new(repository: IngestLogRepository, item: IngestLogItem, upstream_ids: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            repository,
+            item,
+            upstream_ids: Mutex::new(upstream_ids.into_iter().collect())
+        }
+    }
+
+    pub async fn load(db_pool: &Pool, repository: IngestLogRepository, item: IngestLogItem) -> Result<Self> {
+        let upstream_ids = get_ingest_failure_upstream_ids(db_pool, repository.clone(), item.clone()).await?;
+
+        Ok(Self::new(repository, item, upstream_ids))
+    }
+
+    /// Saves an upstream item that could not be retrieved, converted, or written, so that it can be retried later with the retry-failures action.
+    /// Failing to save the item is only logged, so that the rest of the populate/update operation can continue.
+    pub async fn quarantine<P: Serialize>(&self, db_pool: &Pool, upstream_id: String, kind: FailureKind, error: &anyhow::Error, payload: &P) {
+        let result = self.try_quarantine(db_pool, upstream_id, kind, error, payload).await;
+
+        if let Err(err) = result {
+            warn!("{}", err);
+        }
+    }
+
+    /// Removes an item from the ingest failures once it has been written, so that it is no longer retried or kept from being marked deleted.
+    /// Items that were not quarantined are skipped without querying the database.
+    /// Failing to remove the item is only logged, so that the rest of the populate/update operation can continue.
+    pub async fn unquarantine(&self, db_pool: &Pool, upstream_id: &str) {
+        if !self.upstream_ids.lock().expect("quarantined items lock should not be poisoned").remove(upstream_id) {
+            return;
+        }
+
+        let result = delete_ingest_failure(db_pool, self.repository.clone(), self.item.clone(), upstream_id).await;
+
+        if let Err(err) = result {
+            warn!("{}", err);
+        }
+    }
+
+    async fn try_quarantine<P: Serialize>(&self, db_pool: &Pool, upstream_id: String, kind: FailureKind, error: &anyhow::Error, payload: &P) -> Result<()> {
+        let failure = IngestFailure {
+            repository: self.repository.clone(),
+            item: self.item.clone(),
+            upstream_id: upstream_id.clone(),
+            error_kind: kind.to_string(),
+            message: get_error_message(error),
+            payload: serde_json::to_string(payload)?,
+            date_failed: OffsetDateTime::now_utc()
+        };
+
+        upsert_ingest_failure(db_pool, &failure).await?;
+
+        self.upstream_ids.lock().expect("quarantined items lock should not be poisoned").insert(upstream_id);
+
+        Ok(())
+    }
+}
+
+/// Gets the message of the error, followed by its root cause if the message does not already include it.
+fn get_error_message(error: &anyhow::Error) -> String {
+    let message = error.to_string();
+    let root_cause = error.root_cause().to_string();
+
+    if message.contains(&root_cause) {
+        message
+    } else {
+        format!("{}: {}", message, root_cause)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use speculoos::prelude::*;
+    use thiserror::Error;
+
+    #[derive(Debug, Error)]
+    enum TestError {
+        #[error("Skipping resource ID {resource_id}: Database query failed: {source}")]
+        DatabaseQueryFailed {
+            resource_id: i32,
+            source: anyhow::Error
+        },
+        #[error("Skipping resource ID {resource_id}: Database query failed")]
+        DatabaseQueryFailedWithoutSource {
+            resource_id: i32,
+            #[source]
+            source: anyhow::Error
+        }
+    }
+
+    #[test]
+    fn should_get_error_message_that_includes_root_cause() {
+        // Arrange
+        let error: anyhow::Error = TestError::DatabaseQueryFailed { resource_id: 1, source: anyhow::anyhow!("connection closed") }.into();
+
+        // Act
+        let message = get_error_message(&error);
+
+        // Assert
+        assert_that(&message).is_equal_to("Skipping resource ID 1: Database query failed: connection closed".to_string());
+    }
+
+    #[test]
+    fn should_append_root_cause_to_error_message() {
+        // Arrange
+        let error: anyhow::Error = TestError::DatabaseQueryFailedWithoutSource { resource_id: 1, source: anyhow::anyhow!("connection closed") }.into();
+
+        // Act
+        let message = get_error_message(&error);
+
+        // Assert
+        assert_that(&message).is_equal_to("Skipping resource ID 1: Database query failed: connection closed".to_string());
+    }
+
+    #[test]
+    fn should_get_error_message_with_context() {
+        // Arrange
+        let error = anyhow::anyhow!("connection closed").context("Skipping resource ID 1");
+
+        // Act
+        let message = get_error_message(&error);
+
+        // Assert
+        assert_that(&message).is_equal_to("Skipping resource ID 1: connection closed".to_string());
+    }
+}
@@ -1,14 +1,22 @@
 use crate::HttpServer;
+use crate::description::convert_markdown_description;
+use crate::failure::{FailureKind, QuarantinedItems};
+use crate::retry::send_with_retry;
 use crate::hangar::HangarClient;
 use crate::hangar::version::{IncomingHangarVersion, apply_incoming_hangar_version_to_hangar_project};
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_failure::get_ingest_failures;
+use mc_plugin_finder::database::ingest_checkpoint::{IngestCheckpoint, upsert_ingest_checkpoint, get_ingest_checkpoint, delete_ingest_checkpoint, get_checkpoint_start_date};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
-use mc_plugin_finder::database::hangar::project::{HangarProject, upsert_hangar_project};
+use mc_plugin_finder::database::hangar::project::{HangarProject, upsert_hangar_project, mark_unseen_hangar_projects_as_deleted};
+use mc_plugin_finder::database::project_category::ProjectCategory;
+use mc_plugin_finder::database::server_platform::ServerPlatform;
 use mc_plugin_finder::database::source_repository::{SourceRepository, extract_source_repository_from_url};
 
 use anyhow::Result;
 use deadpool_postgres::Pool;
 use futures::future;
-use futures::stream::TryStreamExt;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use page_turner::prelude::*;
 use reqwest::StatusCode;
 use serde::{Serialize, Deserialize};
@@ -20,6 +28,8 @@ use thiserror::Error;
 use time::format_description::well_known::Rfc3339;
 use tracing::{info, warn, instrument};
 
+const HANGAR_SUPPORTS_FOLIA_TAG: &str = "SUPPORTS_FOLIA";
+const HANGAR_LIBRARY_TAG: &str = "LIBRARY";
 const HANGAR_PROJECTS_REQUESTS_AHEAD: usize = 2;
 const HANGAR_PROJECTS_CONCURRENT_FUTURES: usize = 10;
 
@@ -31,7 +41,16 @@ struct GetHangarProjectsRequest {
 }
 
 impl GetHangarProjectsRequest {
-    fn create_request() -> Self {
+    fn create_populate_request() -> Self {
+        Self {
+            limit: 25,
+            offset: 0,
+            // Get projects in creation order, so that projects updated during the run do not move before pages that have already been retrieved.
+            sort: "newest".to_string()
+        }
+    }
+
+    fn create_update_request() -> Self {
         Self {
             limit: 25,
             offset: 0,
@@ -75,6 +94,7 @@ pub struct IncomingHangarProject {
     stats: IncomingHangarProjectStats,
     visibility: String,
     avatar_url: String,
+    category: String,
     settings: IncomingHangarProjectSettings
 }
 
@@ -127,6 +147,20 @@ enum GetHangarProjectsError {
     UnexpectedStatusCode {
         request: GetHangarProjectsRequest,
         status_code: u16
+    },
+    #[error("Could not get Hangar project '{slug}': Received unexpected status code {status_code}")]
+    UnexpectedStatusCodeForProject {
+        slug: String,
+        status_code: u16
+    }
+}
+
+#[derive(Debug, Error)]
+enum GetHangarMainPageError {
+    #[error("Could not get main page of Hangar project '{slug}': Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        slug: String,
+        status_code: u16
     }
 }
 
@@ -134,19 +168,48 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
     #[instrument(
         skip(self, db_pool)
     )]
-    pub async fn populate_hangar_projects(&self, db_pool: &Pool) -> Result<()> {
+    pub async fn populate_hangar_projects(&self, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
         info!("Populating Hangar projects...");
 
-        let request = GetHangarProjectsRequest::create_request();
+        let mut request = GetHangarProjectsRequest::create_populate_request();
         let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Hangar, IngestLogItem::Project).await?;
         let date_started = OffsetDateTime::now_utc();
 
-        let result= self
+        let mut checkpoint_date_started = get_checkpoint_start_date(db_pool).await?;
+
+        if resume {
+            match get_ingest_checkpoint(db_pool, IngestLogRepository::Hangar, IngestLogItem::Project).await? {
+                Some(checkpoint) => {
+                    request.offset = u32::try_from(checkpoint.position)? + request.limit;
+                    checkpoint_date_started = checkpoint.date_started;
+                    info!("Resuming from offset: {}", request.offset);
+                }
+                None => info!("No checkpoint found, starting from the beginning...")
+            }
+        }
+
+        let first_offset = request.offset;
+        let limit = request.limit;
+
+        let result = self
             .pages_ahead(HANGAR_PROJECTS_REQUESTS_AHEAD, Limit::None, request)
-            .items()
-            .try_for_each_concurrent(HANGAR_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, false))
+            .enumerate()
+            .map(|(index, page_result)| page_result.map(|incoming_projects| (first_offset + index as u32 * limit, incoming_projects)))
+            .try_for_each(|(offset, incoming_projects)| self.process_incoming_project_page(offset, incoming_projects, db_pool, &count, &quarantined, checkpoint_date_started))
             .await;
 
+        // The run has completed, so there is nothing left to resume.
+        if result.is_ok() && self.dry_run.is_none() {
+            delete_ingest_checkpoint(db_pool, IngestLogRepository::Hangar, IngestLogItem::Project).await?;
+
+            // Anything that was not seen since the run started is no longer available upstream.
+            if reconcile {
+                let deleted = mark_unseen_hangar_projects_as_deleted(db_pool, checkpoint_date_started).await?;
+                info!("Hangar projects marked as deleted: {}", deleted);
+            }
+        }
+
         let date_finished = OffsetDateTime::now_utc();
         let items_processed = count.load(Ordering::Relaxed);
 
@@ -159,7 +222,9 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Hangar projects populated: {}", items_processed);
 
@@ -172,15 +237,16 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
     pub async fn update_hangar_projects(&self, db_pool: &Pool, update_date_later_than: OffsetDateTime) -> Result<()> {
         info!("Updating Hangar projects since: {}", update_date_later_than);
 
-        let request = GetHangarProjectsRequest::create_request();
+        let request = GetHangarProjectsRequest::create_update_request();
         let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Hangar, IngestLogItem::Project).await?;
         let date_started = OffsetDateTime::now_utc();
 
         let result = self
             .pages_ahead(HANGAR_PROJECTS_REQUESTS_AHEAD, Limit::None, request)
             .items()
             .try_take_while(|x| future::ready(Ok(OffsetDateTime::parse(x.last_updated.as_str(), &Rfc3339).unwrap() > update_date_later_than)))
-            .try_for_each_concurrent(HANGAR_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, true))
+            .try_for_each_concurrent(HANGAR_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, &quarantined, true))
             .await;
 
         let date_finished = OffsetDateTime::now_utc();
@@ -195,15 +261,70 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Hangar projects updated: {}", items_processed);
 
         result
     }
 
-    async fn process_incoming_project(&self, incoming_project: IncomingHangarProject, db_pool: &Pool, count: &Arc<AtomicU32>, get_version: bool) -> Result<()> {
+    /// Re-fetches and processes each project that previously failed, removing it from the ingest failures if it succeeds.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn retry_hangar_project_failures(&self, db_pool: &Pool) -> Result<()> {
+        let failures = get_ingest_failures(db_pool, IngestLogRepository::Hangar, IngestLogItem::Project).await?;
+        info!("Retrying failed Hangar projects: {}", failures.len());
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::new(IngestLogRepository::Hangar, IngestLogItem::Project, failures.iter().map(|x| x.upstream_id.clone()));
+
+        for failure in failures {
+            match self.get_project_from_api(&failure.upstream_id).await {
+                // The project is removed from the ingest failures if it succeeds, or quarantined again if it still fails.
+                Ok(Some(incoming_project)) => self.process_incoming_project(incoming_project, db_pool, &count, &quarantined, true).await?,
+                // The project no longer exists upstream
+                Ok(None) => {
+                    if self.dry_run.is_none() {
+                        quarantined.unquarantine(db_pool, &failure.upstream_id).await;
+                    }
+                }
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        info!("Hangar projects retried: {}", count.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    async fn process_incoming_project_page(&self, offset: u32, incoming_projects: Vec<IncomingHangarProject>, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        stream::iter(incoming_projects)
+            .map(Ok)
+            .try_for_each_concurrent(HANGAR_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, count, quarantined, false))
+            .await?;
+
+        let checkpoint = IngestCheckpoint {
+            repository: IngestLogRepository::Hangar,
+            item: IngestLogItem::Project,
+            position: offset.try_into()?,
+            date_started,
+            date_updated: OffsetDateTime::now_utc()
+        };
+        if self.dry_run.is_none() {
+            upsert_ingest_checkpoint(db_pool, &checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_incoming_project(&self, incoming_project: IncomingHangarProject, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, get_version: bool) -> Result<()> {
+        let slug = incoming_project.namespace.slug.clone();
+        let payload = incoming_project.clone();
         let mut incoming_version: Option<IncomingHangarVersion> = None;
+        let mut main_page: Option<String> = None;
 
         if get_version {
             let version_result = self.get_latest_hangar_project_version_from_api(&incoming_project.namespace.slug).await;
@@ -212,22 +333,48 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
                 Ok(version) => incoming_version = Some(version),
                 Err(err) => warn!("{}", err)
             }
+
+            let main_page_result = self.get_main_page_from_api(&incoming_project.namespace.slug).await;
+
+            match main_page_result {
+                Ok(page) => main_page = Some(page),
+                Err(err) => warn!("{}", err)
+            }
         }
 
-        let convert_result = convert_incoming_project(incoming_project, incoming_version).await;
+        let convert_result = convert_incoming_project(incoming_project, incoming_version, main_page).await;
 
         match convert_result {
             Ok(project) => {
-                let db_result = upsert_hangar_project(db_pool, &project).await;
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &project).await,
+                    None => time_db_upsert("hangar_project", upsert_hangar_project(db_pool, &project)).await
+                };
 
                 match db_result {
                     Ok(_) => {
                         count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::Hangar, IngestLogItem::Project, ItemOutcome::Processed);
+                        if self.dry_run.is_none() {
+                            quarantined.unquarantine(db_pool, &slug).await;
+                        }
                     },
-                    Err(err) => warn!("{}", err)
+                    Err(err) => {
+                        record_item(IngestLogRepository::Hangar, IngestLogItem::Project, ItemOutcome::Failed);
+                        warn!("{}", err);
+                        if self.dry_run.is_none() {
+                            quarantined.quarantine(db_pool, slug.clone(), FailureKind::Database, &err, &payload).await;
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                record_item(IngestLogRepository::Hangar, IngestLogItem::Project, ItemOutcome::Skipped);
+                warn!("{}", err);
+                if self.dry_run.is_none() {
+                    quarantined.quarantine(db_pool, slug.clone(), FailureKind::Conversion, &err, &payload).await;
                 }
             }
-            Err(err) => warn!("{}", err)
         }
 
         Ok(())
@@ -237,13 +384,14 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
         skip(self)
     )]
     async fn get_projects_from_api(&self, request: GetHangarProjectsRequest) -> Result<GetHangarProjectsResponse> {
-        self.rate_limiter.until_ready().await;
-
         let url = self.http_server.base_url().join("projects")?;
-        let raw_response = self.api_client.get(url)
-            .query(&request)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::Hangar,
+            || time_rate_limiter_wait(IngestLogRepository::Hangar, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         if status == StatusCode::OK {
@@ -259,6 +407,59 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
             )
         }
     }
+
+    /// Gets a single project, or None if it no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_project_from_api(&self, slug: &str) -> Result<Option<IncomingHangarProject>> {
+        let path = &["projects/", slug].concat();
+        let url = self.http_server.base_url().join(path)?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::Hangar,
+            || time_rate_limiter_wait(IngestLogRepository::Hangar, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => Ok(Some(raw_response.json().await?)),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(
+                GetHangarProjectsError::UnexpectedStatusCodeForProject {
+                    slug: slug.to_string(),
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    /// Gets the Markdown contents of a project's main page, which serves as its full description.
+    #[instrument(
+        skip(self)
+    )]
+    pub async fn get_main_page_from_api(&self, slug: &str) -> Result<String> {
+        let path = &["pages/main/", slug].concat();
+        let url = self.http_server.base_url().join(path)?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::Hangar,
+            || time_rate_limiter_wait(IngestLogRepository::Hangar, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => Ok(raw_response.text().await?),
+            _ => Err(
+                GetHangarMainPageError::UnexpectedStatusCode {
+                    slug: slug.to_string(),
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
 }
 
 impl<T> PageTurner<GetHangarProjectsRequest> for HangarClient<T> where T: HttpServer + Send + Sync {
@@ -277,7 +478,9 @@ impl<T> PageTurner<GetHangarProjectsRequest> for HangarClient<T> where T: HttpSe
     }
 }
 
-async fn convert_incoming_project(incoming_project: IncomingHangarProject, incoming_version: Option<IncomingHangarVersion>) -> Result<HangarProject> {
+async fn convert_incoming_project(incoming_project: IncomingHangarProject, incoming_version: Option<IncomingHangarVersion>, main_page: Option<String>) -> Result<HangarProject> {
+    let supports_folia = incoming_project.settings.tags.iter().any(|tag| tag == HANGAR_SUPPORTS_FOLIA_TAG);
+    let categories = convert_hangar_category_to_categories(&incoming_project.category, &incoming_project.settings);
     let source_code_link = find_source_code_link(incoming_project.settings);
 
     let mut project = HangarProject {
@@ -295,7 +498,14 @@ async fn convert_incoming_project(incoming_project: IncomingHangarProject, incom
         icon_url: incoming_project.avatar_url,
         version_name: None,
         source_url: source_code_link.clone(),
-        source_repository: None
+        source_repository: None,
+        paper_versions: vec![],
+        velocity_versions: vec![],
+        waterfall_versions: vec![],
+        platforms: if supports_folia { vec![ServerPlatform::Folia] } else { vec![] },
+        categories,
+        minecraft_versions: vec![],
+        full_description: main_page.as_deref().and_then(convert_markdown_description)
     };
 
     if let Some(version) = incoming_version {
@@ -318,6 +528,39 @@ async fn convert_incoming_project(incoming_project: IncomingHangarProject, incom
     Ok(project)
 }
 
+// Hangar projects have a single main category, but may also be tagged as a library or list category names among their keywords.
+fn convert_hangar_category_to_categories(hangar_category: &str, settings: &IncomingHangarProjectSettings) -> Vec<ProjectCategory> {
+    let main_category = match hangar_category {
+        "admin_tools" => Some(ProjectCategory::AdminTools),
+        "chat" => Some(ProjectCategory::Chat),
+        "dev_tools" => Some(ProjectCategory::Library),
+        "economy" => Some(ProjectCategory::Economy),
+        "gameplay" => Some(ProjectCategory::Gameplay),
+        "games" => Some(ProjectCategory::Minigames),
+        "protection" => Some(ProjectCategory::Protection),
+        "role_playing" => Some(ProjectCategory::RolePlaying),
+        "world_management" => Some(ProjectCategory::WorldManagement),
+        "misc" => Some(ProjectCategory::Miscellaneous),
+        _ => None
+    };
+
+    let library = settings.tags.iter()
+        .any(|tag| tag == HANGAR_LIBRARY_TAG)
+        .then_some(ProjectCategory::Library);
+
+    let keyword_categories = settings.keywords.iter()
+        .filter_map(|keyword| keyword.to_lowercase().parse::<ProjectCategory>().ok());
+
+    let mut categories: Vec<ProjectCategory> = main_category.into_iter()
+        .chain(library)
+        .chain(keyword_categories)
+        .collect();
+
+    categories.sort();
+    categories.dedup();
+    categories
+}
+
 fn find_source_code_link(settings: IncomingHangarProjectSettings) -> Option<String> {
     for link_group in settings.links {
         for link in link_group.links {
@@ -346,7 +589,7 @@ mod test {
         // Arrange
         let hangar_server = HangarTestServer::new().await;
 
-        let request = GetHangarProjectsRequest::create_request();
+        let request = GetHangarProjectsRequest::create_populate_request();
 
         let expected_response = GetHangarProjectsResponse {
             pagination: HangarResponsePagination {
@@ -379,15 +622,39 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_get_main_page_from_api() -> Result<()> {
+        // Arrange
+        let hangar_server = HangarTestServer::new().await;
+
+        let expected_page = "# foo-hangar\n\nfoo-hangar-full-description";
+
+        Mock::given(method("GET"))
+            .and(path("/pages/main/foo"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(expected_page))
+            .mount(hangar_server.mock())
+            .await;
+
+        // Act
+        let hangar_client = HangarClient::new(hangar_server)?;
+        let page = hangar_client.get_main_page_from_api("foo").await;
+
+        // Assert
+        assert_that(&page).is_ok().is_equal_to(expected_page.to_string());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_process_incoming_project() -> Result<()> {
         // Arrange
         let incoming_project = create_test_projects()[0].clone();
         let incoming_version = create_test_version().clone();
         let version_name = "v1.2.3";
+        let main_page = "# foo-hangar\n\nfoo-hangar-full-description";
 
         // Act
-        let project = convert_incoming_project(incoming_project, Some(incoming_version)).await?;
+        let project = convert_incoming_project(incoming_project, Some(incoming_version), Some(main_page.to_string())).await?;
 
         // Assert
         let expected_project = HangarProject {
@@ -410,7 +677,14 @@ mod test {
                     owner: "alice".to_string(),
                     name: "foo".to_string(),
                     id: None
-                })
+                }),
+                paper_versions: vec!["1.8".to_string(), "1.9".to_string(), "1.21.2".to_string(), "1.21.3".to_string()],
+                velocity_versions: vec!["3.3".to_string(), "3.4".to_string()],
+                waterfall_versions: vec![],
+                platforms: vec![ServerPlatform::Paper, ServerPlatform::Folia, ServerPlatform::Velocity],
+                categories: vec![ProjectCategory::Chat, ProjectCategory::Economy],
+                minecraft_versions: vec!["1.8".to_string(), "1.9".to_string(), "1.21.2".to_string(), "1.21.3".to_string()],
+                full_description: Some("<h1>foo-hangar</h1>\n<p>foo-hangar-full-description</p>".to_string())
         };
 
         assert_that(&project).is_equal_to(expected_project);
@@ -436,10 +710,11 @@ mod test {
                 },
                 visibility: "public".to_string(),
                 avatar_url: "https://hangarcdn.papermc.io/avatars/project/1.webp?v=1".to_string(),
+                category: "chat".to_string(),
                 settings: IncomingHangarProjectSettings {
                     links: create_test_project_links( SourceRepository { host: "github.com".to_string(), owner: "alice".to_string(), name: "foo".to_string(), id: None } ),
                     tags: vec!["ADDON".to_string(), "SUPPORTS_FOLIA".to_string()],
-                    keywords: vec!["foo".to_string(), "fi".to_string()]
+                    keywords: vec!["foo".to_string(), "fi".to_string(), "Economy".to_string()]
                 }
             },
             IncomingHangarProject {
@@ -458,6 +733,7 @@ mod test {
                 },
                 visibility: "public".to_string(),
                 avatar_url: "https://hangarcdn.papermc.io/avatars/project/1.webp?v=1".to_string(),
+                category: "dev_tools".to_string(),
                 settings: IncomingHangarProjectSettings {
                     links: create_test_project_links( SourceRepository { host: "gitlab.com".to_string(), owner: "bob".to_string(), name: "bar".to_string(), id: None } ),
                     tags: vec!["ADDON".to_string(), "SUPPORTS_FOLIA".to_string()],
@@ -1,8 +1,13 @@
 use crate::HttpServer;
+use crate::description::convert_markdown_description;
+use crate::retry::send_with_retry;
 use crate::hangar::HangarClient;
 use crate::hangar::project::HangarResponsePagination;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
 use mc_plugin_finder::database::hangar::project::{get_hangar_projects, upsert_hangar_project, HangarProject};
+use mc_plugin_finder::database::server_platform::ServerPlatform;
+use mc_plugin_finder::minecraft_version::find_latest_minecraft_version;
 
 use anyhow::Result;
 use deadpool_postgres::Pool;
@@ -53,7 +58,9 @@ pub struct IncomingHangarVersion {
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub struct IncomingHangarVersionProjectDependencies {
-    paper: Option<Vec<String>>
+    paper: Option<Vec<String>>,
+    velocity: Option<Vec<String>>,
+    waterfall: Option<Vec<String>>
 }
 
 #[derive(Debug, Error)]
@@ -80,6 +87,7 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
         let date_started = OffsetDateTime::now_utc();
 
         let projects = get_hangar_projects(db_pool).await?;
+
         let project_stream = stream::iter(projects);
 
         let result = project_stream
@@ -99,7 +107,9 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Hangar versions populated: {}", items_processed);
 
@@ -113,16 +123,32 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
             Ok(version) => {
                 apply_incoming_hangar_version_to_hangar_project(&mut project, &version);
 
-                let db_result = upsert_hangar_project(db_pool, &project).await;
+                // Populating projects does not get their main pages, so their full descriptions are filled in along with their versions.
+                match self.get_main_page_from_api(&project.slug).await {
+                    Ok(page) => project.full_description = convert_markdown_description(&page),
+                    Err(err) => warn!("{}", err)
+                }
+
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &project).await,
+                    None => time_db_upsert("hangar_project", upsert_hangar_project(db_pool, &project)).await
+                };
 
                 match db_result {
                     Ok(_) => {
                         count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::Hangar, IngestLogItem::Version, ItemOutcome::Processed);
                     },
-                    Err(err) => warn!("{}", err)
+                    Err(err) => {
+                        record_item(IngestLogRepository::Hangar, IngestLogItem::Version, ItemOutcome::Failed);
+                        warn!("{}", err)
+                    }
                 }
             }
-            Err(err) => warn!("{}", err)
+            Err(err) => {
+                record_item(IngestLogRepository::Hangar, IngestLogItem::Version, ItemOutcome::Failed);
+                warn!("{}", err)
+            }
         }
 
         Ok(())
@@ -132,16 +158,17 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
         skip(self)
     )]
     pub async fn get_latest_hangar_project_version_from_api(&self, slug: &str) -> Result<IncomingHangarVersion> {
-        self.rate_limiter.until_ready().await;
-
         let request = GetHangarVersionsRequest::create_request();
 
         let path = &["projects/", slug, "/versions"].concat();
         let url = self.http_server.base_url().join(path)?;
-        let raw_response = self.api_client.get(url)
-            .query(&request)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::Hangar,
+            || time_rate_limiter_wait(IngestLogRepository::Hangar, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         match status {
@@ -173,13 +200,40 @@ impl<T> HangarClient<T> where T: HttpServer + Send + Sync {
 pub fn apply_incoming_hangar_version_to_hangar_project(project: &mut HangarProject, version: &IncomingHangarVersion) {
     project.version_name = Some(version.name.clone());
 
-    // Paper versions from Hangar are in lexicographical order, meaning versions like "1.8" are considered later than versions like "1.21.3".
+    // Platform versions from Hangar are in lexicographical order, meaning versions like "1.8" are considered later than versions like "1.21.3".
     // To get the proper latest version, we sort the versions in numerical order and get the last element.
-    if let Some(paper) = &version.platform_dependencies.paper {
-        let mut paper_versions = paper.clone();
-        numeric_sort::sort(&mut paper_versions);
-        project.latest_minecraft_version = paper_versions.last().cloned();
-    }
+    let dependencies = &version.platform_dependencies;
+    project.paper_versions = sort_platform_versions(&dependencies.paper);
+    project.velocity_versions = sort_platform_versions(&dependencies.velocity);
+    project.waterfall_versions = sort_platform_versions(&dependencies.waterfall);
+
+    // Velocity has its own version numbers that do not correspond to Minecraft versions, but Waterfall versions do.
+    // Fall back to Waterfall so that Waterfall-only proxy plugins still get a latest Minecraft version.
+    project.latest_minecraft_version = find_latest_minecraft_version(&project.paper_versions)
+        .or_else(|| find_latest_minecraft_version(&project.waterfall_versions));
+
+    let mut minecraft_versions = [project.paper_versions.clone(), project.waterfall_versions.clone()].concat();
+    numeric_sort::sort(&mut minecraft_versions);
+    minecraft_versions.dedup();
+    project.minecraft_versions = minecraft_versions;
+
+    // Folia support comes from the project's tags rather than its versions, so keep it as is.
+    let supports_folia = project.platforms.contains(&ServerPlatform::Folia);
+    project.platforms = [
+        (ServerPlatform::Paper, !project.paper_versions.is_empty()),
+        (ServerPlatform::Folia, supports_folia),
+        (ServerPlatform::Velocity, !project.velocity_versions.is_empty()),
+        (ServerPlatform::Waterfall, !project.waterfall_versions.is_empty())
+    ]
+        .into_iter()
+        .filter_map(|(platform, supported)| supported.then_some(platform))
+        .collect();
+}
+
+fn sort_platform_versions(versions: &Option<Vec<String>>) -> Vec<String> {
+    let mut sorted_versions = versions.clone().unwrap_or_default();
+    numeric_sort::sort(&mut sorted_versions);
+    sorted_versions
 }
 
 #[cfg(test)]
@@ -288,6 +342,10 @@ pub mod test {
         let mut expected_project = project.clone();
         expected_project.version_name = Some("v1.2.3".to_string());
         expected_project.latest_minecraft_version = Some("1.21.3".to_string());
+        expected_project.paper_versions = vec!["1.8".to_string(), "1.9".to_string(), "1.21.2".to_string(), "1.21.3".to_string()];
+        expected_project.velocity_versions = vec!["3.3".to_string(), "3.4".to_string()];
+        expected_project.platforms = vec![ServerPlatform::Paper, ServerPlatform::Velocity];
+        expected_project.minecraft_versions = expected_project.paper_versions.clone();
 
         let version = create_test_version();
 
@@ -312,7 +370,39 @@ pub mod test {
         let version = IncomingHangarVersion {
             name: "v1.2.3".to_string(),
             platform_dependencies: IncomingHangarVersionProjectDependencies {
-                paper: None
+                paper: None,
+                velocity: None,
+                waterfall: None
+            }
+        };
+
+        // Act
+        apply_incoming_hangar_version_to_hangar_project(&mut project, &version);
+
+        // Assert
+        assert_that(&project).is_equal_to(expected_project);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_apply_incoming_hangar_version_with_only_waterfall_versions_to_hangar_project() -> Result<()> {
+        // Arrange
+        let mut project= create_test_project();
+
+        let mut expected_project = project.clone();
+        expected_project.version_name = Some("v1.2.3".to_string());
+        expected_project.latest_minecraft_version = Some("1.21".to_string());
+        expected_project.waterfall_versions = vec!["1.20".to_string(), "1.21".to_string()];
+        expected_project.platforms = vec![ServerPlatform::Waterfall];
+        expected_project.minecraft_versions = expected_project.waterfall_versions.clone();
+
+        let version = IncomingHangarVersion {
+            name: "v1.2.3".to_string(),
+            platform_dependencies: IncomingHangarVersionProjectDependencies {
+                paper: None,
+                velocity: None,
+                waterfall: Some(vec!["1.21".to_string(), "1.20".to_string()])
             }
         };
 
@@ -325,6 +415,60 @@ pub mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_apply_incoming_hangar_version_with_only_velocity_versions_to_hangar_project() -> Result<()> {
+        // Arrange
+        let mut project= create_test_project();
+
+        let mut expected_project = project.clone();
+        expected_project.version_name = Some("v1.2.3".to_string());
+        expected_project.latest_minecraft_version = None;
+        expected_project.velocity_versions = vec!["3.3".to_string(), "3.4".to_string()];
+        expected_project.platforms = vec![ServerPlatform::Velocity];
+
+        let version = IncomingHangarVersion {
+            name: "v1.2.3".to_string(),
+            platform_dependencies: IncomingHangarVersionProjectDependencies {
+                paper: None,
+                velocity: Some(vec!["3.4".to_string(), "3.3".to_string()]),
+                waterfall: None
+            }
+        };
+
+        // Act
+        apply_incoming_hangar_version_to_hangar_project(&mut project, &version);
+
+        // Assert
+        assert_that(&project).is_equal_to(expected_project);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_keep_folia_support_when_applying_incoming_hangar_version_to_hangar_project() -> Result<()> {
+        // Arrange
+        let mut project= create_test_project();
+        project.platforms = vec![ServerPlatform::Folia];
+
+        let mut expected_project = project.clone();
+        expected_project.version_name = Some("v1.2.3".to_string());
+        expected_project.latest_minecraft_version = Some("1.21.3".to_string());
+        expected_project.paper_versions = vec!["1.8".to_string(), "1.9".to_string(), "1.21.2".to_string(), "1.21.3".to_string()];
+        expected_project.velocity_versions = vec!["3.3".to_string(), "3.4".to_string()];
+        expected_project.platforms = vec![ServerPlatform::Paper, ServerPlatform::Folia, ServerPlatform::Velocity];
+        expected_project.minecraft_versions = expected_project.paper_versions.clone();
+
+        let version = create_test_version();
+
+        // Act
+        apply_incoming_hangar_version_to_hangar_project(&mut project, &version);
+
+        // Assert
+        assert_that(&project).is_equal_to(expected_project);
+
+        Ok(())
+    }
+
     fn create_test_project() -> HangarProject {
         HangarProject {
             slug: "foo".to_string(),
@@ -346,7 +490,14 @@ pub mod test {
                 owner: "alice".to_string(),
                 name: "foo".to_string(),
                 id: None
-            })
+            }),
+            paper_versions: vec![],
+            velocity_versions: vec![],
+            waterfall_versions: vec![],
+            platforms: vec![],
+            categories: vec![],
+            minecraft_versions: vec![],
+            full_description: None
         }
     }
 
@@ -354,7 +505,9 @@ pub mod test {
         IncomingHangarVersion {
             name: "v1.2.3".to_string(),
             platform_dependencies: IncomingHangarVersionProjectDependencies {
-                paper: Some(vec!["1.21.2".to_string(), "1.21.3".to_string(), "1.8".to_string(), "1.9".to_string()])
+                paper: Some(vec!["1.21.2".to_string(), "1.21.3".to_string(), "1.8".to_string(), "1.9".to_string()]),
+                velocity: Some(vec!["3.4".to_string(), "3.3".to_string()]),
+                waterfall: None
             }
         }
     }
@@ -0,0 +1,341 @@
+use crate::metrics::{time_db_upsert, UpstreamServer};
+use crate::retry::{RetryPolicy, send_with_retry};
+
+use mc_plugin_finder::database::icon::{Icon, ProjectIcon, ProjectIconSource, delete_unused_icons, get_outdated_project_icon_sources, icon_exists, insert_icon, upsert_project_icon};
+use mc_plugin_finder::database::ingest_log::IngestLogRepository;
+
+use anyhow::Result;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use deadpool_postgres::Pool;
+use futures::stream::{self, StreamExt};
+use governor::{Quota, RateLimiter};
+use governor::clock::QuantaClock;
+use governor::state::{InMemoryState, NotKeyed};
+use image::{DynamicImage, ImageError, ImageFormat};
+use image::imageops::FilterType;
+use nonzero_ext::*;
+use reqwest::{Client, StatusCode};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use tracing::{info, instrument, warn};
+use url::Url;
+
+const ICON_USER_AGENT: &str = concat!("Frumple/mc-plugin-finder/", env!("CARGO_PKG_VERSION"), " (contact@mcpluginfinder.com)");
+const ICON_RATE_LIMIT_PER_SECOND: NonZeroU32 = nonzero!(10u32);
+const ICON_CONCURRENT_FUTURES: usize = 10;
+
+/// Icons are displayed at 75x75 pixels, so they are stored at up to twice that size for high-DPI displays.
+const ICON_SIZE: u32 = 150;
+
+#[derive(Debug, Error)]
+enum CacheIconError {
+    #[error("Skipping icon for {repository:?} project {project_id}: Invalid icon data: {source}")]
+    InvalidIconData {
+        repository: IngestLogRepository,
+        project_id: String,
+        source: base64::DecodeError
+    },
+    #[error("Skipping icon for {repository:?} project {project_id}: Invalid icon URL {url}: {source}")]
+    InvalidIconUrl {
+        repository: IngestLogRepository,
+        project_id: String,
+        url: String,
+        source: url::ParseError
+    },
+    #[error("Skipping icon for {repository:?} project {project_id}: Received unexpected status code {status_code} from {url}")]
+    UnexpectedStatusCode {
+        repository: IngestLogRepository,
+        project_id: String,
+        url: String,
+        status_code: u16
+    },
+    #[error("Skipping icon for {repository:?} project {project_id}: Invalid image: {source}")]
+    InvalidImage {
+        repository: IngestLogRepository,
+        project_id: String,
+        source: ImageError
+    }
+}
+
+/// Downloads the icons of upstream projects, and stores resized copies of them in the database so that the web app can serve them directly.
+#[derive(Debug)]
+pub struct IconClient {
+    api_client: Client,
+    rate_limiter: RateLimiter<NotKeyed, InMemoryState, QuantaClock>,
+    retry_policy: RetryPolicy
+}
+
+impl IconClient {
+    pub fn new() -> Result<IconClient> {
+        let api_client = reqwest::Client::builder()
+            .user_agent(ICON_USER_AGENT)
+            .build()?;
+
+        let quota = Quota::per_second(ICON_RATE_LIMIT_PER_SECOND);
+        let rate_limiter = RateLimiter::direct(quota);
+
+        Ok(Self { api_client, rate_limiter, retry_policy: RetryPolicy::default() })
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Caches the icon of every project that has a new or changed icon URL, then deletes icons that are no longer used.
+    /// Icons that cannot be retrieved or decoded are logged and skipped, so they are attempted again on the next run.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn cache_project_icons(&self, db_pool: &Pool) -> Result<()> {
+        let sources = get_outdated_project_icon_sources(db_pool).await?;
+        info!("Caching icons of {} projects...", sources.len());
+
+        let count = AtomicU32::new(0);
+
+        stream::iter(sources)
+            .for_each_concurrent(ICON_CONCURRENT_FUTURES, |source| self.process_project_icon_source(source, db_pool, &count))
+            .await;
+
+        let deleted = delete_unused_icons(db_pool).await?;
+        info!("Project icons cached: {}, unused icons deleted: {}", count.load(Ordering::Relaxed), deleted);
+
+        Ok(())
+    }
+
+    async fn process_project_icon_source(&self, source: ProjectIconSource, db_pool: &Pool, count: &AtomicU32) {
+        match self.cache_project_icon(&source, db_pool).await {
+            Ok(()) => {
+                count.fetch_add(1, Ordering::Relaxed);
+            },
+            Err(err) => warn!("{}", err)
+        }
+    }
+
+    async fn cache_project_icon(&self, source: &ProjectIconSource, db_pool: &Pool) -> Result<()> {
+        // Spigot icons are already provided by the Spiget API, so only the other repositories need to be downloaded from.
+        let original = match &source.icon_data {
+            Some(data) => decode_icon_data(source, data)?,
+            None => self.download_icon(source).await?
+        };
+
+        let hash = hash_icon(&original);
+
+        // Identical icons are only resized and stored once.
+        if !icon_exists(db_pool, &hash).await? {
+            let repository = source.repository.clone();
+            let project_id = source.project_id.clone();
+            let icon_hash = hash.clone();
+
+            let icon = tokio::task::spawn_blocking(move || create_icon(icon_hash, &original)).await?
+                .map_err(|source| CacheIconError::InvalidImage {
+                    repository,
+                    project_id,
+                    source
+                })?;
+
+            time_db_upsert("icon", insert_icon(db_pool, &icon)).await?;
+        }
+
+        let project_icon = ProjectIcon {
+            repository: source.repository.clone(),
+            project_id: source.project_id.clone(),
+            source_url: source.icon_url.clone(),
+            hash
+        };
+
+        time_db_upsert("project_icon", upsert_project_icon(db_pool, &project_icon)).await
+    }
+
+    async fn download_icon(&self, source: &ProjectIconSource) -> Result<Vec<u8>> {
+        let url = Url::parse(&source.icon_url)
+            .map_err(|err| CacheIconError::InvalidIconUrl {
+                repository: source.repository.clone(),
+                project_id: source.project_id.clone(),
+                url: source.icon_url.clone(),
+                source: err
+            })?;
+
+        let raw_response = send_with_retry(self.api_client.get(url), &self.retry_policy, UpstreamServer::Icon, || self.rate_limiter.until_ready()).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => Ok(raw_response.bytes().await?.to_vec()),
+            _ => Err(
+                CacheIconError::UnexpectedStatusCode {
+                    repository: source.repository.clone(),
+                    project_id: source.project_id.clone(),
+                    url: source.icon_url.clone(),
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+}
+
+fn decode_icon_data(source: &ProjectIconSource, data: &str) -> Result<Vec<u8>> {
+    let original = STANDARD.decode(data)
+        .map_err(|err| CacheIconError::InvalidIconData {
+            repository: source.repository.clone(),
+            project_id: source.project_id.clone(),
+            source: err
+        })?;
+
+    Ok(original)
+}
+
+fn hash_icon(original: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(original))
+}
+
+/// Decodes the original image, shrinks it to fit within the icon size while keeping its aspect ratio, and encodes it as both WebP and PNG.
+/// Images that are already small enough are not enlarged.
+fn create_icon(hash: String, original: &[u8]) -> Result<Icon, ImageError> {
+    let mut image = image::load_from_memory(original)?;
+
+    if image.width() > ICON_SIZE || image.height() > ICON_SIZE {
+        image = image.resize(ICON_SIZE, ICON_SIZE, FilterType::Lanczos3);
+    }
+
+    // The WebP encoder only supports 8-bit RGB(A) images.
+    let image = DynamicImage::ImageRgba8(image.to_rgba8());
+
+    Ok(Icon {
+        hash,
+        webp: encode_image(&image, ImageFormat::WebP)?,
+        png: encode_image(&image, ImageFormat::Png)?
+    })
+}
+
+fn encode_image(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ImageError> {
+    let mut buffer = Vec::new();
+    image.write_to(&mut Cursor::new(&mut buffer), format)?;
+
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::retry::test::create_test_retry_policy;
+
+    use image::RgbaImage;
+    use speculoos::prelude::*;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use wiremock::matchers::{method, path};
+
+    #[test]
+    fn should_create_resized_icon() -> Result<()> {
+        // Arrange
+        let original = create_test_png(300, 200)?;
+
+        // Act
+        let icon = create_icon(hash_icon(&original), &original)?;
+
+        // Assert
+        let webp = image::load_from_memory_with_format(&icon.webp, ImageFormat::WebP)?;
+        let png = image::load_from_memory_with_format(&icon.png, ImageFormat::Png)?;
+
+        assert_that(&icon.hash.len()).is_equal_to(64);
+        assert_that(&(webp.width(), webp.height())).is_equal_to((150, 100));
+        assert_that(&(png.width(), png.height())).is_equal_to((150, 100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_enlarge_small_icon() -> Result<()> {
+        // Arrange
+        let original = create_test_png(32, 32)?;
+
+        // Act
+        let icon = create_icon(hash_icon(&original), &original)?;
+
+        // Assert
+        let png = image::load_from_memory_with_format(&icon.png, ImageFormat::Png)?;
+        assert_that(&(png.width(), png.height())).is_equal_to((32, 32));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_decode_spigot_icon_data() -> Result<()> {
+        // Arrange
+        let original = create_test_png(32, 32)?;
+        let source = create_test_project_icon_source(IngestLogRepository::Spigot, "data/resource_icons/1/1.jpg", Some(STANDARD.encode(&original)));
+
+        // Act
+        let decoded = decode_icon_data(&source, source.icon_data.as_ref().unwrap())?;
+
+        // Assert
+        assert_that(&hash_icon(&decoded)).is_equal_to(hash_icon(&original));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_download_icon() -> Result<()> {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        let original = create_test_png(32, 32)?;
+
+        Mock::given(method("GET"))
+            .and(path("/data/aaaaaaaa/icon.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(original.clone()))
+            .mount(&mock_server)
+            .await;
+
+        let icon_client = IconClient::new()?.with_retry_policy(create_test_retry_policy());
+        let source = create_test_project_icon_source(IngestLogRepository::Modrinth, &format!("{}/data/aaaaaaaa/icon.png", mock_server.uri()), None);
+
+        // Act
+        let downloaded = icon_client.download_icon(&source).await?;
+
+        // Assert
+        assert_that(&downloaded).is_equal_to(original);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_download_missing_icon() -> Result<()> {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/data/aaaaaaaa/icon.png"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let icon_client = IconClient::new()?.with_retry_policy(create_test_retry_policy());
+        let source = create_test_project_icon_source(IngestLogRepository::Modrinth, &format!("{}/data/aaaaaaaa/icon.png", mock_server.uri()), None);
+
+        // Act
+        let result = icon_client.download_icon(&source).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        Ok(())
+    }
+
+    fn create_test_png(width: u32, height: u32) -> Result<Vec<u8>> {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(width, height, image::Rgba([255, 0, 0, 255])));
+
+        Ok(encode_image(&image, ImageFormat::Png)?)
+    }
+
+    fn create_test_project_icon_source(repository: IngestLogRepository, icon_url: &str, icon_data: Option<String>) -> ProjectIconSource {
+        ProjectIconSource {
+            repository,
+            project_id: "1".to_string(),
+            icon_url: icon_url.to_string(),
+            icon_data
+        }
+    }
+}
\ No newline at end of file
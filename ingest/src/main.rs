@@ -1,16 +1,38 @@
+use crate::builtbybit::{BuiltByBitClient, BuiltByBitServer};
+use crate::cassette::{Cassette, use_cassette};
+use crate::curseforge::{CurseForgeClient, CurseForgeServer};
+use crate::daemon::{DaemonIntervals, run_daemon};
+use crate::dry_run::DryRun;
 use crate::hangar::{HangarClient, HangarServer};
+use crate::icon::IconClient;
+use crate::metrics::serve_metrics;
 use crate::modrinth::{ModrinthClient, ModrinthServer};
+use crate::polymart::{PolymartClient, PolymartServer};
+use crate::retry::RetryPolicy;
+use crate::snapshot::{export_snapshot, import_snapshot};
+use crate::source_repository::{ForgeServer, SourceRepositoryClient, SourceRepositoryServer};
 use crate::spigot::{SpigotClient, SpigotServer};
 
+use mc_plugin_finder::config::{get_config_string, get_optional_config_string};
 use mc_plugin_finder::database::get_db;
+use mc_plugin_finder::database::ingest_lock::try_acquire_ingest_lock;
+use mc_plugin_finder::database::project_stats_snapshot::{insert_project_stats_snapshots, prune_project_stats_snapshots};
 
+use mc_plugin_finder::database::builtbybit::resource::get_latest_builtbybit_resource_update_date;
+use mc_plugin_finder::database::curseforge::project::get_latest_curseforge_project_update_date;
 use mc_plugin_finder::database::hangar::project::get_latest_hangar_project_update_date;
 use mc_plugin_finder::database::modrinth::project::get_latest_modrinth_project_update_date;
+use mc_plugin_finder::database::polymart::resource::get_latest_polymart_resource_update_date;
 use mc_plugin_finder::database::spigot::resource::get_latest_spigot_resource_update_date;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
 use deadpool_postgres::Pool;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use thiserror::Error;
+use time::OffsetDateTime;
 use tracing::{info, warn};
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::EnvFilter;
@@ -18,8 +40,21 @@ use tracing_subscriber::fmt::Layer;
 use tracing_subscriber::fmt::format::FmtSpan;
 use url::Url;
 
+pub mod builtbybit;
+pub mod cassette;
+pub mod curseforge;
+pub mod daemon;
+pub mod description;
+pub mod dry_run;
+pub mod failure;
 pub mod hangar;
+pub mod icon;
+pub mod metrics;
 pub mod modrinth;
+pub mod polymart;
+pub mod retry;
+pub mod snapshot;
+pub mod source_repository;
 pub mod spigot;
 
 pub trait HttpServer {
@@ -40,7 +75,35 @@ struct CommandLineArguments {
 
     /// Refresh common projects after the populate/update operation
     #[arg(global = true, short, long)]
-    refresh: bool
+    refresh: bool,
+
+    /// Resume a previously interrupted populate operation from its last saved checkpoint
+    #[arg(global = true, long)]
+    resume: bool,
+
+    /// Mark items that were not seen during the populate operation as deleted: Run this only with a full populate, and not with CurseForge projects
+    #[arg(global = true, long)]
+    reconcile: bool,
+
+    /// Fetch and convert items as normal, but log how they differ from the current rows instead of writing anything to the database
+    #[arg(global = true, long)]
+    dry_run: bool,
+
+    /// Save every upstream request and response to a cassette directory, so that the run can be replayed later
+    #[arg(global = true, long, value_name = "DIRECTORY", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Serve upstream requests from a cassette directory recorded with --record, instead of sending them over the network
+    #[arg(global = true, long, value_name = "DIRECTORY")]
+    replay: Option<PathBuf>,
+
+    /// Serve Prometheus metrics for this run at /metrics on the given address, such as 0.0.0.0:9898
+    #[arg(global = true, long, value_name = "ADDRESS")]
+    metrics_address: Option<SocketAddr>,
+
+    /// Maximum number of times to retry an upstream API request that failed with a transient error
+    #[arg(global = true, long, default_value_t = 5)]
+    max_retries: u32
 }
 
 #[derive(Subcommand)]
@@ -54,6 +117,21 @@ enum ActionSubcommand {
     Update {
         #[command(subcommand)]
         repository: UpdateRepositorySubcommand
+    },
+    /// Keep running, updating each repository on its own interval and then fixing and refreshing: Stops gracefully on SIGTERM or Ctrl+C
+    Daemon {
+        #[command(flatten)]
+        intervals: DaemonIntervals
+    },
+    /// Re-fetch only the items that previously failed to be converted or written, as recorded in the ingest failures table
+    RetryFailures,
+    /// Write Spigot authors and resources, Modrinth projects and versions, Hangar, CurseForge, Polymart, and BuiltByBit projects/resources, source repository releases and metadata, project stats snapshots, and ingest logs to versioned NDJSON files in a directory
+    Export {
+        directory: PathBuf
+    },
+    /// Load NDJSON files written by export into an empty database, then refresh common projects
+    Import {
+        directory: PathBuf
     }
 }
 
@@ -73,6 +151,28 @@ enum PopulateRepositorySubcommand {
     Hangar {
         #[arg(value_enum)]
         item: PopulateHangarItems
+    },
+    /// CurseForge projects
+    #[command(name = "curseforge")]
+    CurseForge {
+        #[arg(value_enum)]
+        item: PopulateCurseForgeItems
+    },
+    /// Polymart resources
+    Polymart {
+        #[arg(value_enum)]
+        item: PopulatePolymartItems
+    },
+    /// BuiltByBit resources or versions
+    #[command(name = "builtbybit")]
+    BuiltByBit {
+        #[arg(value_enum)]
+        item: PopulateBuiltByBitItems
+    },
+    /// Latest releases or metadata of linked GitHub, GitLab, and Codeberg source repositories: Run this after refreshing common projects
+    SourceRepository {
+        #[arg(value_enum)]
+        item: PopulateSourceRepositoryItems
     }
 }
 
@@ -95,6 +195,28 @@ enum PopulateHangarItems {
     Versions
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum PopulateCurseForgeItems {
+    Projects
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum PopulatePolymartItems {
+    Resources
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum PopulateBuiltByBitItems {
+    Resources,
+    Versions
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum PopulateSourceRepositoryItems {
+    Releases,
+    Metadata
+}
+
 #[derive(Subcommand)]
 enum UpdateRepositorySubcommand {
     /// Spigot resources
@@ -112,6 +234,23 @@ enum UpdateRepositorySubcommand {
         #[arg(value_enum)]
         item: UpdateHangarItems
     },
+    /// CurseForge projects
+    #[command(name = "curseforge")]
+    CurseForge {
+        #[arg(value_enum)]
+        item: UpdateCurseForgeItems
+    },
+    /// Polymart resources
+    Polymart {
+        #[arg(value_enum)]
+        item: UpdatePolymartItems
+    },
+    /// BuiltByBit resources
+    #[command(name = "builtbybit")]
+    BuiltByBit {
+        #[arg(value_enum)]
+        item: UpdateBuiltByBitItems
+    },
     /// All authors, resources, and projects
     All
 }
@@ -131,6 +270,33 @@ enum UpdateHangarItems {
     Projects
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum UpdateCurseForgeItems {
+    Projects
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum UpdatePolymartItems {
+    Resources
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum UpdateBuiltByBitItems {
+    Resources
+}
+
+#[derive(Debug, Error)]
+enum IngestError {
+    #[error("Another ingest run is in progress, please try again once it has finished")]
+    IngestLockHeld,
+    #[error("The daemon does not support --dry-run")]
+    DryRunDaemon,
+    #[error("Export and import do not support --dry-run")]
+    DryRunSnapshot,
+    #[error("Populating CurseForge projects does not support --reconcile, since projects updated during the run can be missed")]
+    ReconcileCurseForge
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Parse command line arguments
@@ -165,20 +331,69 @@ async fn main() -> Result<()> {
     let db = get_db();
     let db_pool = db.create_pool().await?;
 
+    // Record or replay upstream requests for all clients
+    if let Some(directory) = &cli.record {
+        use_cassette(Cassette::record(directory).await?)?;
+    } else if let Some(directory) = &cli.replay {
+        use_cassette(Cassette::replay(directory).await?)?;
+    }
+
+    if let Some(address) = cli.metrics_address {
+        tokio::spawn(async move {
+            if let Err(err) = serve_metrics(address).await {
+                warn!("Metrics server stopped: {}", err);
+            }
+        });
+    }
+
+    let retry_policy = RetryPolicy {
+        max_retries: cli.max_retries,
+        ..Default::default()
+    };
+
+    // The daemon holds the ingest lock during each of its own runs, and always fixes and refreshes afterwards.
+    if let Some(ActionSubcommand::Daemon { intervals }) = &cli.action {
+        if cli.dry_run {
+            return Err(IngestError::DryRunDaemon.into());
+        }
+        return run_daemon(&db_pool, &retry_policy, intervals).await;
+    }
+
+    if let Some(ActionSubcommand::Export { .. } | ActionSubcommand::Import { .. }) = &cli.action {
+        if cli.dry_run {
+            return Err(IngestError::DryRunSnapshot.into());
+        }
+    }
+
+    if let Some(ActionSubcommand::Populate { repository: PopulateRepositorySubcommand::CurseForge { .. } }) = &cli.action {
+        if cli.reconcile {
+            return Err(IngestError::ReconcileCurseForge.into());
+        }
+    }
+
+    // A dry run writes nothing, so it neither needs the ingest lock nor fixes or refreshes afterwards.
+    let dry_run = cli.dry_run.then(|| Arc::new(DryRun::default()));
+
+    // Prevent this run from overlapping with the daemon or another ingest run
+    let ingest_lock = match dry_run {
+        Some(_) => None,
+        None => Some(try_acquire_ingest_lock(&db_pool).await?.ok_or(IngestError::IngestLockHeld)?)
+    };
+
     if let Some(action) = &cli.action {
         match &action {
             ActionSubcommand::Populate { repository } => {
                 match repository {
                     PopulateRepositorySubcommand::Spigot { item } => {
                         let spigot_server = SpigotServer::new().await;
-                        let spigot_client = SpigotClient::new(spigot_server)?;
+                        let spigot_client = SpigotClient::new(spigot_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
                         match item {
                             PopulateSpigotItems::Authors => {
                                 populate_spigot_authors(&spigot_client, &db_pool).await?;
                             },
                             PopulateSpigotItems::Resources => {
-                                populate_spigot_resources(&spigot_client, &db_pool).await?;
+                                populate_spigot_resources(&spigot_client, &db_pool, cli.resume, cli.reconcile).await?;
                             },
                             PopulateSpigotItems::Versions => {
                                 populate_spigot_versions(&spigot_client, &db_pool).await?;
@@ -187,11 +402,11 @@ async fn main() -> Result<()> {
                     },
                     PopulateRepositorySubcommand::Modrinth { item } => {
                         let modrinth_server = ModrinthServer::new().await;
-                        let modrinth_client = ModrinthClient::new(modrinth_server)?;
+                        let modrinth_client = ModrinthClient::new(modrinth_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
                         match item {
                              PopulateModrinthItems::Projects => {
-                                populate_modrinth_projects(&modrinth_client, &db_pool).await?;
+                                populate_modrinth_projects(&modrinth_client, &db_pool, cli.resume, cli.reconcile).await?;
                             },
                             PopulateModrinthItems::Versions => {
                                 populate_modrinth_versions(&modrinth_client, &db_pool).await?;
@@ -200,24 +415,70 @@ async fn main() -> Result<()> {
                     },
                     PopulateRepositorySubcommand::Hangar { item } => {
                         let hangar_server = HangarServer::new().await;
-                        let hangar_client = HangarClient::new(hangar_server)?;
+                        let hangar_client = HangarClient::new(hangar_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
                         match item {
                             PopulateHangarItems::Projects => {
-                               populate_hangar_projects(&hangar_client, &db_pool).await?;
+                               populate_hangar_projects(&hangar_client, &db_pool, cli.resume, cli.reconcile).await?;
                            },
                            PopulateHangarItems::Versions => {
                                populate_hangar_versions(&hangar_client, &db_pool).await?;
                            }
                        }
                     },
+                    PopulateRepositorySubcommand::CurseForge { item } => {
+                        let curseforge_server = CurseForgeServer::new().await;
+                        let curseforge_client = CurseForgeClient::new(curseforge_server, &get_config_string("curseforge.api.key"))?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            PopulateCurseForgeItems::Projects => {
+                                populate_curseforge_projects(&curseforge_client, &db_pool, cli.resume).await?;
+                            }
+                        }
+                    },
+                    PopulateRepositorySubcommand::Polymart { item } => {
+                        let polymart_server = PolymartServer::new().await;
+                        let polymart_client = PolymartClient::new(polymart_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            PopulatePolymartItems::Resources => {
+                                populate_polymart_resources(&polymart_client, &db_pool, cli.resume, cli.reconcile).await?;
+                            }
+                        }
+                    },
+                    PopulateRepositorySubcommand::BuiltByBit { item } => {
+                        let builtbybit_server = BuiltByBitServer::new().await;
+                        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, &get_config_string("builtbybit.api.token"))?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            PopulateBuiltByBitItems::Resources => {
+                                populate_builtbybit_resources(&builtbybit_client, &db_pool, cli.resume, cli.reconcile).await?;
+                            }
+                            PopulateBuiltByBitItems::Versions => {
+                                populate_builtbybit_versions(&builtbybit_client, &db_pool).await?;
+                            }
+                        }
+                    },
+                    PopulateRepositorySubcommand::SourceRepository { item } => {
+                        let source_repository_server = SourceRepositoryServer::new().await;
+                        let source_repository_client = SourceRepositoryClient::new(source_repository_server, get_optional_config_string("github.api.token").as_deref())?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            PopulateSourceRepositoryItems::Releases => {
+                                populate_source_repository_releases(&source_repository_client, &db_pool).await?;
+                            },
+                            PopulateSourceRepositoryItems::Metadata => {
+                                populate_source_repository_metadata(&source_repository_client, &db_pool).await?;
+                            }
+                        }
+                    },
                 }
             },
             ActionSubcommand::Update { repository } => {
                 match repository {
                     UpdateRepositorySubcommand::Spigot { item } => {
                         let spigot_server = SpigotServer::new().await;
-                        let spigot_client = SpigotClient::new(spigot_server)?;
+                        let spigot_client = SpigotClient::new(spigot_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
                         match item {
                             UpdateSpigotItems::Resources => {
@@ -227,7 +488,7 @@ async fn main() -> Result<()> {
                     },
                     UpdateRepositorySubcommand::Modrinth { item } => {
                         let modrinth_server = ModrinthServer::new().await;
-                        let modrinth_client = ModrinthClient::new(modrinth_server)?;
+                        let modrinth_client = ModrinthClient::new(modrinth_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
                         match item {
                             UpdateModrinthItems::Projects => {
@@ -237,7 +498,7 @@ async fn main() -> Result<()> {
                     },
                     UpdateRepositorySubcommand::Hangar { item } => {
                         let hangar_server = HangarServer::new().await;
-                        let hangar_client = HangarClient::new(hangar_server)?;
+                        let hangar_client = HangarClient::new(hangar_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
                         match item {
                             UpdateHangarItems::Projects => {
@@ -245,14 +506,66 @@ async fn main() -> Result<()> {
                             }
                         }
                     },
+                    UpdateRepositorySubcommand::CurseForge { item } => {
+                        let curseforge_server = CurseForgeServer::new().await;
+                        let curseforge_client = CurseForgeClient::new(curseforge_server, &get_config_string("curseforge.api.key"))?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            UpdateCurseForgeItems::Projects => {
+                                update_curseforge_projects(&curseforge_client, &db_pool).await?;
+                            }
+                        }
+                    },
+                    UpdateRepositorySubcommand::Polymart { item } => {
+                        let polymart_server = PolymartServer::new().await;
+                        let polymart_client = PolymartClient::new(polymart_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            UpdatePolymartItems::Resources => {
+                                update_polymart_resources(&polymart_client, &db_pool).await?;
+                            }
+                        }
+                    },
+                    UpdateRepositorySubcommand::BuiltByBit { item } => {
+                        let builtbybit_server = BuiltByBitServer::new().await;
+                        let builtbybit_client = BuiltByBitClient::new(builtbybit_server, &get_config_string("builtbybit.api.token"))?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+                        match item {
+                            UpdateBuiltByBitItems::Resources => {
+                                update_builtbybit_resources(&builtbybit_client, &db_pool).await?;
+                            }
+                        }
+                    },
                     UpdateRepositorySubcommand::All => {
-                        update_all(&db_pool).await?;
+                        update_all(&db_pool, &retry_policy, &dry_run).await?;
                     }
                 }
-            }
+            },
+            ActionSubcommand::RetryFailures => {
+                retry_failures(&db_pool, &retry_policy, &dry_run).await?;
+            },
+            ActionSubcommand::Export { directory } => {
+                export_snapshot(&db_pool, directory).await?;
+            },
+            ActionSubcommand::Import { directory } => {
+                import_snapshot(&db_pool, directory).await?;
+            },
+            ActionSubcommand::Daemon { .. } => unreachable!("the daemon is started before acquiring the ingest lock")
         }
     }
 
+    if let Some(dry_run) = &dry_run {
+        dry_run.log_summary();
+        return Ok(());
+    }
+
+    // Record the stats of every project after each populate/update, so that they can be compared over time
+    // Also cache the icons of any new projects, or projects whose icons have changed
+    if let Some(ActionSubcommand::Populate { .. } | ActionSubcommand::Update { .. }) = &cli.action {
+        snapshot_project_stats(&db_pool).await?;
+        cache_project_icons(&db_pool, &retry_policy).await?;
+    }
+
     // Fix upstream items if specified
     if cli.fix {
         fix_upstream_errors(&db_pool).await?;
@@ -263,6 +576,10 @@ async fn main() -> Result<()> {
         refresh_common_projects(&db_pool).await?;
     }
 
+    if let Some(ingest_lock) = ingest_lock {
+        ingest_lock.release().await?;
+    }
+
     Ok(())
 }
 
@@ -272,8 +589,8 @@ async fn populate_spigot_authors(spigot_client: &SpigotClient<SpigotServer>, db_
     Ok(())
 }
 
-async fn populate_spigot_resources(spigot_client: &SpigotClient<SpigotServer>, db_pool: &Pool) -> Result<()> {
-    spigot_client.populate_spigot_resources(db_pool).await?;
+async fn populate_spigot_resources(spigot_client: &SpigotClient<SpigotServer>, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+    spigot_client.populate_spigot_resources(db_pool, resume, reconcile).await?;
 
     Ok(())
 }
@@ -284,8 +601,8 @@ async fn populate_spigot_versions(spigot_client: &SpigotClient<SpigotServer>, db
     Ok(())
 }
 
-async fn populate_modrinth_projects(modrinth_client: &ModrinthClient<ModrinthServer>, db_pool: &Pool) -> Result<()> {
-    modrinth_client.populate_modrinth_projects(db_pool).await?;
+async fn populate_modrinth_projects(modrinth_client: &ModrinthClient<ModrinthServer>, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+    modrinth_client.populate_modrinth_projects(db_pool, resume, reconcile).await?;
 
     Ok(())
 }
@@ -296,8 +613,8 @@ async fn populate_modrinth_versions(modrinth_client: &ModrinthClient<ModrinthSer
     Ok(())
 }
 
-async fn populate_hangar_projects(hangar_client: &HangarClient<HangarServer>, db_pool: &Pool) -> Result<()> {
-    hangar_client.populate_hangar_projects(db_pool).await?;
+async fn populate_hangar_projects(hangar_client: &HangarClient<HangarServer>, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+    hangar_client.populate_hangar_projects(db_pool, resume, reconcile).await?;
 
     Ok(())
 }
@@ -308,6 +625,42 @@ async fn populate_hangar_versions(hangar_client: &HangarClient<HangarServer>, db
     Ok(())
 }
 
+async fn populate_curseforge_projects(curseforge_client: &CurseForgeClient<CurseForgeServer>, db_pool: &Pool, resume: bool) -> Result<()> {
+    curseforge_client.populate_curseforge_projects(db_pool, resume).await?;
+
+    Ok(())
+}
+
+async fn populate_polymart_resources(polymart_client: &PolymartClient<PolymartServer>, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+    polymart_client.populate_polymart_resources(db_pool, resume, reconcile).await?;
+
+    Ok(())
+}
+
+async fn populate_builtbybit_resources(builtbybit_client: &BuiltByBitClient<BuiltByBitServer>, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+    builtbybit_client.populate_builtbybit_resources(db_pool, resume, reconcile).await?;
+
+    Ok(())
+}
+
+async fn populate_builtbybit_versions(builtbybit_client: &BuiltByBitClient<BuiltByBitServer>, db_pool: &Pool) -> Result<()> {
+    builtbybit_client.populate_builtbybit_versions(db_pool).await?;
+
+    Ok(())
+}
+
+async fn populate_source_repository_releases(source_repository_client: &SourceRepositoryClient<SourceRepositoryServer>, db_pool: &Pool) -> Result<()> {
+    source_repository_client.populate_source_repository_releases(db_pool).await?;
+
+    Ok(())
+}
+
+async fn populate_source_repository_metadata(source_repository_client: &SourceRepositoryClient<SourceRepositoryServer>, db_pool: &Pool) -> Result<()> {
+    source_repository_client.populate_source_repository_metadata(db_pool).await?;
+
+    Ok(())
+}
+
 async fn update_spigot_resources(spigot_client: &SpigotClient<SpigotServer>, db_pool: &Pool) -> Result<()> {
     let latest_update_date = get_latest_spigot_resource_update_date(db_pool).await?;
     spigot_client.update_spigot_resources(db_pool, latest_update_date).await?;
@@ -329,17 +682,41 @@ async fn update_hangar_projects(hangar_client: &HangarClient<HangarServer>, db_p
     Ok(())
 }
 
-async fn update_all(db_pool: &Pool) -> Result<()> {
+async fn update_curseforge_projects(curseforge_client: &CurseForgeClient<CurseForgeServer>, db_pool: &Pool) -> Result<()> {
+    let latest_update_date = get_latest_curseforge_project_update_date(db_pool).await?;
+    curseforge_client.update_curseforge_projects(db_pool, latest_update_date).await?;
+
+    Ok(())
+}
+
+async fn update_polymart_resources(polymart_client: &PolymartClient<PolymartServer>, db_pool: &Pool) -> Result<()> {
+    let latest_update_date = get_latest_polymart_resource_update_date(db_pool).await?;
+    polymart_client.update_polymart_resources(db_pool, latest_update_date).await?;
+
+    Ok(())
+}
+
+async fn update_builtbybit_resources(builtbybit_client: &BuiltByBitClient<BuiltByBitServer>, db_pool: &Pool) -> Result<()> {
+    let latest_update_date = get_latest_builtbybit_resource_update_date(db_pool).await?;
+    builtbybit_client.update_builtbybit_resources(db_pool, latest_update_date).await?;
+
+    Ok(())
+}
+
+async fn update_all(db_pool: &Pool, retry_policy: &RetryPolicy, dry_run: &Option<Arc<DryRun>>) -> Result<()> {
     info!("Updating all items...");
 
     let spigot_server = SpigotServer::new().await;
-    let spigot_client = SpigotClient::new(spigot_server)?;
+    let spigot_client = SpigotClient::new(spigot_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
     let modrinth_server = ModrinthServer::new().await;
-    let modrinth_client = ModrinthClient::new(modrinth_server)?;
+    let modrinth_client = ModrinthClient::new(modrinth_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
     let hangar_server = HangarServer::new().await;
-    let hangar_client = HangarClient::new(hangar_server)?;
+    let hangar_client = HangarClient::new(hangar_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+    let polymart_server = PolymartServer::new().await;
+    let polymart_client = PolymartClient::new(polymart_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
 
     let populate_spigot_authors_result = populate_spigot_authors(&spigot_client, db_pool).await;
     if populate_spigot_authors_result.is_ok() {
@@ -347,6 +724,74 @@ async fn update_all(db_pool: &Pool) -> Result<()> {
     }
     let _ = update_modrinth_projects(&modrinth_client, db_pool).await;
     let _ = update_hangar_projects(&hangar_client, db_pool).await;
+    let _ = update_polymart_resources(&polymart_client, db_pool).await;
+
+    // CurseForge is skipped if no API key has been configured.
+    match get_optional_config_string("curseforge.api.key") {
+        Some(api_key) => {
+            let curseforge_server = CurseForgeServer::new().await;
+            let curseforge_client = CurseForgeClient::new(curseforge_server, &api_key)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+            let _ = update_curseforge_projects(&curseforge_client, db_pool).await;
+        },
+        None => warn!("Skipping CurseForge projects: MCPF_CURSEFORGE_API_KEY is not set.")
+    }
+
+    // BuiltByBit is skipped if no API token has been configured.
+    match get_optional_config_string("builtbybit.api.token") {
+        Some(api_token) => {
+            let builtbybit_server = BuiltByBitServer::new().await;
+            let builtbybit_client = BuiltByBitClient::new(builtbybit_server, &api_token)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+            let _ = update_builtbybit_resources(&builtbybit_client, db_pool).await;
+        },
+        None => warn!("Skipping BuiltByBit resources: MCPF_BUILTBYBIT_API_TOKEN is not set.")
+    }
+
+    Ok(())
+}
+
+async fn retry_failures(db_pool: &Pool, retry_policy: &RetryPolicy, dry_run: &Option<Arc<DryRun>>) -> Result<()> {
+    info!("Retrying failed items...");
+
+    let spigot_server = SpigotServer::new().await;
+    let spigot_client = SpigotClient::new(spigot_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+    let modrinth_server = ModrinthServer::new().await;
+    let modrinth_client = ModrinthClient::new(modrinth_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+    let hangar_server = HangarServer::new().await;
+    let hangar_client = HangarClient::new(hangar_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+    let polymart_server = PolymartServer::new().await;
+    let polymart_client = PolymartClient::new(polymart_server)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+    let _ = spigot_client.retry_spigot_resource_failures(db_pool).await;
+    let _ = modrinth_client.retry_modrinth_project_failures(db_pool).await;
+    let _ = hangar_client.retry_hangar_project_failures(db_pool).await;
+    let _ = polymart_client.retry_polymart_resource_failures(db_pool).await;
+
+    // CurseForge is skipped if no API key has been configured.
+    match get_optional_config_string("curseforge.api.key") {
+        Some(api_key) => {
+            let curseforge_server = CurseForgeServer::new().await;
+            let curseforge_client = CurseForgeClient::new(curseforge_server, &api_key)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+            let _ = curseforge_client.retry_curseforge_project_failures(db_pool).await;
+        },
+        None => warn!("Skipping CurseForge projects: MCPF_CURSEFORGE_API_KEY is not set.")
+    }
+
+    // BuiltByBit is skipped if no API token has been configured.
+    match get_optional_config_string("builtbybit.api.token") {
+        Some(api_token) => {
+            let builtbybit_server = BuiltByBitServer::new().await;
+            let builtbybit_client = BuiltByBitClient::new(builtbybit_server, &api_token)?.with_retry_policy(retry_policy.clone()).with_dry_run(dry_run.clone());
+
+            let _ = builtbybit_client.retry_builtbybit_resource_failures(db_pool).await;
+        },
+        None => warn!("Skipping BuiltByBit resources: MCPF_BUILTBYBIT_API_TOKEN is not set.")
+    }
 
     Ok(())
 }
@@ -357,6 +802,23 @@ async fn fix_upstream_errors(db_pool: &Pool) -> Result<()> {
     Ok(())
 }
 
+async fn snapshot_project_stats(db_pool: &Pool) -> Result<()> {
+    let today = OffsetDateTime::now_utc().date();
+
+    let inserted = insert_project_stats_snapshots(db_pool, today).await?;
+    let pruned = prune_project_stats_snapshots(db_pool, today).await?;
+    info!("Project stats snapshots: {} recorded, {} pruned", inserted, pruned);
+
+    Ok(())
+}
+
+async fn cache_project_icons(db_pool: &Pool, retry_policy: &RetryPolicy) -> Result<()> {
+    let icon_client = IconClient::new()?.with_retry_policy(retry_policy.clone());
+    icon_client.cache_project_icons(db_pool).await?;
+
+    Ok(())
+}
+
 async fn refresh_common_projects(db_pool: &Pool) -> Result<()> {
     mc_plugin_finder::database::common::project::refresh_common_projects(db_pool).await?;
 
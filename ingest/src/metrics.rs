@@ -0,0 +1,278 @@
+use mc_plugin_finder::database::ingest_log::{IngestLogRepository, IngestLogItem};
+
+use anyhow::Result;
+use axum::Router;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder};
+use prometheus::core::Collector;
+use reqwest::StatusCode;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tracing::info;
+
+const METRICS_NAMESPACE: &str = "mcpf_ingest";
+
+static REGISTRY: LazyLock<Registry> = LazyLock::new(|| {
+    Registry::new_custom(Some(METRICS_NAMESPACE.to_string()), None).expect("metrics registry should be valid")
+});
+
+static ITEMS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| register(
+    IntCounterVec::new(
+        Opts::new("items_total", "Upstream items handled by populate/update operations, by outcome"),
+        &["repository", "item", "outcome"]
+    )
+));
+
+static UPSTREAM_REQUEST_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| register(
+    HistogramVec::new(
+        HistogramOpts::new("upstream_request_duration_seconds", "Duration of each attempt of an upstream API request, by server and status code")
+            .buckets(prometheus::exponential_buckets(0.05, 2.0, 10).expect("buckets should be valid")),
+        &["server", "status"]
+    )
+));
+
+static RATE_LIMITER_WAIT_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| register(
+    HistogramVec::new(
+        HistogramOpts::new("rate_limiter_wait_seconds", "Time spent waiting for a repository's rate limiter before an upstream API request")
+            .buckets(prometheus::exponential_buckets(0.001, 4.0, 8).expect("buckets should be valid")),
+        &["repository"]
+    )
+));
+
+static DB_UPSERT_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| register(
+    HistogramVec::new(
+        HistogramOpts::new("db_upsert_duration_seconds", "Duration of each database upsert of an upstream item, by table"),
+        &["table"]
+    )
+));
+
+fn register<T: Collector + Clone + 'static>(collector: prometheus::Result<T>) -> T {
+    let collector = collector.expect("metric should be valid");
+    REGISTRY.register(Box::new(collector.clone())).expect("metric should only be registered once");
+    collector
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemOutcome {
+    /// The item was converted and written to the database
+    Processed,
+    /// The item could not be converted from its upstream representation
+    Skipped,
+    /// The item could not be retrieved from upstream, or could not be written to the database
+    Failed
+}
+
+impl ItemOutcome {
+    fn label(&self) -> &'static str {
+        match self {
+            ItemOutcome::Processed => "processed",
+            ItemOutcome::Skipped => "skipped",
+            ItemOutcome::Failed => "failed"
+        }
+    }
+}
+
+fn repository_label(repository: &IngestLogRepository) -> &'static str {
+    match repository {
+        IngestLogRepository::Spigot => "spigot",
+        IngestLogRepository::Modrinth => "modrinth",
+        IngestLogRepository::Hangar => "hangar",
+        IngestLogRepository::CurseForge => "curseforge",
+        IngestLogRepository::Polymart => "polymart",
+        IngestLogRepository::BuiltByBit => "builtbybit",
+        IngestLogRepository::SourceRepository => "source_repository",
+        IngestLogRepository::Common => "common"
+    }
+}
+
+/// The upstream server a request is sent to. Requests are labelled by this rather than by host,
+/// since icons are downloaded from arbitrary hosts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpstreamServer {
+    Spigot,
+    Modrinth,
+    Hangar,
+    CurseForge,
+    Polymart,
+    BuiltByBit,
+    SourceRepository,
+    Icon
+}
+
+impl UpstreamServer {
+    fn label(&self) -> &'static str {
+        match self {
+            UpstreamServer::Spigot => "spigot",
+            UpstreamServer::Modrinth => "modrinth",
+            UpstreamServer::Hangar => "hangar",
+            UpstreamServer::CurseForge => "curseforge",
+            UpstreamServer::Polymart => "polymart",
+            UpstreamServer::BuiltByBit => "builtbybit",
+            UpstreamServer::SourceRepository => "source_repository",
+            UpstreamServer::Icon => "icon"
+        }
+    }
+}
+
+fn item_label(item: &IngestLogItem) -> &'static str {
+    match item {
+        IngestLogItem::Author => "author",
+        IngestLogItem::Resource => "resource",
+        IngestLogItem::Project => "project",
+        IngestLogItem::Version => "version",
+        IngestLogItem::Release => "release",
+        IngestLogItem::Metadata => "metadata"
+    }
+}
+
+pub fn record_item(repository: IngestLogRepository, item: IngestLogItem, outcome: ItemOutcome) {
+    ITEMS_TOTAL
+        .with_label_values(&[repository_label(&repository), item_label(&item), outcome.label()])
+        .inc();
+}
+
+/// Records a single request attempt. The status is None if no response was received at all.
+pub fn observe_upstream_request(server: UpstreamServer, status: Option<StatusCode>, duration: Duration) {
+    let status = status.map_or("error".to_string(), |x| x.as_u16().to_string());
+
+    UPSTREAM_REQUEST_DURATION_SECONDS
+        .with_label_values(&[server.label(), &status])
+        .observe(duration.as_secs_f64());
+}
+
+/// Awaits the rate limiter, recording how long it took to become ready.
+pub async fn time_rate_limiter_wait<F: Future>(repository: IngestLogRepository, until_ready: F) -> F::Output {
+    let start = Instant::now();
+    let output = until_ready.await;
+
+    RATE_LIMITER_WAIT_SECONDS
+        .with_label_values(&[repository_label(&repository)])
+        .observe(start.elapsed().as_secs_f64());
+
+    output
+}
+
+/// Awaits the upsert, recording how long it took regardless of whether it succeeded.
+pub async fn time_db_upsert<F: Future>(table: &str, upsert: F) -> F::Output {
+    let start = Instant::now();
+    let output = upsert.await;
+
+    DB_UPSERT_DURATION_SECONDS
+        .with_label_values(&[table])
+        .observe(start.elapsed().as_secs_f64());
+
+    output
+}
+
+/// Returns all metrics in the Prometheus text exposition format.
+pub fn gather_metrics() -> Result<String> {
+    // Make sure every metric is listed even before it is first recorded.
+    LazyLock::force(&ITEMS_TOTAL);
+    LazyLock::force(&UPSTREAM_REQUEST_DURATION_SECONDS);
+    LazyLock::force(&RATE_LIMITER_WAIT_SECONDS);
+    LazyLock::force(&DB_UPSERT_DURATION_SECONDS);
+
+    let mut buffer = Vec::new();
+    TextEncoder::new().encode(&REGISTRY.gather(), &mut buffer)?;
+
+    Ok(String::from_utf8(buffer)?)
+}
+
+/// Serves the metrics at /metrics on the given address until the process exits.
+pub async fn serve_metrics(address: SocketAddr) -> Result<()> {
+    let router = Router::new().route("/metrics", get(get_metrics));
+    let listener = TcpListener::bind(address).await?;
+
+    info!("Serving metrics at http://{}/metrics", listener.local_addr()?);
+
+    axum::serve(listener, router).await?;
+
+    Ok(())
+}
+
+async fn get_metrics() -> impl IntoResponse {
+    match gather_metrics() {
+        Ok(metrics) => (StatusCode::OK, [(header::CONTENT_TYPE, TextEncoder::new().format_type().to_string())], metrics),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, [(header::CONTENT_TYPE, "text/plain".to_string())], err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_count_items_by_outcome() {
+        // Arrange
+        let processed = ITEMS_TOTAL.with_label_values(&["common", "author", "processed"]);
+        let failed = ITEMS_TOTAL.with_label_values(&["common", "author", "failed"]);
+        let processed_before = processed.get();
+        let failed_before = failed.get();
+
+        // Act
+        record_item(IngestLogRepository::Common, IngestLogItem::Author, ItemOutcome::Processed);
+        record_item(IngestLogRepository::Common, IngestLogItem::Author, ItemOutcome::Processed);
+        record_item(IngestLogRepository::Common, IngestLogItem::Author, ItemOutcome::Failed);
+
+        // Assert
+        assert_that(&(processed.get() - processed_before)).is_equal_to(2);
+        assert_that(&(failed.get() - failed_before)).is_equal_to(1);
+    }
+
+    #[test]
+    fn should_label_upstream_requests_by_server_and_status() -> Result<()> {
+        // Arrange
+        let ok = UPSTREAM_REQUEST_DURATION_SECONDS.with_label_values(&["icon", "200"]);
+        let error = UPSTREAM_REQUEST_DURATION_SECONDS.with_label_values(&["icon", "error"]);
+        let ok_before = ok.get_sample_count();
+        let error_before = error.get_sample_count();
+
+        // Act
+        observe_upstream_request(UpstreamServer::Icon, Some(StatusCode::OK), Duration::from_millis(120));
+        observe_upstream_request(UpstreamServer::Icon, None, Duration::from_secs(30));
+
+        // Assert
+        assert_that(&(ok.get_sample_count() - ok_before)).is_equal_to(1);
+        assert_that(&(error.get_sample_count() - error_before)).is_equal_to(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_time_db_upsert() -> Result<()> {
+        // Arrange
+        let histogram = DB_UPSERT_DURATION_SECONDS.with_label_values(&["test_table"]);
+        let count_before = histogram.get_sample_count();
+
+        // Act
+        let result = time_db_upsert("test_table", async { Ok::<u32, anyhow::Error>(1) }).await;
+
+        // Assert
+        assert_that(&result.ok()).is_equal_to(Some(1));
+        assert_that(&(histogram.get_sample_count() - count_before)).is_equal_to(1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_gather_metrics_in_text_format() -> Result<()> {
+        // Arrange
+        record_item(IngestLogRepository::Hangar, IngestLogItem::Project, ItemOutcome::Skipped);
+
+        // Act
+        let metrics = gather_metrics()?;
+
+        // Assert
+        assert_that(&metrics).contains("mcpf_ingest_items_total{item=\"project\",outcome=\"skipped\",repository=\"hangar\"}");
+        assert_that(&metrics).contains("# TYPE mcpf_ingest_upstream_request_duration_seconds histogram");
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
 use crate::HttpServer;
+use crate::dry_run::DryRun;
+use crate::retry::RetryPolicy;
 
 use anyhow::Result;
 use governor::{Quota, RateLimiter};
@@ -7,6 +9,7 @@ use governor::state::{InMemoryState, NotKeyed};
 use nonzero_ext::*;
 use reqwest::Client;
 use std::num::NonZeroU32;
+use std::sync::Arc;
 use url::Url;
 
 mod project;
@@ -38,6 +41,8 @@ impl HttpServer for ModrinthServer {
 pub struct ModrinthClient<T> {
     api_client: Client,
     rate_limiter: RateLimiter<NotKeyed, InMemoryState, QuantaClock>,
+    retry_policy: RetryPolicy,
+    dry_run: Option<Arc<DryRun>>,
     http_server: T
 }
 
@@ -50,7 +55,17 @@ impl<T> ModrinthClient<T> {
         let quota = Quota::per_second(MODRINTH_RATE_LIMIT_PER_SECOND);
         let rate_limiter = RateLimiter::direct(quota);
 
-        Ok(Self { api_client, rate_limiter, http_server })
+        Ok(Self { api_client, rate_limiter, retry_policy: RetryPolicy::default(), dry_run: None, http_server })
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: Option<Arc<DryRun>>) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 }
 
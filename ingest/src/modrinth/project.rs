@@ -1,19 +1,27 @@
 use crate::HttpServer;
+use crate::description::convert_markdown_description;
+use crate::failure::{FailureKind, QuarantinedItems};
+use crate::retry::send_with_retry;
 use crate::modrinth::ModrinthClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_failure::get_ingest_failures;
+use mc_plugin_finder::database::ingest_checkpoint::{IngestCheckpoint, upsert_ingest_checkpoint, get_ingest_checkpoint, delete_ingest_checkpoint, get_checkpoint_start_date};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
-use mc_plugin_finder::database::modrinth::project::{ModrinthProject, upsert_modrinth_project};
+use mc_plugin_finder::database::modrinth::project::{ModrinthProject, upsert_modrinth_project, mark_unseen_modrinth_projects_as_deleted};
+use mc_plugin_finder::database::project_category::ProjectCategory;
+use mc_plugin_finder::database::server_platform::ServerPlatform;
 use mc_plugin_finder::database::source_repository::{SourceRepository, extract_source_repository_from_url};
+use mc_plugin_finder::minecraft_version::find_latest_minecraft_version;
 
 use anyhow::Result;
 use deadpool_postgres::Pool;
 use futures::future;
-use futures::stream::TryStreamExt;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use page_turner::prelude::*;
-use regex::Regex;
 use reqwest::StatusCode;
 use serde::{Serialize, Deserialize};
 use std::fmt::Debug;
-use std::sync::{Arc, LazyLock};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use thiserror::Error;
 use time::format_description::well_known::Rfc3339;
@@ -32,7 +40,17 @@ struct SearchModrinthProjectsRequest {
 }
 
 impl SearchModrinthProjectsRequest {
-    fn create_request() -> Self {
+    fn create_populate_request() -> Self {
+        Self {
+            facets: "[[\"project_type:plugin\"]]".to_string(),
+            limit: 100,
+            offset: 0,
+            // Get projects in creation order, so that projects updated during the run do not move before pages that have already been retrieved.
+            index: "newest".to_string()
+        }
+    }
+
+    fn create_update_request() -> Self {
         Self {
             facets: "[[\"project_type:plugin\"]]".to_string(),
             limit: 100,
@@ -40,6 +58,15 @@ impl SearchModrinthProjectsRequest {
             index: "updated".to_string()
         }
     }
+
+    fn create_project_id_request(project_id: &str) -> Self {
+        Self {
+            facets: format!("[[\"project_type:plugin\"],[\"project_id:{}\"]]", project_id),
+            limit: 1,
+            offset: 0,
+            index: "relevance".to_string()
+        }
+    }
 }
 
 impl RequestAhead for SearchModrinthProjectsRequest {
@@ -77,6 +104,7 @@ pub struct IncomingModrinthProject {
     date_created: String,
     date_modified: String,
     versions: Vec<String>,
+    categories: Vec<String>,
     downloads: i32,
     follows: i32,
     icon_url: Option<String>,
@@ -84,9 +112,9 @@ pub struct IncomingModrinthProject {
 }
 
 impl IncomingModrinthProject {
-    // Assume that the last entry in the list of MC versions from the API is the latest version.
+    // Versions like "b1.7.3" that cannot be parsed are ignored, since they would mess up the ordering of versions.
     fn latest_minecraft_version(&self) -> Option<String> {
-        filter_release_minecraft_versions(self.versions.last().cloned())
+        find_latest_minecraft_version(&self.versions)
     }
 }
 
@@ -94,7 +122,9 @@ impl IncomingModrinthProject {
 pub struct GetModrinthProjectResponse {
     source_url: Option<String>,
     status: String,
-    versions: Vec<String>
+    body: Option<String>,
+    versions: Vec<String>,
+    loaders: Vec<String>
 }
 
 impl GetModrinthProjectResponse {
@@ -130,19 +160,48 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
     #[instrument(
         skip(self, db_pool)
     )]
-    pub async fn populate_modrinth_projects(&self, db_pool: &Pool) -> Result<()> {
+    pub async fn populate_modrinth_projects(&self, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
         info!("Populating Modrinth projects...");
 
-        let request = SearchModrinthProjectsRequest::create_request();
+        let mut request = SearchModrinthProjectsRequest::create_populate_request();
         let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Modrinth, IngestLogItem::Project).await?;
         let date_started = OffsetDateTime::now_utc();
 
+        let mut checkpoint_date_started = get_checkpoint_start_date(db_pool).await?;
+
+        if resume {
+            match get_ingest_checkpoint(db_pool, IngestLogRepository::Modrinth, IngestLogItem::Project).await? {
+                Some(checkpoint) => {
+                    request.offset = u32::try_from(checkpoint.position)? + request.limit;
+                    checkpoint_date_started = checkpoint.date_started;
+                    info!("Resuming from offset: {}", request.offset);
+                }
+                None => info!("No checkpoint found, starting from the beginning...")
+            }
+        }
+
+        let first_offset = request.offset;
+        let limit = request.limit;
+
         let result = self
             .pages_ahead(MODRINTH_PROJECTS_REQUESTS_AHEAD, Limit::None, request)
-            .items()
-            .try_for_each_concurrent(MODRINTH_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, false))
+            .enumerate()
+            .map(|(index, page_result)| page_result.map(|incoming_projects| (first_offset + index as u32 * limit, incoming_projects)))
+            .try_for_each(|(offset, incoming_projects)| self.process_incoming_project_page(offset, incoming_projects, db_pool, &count, &quarantined, checkpoint_date_started))
             .await;
 
+        // The run has completed, so there is nothing left to resume.
+        if result.is_ok() && self.dry_run.is_none() {
+            delete_ingest_checkpoint(db_pool, IngestLogRepository::Modrinth, IngestLogItem::Project).await?;
+
+            // Anything that was not seen since the run started is no longer available upstream.
+            if reconcile {
+                let deleted = mark_unseen_modrinth_projects_as_deleted(db_pool, checkpoint_date_started).await?;
+                info!("Modrinth projects marked as deleted: {}", deleted);
+            }
+        }
+
         let date_finished = OffsetDateTime::now_utc();
         let items_processed = count.load(Ordering::Relaxed);
 
@@ -155,7 +214,9 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Modrinth projects populated: {}", items_processed);
 
@@ -168,15 +229,16 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
     pub async fn update_modrinth_projects(&self, db_pool: &Pool, update_date_later_than: OffsetDateTime) -> Result<()> {
         info!("Updating Modrinth projects since: {}", update_date_later_than);
 
-        let request = SearchModrinthProjectsRequest::create_request();
+        let request = SearchModrinthProjectsRequest::create_update_request();
         let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Modrinth, IngestLogItem::Project).await?;
         let date_started = OffsetDateTime::now_utc();
 
         let result = self
             .pages_ahead(MODRINTH_PROJECTS_REQUESTS_AHEAD, Limit::None, request)
             .items()
             .try_take_while(|x| future::ready(Ok(OffsetDateTime::parse(x.date_modified.as_str(), &Rfc3339).unwrap() > update_date_later_than)))
-            .try_for_each_concurrent(MODRINTH_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, true))
+            .try_for_each_concurrent(MODRINTH_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, &count, &quarantined, true))
             .await;
 
             let date_finished = OffsetDateTime::now_utc();
@@ -191,15 +253,72 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
                 items_processed: items_processed.try_into()?,
                 success: result.is_ok()
             };
-            insert_ingest_log(db_pool, &ingest_log).await?;
+            if self.dry_run.is_none() {
+                insert_ingest_log(db_pool, &ingest_log).await?;
+            }
 
         info!("Modrinth projects updated: {}", items_processed);
 
         result
     }
 
-    async fn process_incoming_project(&self, incoming_project: IncomingModrinthProject, db_pool: &Pool, count: &Arc<AtomicU32>, get_version: bool) -> Result<()> {
+    /// Re-fetches and processes each project that previously failed, removing it from the ingest failures if it succeeds.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn retry_modrinth_project_failures(&self, db_pool: &Pool) -> Result<()> {
+        let failures = get_ingest_failures(db_pool, IngestLogRepository::Modrinth, IngestLogItem::Project).await?;
+        info!("Retrying failed Modrinth projects: {}", failures.len());
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::new(IngestLogRepository::Modrinth, IngestLogItem::Project, failures.iter().map(|x| x.upstream_id.clone()));
+
+        for failure in failures {
+            let request = SearchModrinthProjectsRequest::create_project_id_request(&failure.upstream_id);
+
+            match self.get_projects_from_api(request).await {
+                // The project is removed from the ingest failures if it succeeds, or quarantined again if it still fails.
+                Ok(response) => match response.hits.into_iter().next() {
+                    Some(incoming_project) => self.process_incoming_project(incoming_project, db_pool, &count, &quarantined, true).await?,
+                    None => {
+                        // The project no longer exists upstream
+                        if self.dry_run.is_none() {
+                            quarantined.unquarantine(db_pool, &failure.upstream_id).await;
+                        }
+                    }
+                },
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        info!("Modrinth projects retried: {}", count.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    async fn process_incoming_project_page(&self, offset: u32, incoming_projects: Vec<IncomingModrinthProject>, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        stream::iter(incoming_projects)
+            .map(Ok)
+            .try_for_each_concurrent(MODRINTH_PROJECTS_CONCURRENT_FUTURES, |incoming_project| self.process_incoming_project(incoming_project, db_pool, count, quarantined, false))
+            .await?;
+
+        let checkpoint = IngestCheckpoint {
+            repository: IngestLogRepository::Modrinth,
+            item: IngestLogItem::Project,
+            position: offset.try_into()?,
+            date_started,
+            date_updated: OffsetDateTime::now_utc()
+        };
+        if self.dry_run.is_none() {
+            upsert_ingest_checkpoint(db_pool, &checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_incoming_project(&self, incoming_project: IncomingModrinthProject, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, get_version: bool) -> Result<()> {
         let project_id = incoming_project.project_id.clone();
+        let payload = incoming_project.clone();
         let project_result = self.get_project_from_api(&project_id).await;
 
         match project_result {
@@ -224,19 +343,44 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
 
                 match convert_result {
                     Ok(project) => {
-                        let db_result = upsert_modrinth_project(db_pool, &project).await;
+                        let db_result = match &self.dry_run {
+                            Some(dry_run) => dry_run.report(db_pool, &project).await,
+                            None => time_db_upsert("modrinth_project", upsert_modrinth_project(db_pool, &project)).await
+                        };
 
                         match db_result {
                             Ok(_) => {
                                 count.fetch_add(1, Ordering::Relaxed);
+                                record_item(IngestLogRepository::Modrinth, IngestLogItem::Project, ItemOutcome::Processed);
+                                if self.dry_run.is_none() {
+                                    quarantined.unquarantine(db_pool, &project_id).await;
+                                }
                             },
-                            Err(err) => warn!("{}", err)
+                            Err(err) => {
+                                record_item(IngestLogRepository::Modrinth, IngestLogItem::Project, ItemOutcome::Failed);
+                                warn!("{}", err);
+                                if self.dry_run.is_none() {
+                                    quarantined.quarantine(db_pool, project_id.clone(), FailureKind::Database, &err, &payload).await;
+                                }
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        record_item(IngestLogRepository::Modrinth, IngestLogItem::Project, ItemOutcome::Skipped);
+                        warn!("{}", err);
+                        if self.dry_run.is_none() {
+                            quarantined.quarantine(db_pool, project_id.clone(), FailureKind::Conversion, &err, &payload).await;
                         }
                     }
-                    Err(err) => warn!("{}", err)
                 }
             }
-            Err(err) => warn!("{}", err)
+            Err(err) => {
+                record_item(IngestLogRepository::Modrinth, IngestLogItem::Project, ItemOutcome::Failed);
+                warn!("{}", err);
+                if self.dry_run.is_none() {
+                    quarantined.quarantine(db_pool, project_id.clone(), FailureKind::Fetch, &err, &payload).await;
+                }
+            }
         }
 
         Ok(())
@@ -246,13 +390,14 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
         skip(self)
     )]
     async fn get_projects_from_api(&self, request: SearchModrinthProjectsRequest) -> Result<SearchModrinthProjectsResponse> {
-        self.rate_limiter.until_ready().await;
-
         let url = self.http_server.base_url().join("search")?;
-        let raw_response = self.api_client.get(url)
-            .query(&request)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::Modrinth,
+            || time_rate_limiter_wait(IngestLogRepository::Modrinth, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         if status == StatusCode::OK {
@@ -272,13 +417,14 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
         skip(self)
     )]
     async fn get_project_from_api(&self, id: &str) -> Result<GetModrinthProjectResponse> {
-        self.rate_limiter.until_ready().await;
-
         let path = &["project/", id].concat();
         let url = self.http_server.base_url().join(path)?;
-        let raw_response = self.api_client.get(url)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::Modrinth,
+            || time_rate_limiter_wait(IngestLogRepository::Modrinth, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         match status {
@@ -340,7 +486,11 @@ async fn convert_incoming_project(incoming_project: IncomingModrinthProject, pro
         status: project_response.status.clone(),
         icon_url: incoming_project.icon_url,
         source_url: project_response.source_url.clone(),
-        source_repository: None
+        source_repository: None,
+        platforms: convert_modrinth_loaders_to_platforms(&project_response.loaders),
+        categories: convert_modrinth_categories_to_categories(&incoming_project.categories),
+        minecraft_versions: incoming_project.versions,
+        full_description: project_response.body.as_deref().and_then(convert_markdown_description)
     };
 
     if let Some(url) = &project_response.source_url {
@@ -359,16 +509,51 @@ async fn convert_incoming_project(incoming_project: IncomingModrinthProject, pro
     Ok(project)
 }
 
-static MINECRAFT_VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+\.\d+(\.\d)?$").unwrap());
+// Modrinth loaders for platforms that we do not track (e.g. "sponge") are ignored.
+fn convert_modrinth_loaders_to_platforms(loaders: &[String]) -> Vec<ServerPlatform> {
+    let mut platforms: Vec<ServerPlatform> = loaders
+        .iter()
+        .filter_map(|loader| match loader.as_str() {
+            "bukkit" => Some(ServerPlatform::Bukkit),
+            "spigot" => Some(ServerPlatform::Spigot),
+            "paper" => Some(ServerPlatform::Paper),
+            "folia" => Some(ServerPlatform::Folia),
+            "purpur" => Some(ServerPlatform::Purpur),
+            "velocity" => Some(ServerPlatform::Velocity),
+            "bungeecord" => Some(ServerPlatform::BungeeCord),
+            "waterfall" => Some(ServerPlatform::Waterfall),
+            _ => None
+        })
+        .collect();
+
+    platforms.sort();
+    platforms.dedup();
+    platforms
+}
 
-// Ignore pre-releases, release candidates, snapshots, and other versions like "b1.7.3" that can mess up the ordering of versions.
-fn filter_release_minecraft_versions(latest_minecraft_version: Option<String>) -> Option<String> {
-    if let Some(ref version) = latest_minecraft_version {
-        if MINECRAFT_VERSION_REGEX.is_match(version) {
-            return latest_minecraft_version;
-        }
-    }
-    None
+// Modrinth lists loaders such as "paper" alongside categories, so anything that is not a known category is ignored.
+fn convert_modrinth_categories_to_categories(modrinth_categories: &[String]) -> Vec<ProjectCategory> {
+    let mut categories: Vec<ProjectCategory> = modrinth_categories
+        .iter()
+        .filter_map(|category| match category.as_str() {
+            "adventure" | "equipment" | "food" | "game-mechanics" | "magic" | "mobs" | "technology" => Some(ProjectCategory::Gameplay),
+            "cursed" | "decoration" => Some(ProjectCategory::Miscellaneous),
+            "economy" => Some(ProjectCategory::Economy),
+            "library" => Some(ProjectCategory::Library),
+            "management" => Some(ProjectCategory::AdminTools),
+            "minigame" => Some(ProjectCategory::Minigames),
+            "optimization" => Some(ProjectCategory::Optimization),
+            "social" => Some(ProjectCategory::Chat),
+            "storage" | "utility" => Some(ProjectCategory::Utility),
+            "transportation" => Some(ProjectCategory::Transportation),
+            "worldgen" => Some(ProjectCategory::WorldManagement),
+            _ => None
+        })
+        .collect();
+
+    categories.sort();
+    categories.dedup();
+    categories
 }
 
 #[cfg(test)]
@@ -387,7 +572,7 @@ mod test {
         // Arrange
         let modrinth_server = ModrinthTestServer::new().await;
 
-        let request = SearchModrinthProjectsRequest::create_request();
+        let request = SearchModrinthProjectsRequest::create_populate_request();
 
         let expected_response = SearchModrinthProjectsResponse {
             hits: create_test_modrinth_projects(),
@@ -426,7 +611,9 @@ mod test {
         let expected_response = GetModrinthProjectResponse {
             source_url: Some("https://github.com/alice/foo".to_string()),
             status: "approved".to_string(),
-            versions: vec!["aaaa1111".to_string(), "bbbb2222".to_string(), "cccc3333".to_string()]
+            body: Some("foo-modrinth-**full**-description".to_string()),
+            versions: vec!["aaaa1111".to_string(), "bbbb2222".to_string(), "cccc3333".to_string()],
+            loaders: vec!["paper".to_string(), "folia".to_string(), "sponge".to_string()]
         };
 
         let response_template = ResponseTemplate::new(200)
@@ -457,7 +644,9 @@ mod test {
         let project_response = GetModrinthProjectResponse {
             source_url: Some("https://github.com/alice/foo".to_string()),
             status: "approved".to_string(),
-            versions: vec!["aaaa1111".to_string(), "bbbb2222".to_string(), "cccc3333".to_string()]
+            body: Some("foo-modrinth-**full**-description".to_string()),
+            versions: vec!["aaaa1111".to_string(), "bbbb2222".to_string(), "cccc3333".to_string()],
+            loaders: vec!["paper".to_string(), "folia".to_string(), "sponge".to_string()]
         };
 
         let version_id = "cccc3333";
@@ -488,7 +677,11 @@ mod test {
                 owner: "alice".to_string(),
                 name: "foo".to_string(),
                 id: None
-            })
+            }),
+            platforms: vec![ServerPlatform::Paper, ServerPlatform::Folia],
+            categories: vec![ProjectCategory::Chat, ProjectCategory::Economy],
+            minecraft_versions: vec!["1.20".to_string(), "1.20.6".to_string(), "1.21".to_string()],
+            full_description: Some("<p>foo-modrinth-<strong>full</strong>-description</p>".to_string())
         };
 
         assert_that(&project).is_equal_to(expected_project);
@@ -498,18 +691,23 @@ mod test {
 
     #[tokio::test]
     #[rstest]
-    #[case::beta("b1.7.3")]
-    #[case::snapshot("24w46a")]
-    #[case::prerelease("1.21.4-pre3")]
-    #[case::release_candidate("1.21.4-rc3")]
-    async fn should_process_incoming_project_where_latest_minecraft_version_is_not_release_version(#[case] version: &str) -> Result<()> {
+    #[case::beta(vec!["b1.7.3"], None)]
+    #[case::snapshot(vec!["1.21.3", "24w46a"], Some("24w46a"))]
+    #[case::prerelease(vec!["1.21.3", "24w46a", "1.21.4-pre3"], Some("1.21.4-pre3"))]
+    #[case::release_candidate(vec!["1.21.3", "1.21.4-pre3", "1.21.4-rc3"], Some("1.21.4-rc3"))]
+    #[case::double_digit_patch_version(vec!["1.21.9", "1.21.10"], Some("1.21.10"))]
+    #[case::year_based_version(vec!["1.21.11", "26.1"], Some("26.1"))]
+    #[case::unordered_versions(vec!["1.21.4", "1.8.8", "b1.7.3"], Some("1.21.4"))]
+    async fn should_process_incoming_project_with_latest_minecraft_version(#[case] versions: Vec<&str>, #[case] expected_version: Option<&str>) -> Result<()> {
         // Arrange
         let mut incoming_project = create_test_modrinth_projects()[0].clone();
-        incoming_project.versions = vec![version.to_string()];
+        incoming_project.versions = versions.into_iter().map(|x| x.to_string()).collect();
         let project_response = GetModrinthProjectResponse {
             source_url: Some("https://github.com/alice/foo".to_string()),
             status: "approved".to_string(),
-            versions: vec!["aaaa1111".to_string(), "bbbb2222".to_string(), "cccc3333".to_string()]
+            body: Some("foo-modrinth-**full**-description".to_string()),
+            versions: vec!["aaaa1111".to_string(), "bbbb2222".to_string(), "cccc3333".to_string()],
+            loaders: vec!["paper".to_string(), "folia".to_string(), "sponge".to_string()]
         };
 
         let version_id = "cccc3333";
@@ -519,7 +717,7 @@ mod test {
         let project = convert_incoming_project(incoming_project, &project_response, &Some(version_id.to_string()), &Some(version_name.to_string())).await?;
 
         // Assert
-        assert_that(&project.latest_minecraft_version).is_none();
+        assert_that(&project.latest_minecraft_version).is_equal_to(expected_version.map(|x| x.to_string()));
 
         Ok(())
     }
@@ -535,6 +733,7 @@ mod test {
                 date_created: "2021-01-01T00:00:00Z".to_string(),
                 date_modified: "2021-02-03T00:00:00Z".to_string(),
                 versions: vec!["1.20".to_string(), "1.20.6".to_string(), "1.21".to_string()],
+                categories: vec!["social".to_string(), "economy".to_string(), "paper".to_string()],
                 downloads: 100,
                 follows: 200,
                 icon_url: Some("https://cdn.modrinth.com/data/aaaaaaaa/icon.png".to_string()),
@@ -549,6 +748,7 @@ mod test {
                 date_created: "2021-01-02T00:00:00Z".to_string(),
                 date_modified: "2021-02-02T00:00:00Z".to_string(),
                 versions: vec!["1.6".to_string(), "1.7".to_string(), "1.8".to_string()],
+                categories: vec!["library".to_string()],
                 downloads: 300,
                 follows: 300,
                 icon_url: Some("https://cdn.modrinth.com/data/bbbbbbbb/icon.png".to_string()),
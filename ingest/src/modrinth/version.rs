@@ -1,7 +1,10 @@
 use crate::HttpServer;
+use crate::retry::send_with_retry;
 use crate::modrinth::ModrinthClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
 use mc_plugin_finder::database::modrinth::project::{get_modrinth_projects, upsert_modrinth_project, ModrinthProject};
+use mc_plugin_finder::database::modrinth::version::{ModrinthVersion, upsert_modrinth_version};
 
 use anyhow::Result;
 use deadpool_postgres::Pool;
@@ -12,6 +15,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use thiserror::Error;
+use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 use tracing::{info, warn, instrument};
 
@@ -22,6 +26,31 @@ pub struct GetModrinthVersionResponse {
     version_number: String
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingModrinthVersion {
+    id: String,
+    project_id: String,
+    name: String,
+    version_number: String,
+    version_type: String,
+    loaders: Vec<String>,
+    game_versions: Vec<String>,
+    date_published: String,
+    downloads: i32,
+    files: Vec<IncomingModrinthVersionFile>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingModrinthVersionFile {
+    hashes: IncomingModrinthVersionFileHashes
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingModrinthVersionFileHashes {
+    sha1: String,
+    sha512: String
+}
+
 #[derive(Debug, Error)]
 enum GetModrinthVersionError {
     #[error("Project '{project_id}' and version '{version_id}': Latest version not found.")]
@@ -37,6 +66,19 @@ enum GetModrinthVersionError {
     }
 }
 
+#[derive(Debug, Error)]
+enum GetModrinthProjectVersionsError {
+    #[error("Project '{project_id}': Project not found.")]
+    ProjectNotFound {
+        project_id: String
+    },
+    #[error("Project '{project_id}': Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        project_id: String,
+        status_code: u16
+    }
+}
+
 impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
     #[instrument(
         skip(self, db_pool)
@@ -48,6 +90,7 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
         let date_started = OffsetDateTime::now_utc();
 
         let projects = get_modrinth_projects(db_pool).await?;
+
         let project_stream = stream::iter(projects);
 
         let result = project_stream
@@ -67,7 +110,9 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Modrinth versions populated: {}", items_processed);
 
@@ -75,14 +120,48 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
     }
 
     async fn process_modrinth_project(&self, project: ModrinthProject, db_pool: &Pool, count: &Arc<AtomicU32>) -> Result<()> {
-        if let Some(ref version_id) = project.version_id {
-            let version_result = self.get_latest_modrinth_project_version_from_api(&project.id, version_id).await;
+        let versions_result = self.get_modrinth_project_versions_from_api(&project.id).await;
+
+        match versions_result {
+            Ok(incoming_versions) => {
+                let mut version_name = None;
+
+                for incoming_version in incoming_versions {
+                    if project.version_id.as_ref() == Some(&incoming_version.id) {
+                        version_name = Some(incoming_version.version_number.clone());
+                    }
 
-            match version_result {
-                Ok(version_name) => {
+                    let convert_result = convert_incoming_version(incoming_version);
+
+                    match convert_result {
+                        Ok(version) => {
+                            let db_result = match &self.dry_run {
+                                Some(dry_run) => dry_run.report(db_pool, &version).await,
+                                None => time_db_upsert("modrinth_version", upsert_modrinth_version(db_pool, &version)).await
+                            };
+
+                            match db_result {
+                                Ok(_) => record_item(IngestLogRepository::Modrinth, IngestLogItem::Version, ItemOutcome::Processed),
+                                Err(err) => {
+                                    record_item(IngestLogRepository::Modrinth, IngestLogItem::Version, ItemOutcome::Failed);
+                                    warn!("{}", err)
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            record_item(IngestLogRepository::Modrinth, IngestLogItem::Version, ItemOutcome::Skipped);
+                            warn!("{}", err)
+                        }
+                    }
+                }
+
+                if version_name.is_some() {
                     let mut new_project = project.clone();
-                    new_project.version_name = Some(version_name);
-                    let db_result = upsert_modrinth_project(db_pool, &new_project).await;
+                    new_project.version_name = version_name;
+                    let db_result = match &self.dry_run {
+                        Some(dry_run) => dry_run.report_against(Some(&project), &new_project),
+                        None => time_db_upsert("modrinth_project", upsert_modrinth_project(db_pool, &new_project)).await
+                    };
 
                     match db_result {
                         Ok(_) => {
@@ -90,11 +169,11 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
                         },
                         Err(err) => warn!("{}", err)
                     }
+                } else {
+                    warn!("Project '{}': Latest version not found.", project.id);
                 }
-                Err(err) => warn!("{}", err)
             }
-        } else {
-            warn!("Skipping project '{}': Version ID not found.", project.id);
+            Err(err) => warn!("{}", err)
         }
 
         Ok(())
@@ -103,15 +182,54 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
     #[instrument(
         skip(self)
     )]
-    pub async fn get_latest_modrinth_project_version_from_api(&self, project_id: &str, version_id: &str) -> Result<String> {
-        self.rate_limiter.until_ready().await;
+    async fn get_modrinth_project_versions_from_api(&self, project_id: &str) -> Result<Vec<IncomingModrinthVersion>> {
+        let path = &["project/", project_id, "/version"].concat();
+        let url = self.http_server.base_url().join(path)?;
+
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::Modrinth,
+            || time_rate_limiter_wait(IngestLogRepository::Modrinth, self.rate_limiter.until_ready())
+        ).await?;
 
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let response: Vec<IncomingModrinthVersion> = raw_response.json().await?;
+                Ok(response)
+            }
+            StatusCode::NOT_FOUND => {
+                Err(
+                    GetModrinthProjectVersionsError::ProjectNotFound {
+                        project_id: project_id.to_string()
+                    }.into()
+                )
+            }
+            _ => {
+                Err(
+                    GetModrinthProjectVersionsError::UnexpectedStatusCode {
+                        project_id: project_id.to_string(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self)
+    )]
+    pub async fn get_latest_modrinth_project_version_from_api(&self, project_id: &str, version_id: &str) -> Result<String> {
         let path = &["version/", version_id].concat();
         let url = self.http_server.base_url().join(path)?;
 
-        let raw_response = self.api_client.get(url)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::Modrinth,
+            || time_rate_limiter_wait(IngestLogRepository::Modrinth, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         match status {
@@ -140,12 +258,36 @@ impl<T> ModrinthClient<T> where T: HttpServer + Send + Sync {
     }
 }
 
+fn convert_incoming_version(incoming_version: IncomingModrinthVersion) -> Result<ModrinthVersion> {
+    let (file_sha1_hashes, file_sha512_hashes) = incoming_version.files
+        .into_iter()
+        .map(|file| (file.hashes.sha1, file.hashes.sha512))
+        .unzip();
+
+    let version = ModrinthVersion {
+        id: incoming_version.id,
+        project_id: incoming_version.project_id,
+        name: incoming_version.name,
+        version_number: incoming_version.version_number,
+        version_type: incoming_version.version_type,
+        loaders: incoming_version.loaders,
+        game_versions: incoming_version.game_versions,
+        date_published: OffsetDateTime::parse(&incoming_version.date_published, &Rfc3339)?,
+        downloads: incoming_version.downloads,
+        file_sha1_hashes,
+        file_sha512_hashes
+    };
+
+    Ok(version)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::modrinth::test::ModrinthTestServer;
 
     use speculoos::prelude::*;
+    use time::macros::datetime;
     use wiremock::{Mock, ResponseTemplate};
     use wiremock::matchers::{method, path};
 
@@ -220,4 +362,87 @@ mod test {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn should_get_project_versions_from_api() -> Result<()> {
+        // Arrange
+        let modrinth_server = ModrinthTestServer::new().await;
+
+        let expected_response = vec![create_test_version()];
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        let project_id = "aaaaaaaa";
+        let api_path = &["/project/", project_id, "/version"].concat();
+        Mock::given(method("GET"))
+            .and(path(api_path))
+            .respond_with(response_template)
+            .mount(modrinth_server.mock())
+            .await;
+
+        // Act
+        let modrinth_client = ModrinthClient::new(modrinth_server)?;
+        let result = modrinth_client.get_modrinth_project_versions_from_api(project_id).await;
+
+        // Assert
+        assert_that(&result).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_incoming_version() -> Result<()> {
+        // Arrange
+        let incoming_version = create_test_version();
+
+        // Act
+        let version = convert_incoming_version(incoming_version)?;
+
+        // Assert
+        let expected_version = ModrinthVersion {
+            id: "aaaa1111".to_string(),
+            project_id: "aaaaaaaa".to_string(),
+            name: "foo v1.2.3".to_string(),
+            version_number: "v1.2.3".to_string(),
+            version_type: "release".to_string(),
+            loaders: vec!["paper".to_string(), "spigot".to_string()],
+            game_versions: vec!["1.20.6".to_string(), "1.21".to_string()],
+            date_published: datetime!(2021-02-03 0:00 UTC),
+            downloads: 60,
+            file_sha1_hashes: vec!["aaaa1111sha1".to_string(), "aaaa2222sha1".to_string()],
+            file_sha512_hashes: vec!["aaaa1111sha512".to_string(), "aaaa2222sha512".to_string()]
+        };
+
+        assert_that(&version).is_equal_to(expected_version);
+
+        Ok(())
+    }
+
+    fn create_test_version() -> IncomingModrinthVersion {
+        IncomingModrinthVersion {
+            id: "aaaa1111".to_string(),
+            project_id: "aaaaaaaa".to_string(),
+            name: "foo v1.2.3".to_string(),
+            version_number: "v1.2.3".to_string(),
+            version_type: "release".to_string(),
+            loaders: vec!["paper".to_string(), "spigot".to_string()],
+            game_versions: vec!["1.20.6".to_string(), "1.21".to_string()],
+            date_published: "2021-02-03T00:00:00Z".to_string(),
+            downloads: 60,
+            files: vec![
+                IncomingModrinthVersionFile {
+                    hashes: IncomingModrinthVersionFileHashes {
+                        sha1: "aaaa1111sha1".to_string(),
+                        sha512: "aaaa1111sha512".to_string()
+                    }
+                },
+                IncomingModrinthVersionFile {
+                    hashes: IncomingModrinthVersionFileHashes {
+                        sha1: "aaaa2222sha1".to_string(),
+                        sha512: "aaaa2222sha512".to_string()
+                    }
+                }
+            ]
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::HttpServer;
+use crate::dry_run::DryRun;
+use crate::retry::RetryPolicy;
+
+use anyhow::Result;
+use governor::{Quota, RateLimiter};
+use governor::clock::QuantaClock;
+use governor::state::{InMemoryState, NotKeyed};
+use nonzero_ext::*;
+use reqwest::Client;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use url::Url;
+
+mod resource;
+
+const POLYMART_BASE_URL: &str = "https://api.polymart.org/v1/";
+
+const POLYMART_USER_AGENT: &str = concat!("Frumple/mc-plugin-finder/", env!("CARGO_PKG_VERSION"), " (contact@mcpluginfinder.com)");
+const POLYMART_RATE_LIMIT_PER_SECOND: NonZeroU32 = nonzero!(2u32);
+
+#[derive(Debug)]
+pub struct PolymartServer;
+
+impl HttpServer for PolymartServer {
+    async fn new() -> Self {
+        Self
+    }
+
+    fn base_url(&self) -> Url {
+        Url::parse(POLYMART_BASE_URL)
+          .expect("Polymart base URL could not be parsed")
+    }
+}
+
+#[derive(Debug)]
+pub struct PolymartClient<T> {
+    api_client: Client,
+    rate_limiter: RateLimiter<NotKeyed, InMemoryState, QuantaClock>,
+    retry_policy: RetryPolicy,
+    dry_run: Option<Arc<DryRun>>,
+    http_server: T
+}
+
+impl<T> PolymartClient<T> {
+    pub fn new(http_server: T) -> Result<PolymartClient<T>> {
+        let api_client = reqwest::Client::builder()
+            .user_agent(POLYMART_USER_AGENT)
+            .build()?;
+
+        let quota = Quota::per_second(POLYMART_RATE_LIMIT_PER_SECOND);
+        let rate_limiter = RateLimiter::direct(quota);
+
+        Ok(Self { api_client, rate_limiter, retry_policy: RetryPolicy::default(), dry_run: None, http_server })
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: Option<Arc<DryRun>>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wiremock::MockServer;
+
+    #[derive(Debug)]
+    pub struct PolymartTestServer {
+        mock_server: MockServer
+    }
+
+    impl PolymartTestServer {
+        pub fn mock(&self) -> &MockServer {
+            &self.mock_server
+        }
+    }
+
+    impl HttpServer for PolymartTestServer {
+        async fn new() -> Self {
+            Self {
+                mock_server: MockServer::start().await
+            }
+        }
+
+        fn base_url(&self) -> Url {
+            Url::parse(&self.mock_server.uri())
+                .expect("Polymart mock server base URL could not be parsed")
+        }
+    }
+}
\ No newline at end of file
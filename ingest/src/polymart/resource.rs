@@ -0,0 +1,856 @@
+use crate::HttpServer;
+use crate::failure::{FailureKind, QuarantinedItems};
+use crate::retry::send_with_retry;
+use crate::polymart::PolymartClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_failure::get_ingest_failures;
+use mc_plugin_finder::database::ingest_checkpoint::{IngestCheckpoint, upsert_ingest_checkpoint, get_ingest_checkpoint, delete_ingest_checkpoint, get_checkpoint_start_date};
+use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
+use mc_plugin_finder::database::polymart::resource::{PolymartResource, upsert_polymart_resource, mark_unseen_polymart_resources_as_deleted};
+use mc_plugin_finder::database::project_category::ProjectCategory;
+use mc_plugin_finder::database::server_platform::ServerPlatform;
+use mc_plugin_finder::database::source_repository::{SourceRepository, extract_source_repository_from_url};
+use mc_plugin_finder::minecraft_version::{MinecraftVersion, find_latest_minecraft_version};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use futures::future;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use page_turner::prelude::*;
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::{info, warn, instrument};
+
+const POLYMART_RESOURCES_REQUESTS_AHEAD: usize = 2;
+const POLYMART_RESOURCES_CONCURRENT_FUTURES: usize = 10;
+
+#[derive(Clone, Debug, Serialize)]
+struct GetPolymartResourcesRequest {
+    query: String,
+    start: u32,
+    limit: u32,
+    sort: String
+}
+
+impl GetPolymartResourcesRequest {
+    fn create_populate_request() -> Self {
+        Self {
+            query: "".to_string(),
+            start: 0,
+            limit: 100,
+            sort: "newest".to_string()
+        }
+    }
+
+    fn create_update_request() -> Self {
+        Self {
+            query: "".to_string(),
+            start: 0,
+            limit: 100,
+            sort: "updated".to_string()
+        }
+    }
+}
+
+impl RequestAhead for GetPolymartResourcesRequest {
+    fn next_request(&self) -> Self {
+        Self {
+            query: self.query.clone(),
+            start: self.start + self.limit,
+            limit: self.limit,
+            sort: self.sort.clone()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetPolymartResourcesResponse {
+    response: GetPolymartResourcesResponseBody
+}
+
+impl GetPolymartResourcesResponse {
+    fn more_resources_available(&self) -> bool {
+        self.response.more
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetPolymartResourcesResponseBody {
+    success: bool,
+    total: u32,
+    more: bool,
+    result: Vec<IncomingPolymartResource>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetPolymartResourceResponse {
+    response: GetPolymartResourceResponseBody
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GetPolymartResourceResponseBody {
+    success: bool,
+    resource: Option<IncomingPolymartResource>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingPolymartResource {
+    id: i32,
+    title: String,
+    subtitle: String,
+    owner: IncomingPolymartResourceNestedOwner,
+    version: Option<String>,
+    price: f64,
+    downloads: i32,
+    creation_time: i64,
+    last_update_time: i64,
+    #[serde(rename = "thumbnailURL")]
+    thumbnail_url: Option<String>,
+    supported_minecraft_versions: Option<Vec<String>>,
+    supported_server_software: Option<Vec<String>>,
+    #[serde(rename = "sourceCodeURL")]
+    source_code_url: Option<String>,
+    tags: Option<Vec<String>>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingPolymartResourceNestedOwner {
+    id: i32,
+    name: String
+}
+
+#[derive(Debug, Error)]
+enum GetPolymartResourcesError {
+    #[error("Could not get Polymart resources {request:?}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        request: GetPolymartResourcesRequest,
+        status_code: u16
+    },
+    #[error("Could not get Polymart resources {request:?}: Request was not successful")]
+    Unsuccessful {
+        request: GetPolymartResourcesRequest
+    },
+    #[error("Could not get Polymart resource ID {resource_id}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCodeForResource {
+        resource_id: i32,
+        status_code: u16
+    }
+}
+
+impl<T> PolymartClient<T> where T: HttpServer + Send + Sync {
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn populate_polymart_resources(&self, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
+        info!("Populating Polymart resources...");
+
+        let mut request = GetPolymartResourcesRequest::create_populate_request();
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Polymart, IngestLogItem::Resource).await?;
+        let date_started = OffsetDateTime::now_utc();
+
+        let mut checkpoint_date_started = get_checkpoint_start_date(db_pool).await?;
+
+        if resume {
+            match get_ingest_checkpoint(db_pool, IngestLogRepository::Polymart, IngestLogItem::Resource).await? {
+                Some(checkpoint) => {
+                    request.start = u32::try_from(checkpoint.position)? + request.limit;
+                    checkpoint_date_started = checkpoint.date_started;
+                    info!("Resuming from start: {}", request.start);
+                }
+                None => info!("No checkpoint found, starting from the beginning...")
+            }
+        }
+
+        let first_start = request.start;
+        let limit = request.limit;
+
+        let result = self
+            .pages_ahead(POLYMART_RESOURCES_REQUESTS_AHEAD, Limit::None, request)
+            .enumerate()
+            .map(|(index, page_result)| page_result.map(|incoming_resources| (first_start + index as u32 * limit, incoming_resources)))
+            .try_for_each(|(start, incoming_resources)| self.process_incoming_resource_page(start, incoming_resources, db_pool, &count, &quarantined, checkpoint_date_started))
+            .await;
+
+        // The run has completed, so there is nothing left to resume.
+        if result.is_ok() && self.dry_run.is_none() {
+            delete_ingest_checkpoint(db_pool, IngestLogRepository::Polymart, IngestLogItem::Resource).await?;
+
+            // Anything that was not seen since the run started is no longer available upstream.
+            if reconcile {
+                let deleted = mark_unseen_polymart_resources_as_deleted(db_pool, checkpoint_date_started).await?;
+                info!("Polymart resources marked as deleted: {}", deleted);
+            }
+        }
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::Polymart,
+            item: IngestLogItem::Resource,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("Polymart resources populated: {}", items_processed);
+
+        result
+    }
+
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn update_polymart_resources(&self, db_pool: &Pool, update_date_later_than: OffsetDateTime) -> Result<()> {
+        info!("Updating Polymart resources since: {}", update_date_later_than);
+
+        let request = GetPolymartResourcesRequest::create_update_request();
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Polymart, IngestLogItem::Resource).await?;
+        let date_started = OffsetDateTime::now_utc();
+
+        let result = self
+            .pages_ahead(POLYMART_RESOURCES_REQUESTS_AHEAD, Limit::None, request)
+            .items()
+            .try_take_while(|x| future::ready(Ok(x.last_update_time > update_date_later_than.unix_timestamp())))
+            .try_for_each_concurrent(POLYMART_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, &count, &quarantined))
+            .await;
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Update,
+            repository: IngestLogRepository::Polymart,
+            item: IngestLogItem::Resource,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("Polymart resources updated: {}", items_processed);
+
+        result
+    }
+
+    /// Re-fetches and processes each resource that previously failed, removing it from the ingest failures if it succeeds.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn retry_polymart_resource_failures(&self, db_pool: &Pool) -> Result<()> {
+        let failures = get_ingest_failures(db_pool, IngestLogRepository::Polymart, IngestLogItem::Resource).await?;
+        info!("Retrying failed Polymart resources: {}", failures.len());
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::new(IngestLogRepository::Polymart, IngestLogItem::Resource, failures.iter().map(|x| x.upstream_id.clone()));
+
+        for failure in failures {
+            let resource_id: i32 = match failure.upstream_id.parse() {
+                Ok(resource_id) => resource_id,
+                Err(err) => {
+                    warn!("Skipping Polymart resource failure {}: {}", failure.upstream_id, err);
+                    continue;
+                }
+            };
+
+            match self.get_resource_from_api(resource_id).await {
+                // The resource is removed from the ingest failures if it succeeds, or quarantined again if it still fails.
+                Ok(Some(incoming_resource)) => self.process_incoming_resource(incoming_resource, db_pool, &count, &quarantined).await?,
+                // The resource no longer exists upstream
+                Ok(None) => {
+                    if self.dry_run.is_none() {
+                        quarantined.unquarantine(db_pool, &failure.upstream_id).await;
+                    }
+                }
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        info!("Polymart resources retried: {}", count.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_resources_from_api(&self, request: GetPolymartResourcesRequest) -> Result<GetPolymartResourcesResponse> {
+        let url = self.http_server.base_url().join("search")?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::Polymart,
+            || time_rate_limiter_wait(IngestLogRepository::Polymart, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        if status == StatusCode::OK {
+            let response: GetPolymartResourcesResponse = raw_response.json().await?;
+
+            // Polymart reports some errors with a 200 status code, so the success flag must be checked as well.
+            if !response.response.success {
+                return Err(
+                    GetPolymartResourcesError::Unsuccessful {
+                        request
+                    }.into()
+                )
+            }
+
+            Ok(response)
+        } else {
+            Err(
+                GetPolymartResourcesError::UnexpectedStatusCode {
+                    request,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    /// Gets a single resource, or None if it no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_resource_from_api(&self, resource_id: i32) -> Result<Option<IncomingPolymartResource>> {
+        let url = self.http_server.base_url().join("getResourceInfo")?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&[("resource_id", resource_id)]),
+            &self.retry_policy,
+            UpstreamServer::Polymart,
+            || time_rate_limiter_wait(IngestLogRepository::Polymart, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        if status == StatusCode::OK {
+            let response: GetPolymartResourceResponse = raw_response.json().await?;
+
+            // Polymart reports a missing resource with a 200 status code and an unsuccessful response.
+            if !response.response.success {
+                return Ok(None);
+            }
+
+            Ok(response.response.resource)
+        } else {
+            Err(
+                GetPolymartResourcesError::UnexpectedStatusCodeForResource {
+                    resource_id,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    async fn process_incoming_resource_page(&self, start: u32, incoming_resources: Vec<IncomingPolymartResource>, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        stream::iter(incoming_resources)
+            .map(Ok)
+            .try_for_each_concurrent(POLYMART_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, count, quarantined))
+            .await?;
+
+        let checkpoint = IngestCheckpoint {
+            repository: IngestLogRepository::Polymart,
+            item: IngestLogItem::Resource,
+            position: start.try_into()?,
+            date_started,
+            date_updated: OffsetDateTime::now_utc()
+        };
+        if self.dry_run.is_none() {
+            upsert_ingest_checkpoint(db_pool, &checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_incoming_resource(&self, incoming_resource: IncomingPolymartResource, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems) -> Result<()> {
+        let resource_id = incoming_resource.id;
+        let payload = incoming_resource.clone();
+        let convert_result = convert_incoming_resource(incoming_resource);
+
+        match convert_result {
+            Ok(resource) => {
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &resource).await,
+                    None => time_db_upsert("polymart_resource", upsert_polymart_resource(db_pool, &resource)).await
+                };
+
+                match db_result {
+                    Ok(_) => {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::Polymart, IngestLogItem::Resource, ItemOutcome::Processed);
+                        if self.dry_run.is_none() {
+                            quarantined.unquarantine(db_pool, &resource_id.to_string()).await;
+                        }
+                    }
+                    Err(err) => {
+                        record_item(IngestLogRepository::Polymart, IngestLogItem::Resource, ItemOutcome::Failed);
+                        warn!("{}", err);
+                        if self.dry_run.is_none() {
+                            quarantined.quarantine(db_pool, resource_id.to_string(), FailureKind::Database, &err, &payload).await;
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                record_item(IngestLogRepository::Polymart, IngestLogItem::Resource, ItemOutcome::Skipped);
+                warn!("{}", err);
+                if self.dry_run.is_none() {
+                    quarantined.quarantine(db_pool, resource_id.to_string(), FailureKind::Conversion, &err, &payload).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> PageTurner<GetPolymartResourcesRequest> for PolymartClient<T> where T: HttpServer + Send + Sync {
+    type PageItems = Vec<IncomingPolymartResource>;
+    type PageError = anyhow::Error;
+
+    async fn turn_page(&self, mut request: GetPolymartResourcesRequest) -> TurnedPageResult<Self, GetPolymartResourcesRequest> {
+        let response = self.get_resources_from_api(request.clone()).await?;
+
+        if response.more_resources_available() {
+            request.start += request.limit;
+            Ok(TurnedPage::next(response.response.result, request))
+        } else {
+            Ok(TurnedPage::last(response.response.result))
+        }
+    }
+}
+
+fn convert_incoming_resource(incoming_resource: IncomingPolymartResource) -> Result<PolymartResource> {
+    // "supportedMinecraftVersions" may contain entries that are not Minecraft versions, such as "Latest".
+    let mut minecraft_versions: Vec<String> = incoming_resource.supported_minecraft_versions.unwrap_or_default()
+        .into_iter()
+        .filter(|version| version.parse::<MinecraftVersion>().is_ok())
+        .collect();
+    numeric_sort::sort(&mut minecraft_versions);
+    minecraft_versions.dedup();
+
+    let mut resource = PolymartResource {
+        id: incoming_resource.id,
+        name: incoming_resource.title,
+        description: incoming_resource.subtitle,
+        author: incoming_resource.owner.name,
+        date_created: OffsetDateTime::from_unix_timestamp(incoming_resource.creation_time)?,
+        date_updated: OffsetDateTime::from_unix_timestamp(incoming_resource.last_update_time)?,
+        latest_minecraft_version: find_latest_minecraft_version(&minecraft_versions),
+        downloads: incoming_resource.downloads,
+
+        // Free resources have a price of zero.
+        premium: incoming_resource.price > 0.0,
+
+        // "thumbnailURL" may be empty if the resource has no icon.
+        icon_url: incoming_resource.thumbnail_url.filter(|url| !url.is_empty()),
+        version_name: incoming_resource.version,
+        source_url: incoming_resource.source_code_url.clone(),
+        source_repository: None,
+        platforms: convert_polymart_server_software_to_platforms(incoming_resource.supported_server_software.as_deref().unwrap_or_default()),
+        categories: convert_polymart_tags_to_categories(incoming_resource.tags.as_deref().unwrap_or_default()),
+        minecraft_versions
+    };
+
+    if let Some(url) = incoming_resource.source_code_url {
+        let option_repo = extract_source_repository_from_url(url.as_str());
+
+        if let Some(repo) = option_repo {
+            resource.source_repository = Some(SourceRepository {
+                host: repo.host,
+                owner: repo.owner,
+                name: repo.name,
+                id: None
+            });
+        }
+    }
+
+    Ok(resource)
+}
+
+// Polymart lets authors pick the server software their resource supports, but many leave it empty.
+// In that case, we assume the resource is a Spigot plugin, like Spigot resources that have no category.
+fn convert_polymart_server_software_to_platforms(server_software: &[String]) -> Vec<ServerPlatform> {
+    let mut platforms: Vec<ServerPlatform> = server_software.iter()
+        .filter_map(|software| match software.to_lowercase().as_str() {
+            "bukkit" | "craftbukkit" => Some(ServerPlatform::Bukkit),
+            "spigot" => Some(ServerPlatform::Spigot),
+            "paper" => Some(ServerPlatform::Paper),
+            "folia" => Some(ServerPlatform::Folia),
+            "purpur" => Some(ServerPlatform::Purpur),
+            "velocity" => Some(ServerPlatform::Velocity),
+            "bungeecord" | "bungee" => Some(ServerPlatform::BungeeCord),
+            "waterfall" => Some(ServerPlatform::Waterfall),
+            _ => None
+        })
+        .collect();
+
+    if platforms.is_empty() {
+        return vec![ServerPlatform::Spigot];
+    }
+
+    platforms.sort();
+    platforms.dedup();
+    platforms
+}
+
+// Polymart categorizes resources with free-form tags, which are matched by name regardless of case or separators.
+fn convert_polymart_tags_to_categories(tags: &[String]) -> Vec<ProjectCategory> {
+    let mut categories: Vec<ProjectCategory> = tags.iter()
+        .filter_map(|tag| match tag.to_lowercase().replace(['-', '_'], " ").trim() {
+            "admin tools" | "management" => Some(ProjectCategory::AdminTools),
+            "anti cheat" | "protection" | "security" => Some(ProjectCategory::Protection),
+            "chat" => Some(ProjectCategory::Chat),
+            "api" | "libraries" | "library" => Some(ProjectCategory::Library),
+            "economy" => Some(ProjectCategory::Economy),
+            "fun" | "gameplay" | "mechanics" => Some(ProjectCategory::Gameplay),
+            "game modes" | "minigames" => Some(ProjectCategory::Minigames),
+            "misc" | "miscellaneous" => Some(ProjectCategory::Miscellaneous),
+            "optimization" | "performance" => Some(ProjectCategory::Optimization),
+            "role playing" | "rpg" => Some(ProjectCategory::RolePlaying),
+            "teleportation" | "transportation" => Some(ProjectCategory::Transportation),
+            "tools" | "utilities" | "utility" => Some(ProjectCategory::Utility),
+            "world" | "world generation" | "world management" => Some(ProjectCategory::WorldManagement),
+            _ => None
+        })
+        .collect();
+
+    categories.sort();
+    categories.dedup();
+    categories
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::retry::test::create_test_retry_policy;
+    use crate::polymart::test::PolymartTestServer;
+
+    use rstest::*;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{method, path, query_param};
+
+    #[tokio::test]
+    async fn should_get_resources_from_api() -> Result<()> {
+        // Arrange
+        let polymart_server = PolymartTestServer::new().await;
+
+        let request = GetPolymartResourcesRequest::create_populate_request();
+
+        let expected_response = GetPolymartResourcesResponse {
+            response: GetPolymartResourcesResponseBody {
+                success: true,
+                total: 2,
+                more: false,
+                result: create_test_resources()
+            }
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("query", request.query.as_str()))
+            .and(query_param("start", request.start.to_string().as_str()))
+            .and(query_param("limit", request.limit.to_string().as_str()))
+            .and(query_param("sort", request.sort.as_str()))
+            .respond_with(response_template)
+            .mount(polymart_server.mock())
+            .await;
+
+        // Act
+        let polymart_client = PolymartClient::new(polymart_server)?;
+        let response = polymart_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_resource_from_api() -> Result<()> {
+        // Arrange
+        let polymart_server = PolymartTestServer::new().await;
+
+        let expected_resource = create_test_resources()[0].clone();
+
+        let response = GetPolymartResourceResponse {
+            response: GetPolymartResourceResponseBody {
+                success: true,
+                resource: Some(expected_resource.clone())
+            }
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/getResourceInfo"))
+            .and(query_param("resource_id", expected_resource.id.to_string().as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(polymart_server.mock())
+            .await;
+
+        // Act
+        let polymart_client = PolymartClient::new(polymart_server)?;
+        let resource = polymart_client.get_resource_from_api(expected_resource.id).await?;
+
+        // Assert
+        assert_that(&resource).is_some().is_equal_to(expected_resource);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_resource_is_not_successful() -> Result<()> {
+        // Arrange
+        let polymart_server = PolymartTestServer::new().await;
+
+        let response = GetPolymartResourceResponse {
+            response: GetPolymartResourceResponseBody {
+                success: false,
+                resource: None
+            }
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/getResourceInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(polymart_server.mock())
+            .await;
+
+        // Act
+        let polymart_client = PolymartClient::new(polymart_server)?;
+        let resource = polymart_client.get_resource_from_api(1).await?;
+
+        // Assert
+        assert_that(&resource).is_none();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_retry_getting_resources_from_api_after_transient_error() -> Result<()> {
+        // Arrange
+        let polymart_server = PolymartTestServer::new().await;
+
+        let request = GetPolymartResourcesRequest::create_populate_request();
+
+        let expected_response = GetPolymartResourcesResponse {
+            response: GetPolymartResourcesResponseBody {
+                success: true,
+                total: 2,
+                more: false,
+                result: create_test_resources()
+            }
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(expected_response.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(polymart_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(response_template)
+            .expect(1)
+            .mount(polymart_server.mock())
+            .await;
+
+        // Act
+        let polymart_client = PolymartClient::new(polymart_server)?.with_retry_policy(create_test_retry_policy());
+        let response = polymart_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_get_resources_from_api_when_unsuccessful() -> Result<()> {
+        // Arrange
+        let polymart_server = PolymartTestServer::new().await;
+
+        let request = GetPolymartResourcesRequest::create_populate_request();
+
+        let unsuccessful_response = GetPolymartResourcesResponse {
+            response: GetPolymartResourcesResponseBody {
+                success: false,
+                total: 0,
+                more: false,
+                result: vec![]
+            }
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(unsuccessful_response))
+            .mount(polymart_server.mock())
+            .await;
+
+        // Act
+        let polymart_client = PolymartClient::new(polymart_server)?;
+        let response = polymart_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_incoming_resource() -> Result<()> {
+        // Arrange
+        let incoming_resource = create_test_resources()[0].clone();
+
+        // Act
+        let resource = convert_incoming_resource(incoming_resource)?;
+
+        // Assert
+        let expected_resource = PolymartResource {
+            id: 1,
+            name: "foo-polymart".to_string(),
+            description: "foo-polymart-description".to_string(),
+            author: "alice".to_string(),
+            date_created: datetime!(2018-01-01 0:00 UTC),
+            date_updated: datetime!(2018-02-03 0:00 UTC),
+            latest_minecraft_version: Some("1.21.4".to_string()),
+            downloads: 100,
+            premium: false,
+            icon_url: Some("https://polymart.org/resource/1/thumbnail.png".to_string()),
+            version_name: Some("1.2.3".to_string()),
+            source_url: Some("https://github.com/alice/foo".to_string()),
+            source_repository: Some(SourceRepository {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo".to_string(),
+                id: None
+            }),
+            platforms: vec![ServerPlatform::Spigot, ServerPlatform::Paper],
+            categories: vec![ProjectCategory::Chat, ProjectCategory::Utility],
+            minecraft_versions: vec!["1.21.3".to_string(), "1.21.4".to_string()]
+        };
+
+        assert_that(&resource).is_equal_to(expected_resource);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_incoming_premium_resource_without_icon() -> Result<()> {
+        // Arrange
+        let incoming_resource = create_test_resources()[1].clone();
+
+        // Act
+        let resource = convert_incoming_resource(incoming_resource)?;
+
+        // Assert
+        assert_that(&resource.premium).is_true();
+        assert_that(&resource.icon_url).is_none();
+        assert_that(&resource.source_repository).is_none();
+        assert_that(&resource.platforms).is_equal_to(vec![ServerPlatform::Spigot]);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::single(vec!["Paper"], vec![ServerPlatform::Paper])]
+    #[case::multiple(vec!["Velocity", "BungeeCord"], vec![ServerPlatform::Velocity, ServerPlatform::BungeeCord])]
+    #[case::duplicates(vec!["Bungee", "BungeeCord"], vec![ServerPlatform::BungeeCord])]
+    #[case::unknown(vec!["Sponge"], vec![ServerPlatform::Spigot])]
+    #[case::empty(vec![], vec![ServerPlatform::Spigot])]
+    fn should_convert_polymart_server_software_to_platforms(#[case] server_software: Vec<&str>, #[case] expected_platforms: Vec<ServerPlatform>) {
+        // Arrange
+        let server_software: Vec<String> = server_software.into_iter().map(|x| x.to_string()).collect();
+
+        // Act
+        let platforms = convert_polymart_server_software_to_platforms(&server_software);
+
+        // Assert
+        assert_that(&platforms).is_equal_to(expected_platforms);
+    }
+
+    #[rstest]
+    #[case::single(vec!["Chat"], vec![ProjectCategory::Chat])]
+    #[case::multiple(vec!["Economy", "Fun"], vec![ProjectCategory::Economy, ProjectCategory::Gameplay])]
+    #[case::separators(vec!["admin-tools", "World_Management"], vec![ProjectCategory::AdminTools, ProjectCategory::WorldManagement])]
+    #[case::merged(vec!["Libraries", "API"], vec![ProjectCategory::Library])]
+    #[case::unknown(vec!["Cosmetics"], vec![])]
+    fn should_convert_polymart_tags_to_categories(#[case] tags: Vec<&str>, #[case] expected_categories: Vec<ProjectCategory>) {
+        // Arrange
+        let tags: Vec<String> = tags.into_iter().map(|x| x.to_string()).collect();
+
+        // Act
+        let categories = convert_polymart_tags_to_categories(&tags);
+
+        // Assert
+        assert_that(&categories).is_equal_to(expected_categories);
+    }
+
+    fn create_test_resources() -> Vec<IncomingPolymartResource> {
+        vec![
+            IncomingPolymartResource {
+                id: 1,
+                title: "foo-polymart".to_string(),
+                subtitle: "foo-polymart-description".to_string(),
+                owner: IncomingPolymartResourceNestedOwner {
+                    id: 1,
+                    name: "alice".to_string()
+                },
+                version: Some("1.2.3".to_string()),
+                price: 0.0,
+                downloads: 100,
+                creation_time: 1514764800,
+                last_update_time: 1517616000,
+                thumbnail_url: Some("https://polymart.org/resource/1/thumbnail.png".to_string()),
+                supported_minecraft_versions: Some(vec!["1.21.4".to_string(), "1.21.3".to_string(), "Latest".to_string()]),
+                supported_server_software: Some(vec!["Spigot".to_string(), "Paper".to_string()]),
+                source_code_url: Some("https://github.com/alice/foo".to_string()),
+                tags: Some(vec!["Chat".to_string(), "Tools".to_string()])
+            },
+            IncomingPolymartResource {
+                id: 2,
+                title: "bar-polymart".to_string(),
+                subtitle: "bar-polymart-description".to_string(),
+                owner: IncomingPolymartResourceNestedOwner {
+                    id: 2,
+                    name: "bob".to_string()
+                },
+                version: Some("1.2.3".to_string()),
+                price: 4.99,
+                downloads: 300,
+                creation_time: 1514851200,
+                last_update_time: 1517529600,
+                thumbnail_url: Some("".to_string()),
+                supported_minecraft_versions: Some(vec!["1.8".to_string()]),
+                supported_server_software: None,
+                source_code_url: None,
+                tags: None
+            }
+        ]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,354 @@
+use crate::cassette::get_cassette;
+use crate::metrics::{observe_upstream_request, UpstreamServer};
+
+use rand::Rng;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use reqwest::header::RETRY_AFTER;
+use std::future::Future;
+use std::time::{Duration, Instant, SystemTime};
+use thiserror::Error;
+use tracing::warn;
+
+const DEFAULT_MAX_RETRIES: u32 = 5;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Determines how many times, and how long to wait between each time, a failed upstream API request is retried.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with full jitter: A random delay between zero and the exponential upper bound.
+    fn backoff_delay(&self, retry: u32) -> Duration {
+        let upper_bound = self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+
+        let upper_bound_millis = u64::try_from(upper_bound.as_millis()).unwrap_or(u64::MAX);
+        Duration::from_millis(rand::rng().random_range(0..=upper_bound_millis))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RetryError {
+    #[error("Request to {url} failed after {attempts} attempts: Received status code {status_code}")]
+    UnexpectedStatusCode {
+        url: String,
+        attempts: u32,
+        status_code: u16
+    },
+    #[error("Request to {url} failed after {attempts} attempts: {source}")]
+    RequestFailed {
+        url: String,
+        attempts: u32,
+        source: reqwest::Error
+    },
+    #[error("Request could not be retried because it has a streaming body")]
+    RequestNotCloneable,
+    #[error("Request could not be served by the cassette: {source}")]
+    Cassette {
+        source: anyhow::Error
+    }
+}
+
+/// Sends the request, retrying on transient status codes and connection errors until the retry budget is used up.
+/// Any other response, including non-transient error statuses, is returned to the caller as is.
+/// The rate limiter is awaited before every attempt, so retries count against the upstream quota too.
+/// If a cassette is in use, the request is recorded to or replayed from it instead.
+pub async fn send_with_retry<F, Fut>(request: RequestBuilder, retry_policy: &RetryPolicy, server: UpstreamServer, until_ready: F) -> Result<Response, RetryError> where F: Fn() -> Fut, Fut: Future<Output = ()> {
+    match get_cassette() {
+        Some(cassette) => cassette.send(request, retry_policy, server, until_ready).await.map_err(|source| RetryError::Cassette { source }),
+        None => send_with_retry_over_network(request, retry_policy, server, until_ready).await
+    }
+}
+
+pub async fn send_with_retry_over_network<F, Fut>(request: RequestBuilder, retry_policy: &RetryPolicy, server: UpstreamServer, until_ready: F) -> Result<Response, RetryError> where F: Fn() -> Fut, Fut: Future<Output = ()> {
+    let mut retry = 0;
+
+    loop {
+        until_ready().await;
+
+        let attempt = request.try_clone().ok_or(RetryError::RequestNotCloneable)?;
+        let attempts = retry + 1;
+        let start = Instant::now();
+
+        let delay = match attempt.send().await {
+            Ok(response) => {
+                let status = response.status();
+                observe_upstream_request(server, Some(status), start.elapsed());
+
+                if !is_transient_status(status) {
+                    return Ok(response);
+                }
+
+                if retry >= retry_policy.max_retries {
+                    return Err(
+                        RetryError::UnexpectedStatusCode {
+                            url: response.url().to_string(),
+                            attempts,
+                            status_code: status.into()
+                        }
+                    );
+                }
+
+                let delay = parse_retry_after(&response)
+                    .map(|x| x.min(retry_policy.max_delay))
+                    .unwrap_or_else(|| retry_policy.backoff_delay(retry));
+                warn!("Request to {} received status code {}, retrying in {:?}...", response.url(), status, delay);
+                delay
+            }
+            Err(err) => {
+                observe_upstream_request(server, None, start.elapsed());
+                let url = err.url().map(|url| url.to_string()).unwrap_or_default();
+
+                if !is_transient_error(&err) || retry >= retry_policy.max_retries {
+                    return Err(
+                        RetryError::RequestFailed {
+                            url,
+                            attempts,
+                            source: err
+                        }
+                    );
+                }
+
+                let delay = retry_policy.backoff_delay(retry);
+                warn!("Request to {} failed: {}, retrying in {:?}...", url, err, delay);
+                delay
+            }
+        };
+
+        tokio::time::sleep(delay).await;
+        retry += 1;
+    }
+}
+
+fn is_transient_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT |
+        StatusCode::TOO_MANY_REQUESTS |
+        StatusCode::INTERNAL_SERVER_ERROR |
+        StatusCode::BAD_GATEWAY |
+        StatusCode::SERVICE_UNAVAILABLE |
+        StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+fn is_transient_error(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_timeout()
+}
+
+/// "Retry-After" may either be a number of seconds or an HTTP date.
+fn parse_retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    use speculoos::prelude::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use wiremock::matchers::{method, path};
+
+    pub fn create_test_retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10)
+        }
+    }
+
+    #[tokio::test]
+    async fn should_retry_transient_status_until_success() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = reqwest::Client::new().get(format!("{}/foo", mock_server.uri()));
+
+        // Act
+        let result = send_with_retry(request, &create_test_retry_policy(), UpstreamServer::Spigot, || async {}).await;
+
+        // Assert
+        assert_that(&result).is_ok();
+        assert_that(&result.unwrap().status()).is_equal_to(StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_max_retries() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let request = reqwest::Client::new().get(format!("{}/foo", mock_server.uri()));
+
+        // Act
+        let result = send_with_retry(request, &create_test_retry_policy(), UpstreamServer::Spigot, || async {}).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        if let Err(RetryError::UnexpectedStatusCode { attempts, status_code, .. }) = result {
+            assert_that(&attempts).is_equal_to(3);
+            assert_that(&status_code).is_equal_to(429);
+        } else {
+            panic!("expected error to be UnexpectedStatusCode, but was {:?}", result);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_non_transient_status() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = reqwest::Client::new().get(format!("{}/foo", mock_server.uri()));
+
+        // Act
+        let result = send_with_retry(request, &create_test_retry_policy(), UpstreamServer::Spigot, || async {}).await;
+
+        // Assert
+        assert_that(&result).is_ok();
+        assert_that(&result.unwrap().status()).is_equal_to(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn should_honour_retry_after_header() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(429).append_header("retry-after", "1"))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let request = reqwest::Client::new().get(format!("{}/foo", mock_server.uri()));
+        let retry_policy = RetryPolicy {
+            max_delay: Duration::from_secs(10),
+            ..create_test_retry_policy()
+        };
+        let started = std::time::Instant::now();
+
+        // Act
+        let result = send_with_retry(request, &retry_policy, UpstreamServer::Spigot, || async {}).await;
+
+        // Assert
+        assert_that(&result).is_ok();
+        assert_that(&started.elapsed()).is_greater_than_or_equal_to(Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn should_cap_retry_after_header_at_max_delay() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(429).append_header("retry-after", "3600"))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let request = reqwest::Client::new().get(format!("{}/foo", mock_server.uri()));
+        let started = std::time::Instant::now();
+
+        // Act
+        let result = send_with_retry(request, &create_test_retry_policy(), UpstreamServer::Spigot, || async {}).await;
+
+        // Assert
+        assert_that(&result).is_ok();
+        assert_that(&started.elapsed()).is_less_than(Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_rate_limiter_before_every_attempt() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/foo"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let request = reqwest::Client::new().get(format!("{}/foo", mock_server.uri()));
+        let waits = AtomicU32::new(0);
+
+        // Act
+        let result = send_with_retry(request, &create_test_retry_policy(), UpstreamServer::Spigot, || async { waits.fetch_add(1, Ordering::Relaxed); }).await;
+
+        // Assert
+        assert_that(&result).is_ok();
+        assert_that(&waits.load(Ordering::Relaxed)).is_equal_to(3);
+    }
+}
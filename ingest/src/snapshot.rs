@@ -0,0 +1,331 @@
+use mc_plugin_finder::database::builtbybit::resource::get_builtbybit_resources;
+use mc_plugin_finder::database::common::project::refresh_common_projects;
+use mc_plugin_finder::database::curseforge::project::get_curseforge_projects;
+use mc_plugin_finder::database::hangar::project::get_hangar_projects;
+use mc_plugin_finder::database::ingest_log::get_ingest_logs;
+use mc_plugin_finder::database::modrinth::project::get_modrinth_projects;
+use mc_plugin_finder::database::modrinth::version::get_modrinth_versions;
+use mc_plugin_finder::database::polymart::resource::get_polymart_resources;
+use mc_plugin_finder::database::project_stats_snapshot::get_all_project_stats_snapshots;
+use mc_plugin_finder::database::snapshot::{Snapshot, insert_snapshot};
+use mc_plugin_finder::database::source_repository_metadata::get_source_repository_metadata;
+use mc_plugin_finder::database::source_repository_release::get_source_repository_releases;
+use mc_plugin_finder::database::spigot::author::get_spigot_authors;
+use mc_plugin_finder::database::spigot::resource::get_spigot_resources;
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::path::Path;
+use thiserror::Error;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tracing::{info, instrument};
+
+/// Increment this whenever a snapshot item changes in a way that older snapshots can no longer be imported.
+const SNAPSHOT_VERSION: u32 = 2;
+
+const SPIGOT_AUTHOR_KIND: &str = "spigot_author";
+const SPIGOT_RESOURCE_KIND: &str = "spigot_resource";
+const MODRINTH_PROJECT_KIND: &str = "modrinth_project";
+const MODRINTH_VERSION_KIND: &str = "modrinth_version";
+const HANGAR_PROJECT_KIND: &str = "hangar_project";
+const CURSEFORGE_PROJECT_KIND: &str = "curseforge_project";
+const POLYMART_RESOURCE_KIND: &str = "polymart_resource";
+const BUILTBYBIT_RESOURCE_KIND: &str = "builtbybit_resource";
+const SOURCE_REPOSITORY_RELEASE_KIND: &str = "source_repository_release";
+const SOURCE_REPOSITORY_METADATA_KIND: &str = "source_repository_metadata";
+const PROJECT_STATS_SNAPSHOT_KIND: &str = "project_stats_snapshot";
+const INGEST_LOG_KIND: &str = "ingest_log";
+
+/// The first line of every snapshot file, followed by one item per line.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct SnapshotHeader {
+    version: u32,
+    kind: String,
+    count: usize
+}
+
+#[derive(Debug, Error)]
+enum SnapshotError {
+    #[error("Snapshot file {file} is empty")]
+    MissingHeader {
+        file: String
+    },
+    #[error("Snapshot file {file} has version {version}, but only version {SNAPSHOT_VERSION} can be imported")]
+    UnsupportedVersion {
+        file: String,
+        version: u32
+    },
+    #[error("Snapshot file {file} contains {actual} items, but expected {expected}")]
+    UnexpectedKind {
+        file: String,
+        expected: String,
+        actual: String
+    },
+    #[error("Snapshot file {file} contains {actual} items, but its header declares {expected}")]
+    UnexpectedCount {
+        file: String,
+        expected: usize,
+        actual: usize
+    }
+}
+
+/// Writes every table of the snapshot to its own NDJSON file in the directory.
+/// Deleted projects are left out, along with the Modrinth versions that belong to them.
+#[instrument(
+    skip(db_pool)
+)]
+pub async fn export_snapshot(db_pool: &Pool, directory: &Path) -> Result<()> {
+    tokio::fs::create_dir_all(directory).await?;
+
+    let modrinth_projects = get_modrinth_projects(db_pool).await?;
+    let modrinth_project_ids: HashSet<&str> = modrinth_projects.iter().map(|x| x.id.as_str()).collect();
+
+    let mut modrinth_versions = get_modrinth_versions(db_pool).await?;
+    modrinth_versions.retain(|x| modrinth_project_ids.contains(x.project_id.as_str()));
+
+    let mut ingest_logs = get_ingest_logs(db_pool).await?;
+    ingest_logs.sort_by_key(|x| x.date_started);
+
+    write_snapshot_file(directory, SPIGOT_AUTHOR_KIND, &get_spigot_authors(db_pool).await?).await?;
+    write_snapshot_file(directory, SPIGOT_RESOURCE_KIND, &get_spigot_resources(db_pool).await?).await?;
+    write_snapshot_file(directory, MODRINTH_PROJECT_KIND, &modrinth_projects).await?;
+    write_snapshot_file(directory, MODRINTH_VERSION_KIND, &modrinth_versions).await?;
+    write_snapshot_file(directory, HANGAR_PROJECT_KIND, &get_hangar_projects(db_pool).await?).await?;
+    write_snapshot_file(directory, CURSEFORGE_PROJECT_KIND, &get_curseforge_projects(db_pool).await?).await?;
+    write_snapshot_file(directory, POLYMART_RESOURCE_KIND, &get_polymart_resources(db_pool).await?).await?;
+    write_snapshot_file(directory, BUILTBYBIT_RESOURCE_KIND, &get_builtbybit_resources(db_pool).await?).await?;
+    write_snapshot_file(directory, SOURCE_REPOSITORY_RELEASE_KIND, &get_source_repository_releases(db_pool).await?).await?;
+    write_snapshot_file(directory, SOURCE_REPOSITORY_METADATA_KIND, &get_source_repository_metadata(db_pool).await?).await?;
+    write_snapshot_file(directory, PROJECT_STATS_SNAPSHOT_KIND, &get_all_project_stats_snapshots(db_pool).await?).await?;
+    write_snapshot_file(directory, INGEST_LOG_KIND, &ingest_logs).await?;
+
+    Ok(())
+}
+
+/// Loads a snapshot written by `export_snapshot` into an empty database, then refreshes the common projects.
+#[instrument(
+    skip(db_pool)
+)]
+pub async fn import_snapshot(db_pool: &Pool, directory: &Path) -> Result<()> {
+    // Read every file before writing anything, so that an invalid snapshot leaves the database untouched.
+    let snapshot = Snapshot {
+        spigot_authors: read_snapshot_file(directory, SPIGOT_AUTHOR_KIND).await?,
+        spigot_resources: read_snapshot_file(directory, SPIGOT_RESOURCE_KIND).await?,
+        modrinth_projects: read_snapshot_file(directory, MODRINTH_PROJECT_KIND).await?,
+        modrinth_versions: read_snapshot_file(directory, MODRINTH_VERSION_KIND).await?,
+        hangar_projects: read_snapshot_file(directory, HANGAR_PROJECT_KIND).await?,
+        curseforge_projects: read_snapshot_file(directory, CURSEFORGE_PROJECT_KIND).await?,
+        polymart_resources: read_snapshot_file(directory, POLYMART_RESOURCE_KIND).await?,
+        builtbybit_resources: read_snapshot_file(directory, BUILTBYBIT_RESOURCE_KIND).await?,
+        source_repository_releases: read_snapshot_file(directory, SOURCE_REPOSITORY_RELEASE_KIND).await?,
+        source_repository_metadata: read_snapshot_file(directory, SOURCE_REPOSITORY_METADATA_KIND).await?,
+        project_stats_snapshots: read_snapshot_file(directory, PROJECT_STATS_SNAPSHOT_KIND).await?,
+        ingest_logs: read_snapshot_file(directory, INGEST_LOG_KIND).await?
+    };
+
+    insert_snapshot(db_pool, &snapshot).await?;
+
+    refresh_common_projects(db_pool).await?;
+
+    info!("Snapshot imported: {} spigot authors, {} spigot resources, {} modrinth projects, {} modrinth versions, {} hangar projects, {} curseforge projects, {} polymart resources, {} builtbybit resources, {} source repository releases, {} source repository metadata, {} project stats snapshots, {} ingest logs",
+        snapshot.spigot_authors.len(), snapshot.spigot_resources.len(), snapshot.modrinth_projects.len(), snapshot.modrinth_versions.len(),
+        snapshot.hangar_projects.len(), snapshot.curseforge_projects.len(), snapshot.polymart_resources.len(), snapshot.builtbybit_resources.len(),
+        snapshot.source_repository_releases.len(), snapshot.source_repository_metadata.len(), snapshot.project_stats_snapshots.len(), snapshot.ingest_logs.len());
+
+    Ok(())
+}
+
+fn get_snapshot_file_name(kind: &str) -> String {
+    format!("{}.ndjson", kind)
+}
+
+async fn write_snapshot_file<T: Serialize>(directory: &Path, kind: &str, items: &[T]) -> Result<()> {
+    let file_name = get_snapshot_file_name(kind);
+    let mut writer = BufWriter::new(File::create(directory.join(&file_name)).await?);
+
+    let header = SnapshotHeader {
+        version: SNAPSHOT_VERSION,
+        kind: kind.to_string(),
+        count: items.len()
+    };
+
+    writer.write_all(&serde_json::to_vec(&header)?).await?;
+    writer.write_all(b"\n").await?;
+
+    for item in items {
+        writer.write_all(&serde_json::to_vec(item)?).await?;
+        writer.write_all(b"\n").await?;
+    }
+
+    writer.flush().await?;
+
+    info!("Exported {} items to {}", items.len(), file_name);
+
+    Ok(())
+}
+
+async fn read_snapshot_file<T: DeserializeOwned>(directory: &Path, kind: &str) -> Result<Vec<T>> {
+    let file_name = get_snapshot_file_name(kind);
+    let mut lines = BufReader::new(File::open(directory.join(&file_name)).await?).lines();
+
+    let header: SnapshotHeader = match lines.next_line().await? {
+        Some(line) => serde_json::from_str(&line)?,
+        None => return Err(SnapshotError::MissingHeader { file: file_name }.into())
+    };
+
+    if header.version != SNAPSHOT_VERSION {
+        return Err(SnapshotError::UnsupportedVersion { file: file_name, version: header.version }.into());
+    }
+
+    if header.kind != kind {
+        return Err(SnapshotError::UnexpectedKind { file: file_name, expected: kind.to_string(), actual: header.kind }.into());
+    }
+
+    let mut items = Vec::with_capacity(header.count);
+    while let Some(line) = lines.next_line().await? {
+        if !line.is_empty() {
+            items.push(serde_json::from_str(&line)?);
+        }
+    }
+
+    if items.len() != header.count {
+        return Err(SnapshotError::UnexpectedCount { file: file_name, expected: header.count, actual: items.len() }.into());
+    }
+
+    Ok(items)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem};
+    use mc_plugin_finder::database::spigot::author::SpigotAuthor;
+    use speculoos::prelude::*;
+    use std::path::PathBuf;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn should_read_items_that_were_written() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_read_items_that_were_written").await?;
+        let logs = vec![
+            IngestLog {
+                action: IngestLogAction::Populate,
+                repository: IngestLogRepository::Spigot,
+                item: IngestLogItem::Resource,
+                date_started: datetime!(2024-01-01 0:00 UTC),
+                date_finished: datetime!(2024-01-01 1:00 UTC),
+                items_processed: 100,
+                success: true
+            },
+            IngestLog {
+                action: IngestLogAction::Update,
+                repository: IngestLogRepository::Modrinth,
+                item: IngestLogItem::Project,
+                date_started: datetime!(2024-01-02 0:00 UTC),
+                date_finished: datetime!(2024-01-02 1:00 UTC),
+                items_processed: 5,
+                success: false
+            }
+        ];
+
+        // Act
+        write_snapshot_file(&directory, INGEST_LOG_KIND, &logs).await?;
+        let read_logs: Vec<IngestLog> = read_snapshot_file(&directory, INGEST_LOG_KIND).await?;
+
+        // Assert
+        assert_that(&read_logs).is_equal_to(logs);
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_write_header_as_first_line() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_write_header_as_first_line").await?;
+        let authors = vec![SpigotAuthor { id: 1, name: "alice".to_string() }];
+
+        // Act
+        write_snapshot_file(&directory, SPIGOT_AUTHOR_KIND, &authors).await?;
+        let contents = tokio::fs::read_to_string(directory.join("spigot_author.ndjson")).await?;
+
+        // Assert
+        assert_that(&contents).is_equal_to("{\"version\":2,\"kind\":\"spigot_author\",\"count\":1}\n{\"id\":1,\"name\":\"alice\"}\n".to_string());
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_version_is_unsupported() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_return_error_if_version_is_unsupported").await?;
+        tokio::fs::write(directory.join("spigot_author.ndjson"), "{\"version\":999,\"kind\":\"spigot_author\",\"count\":0}\n").await?;
+
+        // Act
+        let result: Result<Vec<SpigotAuthor>> = read_snapshot_file(&directory, SPIGOT_AUTHOR_KIND).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<SnapshotError>().unwrap();
+
+        if let SnapshotError::UnsupportedVersion{file, version} = downcast_error {
+            assert_that(file).is_equal_to("spigot_author.ndjson".to_string());
+            assert_that(version).is_equal_to(999);
+        } else {
+            panic!("expected error to be UnsupportedVersion, but was {}", downcast_error);
+        }
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_file_is_truncated() -> Result<()> {
+        // Arrange
+        let directory = create_test_directory("should_return_error_if_file_is_truncated").await?;
+        tokio::fs::write(directory.join("spigot_author.ndjson"), "{\"version\":2,\"kind\":\"spigot_author\",\"count\":2}\n{\"id\":1,\"name\":\"alice\"}\n").await?;
+
+        // Act
+        let result: Result<Vec<SpigotAuthor>> = read_snapshot_file(&directory, SPIGOT_AUTHOR_KIND).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<SnapshotError>().unwrap();
+
+        if let SnapshotError::UnexpectedCount{expected, actual, ..} = downcast_error {
+            assert_that(expected).is_equal_to(2);
+            assert_that(actual).is_equal_to(1);
+        } else {
+            panic!("expected error to be UnexpectedCount, but was {}", downcast_error);
+        }
+
+        tokio::fs::remove_dir_all(&directory).await?;
+
+        Ok(())
+    }
+
+    async fn create_test_directory(name: &str) -> Result<PathBuf> {
+        let directory = std::env::temp_dir().join("mcpf-snapshot-test").join(name);
+
+        if tokio::fs::try_exists(&directory).await? {
+            tokio::fs::remove_dir_all(&directory).await?;
+        }
+        tokio::fs::create_dir_all(&directory).await?;
+
+        Ok(directory)
+    }
+}
\ No newline at end of file
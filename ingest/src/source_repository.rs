@@ -0,0 +1,163 @@
+use crate::dry_run::DryRun;
+use crate::retry::RetryPolicy;
+
+use anyhow::Result;
+use governor::{Quota, RateLimiter};
+use governor::clock::QuantaClock;
+use governor::state::{InMemoryState, NotKeyed};
+use nonzero_ext::*;
+use reqwest::{Client, RequestBuilder};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use url::Url;
+
+mod metadata;
+mod release;
+
+const GITHUB_BASE_URL: &str = "https://api.github.com/";
+const GITLAB_BASE_URL: &str = "https://gitlab.com/api/v4/";
+const CODEBERG_BASE_URL: &str = "https://codeberg.org/api/v1/";
+
+const SOURCE_REPOSITORY_USER_AGENT: &str = concat!("Frumple/mc-plugin-finder/", env!("CARGO_PKG_VERSION"), " (contact@mcpluginfinder.com)");
+const SOURCE_REPOSITORY_RATE_LIMIT_PER_SECOND: NonZeroU32 = nonzero!(1u32);
+
+/// Source repository hosts that publish releases through an API.
+/// Bitbucket is not included, since it has no concept of releases.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    Codeberg
+}
+
+impl Forge {
+    pub fn from_host(host: &str) -> Option<Forge> {
+        match host.to_lowercase().as_str() {
+            "github.com" => Some(Forge::GitHub),
+            "gitlab.com" => Some(Forge::GitLab),
+            "codeberg.org" => Some(Forge::Codeberg),
+            _ => None
+        }
+    }
+}
+
+/// Unlike the upstream repositories, source repositories are spread across several forges, each with its own API base URL.
+pub trait ForgeServer {
+    #[allow(async_fn_in_trait)]
+    async fn new() -> Self;
+    fn base_url(&self, forge: Forge) -> Url;
+}
+
+#[derive(Debug)]
+pub struct SourceRepositoryServer;
+
+impl ForgeServer for SourceRepositoryServer {
+    async fn new() -> Self {
+        Self
+    }
+
+    fn base_url(&self, forge: Forge) -> Url {
+        let base_url = match forge {
+            Forge::GitHub => GITHUB_BASE_URL,
+            Forge::GitLab => GITLAB_BASE_URL,
+            Forge::Codeberg => CODEBERG_BASE_URL
+        };
+
+        Url::parse(base_url)
+          .expect("Source repository base URL could not be parsed")
+    }
+}
+
+#[derive(Debug)]
+pub struct SourceRepositoryClient<T> {
+    api_client: Client,
+    github_headers: HeaderMap,
+    rate_limiter: RateLimiter<NotKeyed, InMemoryState, QuantaClock>,
+    retry_policy: RetryPolicy,
+    dry_run: Option<Arc<DryRun>>,
+    http_server: T
+}
+
+impl<T> SourceRepositoryClient<T> {
+    /// The GitHub token is optional, but without one GitHub only allows 60 requests per hour.
+    pub fn new(http_server: T, github_token: Option<&str>) -> Result<SourceRepositoryClient<T>> {
+        let mut github_headers = HeaderMap::new();
+        github_headers.insert("accept", HeaderValue::from_static("application/vnd.github+json"));
+
+        if let Some(token) = github_token {
+            let mut authorization_value = HeaderValue::from_str(&["Bearer ", token].concat())?;
+            authorization_value.set_sensitive(true);
+            github_headers.insert(AUTHORIZATION, authorization_value);
+        }
+
+        let api_client = reqwest::Client::builder()
+            .user_agent(SOURCE_REPOSITORY_USER_AGENT)
+            .build()?;
+
+        let quota = Quota::per_second(SOURCE_REPOSITORY_RATE_LIMIT_PER_SECOND);
+        let rate_limiter = RateLimiter::direct(quota);
+
+        Ok(Self { api_client, github_headers, rate_limiter, retry_policy: RetryPolicy::default(), dry_run: None, http_server })
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: Option<Arc<DryRun>>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Builds a GET request to the given forge, authenticating with the GitHub token if the forge is GitHub.
+    fn get(&self, forge: Forge, url: Url) -> RequestBuilder {
+        let request = self.api_client.get(url);
+
+        match forge {
+            Forge::GitHub => request.headers(self.github_headers.clone()),
+            _ => request
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wiremock::MockServer;
+
+    pub const GITHUB_TEST_API_TOKEN: &str = "test-github-token";
+
+    /// A single mock server stands in for every forge, with each forge's API under its own path prefix.
+    #[derive(Debug)]
+    pub struct SourceRepositoryTestServer {
+        mock_server: MockServer
+    }
+
+    impl SourceRepositoryTestServer {
+        pub fn mock(&self) -> &MockServer {
+            &self.mock_server
+        }
+    }
+
+    impl ForgeServer for SourceRepositoryTestServer {
+        async fn new() -> Self {
+            Self {
+                mock_server: MockServer::start().await
+            }
+        }
+
+        fn base_url(&self, forge: Forge) -> Url {
+            let prefix = match forge {
+                Forge::GitHub => "github/",
+                Forge::GitLab => "gitlab/",
+                Forge::Codeberg => "codeberg/"
+            };
+
+            Url::parse(&self.mock_server.uri())
+                .and_then(|x| x.join(prefix))
+                .expect("Source repository mock server base URL could not be parsed")
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,553 @@
+use crate::retry::send_with_retry;
+use crate::source_repository::{Forge, ForgeServer, SourceRepositoryClient};
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
+use mc_plugin_finder::database::source_repository::SourceRepository;
+use mc_plugin_finder::database::source_repository_metadata::{SourceRepositoryMetadata, upsert_source_repository_metadata};
+use mc_plugin_finder::database::source_repository_release::get_distinct_source_repositories;
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tracing::{info, warn, instrument};
+use url::Url;
+
+const SOURCE_REPOSITORY_METADATA_CONCURRENT_FUTURES: usize = 10;
+
+// GitHub reports this instead of an SPDX identifier when it cannot determine the license.
+const GITHUB_UNKNOWN_LICENSE: &str = "NOASSERTION";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitHubRepository {
+    stargazers_count: i32,
+    archived: bool,
+    disabled: bool,
+    license: Option<IncomingGitHubLicense>,
+    open_issues_count: i32,
+    default_branch: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitHubLicense {
+    spdx_id: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCodebergRepository {
+    stars_count: i32,
+    archived: bool,
+    #[serde(default)]
+    licenses: Vec<String>,
+    open_issues_count: i32,
+    default_branch: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabProject {
+    star_count: i32,
+    archived: bool,
+    license: Option<IncomingGitLabLicense>,
+    open_issues_count: Option<i32>,
+    default_branch: Option<String>
+}
+
+/// GitLab only reports its own license keys, which are lowercase SPDX identifiers in most cases.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabLicense {
+    key: String
+}
+
+/// A commit as returned by GitHub and Codeberg
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCommit {
+    commit: IncomingCommitDetails
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCommitDetails {
+    committer: Option<IncomingCommitCommitter>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCommitCommitter {
+    date: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabCommit {
+    committed_date: String
+}
+
+#[derive(Debug, Error)]
+enum GetSourceRepositoryMetadataError {
+    #[error("Source repository {url}: Host is not supported")]
+    UnsupportedHost {
+        url: String
+    },
+    #[error("Source repository {url}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        url: String,
+        status_code: u16
+    }
+}
+
+impl<T> SourceRepositoryClient<T> where T: ForgeServer + Send + Sync {
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn populate_source_repository_metadata(&self, db_pool: &Pool) -> Result<()> {
+        info!("Populating source repository metadata...");
+
+        let count = Arc::new(AtomicU32::new(0));
+        let date_started = OffsetDateTime::now_utc();
+
+        let repositories = get_distinct_source_repositories(db_pool).await?
+            .into_iter()
+            .filter(|x| Forge::from_host(&x.host).is_some());
+        let repository_stream = stream::iter(repositories);
+
+        let result = repository_stream
+            .map(Ok)
+            .try_for_each_concurrent(SOURCE_REPOSITORY_METADATA_CONCURRENT_FUTURES, |repository| self.process_source_repository_metadata(repository, db_pool, &count))
+            .await;
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::SourceRepository,
+            item: IngestLogItem::Metadata,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("Source repository metadata populated: {}", items_processed);
+
+        result
+    }
+
+    async fn process_source_repository_metadata(&self, repository: SourceRepository, db_pool: &Pool, count: &Arc<AtomicU32>) -> Result<()> {
+        let metadata_result = self.get_source_repository_metadata_from_api(&repository).await;
+
+        match metadata_result {
+            Ok(Some(metadata)) => {
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &metadata).await,
+                    None => time_db_upsert("source_repository_metadata", upsert_source_repository_metadata(db_pool, &metadata)).await
+                };
+
+                match db_result {
+                    Ok(_) => {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::SourceRepository, IngestLogItem::Metadata, ItemOutcome::Processed);
+                    },
+                    Err(err) => {
+                        record_item(IngestLogRepository::SourceRepository, IngestLogItem::Metadata, ItemOutcome::Failed);
+                        warn!("{}", err)
+                    }
+                }
+            }
+            Ok(None) => (),
+            Err(err) => {
+                record_item(IngestLogRepository::SourceRepository, IngestLogItem::Metadata, ItemOutcome::Failed);
+                warn!("{}", err)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the metadata of the source repository, or None if the repository no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    pub async fn get_source_repository_metadata_from_api(&self, repository: &SourceRepository) -> Result<Option<SourceRepositoryMetadata>> {
+        let forge = Forge::from_host(&repository.host)
+            .ok_or_else(|| GetSourceRepositoryMetadataError::UnsupportedHost { url: repository.url() })?;
+
+        let base_url = self.http_server.base_url(forge);
+
+        match forge {
+            Forge::GitHub => {
+                let repository_path = &["repos/", &repository.owner, "/", &repository.name].concat();
+                let incoming_repository: Option<IncomingGitHubRepository> = self.get_json_from_api(forge, repository, base_url.join(repository_path)?, &[]).await?;
+
+                let Some(incoming_repository) = incoming_repository else {
+                    return Ok(None)
+                };
+
+                let date_last_commit = self.get_date_last_commit_from_api(forge, repository, &incoming_repository.default_branch).await?;
+
+                Ok(Some(SourceRepositoryMetadata {
+                    stars: incoming_repository.stargazers_count,
+                    archived: incoming_repository.archived || incoming_repository.disabled,
+                    license: incoming_repository.license
+                        .and_then(|x| x.spdx_id)
+                        .filter(|x| x != GITHUB_UNKNOWN_LICENSE),
+                    open_issues: Some(incoming_repository.open_issues_count),
+                    date_last_commit,
+                    ..create_empty_source_repository_metadata(repository)
+                }))
+            },
+            Forge::Codeberg => {
+                let repository_path = &["repos/", &repository.owner, "/", &repository.name].concat();
+                let incoming_repository: Option<IncomingCodebergRepository> = self.get_json_from_api(forge, repository, base_url.join(repository_path)?, &[]).await?;
+
+                let Some(incoming_repository) = incoming_repository else {
+                    return Ok(None)
+                };
+
+                let date_last_commit = self.get_date_last_commit_from_api(forge, repository, &incoming_repository.default_branch).await?;
+
+                Ok(Some(SourceRepositoryMetadata {
+                    stars: incoming_repository.stars_count,
+                    archived: incoming_repository.archived,
+                    license: incoming_repository.licenses.into_iter().next(),
+                    open_issues: Some(incoming_repository.open_issues_count),
+                    date_last_commit,
+                    ..create_empty_source_repository_metadata(repository)
+                }))
+            },
+            Forge::GitLab => {
+                // GitLab identifies projects by their URL-encoded path
+                let project_path = &["projects/", &repository.owner, "%2F", &repository.name].concat();
+                let incoming_project: Option<IncomingGitLabProject> = self.get_json_from_api(forge, repository, base_url.join(project_path)?, &[("license", "true")]).await?;
+
+                let Some(incoming_project) = incoming_project else {
+                    return Ok(None)
+                };
+
+                let mut date_last_commit = None;
+
+                if let Some(default_branch) = &incoming_project.default_branch {
+                    let commits_url = base_url.join(&[project_path, "/repository/commits"].concat())?;
+                    let incoming_commits: Option<Vec<IncomingGitLabCommit>> = self.get_json_from_api(forge, repository, commits_url, &[("ref_name", default_branch), ("per_page", "1")]).await?;
+
+                    if let Some(commit) = incoming_commits.and_then(|x| x.into_iter().next()) {
+                        date_last_commit = Some(OffsetDateTime::parse(&commit.committed_date, &Rfc3339)?);
+                    }
+                }
+
+                Ok(Some(SourceRepositoryMetadata {
+                    stars: incoming_project.star_count,
+                    archived: incoming_project.archived,
+                    license: incoming_project.license.map(|x| x.key),
+                    open_issues: incoming_project.open_issues_count,
+                    date_last_commit,
+                    ..create_empty_source_repository_metadata(repository)
+                }))
+            }
+        }
+    }
+
+    /// Gets the date of the latest commit on the default branch from GitHub or Codeberg, whose commit APIs share the same shape.
+    async fn get_date_last_commit_from_api(&self, forge: Forge, repository: &SourceRepository, default_branch: &Option<String>) -> Result<Option<OffsetDateTime>> {
+        let Some(default_branch) = default_branch else {
+            return Ok(None)
+        };
+
+        let commits_path = &["repos/", &repository.owner, "/", &repository.name, "/commits"].concat();
+        let commits_url = self.http_server.base_url(forge).join(commits_path)?;
+
+        let limit_param = match forge {
+            Forge::Codeberg => "limit",
+            _ => "per_page"
+        };
+
+        let incoming_commits: Option<Vec<IncomingCommit>> = self.get_json_from_api(forge, repository, commits_url, &[("sha", default_branch), (limit_param, "1")]).await?;
+
+        let date = incoming_commits
+            .and_then(|x| x.into_iter().next())
+            .and_then(|x| x.commit.committer)
+            .map(|x| OffsetDateTime::parse(&x.date, &Rfc3339))
+            .transpose()?;
+
+        Ok(date)
+    }
+
+    /// Returns None if the repository or branch was not found, or if the repository is empty.
+    async fn get_json_from_api<R: DeserializeOwned>(&self, forge: Forge, repository: &SourceRepository, url: Url, query: &[(&str, &str)]) -> Result<Option<R>> {
+        let raw_response = send_with_retry(
+            self.get(forge, url).query(query),
+            &self.retry_policy,
+            UpstreamServer::SourceRepository,
+            || time_rate_limiter_wait(IngestLogRepository::SourceRepository, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => Ok(Some(raw_response.json().await?)),
+            StatusCode::NOT_FOUND | StatusCode::CONFLICT => Ok(None),
+            _ => {
+                Err(
+                    GetSourceRepositoryMetadataError::UnexpectedStatusCode {
+                        url: repository.url(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+}
+
+fn create_empty_source_repository_metadata(repository: &SourceRepository) -> SourceRepositoryMetadata {
+    SourceRepositoryMetadata {
+        host: repository.host.to_lowercase(),
+        owner: repository.owner.to_lowercase(),
+        name: repository.name.to_lowercase(),
+        stars: 0,
+        archived: false,
+        license: None,
+        open_issues: None,
+        date_last_commit: None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::source_repository::test::{SourceRepositoryTestServer, GITHUB_TEST_API_TOKEN};
+
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{header, method, path, query_param};
+
+    #[tokio::test]
+    async fn should_get_github_metadata_from_api() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_repository = IncomingGitHubRepository {
+            stargazers_count: 100,
+            archived: false,
+            disabled: true,
+            license: Some(IncomingGitHubLicense {
+                spdx_id: Some("MIT".to_string())
+            }),
+            open_issues_count: 5,
+            default_branch: Some("main".to_string())
+        };
+
+        let incoming_commits = vec![
+            create_test_incoming_commit("2024-01-02T03:04:05Z")
+        ];
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo"))
+            .and(header("authorization", ["Bearer ", GITHUB_TEST_API_TOKEN].concat().as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_repository))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/commits"))
+            .and(query_param("sha", "main"))
+            .and(query_param("per_page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_commits))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, Some(GITHUB_TEST_API_TOKEN))?;
+        let metadata = source_repository_client.get_source_repository_metadata_from_api(&create_test_source_repository("github.com")).await?;
+
+        // Assert
+        assert_that(&metadata).is_some().is_equal_to(SourceRepositoryMetadata {
+            host: "github.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            stars: 100,
+            archived: true,
+            license: Some("MIT".to_string()),
+            open_issues: Some(5),
+            date_last_commit: Some(datetime!(2024-01-02 3:04:05 UTC))
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_gitlab_metadata_from_api() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_project = IncomingGitLabProject {
+            star_count: 10,
+            archived: true,
+            license: Some(IncomingGitLabLicense {
+                key: "gpl-3.0".to_string()
+            }),
+            open_issues_count: None,
+            default_branch: Some("master".to_string())
+        };
+
+        let incoming_commits = vec![
+            IncomingGitLabCommit {
+                committed_date: "2018-01-01T00:00:00.000+00:00".to_string()
+            }
+        ];
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo"))
+            .and(query_param("license", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_project))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo/repository/commits"))
+            .and(query_param("ref_name", "master"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_commits))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let metadata = source_repository_client.get_source_repository_metadata_from_api(&create_test_source_repository("gitlab.com")).await?;
+
+        // Assert
+        assert_that(&metadata).is_some().is_equal_to(SourceRepositoryMetadata {
+            host: "gitlab.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            stars: 10,
+            archived: true,
+            license: Some("gpl-3.0".to_string()),
+            open_issues: None,
+            date_last_commit: Some(datetime!(2018-01-01 0:00 UTC))
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_codeberg_metadata_of_empty_repository_from_api() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_repository = IncomingCodebergRepository {
+            stars_count: 3,
+            archived: false,
+            licenses: vec!["Apache-2.0".to_string()],
+            open_issues_count: 1,
+            default_branch: Some("main".to_string())
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/codeberg/repos/alice/foo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_repository))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/codeberg/repos/alice/foo/commits"))
+            .and(query_param("limit", "1"))
+            .respond_with(ResponseTemplate::new(409))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let metadata = source_repository_client.get_source_repository_metadata_from_api(&create_test_source_repository("codeberg.org")).await?;
+
+        // Assert
+        assert_that(&metadata).is_some().is_equal_to(SourceRepositoryMetadata {
+            host: "codeberg.org".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            stars: 3,
+            archived: false,
+            license: Some("Apache-2.0".to_string()),
+            open_issues: Some(1),
+            date_last_commit: None
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_source_repository_is_not_found() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let metadata = source_repository_client.get_source_repository_metadata_from_api(&create_test_source_repository("github.com")).await?;
+
+        // Assert
+        assert_that(&metadata).is_none();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_metadata_request_is_forbidden() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let result = source_repository_client.get_source_repository_metadata_from_api(&create_test_source_repository("github.com")).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<GetSourceRepositoryMetadataError>().unwrap();
+
+        if let GetSourceRepositoryMetadataError::UnexpectedStatusCode{url, status_code} = downcast_error {
+            assert_that(url).is_equal_to("https://github.com/alice/foo".to_string());
+            assert_that(status_code).is_equal_to(403);
+        } else {
+            panic!("expected error to be UnexpectedStatusCode, but was {}", downcast_error);
+        }
+
+        Ok(())
+    }
+
+    fn create_test_incoming_commit(date: &str) -> IncomingCommit {
+        IncomingCommit {
+            commit: IncomingCommitDetails {
+                committer: Some(IncomingCommitCommitter {
+                    date: date.to_string()
+                })
+            }
+        }
+    }
+
+    fn create_test_source_repository(host: &str) -> SourceRepository {
+        SourceRepository {
+            host: host.to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            id: None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,750 @@
+use crate::retry::send_with_retry;
+use crate::source_repository::{Forge, ForgeServer, SourceRepositoryClient};
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
+use mc_plugin_finder::database::source_repository::SourceRepository;
+use mc_plugin_finder::database::source_repository_release::{SourceRepositoryRelease, upsert_source_repository_release, get_distinct_source_repositories};
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{Serialize, Deserialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tracing::{info, warn, instrument};
+
+const SOURCE_REPOSITORY_RELEASES_CONCURRENT_FUTURES: usize = 10;
+
+/// The latest release as returned by GitHub, and by Codeberg (Forgejo), whose API mirrors GitHub's.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingLatestRelease {
+    tag_name: String,
+    name: Option<String>,
+    html_url: Option<String>,
+    created_at: String,
+    published_at: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabRelease {
+    tag_name: String,
+    name: Option<String>,
+    created_at: String,
+    released_at: Option<String>,
+    #[serde(rename = "_links")]
+    links: Option<IncomingGitLabReleaseLinks>
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabReleaseLinks {
+    #[serde(rename = "self")]
+    r_self: Option<String>
+}
+
+/// A tag as returned by GitHub, and by Codeberg (Forgejo), which also includes the date of the tagged commit.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingTag {
+    name: String,
+    commit: IncomingTagCommit
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingTagCommit {
+    sha: String,
+    created: Option<String>
+}
+
+/// A commit as returned by GitHub
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCommit {
+    commit: IncomingCommitDetails
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCommitDetails {
+    committer: IncomingCommitCommitter
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingCommitCommitter {
+    date: String
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabTag {
+    name: String,
+    commit: IncomingGitLabTagCommit
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct IncomingGitLabTagCommit {
+    committed_date: String
+}
+
+#[derive(Debug, Error)]
+enum GetSourceRepositoryReleaseError {
+    #[error("Source repository {url}: Host is not supported")]
+    UnsupportedHost {
+        url: String
+    },
+    #[error("Source repository {url}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCode {
+        url: String,
+        status_code: u16
+    }
+}
+
+impl<T> SourceRepositoryClient<T> where T: ForgeServer + Send + Sync {
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn populate_source_repository_releases(&self, db_pool: &Pool) -> Result<()> {
+        info!("Populating source repository releases...");
+
+        let count = Arc::new(AtomicU32::new(0));
+        let date_started = OffsetDateTime::now_utc();
+
+        let repositories = get_distinct_source_repositories(db_pool).await?
+            .into_iter()
+            .filter(|x| Forge::from_host(&x.host).is_some());
+        let repository_stream = stream::iter(repositories);
+
+        let result = repository_stream
+            .map(Ok)
+            .try_for_each_concurrent(SOURCE_REPOSITORY_RELEASES_CONCURRENT_FUTURES, |repository| self.process_source_repository(repository, db_pool, &count))
+            .await;
+
+        let date_finished = OffsetDateTime::now_utc();
+        let items_processed = count.load(Ordering::Relaxed);
+
+        let ingest_log = IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::SourceRepository,
+            item: IngestLogItem::Release,
+            date_started,
+            date_finished,
+            items_processed: items_processed.try_into()?,
+            success: result.is_ok()
+        };
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
+
+        info!("Source repository releases populated: {}", items_processed);
+
+        result
+    }
+
+    async fn process_source_repository(&self, repository: SourceRepository, db_pool: &Pool, count: &Arc<AtomicU32>) -> Result<()> {
+        let release_result = self.get_latest_source_repository_release_from_api(&repository).await;
+
+        match release_result {
+            Ok(Some(release)) => {
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &release).await,
+                    None => time_db_upsert("source_repository_release", upsert_source_repository_release(db_pool, &release)).await
+                };
+
+                match db_result {
+                    Ok(_) => {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::SourceRepository, IngestLogItem::Release, ItemOutcome::Processed);
+                    },
+                    Err(err) => {
+                        record_item(IngestLogRepository::SourceRepository, IngestLogItem::Release, ItemOutcome::Failed);
+                        warn!("{}", err)
+                    }
+                }
+            }
+            Ok(None) => (),
+            Err(err) => {
+                record_item(IngestLogRepository::SourceRepository, IngestLogItem::Release, ItemOutcome::Failed);
+                warn!("{}", err)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the latest release of the source repository, falling back to its latest tag if it has no releases.
+    /// Returns None if the repository has neither releases nor tags, or no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    pub async fn get_latest_source_repository_release_from_api(&self, repository: &SourceRepository) -> Result<Option<SourceRepositoryRelease>> {
+        let forge = Forge::from_host(&repository.host)
+            .ok_or_else(|| GetSourceRepositoryReleaseError::UnsupportedHost { url: repository.url() })?;
+
+        let incoming_release = match forge {
+            Forge::GitHub | Forge::Codeberg => self.get_latest_release_from_api(forge, repository).await?,
+            Forge::GitLab => self.get_latest_gitlab_release_from_api(repository).await?
+        };
+
+        incoming_release.transpose()
+    }
+
+    async fn get_latest_release_from_api(&self, forge: Forge, repository: &SourceRepository) -> Result<Option<Result<SourceRepositoryRelease>>> {
+        let path = &["repos/", &repository.owner, "/", &repository.name, "/releases/latest"].concat();
+        let url = self.http_server.base_url(forge).join(path)?;
+
+        let raw_response = send_with_retry(
+            self.get(forge, url),
+            &self.retry_policy,
+            UpstreamServer::SourceRepository,
+            || time_rate_limiter_wait(IngestLogRepository::SourceRepository, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let incoming_release: IncomingLatestRelease = raw_response.json().await?;
+                Ok(Some(convert_incoming_latest_release(repository, incoming_release)))
+            }
+            // Also returned when the repository exists but has no releases
+            StatusCode::NOT_FOUND => self.get_latest_tag_from_api(forge, repository).await,
+            _ => {
+                Err(
+                    GetSourceRepositoryReleaseError::UnexpectedStatusCode {
+                        url: repository.url(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+
+    async fn get_latest_gitlab_release_from_api(&self, repository: &SourceRepository) -> Result<Option<Result<SourceRepositoryRelease>>> {
+        // GitLab identifies projects by their URL-encoded path
+        let path = &["projects/", &repository.owner, "%2F", &repository.name, "/releases"].concat();
+        let url = self.http_server.base_url(Forge::GitLab).join(path)?;
+
+        let raw_response = send_with_retry(
+            self.get(Forge::GitLab, url).query(&[("per_page", "1")]),
+            &self.retry_policy,
+            UpstreamServer::SourceRepository,
+            || time_rate_limiter_wait(IngestLogRepository::SourceRepository, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let incoming_releases: Vec<IncomingGitLabRelease> = raw_response.json().await?;
+                match incoming_releases.into_iter().next() {
+                    Some(incoming_release) => Ok(Some(convert_incoming_gitlab_release(repository, incoming_release))),
+                    None => self.get_latest_gitlab_tag_from_api(repository).await
+                }
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => {
+                Err(
+                    GetSourceRepositoryReleaseError::UnexpectedStatusCode {
+                        url: repository.url(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+
+    /// Gets the first tag listed by GitHub or Codeberg. Codeberg lists the most recently created tag first, while GitHub lists tags in reverse order by name.
+    async fn get_latest_tag_from_api(&self, forge: Forge, repository: &SourceRepository) -> Result<Option<Result<SourceRepositoryRelease>>> {
+        let path = &["repos/", &repository.owner, "/", &repository.name, "/tags"].concat();
+        let url = self.http_server.base_url(forge).join(path)?;
+
+        let limit_param = match forge {
+            Forge::Codeberg => "limit",
+            _ => "per_page"
+        };
+
+        let raw_response = send_with_retry(
+            self.get(forge, url).query(&[(limit_param, "1")]),
+            &self.retry_policy,
+            UpstreamServer::SourceRepository,
+            || time_rate_limiter_wait(IngestLogRepository::SourceRepository, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let incoming_tags: Vec<IncomingTag> = raw_response.json().await?;
+                let Some(incoming_tag) = incoming_tags.into_iter().next() else {
+                    return Ok(None)
+                };
+
+                // GitHub does not include the date of the tagged commit, so it has to be requested separately
+                let date_published = match incoming_tag.commit.created {
+                    Some(created) => created,
+                    None => self.get_commit_date_from_api(forge, repository, &incoming_tag.commit.sha).await?
+                };
+
+                Ok(Some(convert_incoming_tag(forge, repository, incoming_tag.name, &date_published)))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => {
+                Err(
+                    GetSourceRepositoryReleaseError::UnexpectedStatusCode {
+                        url: repository.url(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+
+    async fn get_commit_date_from_api(&self, forge: Forge, repository: &SourceRepository, sha: &str) -> Result<String> {
+        let path = &["repos/", &repository.owner, "/", &repository.name, "/commits/", sha].concat();
+        let url = self.http_server.base_url(forge).join(path)?;
+
+        let raw_response = send_with_retry(
+            self.get(forge, url),
+            &self.retry_policy,
+            UpstreamServer::SourceRepository,
+            || time_rate_limiter_wait(IngestLogRepository::SourceRepository, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let incoming_commit: IncomingCommit = raw_response.json().await?;
+                Ok(incoming_commit.commit.committer.date)
+            }
+            _ => {
+                Err(
+                    GetSourceRepositoryReleaseError::UnexpectedStatusCode {
+                        url: repository.url(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+
+    /// Gets the tag whose commit was most recently updated
+    async fn get_latest_gitlab_tag_from_api(&self, repository: &SourceRepository) -> Result<Option<Result<SourceRepositoryRelease>>> {
+        let path = &["projects/", &repository.owner, "%2F", &repository.name, "/repository/tags"].concat();
+        let url = self.http_server.base_url(Forge::GitLab).join(path)?;
+
+        let raw_response = send_with_retry(
+            self.get(Forge::GitLab, url).query(&[("order_by", "updated"), ("sort", "desc"), ("per_page", "1")]),
+            &self.retry_policy,
+            UpstreamServer::SourceRepository,
+            || time_rate_limiter_wait(IngestLogRepository::SourceRepository, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => {
+                let incoming_tags: Vec<IncomingGitLabTag> = raw_response.json().await?;
+                Ok(incoming_tags.into_iter().next().map(|x| convert_incoming_tag(Forge::GitLab, repository, x.name, &x.commit.committed_date)))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => {
+                Err(
+                    GetSourceRepositoryReleaseError::UnexpectedStatusCode {
+                        url: repository.url(),
+                        status_code: status.into()
+                    }.into()
+                )
+            }
+        }
+    }
+}
+
+fn convert_incoming_latest_release(repository: &SourceRepository, incoming_release: IncomingLatestRelease) -> Result<SourceRepositoryRelease> {
+    let date_published = incoming_release.published_at.unwrap_or(incoming_release.created_at);
+
+    Ok(SourceRepositoryRelease {
+        host: repository.host.to_lowercase(),
+        owner: repository.owner.to_lowercase(),
+        name: repository.name.to_lowercase(),
+        tag_name: incoming_release.tag_name,
+        release_name: incoming_release.name.filter(|x| !x.is_empty()),
+        url: incoming_release.html_url,
+        date_published: OffsetDateTime::parse(&date_published, &Rfc3339)?
+    })
+}
+
+fn convert_incoming_gitlab_release(repository: &SourceRepository, incoming_release: IncomingGitLabRelease) -> Result<SourceRepositoryRelease> {
+    let date_published = incoming_release.released_at.unwrap_or(incoming_release.created_at);
+
+    Ok(SourceRepositoryRelease {
+        host: repository.host.to_lowercase(),
+        owner: repository.owner.to_lowercase(),
+        name: repository.name.to_lowercase(),
+        tag_name: incoming_release.tag_name,
+        release_name: incoming_release.name.filter(|x| !x.is_empty()),
+        url: incoming_release.links.and_then(|x| x.r_self),
+        date_published: OffsetDateTime::parse(&date_published, &Rfc3339)?
+    })
+}
+
+/// Converts a tag without a release, which is dated by its commit and links to the tag's page on the forge.
+fn convert_incoming_tag(forge: Forge, repository: &SourceRepository, tag_name: String, date_published: &str) -> Result<SourceRepositoryRelease> {
+    let tag_path = match forge {
+        Forge::GitHub => "/releases/tag/",
+        Forge::GitLab => "/-/tags/",
+        Forge::Codeberg => "/src/tag/"
+    };
+
+    Ok(SourceRepositoryRelease {
+        host: repository.host.to_lowercase(),
+        owner: repository.owner.to_lowercase(),
+        name: repository.name.to_lowercase(),
+        url: Some([&repository.url(), tag_path, &tag_name].concat()),
+        tag_name,
+        release_name: None,
+        date_published: OffsetDateTime::parse(date_published, &Rfc3339)?
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::source_repository::test::{SourceRepositoryTestServer, GITHUB_TEST_API_TOKEN};
+
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+    use wiremock::{Mock, ResponseTemplate};
+    use wiremock::matchers::{header, method, path, query_param};
+
+    #[tokio::test]
+    async fn should_get_latest_github_release_from_api() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_release = IncomingLatestRelease {
+            tag_name: "v1.2.3".to_string(),
+            name: Some("Version 1.2.3".to_string()),
+            html_url: Some("https://github.com/alice/foo/releases/tag/v1.2.3".to_string()),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            published_at: Some("2024-01-02T00:00:00Z".to_string())
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(incoming_release);
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/releases/latest"))
+            .and(header("authorization", ["Bearer ", GITHUB_TEST_API_TOKEN].concat().as_str()))
+            .respond_with(response_template)
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, Some(GITHUB_TEST_API_TOKEN))?;
+        let release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("github.com")).await?;
+
+        // Assert
+        assert_that(&release).is_some().is_equal_to(SourceRepositoryRelease {
+            host: "github.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            tag_name: "v1.2.3".to_string(),
+            release_name: Some("Version 1.2.3".to_string()),
+            url: Some("https://github.com/alice/foo/releases/tag/v1.2.3".to_string()),
+            date_published: datetime!(2024-01-02 0:00 UTC)
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_latest_gitlab_release_from_api() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_releases = vec![
+            IncomingGitLabRelease {
+                tag_name: "v1.2.3".to_string(),
+                name: Some("".to_string()),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                released_at: None,
+                links: Some(IncomingGitLabReleaseLinks {
+                    r_self: Some("https://gitlab.com/alice/foo/-/releases/v1.2.3".to_string())
+                })
+            }
+        ];
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(incoming_releases);
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo/releases"))
+            .and(query_param("per_page", "1"))
+            .respond_with(response_template)
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("gitlab.com")).await?;
+
+        // Assert
+        assert_that(&release).is_some().is_equal_to(SourceRepositoryRelease {
+            host: "gitlab.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            tag_name: "v1.2.3".to_string(),
+            release_name: None,
+            url: Some("https://gitlab.com/alice/foo/-/releases/v1.2.3".to_string()),
+            date_published: datetime!(2024-01-01 0:00 UTC)
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_latest_codeberg_release_from_api() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_release = IncomingLatestRelease {
+            tag_name: "1.0.0".to_string(),
+            name: None,
+            html_url: None,
+            created_at: "2024-01-01T00:00:00+00:00".to_string(),
+            published_at: None
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .set_body_json(incoming_release);
+
+        Mock::given(method("GET"))
+            .and(path("/codeberg/repos/alice/foo/releases/latest"))
+            .respond_with(response_template)
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("codeberg.org")).await?;
+
+        // Assert
+        assert_that(&release.map(|x| x.date_published)).is_some().is_equal_to(datetime!(2024-01-01 0:00 UTC));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_latest_github_tag_from_api_if_source_repository_has_no_releases() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_tags = vec![
+            IncomingTag {
+                name: "v1.2.3".to_string(),
+                commit: IncomingTagCommit {
+                    sha: "abc123".to_string(),
+                    created: None
+                }
+            }
+        ];
+
+        let incoming_commit = IncomingCommit {
+            commit: IncomingCommitDetails {
+                committer: IncomingCommitCommitter {
+                    date: "2024-01-03T00:00:00Z".to_string()
+                }
+            }
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/releases/latest"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/tags"))
+            .and(query_param("per_page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_tags))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/commits/abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_commit))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("github.com")).await?;
+
+        // Assert
+        assert_that(&release).is_some().is_equal_to(SourceRepositoryRelease {
+            host: "github.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            tag_name: "v1.2.3".to_string(),
+            release_name: None,
+            url: Some("https://github.com/alice/foo/releases/tag/v1.2.3".to_string()),
+            date_published: datetime!(2024-01-03 0:00 UTC)
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_latest_gitlab_tag_from_api_if_source_repository_has_no_releases() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_tags = vec![
+            IncomingGitLabTag {
+                name: "v1.2.3".to_string(),
+                commit: IncomingGitLabTagCommit {
+                    committed_date: "2024-01-03T00:00:00.000+00:00".to_string()
+                }
+            }
+        ];
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<IncomingGitLabRelease>::new()))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo/repository/tags"))
+            .and(query_param("order_by", "updated"))
+            .and(query_param("per_page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_tags))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("gitlab.com")).await?;
+
+        // Assert
+        assert_that(&release).is_some().is_equal_to(SourceRepositoryRelease {
+            host: "gitlab.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            tag_name: "v1.2.3".to_string(),
+            release_name: None,
+            url: Some("https://gitlab.com/alice/foo/-/tags/v1.2.3".to_string()),
+            date_published: datetime!(2024-01-03 0:00 UTC)
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_latest_codeberg_tag_from_api_if_source_repository_has_no_releases() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        let incoming_tags = vec![
+            IncomingTag {
+                name: "1.0.0".to_string(),
+                commit: IncomingTagCommit {
+                    sha: "abc123".to_string(),
+                    created: Some("2024-01-03T00:00:00+00:00".to_string())
+                }
+            }
+        ];
+
+        Mock::given(method("GET"))
+            .and(path("/codeberg/repos/alice/foo/releases/latest"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/codeberg/repos/alice/foo/tags"))
+            .and(query_param("limit", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(incoming_tags))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("codeberg.org")).await?;
+
+        // Assert
+        assert_that(&release.as_ref().map(|x| x.date_published)).is_some().is_equal_to(datetime!(2024-01-03 0:00 UTC));
+        assert_that(&release.and_then(|x| x.url)).is_some().is_equal_to("https://codeberg.org/alice/foo/src/tag/1.0.0".to_string());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_source_repository_has_no_releases_or_tags() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/releases/latest"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/github/repos/alice/foo/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<IncomingTag>::new()))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<IncomingGitLabRelease>::new()))
+            .mount(source_repository_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/gitlab/projects/alice%2Ffoo/repository/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<IncomingGitLabTag>::new()))
+            .mount(source_repository_server.mock())
+            .await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let github_release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("github.com")).await?;
+        let gitlab_release = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("gitlab.com")).await?;
+
+        // Assert
+        assert_that(&github_release).is_none();
+        assert_that(&gitlab_release).is_none();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_source_repository_host_is_not_supported() -> Result<()> {
+        // Arrange
+        let source_repository_server = SourceRepositoryTestServer::new().await;
+
+        // Act
+        let source_repository_client = SourceRepositoryClient::new(source_repository_server, None)?;
+        let result = source_repository_client.get_latest_source_repository_release_from_api(&create_test_source_repository("bitbucket.org")).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<GetSourceRepositoryReleaseError>().unwrap();
+
+        if let GetSourceRepositoryReleaseError::UnsupportedHost{url} = downcast_error {
+            assert_that(url).is_equal_to("https://bitbucket.org/alice/foo".to_string());
+        } else {
+            panic!("expected error to be UnsupportedHost, but was {}", downcast_error);
+        }
+
+        Ok(())
+    }
+
+    fn create_test_source_repository(host: &str) -> SourceRepository {
+        SourceRepository {
+            host: host.to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            id: None
+        }
+    }
+}
\ No newline at end of file
@@ -1,5 +1,8 @@
 use crate::HttpServer;
+use crate::dry_run::DryRun;
+use crate::retry::send_with_retry;
 use crate::spigot::SpigotClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
 use mc_plugin_finder::database::spigot::author::{SpigotAuthor, insert_spigot_author};
 
@@ -106,7 +109,7 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
         let result = self
             .pages_ahead(SPIGOT_AUTHORS_REQUESTS_AHEAD, Limit::None, request)
             .items()
-            .try_for_each_concurrent(SPIGOT_AUTHORS_CONCURRENT_FUTURES, |incoming_author| process_incoming_author(incoming_author, db_pool, &count))
+            .try_for_each_concurrent(SPIGOT_AUTHORS_CONCURRENT_FUTURES, |incoming_author| process_incoming_author(incoming_author, db_pool, self.dry_run.as_deref(), &count))
             .await;
 
         let date_finished = OffsetDateTime::now_utc();
@@ -121,7 +124,9 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Spigot authors populated: {}", items_processed);
 
@@ -132,14 +137,15 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
         skip(self)
     )]
     async fn get_authors_from_api(&self, request: GetSpigotAuthorsRequest) -> Result<GetSpigotAuthorsResponse> {
-        self.rate_limiter.until_ready().await;
-
         let url = self.http_server.base_url().join("authors")?;
 
-        let raw_response = self.api_client.get(url)
-            .query(&request)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::Spigot,
+            || time_rate_limiter_wait(IngestLogRepository::Spigot, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         if status == StatusCode::OK {
@@ -185,14 +191,23 @@ impl<T> PageTurner<GetSpigotAuthorsRequest> for SpigotClient<T> where T: HttpSer
     }
 }
 
-async fn process_incoming_author(incoming_author: IncomingSpigotAuthor, db_pool: &Pool, count: &Arc<AtomicU32>) -> Result<()> {
-    let db_result = insert_spigot_author(db_pool, &incoming_author.into()).await;
+async fn process_incoming_author(incoming_author: IncomingSpigotAuthor, db_pool: &Pool, dry_run: Option<&DryRun>, count: &Arc<AtomicU32>) -> Result<()> {
+    let author: SpigotAuthor = incoming_author.into();
+
+    let db_result = match dry_run {
+        Some(dry_run) => dry_run.report(db_pool, &author).await,
+        None => time_db_upsert("spigot_author", insert_spigot_author(db_pool, &author)).await
+    };
 
     match db_result {
         Ok(_) => {
             count.fetch_add(1, Ordering::Relaxed);
+            record_item(IngestLogRepository::Spigot, IngestLogItem::Author, ItemOutcome::Processed);
+        }
+        Err(err) => {
+            record_item(IngestLogRepository::Spigot, IngestLogItem::Author, ItemOutcome::Failed);
+            warn!("{}", err)
         }
-        Err(err) => warn!("{}", err)
     }
 
     Ok(())
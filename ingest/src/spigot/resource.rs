@@ -1,16 +1,27 @@
 pub mod name;
+pub mod tested_versions;
 
 use crate::HttpServer;
+use crate::description::convert_base64_html_description;
+use crate::failure::{FailureKind, QuarantinedItems};
+use crate::retry::send_with_retry;
 use crate::spigot::SpigotClient;
 use crate::spigot::resource::name::{ABANDONMENT_REGEX, parse_spigot_resource_name};
+use crate::spigot::resource::tested_versions::parse_spigot_tested_versions;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
+use mc_plugin_finder::database::ingest_failure::get_ingest_failures;
+use mc_plugin_finder::database::ingest_checkpoint::{IngestCheckpoint, upsert_ingest_checkpoint, get_ingest_checkpoint, delete_ingest_checkpoint, get_checkpoint_start_date};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
-use mc_plugin_finder::database::spigot::resource::{SpigotResource, upsert_spigot_resource};
+use mc_plugin_finder::database::spigot::resource::{SpigotResource, upsert_spigot_resource, mark_unseen_spigot_resources_as_deleted};
+use mc_plugin_finder::database::project_category::ProjectCategory;
+use mc_plugin_finder::database::server_platform::ServerPlatform;
 use mc_plugin_finder::database::source_repository::{SourceRepository, extract_source_repository_from_url};
+use mc_plugin_finder::minecraft_version::find_latest_minecraft_version;
 
 use anyhow::Result;
 use deadpool_postgres::Pool;
 use futures::future;
-use futures::stream::TryStreamExt;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use page_turner::prelude::*;
 use regex::Regex;
 use reqwest::StatusCode;
@@ -22,7 +33,9 @@ use thiserror::Error;
 use time::OffsetDateTime;
 use tracing::{info, warn, instrument};
 
-const SPIGOT_RESOURCES_REQUEST_FIELDS: &str = "id,name,tag,icon,releaseDate,updateDate,testedVersions,downloads,likes,file,author,version,premium,sourceCodeLink";
+const SPIGOT_RESOURCES_REQUEST_FIELDS: &str = "id,name,tag,description,icon,releaseDate,updateDate,testedVersions,downloads,likes,file,author,version,premium,sourceCodeLink,category";
+const SPIGOT_BUNGEE_SPIGOT_CATEGORY_ID: i32 = 2;
+const SPIGOT_BUNGEE_PROXY_CATEGORY_ID: i32 = 3;
 const SPIGOT_RESOURCES_REQUESTS_AHEAD: usize = 2;
 const SPIGOT_RESOURCES_CONCURRENT_FUTURES: usize = 10;
 
@@ -89,6 +102,7 @@ pub struct IncomingSpigotResource {
     id: i32,
     name: String,
     tag: String,
+    description: Option<String>,
     icon: Option<IncomingSpigotResourceNestedIcon>,
     release_date: i64,
     update_date: i64,
@@ -100,6 +114,7 @@ pub struct IncomingSpigotResource {
     version: IncomingSpigotResourceNestedVersion,
     premium: Option<bool>,
     source_code_link: Option<String>,
+    category: Option<IncomingSpigotResourceNestedCategory>
 }
 
 impl IncomingSpigotResource {
@@ -130,12 +145,22 @@ pub struct IncomingSpigotResourceNestedVersion {
     id: i32
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IncomingSpigotResourceNestedCategory {
+    id: i32
+}
+
 #[derive(Debug, Error)]
 enum GetSpigotResourcesError {
     #[error("Could not get Spigot resources {request:?}: Received unexpected status code {status_code}")]
     UnexpectedStatusCode {
         request: GetSpigotResourcesRequest,
         status_code: u16
+    },
+    #[error("Could not get Spigot resource ID {resource_id}: Received unexpected status code {status_code}")]
+    UnexpectedStatusCodeForResource {
+        resource_id: i32,
+        status_code: u16
     }
 }
 
@@ -156,19 +181,47 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
     #[instrument(
         skip(self, db_pool)
     )]
-    pub async fn populate_spigot_resources(&self, db_pool: &Pool) -> Result<()> {
+    pub async fn populate_spigot_resources(&self, db_pool: &Pool, resume: bool, reconcile: bool) -> Result<()> {
         info!("Populating Spigot resources...");
 
-        let request = GetSpigotResourcesRequest::create_populate_request();
+        let mut request = GetSpigotResourcesRequest::create_populate_request();
         let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
         let date_started = OffsetDateTime::now_utc();
 
+        let mut checkpoint_date_started = get_checkpoint_start_date(db_pool).await?;
+
+        if resume {
+            match get_ingest_checkpoint(db_pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await? {
+                Some(checkpoint) => {
+                    request.page = u32::try_from(checkpoint.position)? + 1;
+                    checkpoint_date_started = checkpoint.date_started;
+                    info!("Resuming from page: {}", request.page);
+                }
+                None => info!("No checkpoint found, starting from the beginning...")
+            }
+        }
+
+        let first_page = request.page;
+
         let result = self
             .pages_ahead(SPIGOT_RESOURCES_REQUESTS_AHEAD, Limit::None, request)
-            .items()
-            .try_for_each_concurrent(SPIGOT_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, &count, false))
+            .enumerate()
+            .map(|(index, page_result)| page_result.map(|incoming_resources| (first_page + index as u32, incoming_resources)))
+            .try_for_each(|(page, incoming_resources)| self.process_incoming_resource_page(page, incoming_resources, db_pool, &count, &quarantined, checkpoint_date_started))
             .await;
 
+        // The run has completed, so there is nothing left to resume.
+        if result.is_ok() && self.dry_run.is_none() {
+            delete_ingest_checkpoint(db_pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+
+            // Anything that was not seen since the run started is no longer available upstream.
+            if reconcile {
+                let deleted = mark_unseen_spigot_resources_as_deleted(db_pool, checkpoint_date_started).await?;
+                info!("Spigot resources marked as deleted: {}", deleted);
+            }
+        }
+
         let date_finished = OffsetDateTime::now_utc();
         let items_processed = count.load(Ordering::Relaxed);
 
@@ -181,7 +234,9 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Spigot resources populated: {}", items_processed);
 
@@ -196,13 +251,14 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
 
         let request = GetSpigotResourcesRequest::create_update_request();
         let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::load(db_pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
         let date_started = OffsetDateTime::now_utc();
 
         let result = self
             .pages_ahead(SPIGOT_RESOURCES_REQUESTS_AHEAD, Limit::None, request)
             .items()
             .try_take_while(|x| future::ready(Ok(x.update_date > update_date_later_than.unix_timestamp())))
-            .try_for_each_concurrent(SPIGOT_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, &count, true))
+            .try_for_each_concurrent(SPIGOT_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, &count, &quarantined, true))
             .await;
 
         let date_finished = OffsetDateTime::now_utc();
@@ -217,24 +273,65 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Spigot resources updated: {}", items_processed);
 
         result
     }
 
+    /// Re-fetches and processes each resource that previously failed, removing it from the ingest failures if it succeeds.
+    #[instrument(
+        skip(self, db_pool)
+    )]
+    pub async fn retry_spigot_resource_failures(&self, db_pool: &Pool) -> Result<()> {
+        let failures = get_ingest_failures(db_pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+        info!("Retrying failed Spigot resources: {}", failures.len());
+
+        let count = Arc::new(AtomicU32::new(0));
+        let quarantined = QuarantinedItems::new(IngestLogRepository::Spigot, IngestLogItem::Resource, failures.iter().map(|x| x.upstream_id.clone()));
+
+        for failure in failures {
+            let resource_id: i32 = match failure.upstream_id.parse() {
+                Ok(resource_id) => resource_id,
+                Err(err) => {
+                    warn!("Skipping Spigot resource failure {}: {}", failure.upstream_id, err);
+                    continue;
+                }
+            };
+
+            match self.get_resource_from_api(resource_id).await {
+                // The resource is removed from the ingest failures if it succeeds, or quarantined again if it still fails.
+                Ok(Some(incoming_resource)) => self.process_incoming_resource(incoming_resource, db_pool, &count, &quarantined, true).await?,
+                // The resource no longer exists upstream
+                Ok(None) => {
+                    if self.dry_run.is_none() {
+                        quarantined.unquarantine(db_pool, &failure.upstream_id).await;
+                    }
+                }
+                Err(err) => warn!("{}", err)
+            }
+        }
+
+        info!("Spigot resources retried: {}", count.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
     #[instrument(
         skip(self)
     )]
     async fn get_resources_from_api(&self, request: GetSpigotResourcesRequest) -> Result<GetSpigotResourcesResponse> {
-        self.rate_limiter.until_ready().await;
-
         let url = self.http_server.base_url().join("resources")?;
-        let raw_response = self.api_client.get(url)
-            .query(&request)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&request),
+            &self.retry_policy,
+            UpstreamServer::Spigot,
+            || time_rate_limiter_wait(IngestLogRepository::Spigot, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         if status == StatusCode::OK {
@@ -263,7 +360,55 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
         }
     }
 
-    async fn process_incoming_resource(&self, incoming_resource: IncomingSpigotResource, db_pool: &Pool, count: &Arc<AtomicU32>, get_version: bool) -> Result<()> {
+    /// Gets a single resource, or None if it no longer exists.
+    #[instrument(
+        skip(self)
+    )]
+    async fn get_resource_from_api(&self, resource_id: i32) -> Result<Option<IncomingSpigotResource>> {
+        let path = &["resources/", resource_id.to_string().as_str()].concat();
+        let url = self.http_server.base_url().join(path)?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url)
+                .query(&[("fields", SPIGOT_RESOURCES_REQUEST_FIELDS)]),
+            &self.retry_policy,
+            UpstreamServer::Spigot,
+            || time_rate_limiter_wait(IngestLogRepository::Spigot, self.rate_limiter.until_ready())
+        ).await?;
+
+        let status = raw_response.status();
+        match status {
+            StatusCode::OK => Ok(Some(raw_response.json().await?)),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(
+                GetSpigotResourcesError::UnexpectedStatusCodeForResource {
+                    resource_id,
+                    status_code: status.into()
+                }.into()
+            )
+        }
+    }
+
+    async fn process_incoming_resource_page(&self, page: u32, incoming_resources: Vec<IncomingSpigotResource>, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, date_started: OffsetDateTime) -> Result<()> {
+        stream::iter(incoming_resources)
+            .map(Ok)
+            .try_for_each_concurrent(SPIGOT_RESOURCES_CONCURRENT_FUTURES, |incoming_resource| self.process_incoming_resource(incoming_resource, db_pool, count, quarantined, false))
+            .await?;
+
+        let checkpoint = IngestCheckpoint {
+            repository: IngestLogRepository::Spigot,
+            item: IngestLogItem::Resource,
+            position: page.try_into()?,
+            date_started,
+            date_updated: OffsetDateTime::now_utc()
+        };
+        if self.dry_run.is_none() {
+            upsert_ingest_checkpoint(db_pool, &checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_incoming_resource(&self, incoming_resource: IncomingSpigotResource, db_pool: &Pool, count: &Arc<AtomicU32>, quarantined: &QuarantinedItems, get_version: bool) -> Result<()> {
         let mut version_name = None;
 
         if get_version {
@@ -275,20 +420,41 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
             }
         }
 
+        let resource_id = incoming_resource.id;
+        let payload = incoming_resource.clone();
         let convert_result = convert_incoming_resource(incoming_resource, &version_name).await;
 
         match convert_result {
             Ok(resource) => {
-                let db_result = upsert_spigot_resource(db_pool, &resource).await;
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report(db_pool, &resource).await,
+                    None => time_db_upsert("spigot_resource", upsert_spigot_resource(db_pool, &resource)).await
+                };
 
                 match db_result {
                     Ok(_) => {
                         count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::Spigot, IngestLogItem::Resource, ItemOutcome::Processed);
+                        if self.dry_run.is_none() {
+                            quarantined.unquarantine(db_pool, &resource_id.to_string()).await;
+                        }
+                    }
+                    Err(err) => {
+                        record_item(IngestLogRepository::Spigot, IngestLogItem::Resource, ItemOutcome::Failed);
+                        warn!("{}", err);
+                        if self.dry_run.is_none() {
+                            quarantined.quarantine(db_pool, resource_id.to_string(), FailureKind::Database, &err, &payload).await;
+                        }
                     }
-                    Err(err) => warn!("{}", err)
                 }
             }
-            Err(err) => warn!("{}", err)
+            Err(err) => {
+                record_item(IngestLogRepository::Spigot, IngestLogItem::Resource, ItemOutcome::Skipped);
+                warn!("{}", err);
+                if self.dry_run.is_none() {
+                    quarantined.quarantine(db_pool, resource_id.to_string(), FailureKind::Conversion, &err, &payload).await;
+                }
+            }
         }
 
         Ok(())
@@ -317,8 +483,20 @@ async fn convert_incoming_resource(incoming_resource: IncomingSpigotResource, ve
     if let Some(ref file) = incoming_resource.file {
         if let Some(slug) = extract_slug_from_file_download_url(&file.url) {
             let parsed_name = parse_spigot_resource_name(&incoming_resource.name);
+
+            // "testedVersions" may not exist in the API response, default to an empty vec if this is the case.
+            let minecraft_versions = parse_spigot_tested_versions(incoming_resource.tested_versions.as_deref().unwrap_or_default());
             let abandoned = incoming_resource.is_abandoned();
 
+            // An invalid full description should not prevent the rest of the resource from being saved.
+            let full_description = incoming_resource.description.as_deref().and_then(|data| {
+                convert_base64_html_description(data)
+                    .unwrap_or_else(|err| {
+                        warn!("Ignoring full description of resource ID {}: {}", resource_id, err);
+                        None
+                    })
+            });
+
             let mut resource = SpigotResource {
                 id: incoming_resource.id,
                 name: incoming_resource.name,
@@ -328,9 +506,7 @@ async fn convert_incoming_resource(incoming_resource: IncomingSpigotResource, ve
                 date_created: OffsetDateTime::from_unix_timestamp(incoming_resource.release_date)?,
                 date_updated: OffsetDateTime::from_unix_timestamp(incoming_resource.update_date)?,
 
-                // "testedVersions" may not exist in the API response, default to an empty vec if this is the case.
-                // Assume that the last entry in the given list of versions from the API is the latest version.
-                latest_minecraft_version: incoming_resource.tested_versions.unwrap_or_default().last().cloned(),
+                latest_minecraft_version: find_latest_minecraft_version(&minecraft_versions),
                 downloads: incoming_resource.downloads,
 
                 // "likes" may not exist in the API response, default to 0 if this is the case.
@@ -351,7 +527,15 @@ async fn convert_incoming_resource(incoming_resource: IncomingSpigotResource, ve
                 icon_data: incoming_resource.icon.map(|icon| icon.data),
 
                 source_url: incoming_resource.source_code_link.clone(),
-                source_repository: None
+                source_repository: None,
+
+                // "category" may not exist in the API response, assume the resource is a Spigot plugin if this is the case.
+                platforms: convert_spigot_category_to_platforms(incoming_resource.category.as_ref().map(|category| category.id)),
+                categories: convert_spigot_category_to_categories(incoming_resource.category.map(|category| category.id)),
+                minecraft_versions,
+
+                // "description" may not exist in the API response, set "full_description" to None if this is the case.
+                full_description
             };
 
             if let Some(url) = incoming_resource.source_code_link {
@@ -385,7 +569,37 @@ async fn convert_incoming_resource(incoming_resource: IncomingSpigotResource, ve
     }
 }
 
+// Spigot does not record which server software a resource supports, so we infer it from the resource's category.
+// "Tested versions" are only Minecraft versions, so they cannot tell us anything further.
+// Resources are usually filed under one of the subcategories, so those are checked along with their parent category.
+fn convert_spigot_category_to_platforms(category_id: Option<i32>) -> Vec<ServerPlatform> {
+    match category_id {
+        // "Bungee - Spigot": Spigot plugins that work alongside a BungeeCord proxy, often shipped with a proxy counterpart.
+        Some(SPIGOT_BUNGEE_SPIGOT_CATEGORY_ID | 5..=8) => vec![ServerPlatform::Spigot, ServerPlatform::BungeeCord],
+        // "Bungee - Proxy": Plugins that run on the BungeeCord proxy itself.
+        Some(SPIGOT_BUNGEE_PROXY_CATEGORY_ID | 9..=13) => vec![ServerPlatform::BungeeCord],
+        _ => vec![ServerPlatform::Spigot]
+    }
+}
 
+// Maps Spigot's subcategories onto our own categories. The same subcategory name appears under several parent categories with different IDs.
+// Parent categories and subcategories with no equivalent (e.g. "Skript", "Standalone") are left uncategorized.
+fn convert_spigot_category_to_categories(category_id: Option<i32>) -> Vec<ProjectCategory> {
+    let category = match category_id {
+        Some(5 | 10) => Some(ProjectCategory::Transportation),
+        Some(6 | 11 | 14) => Some(ProjectCategory::Chat),
+        Some(7 | 12 | 15) => Some(ProjectCategory::Utility),
+        Some(8 | 13 | 16) => Some(ProjectCategory::Miscellaneous),
+        Some(9 | 26) => Some(ProjectCategory::Library),
+        Some(17 | 22) => Some(ProjectCategory::Gameplay),
+        Some(18) => Some(ProjectCategory::WorldManagement),
+        Some(23) => Some(ProjectCategory::Economy),
+        Some(24) => Some(ProjectCategory::Minigames),
+        _ => None
+    };
+
+    category.into_iter().collect()
+}
 
 static SLUG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"resources/(\S+\.\d+)/download.*").unwrap());
 
@@ -398,6 +612,7 @@ fn extract_slug_from_file_download_url(url: &str) -> Option<String> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::retry::test::create_test_retry_policy;
     use crate::spigot::test::SpigotTestServer;
     use mc_plugin_finder::database::spigot::test::SPIGOT_BASE64_TEST_ICON_DATA;
 
@@ -463,6 +678,97 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_get_resource_from_api() -> Result<()> {
+        // Arrange
+        let spigot_server = SpigotTestServer::new().await;
+
+        let expected_resource = create_test_resources()[0].clone();
+        let api_path = &["/resources/", expected_resource.id.to_string().as_str()].concat();
+
+        Mock::given(method("GET"))
+            .and(path(api_path))
+            .and(query_param("fields", SPIGOT_RESOURCES_REQUEST_FIELDS))
+            .respond_with(ResponseTemplate::new(200).set_body_json(expected_resource.clone()))
+            .mount(spigot_server.mock())
+            .await;
+
+        // Act
+        let spigot_client = SpigotClient::new(spigot_server)?;
+        let resource = spigot_client.get_resource_from_api(expected_resource.id).await?;
+
+        // Assert
+        assert_that(&resource).is_some().is_equal_to(expected_resource);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_resource_is_not_found() -> Result<()> {
+        // Arrange
+        let spigot_server = SpigotTestServer::new().await;
+
+        Mock::given(method("GET"))
+            .and(path("/resources/1"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(spigot_server.mock())
+            .await;
+
+        // Act
+        let spigot_client = SpigotClient::new(spigot_server)?;
+        let resource = spigot_client.get_resource_from_api(1).await?;
+
+        // Assert
+        assert_that(&resource).is_none();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_retry_getting_resources_from_api_after_transient_error() -> Result<()> {
+        // Arrange
+        let spigot_server = SpigotTestServer::new().await;
+
+        let request = GetSpigotResourcesRequest::create_populate_request();
+
+        let expected_response = GetSpigotResourcesResponse {
+            headers: GetSpigotResourcesResponseHeaders {
+                x_page_index: 1,
+                x_page_count: 10
+            },
+            resources: create_test_resources()
+        };
+
+        let response_template = ResponseTemplate::new(200)
+            .append_header("x-page-index", expected_response.headers.x_page_index.to_string().as_str())
+            .append_header("x-page-count", expected_response.headers.x_page_count.to_string().as_str())
+            .set_body_json(expected_response.resources.clone());
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(spigot_server.mock())
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/resources"))
+            .respond_with(response_template)
+            .expect(1)
+            .mount(spigot_server.mock())
+            .await;
+
+        // Act
+        let spigot_client = SpigotClient::new(spigot_server)?.with_retry_policy(create_test_retry_policy());
+        let response = spigot_client.get_resources_from_api(request).await;
+
+        // Assert
+        assert_that(&response).is_ok().is_equal_to(expected_response);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_convert_incoming_resource() -> Result<()> {
         // Arrange
@@ -497,7 +803,11 @@ mod test {
                 owner: "alice".to_string(),
                 name: "foo".to_string(),
                 id: None
-            })
+            }),
+            platforms: vec![ServerPlatform::Spigot],
+            categories: vec![ProjectCategory::Chat],
+            minecraft_versions: vec!["1.20".to_string(), "1.20.6".to_string(), "1.21".to_string()],
+            full_description: Some("<p>foo-spigot-full-description</p>".to_string())
         };
 
         assert_that(&resource).is_equal_to(expected_resource);
@@ -546,7 +856,11 @@ mod test {
                 owner: "alice".to_string(),
                 name: "foo".to_string(),
                 id: None
-            })
+            }),
+            platforms: vec![ServerPlatform::Spigot],
+            categories: vec![ProjectCategory::Chat],
+            minecraft_versions: vec!["1.20".to_string(), "1.20.6".to_string(), "1.21".to_string()],
+            full_description: Some("<p>foo-spigot-full-description</p>".to_string())
         };
 
         assert_that(&resource).is_equal_to(expected_resource);
@@ -554,6 +868,38 @@ mod test {
         Ok(())
     }
 
+    #[rstest]
+    #[case::bungee_spigot(Some(2), vec![ServerPlatform::Spigot, ServerPlatform::BungeeCord])]
+    #[case::bungee_spigot_subcategory(Some(6), vec![ServerPlatform::Spigot, ServerPlatform::BungeeCord])]
+    #[case::bungee_proxy(Some(3), vec![ServerPlatform::BungeeCord])]
+    #[case::bungee_proxy_subcategory(Some(11), vec![ServerPlatform::BungeeCord])]
+    #[case::spigot(Some(4), vec![ServerPlatform::Spigot])]
+    #[case::no_category(None, vec![ServerPlatform::Spigot])]
+    fn should_convert_spigot_category_to_platforms(#[case] category_id: Option<i32>, #[case] expected_platforms: Vec<ServerPlatform>) {
+        // Act
+        let platforms = convert_spigot_category_to_platforms(category_id);
+
+        // Assert
+        assert_that(&platforms).is_equal_to(expected_platforms);
+    }
+
+    #[rstest]
+    #[case::bungee_spigot_chat(Some(6), vec![ProjectCategory::Chat])]
+    #[case::bungee_proxy_chat(Some(11), vec![ProjectCategory::Chat])]
+    #[case::spigot_chat(Some(14), vec![ProjectCategory::Chat])]
+    #[case::spigot_economy(Some(23), vec![ProjectCategory::Economy])]
+    #[case::spigot_game_mode(Some(24), vec![ProjectCategory::Minigames])]
+    #[case::spigot_skript(Some(25), vec![])]
+    #[case::parent_category(Some(4), vec![])]
+    #[case::no_category(None, vec![])]
+    fn should_convert_spigot_category_to_categories(#[case] category_id: Option<i32>, #[case] expected_categories: Vec<ProjectCategory>) {
+        // Act
+        let categories = convert_spigot_category_to_categories(category_id);
+
+        // Assert
+        assert_that(&categories).is_equal_to(expected_categories);
+    }
+
     #[tokio::test]
     async fn should_not_convert_resource_with_invalid_slug() -> Result<()> {
         // Arrange
@@ -617,6 +963,7 @@ mod test {
                 id: 1,
                 name: "foo-spigot".to_string(),
                 tag: "foo-spigot-description".to_string(),
+                description: Some("PHA+Zm9vLXNwaWdvdC1mdWxsLWRlc2NyaXB0aW9uPC9wPg==".to_string()),
                 icon: Some(IncomingSpigotResourceNestedIcon {
                     url: "data/resource_icons/1/1.jpg".to_string(),
                     data: SPIGOT_BASE64_TEST_ICON_DATA.to_string(),
@@ -636,12 +983,16 @@ mod test {
                     id: 1
                 },
                 premium: Some(false),
-                source_code_link: Some("https://github.com/alice/foo".to_string())
+                source_code_link: Some("https://github.com/alice/foo".to_string()),
+                category: Some(IncomingSpigotResourceNestedCategory {
+                    id: 14
+                })
             },
             IncomingSpigotResource {
                 id: 2,
                 name: "bar-spigot".to_string(),
                 tag: "bar-spigot-description".to_string(),
+                description: None,
                 icon: Some(IncomingSpigotResourceNestedIcon {
                     url: "data/resource_icons/2/2.jpg".to_string(),
                     data: SPIGOT_BASE64_TEST_ICON_DATA.to_string()
@@ -661,7 +1012,10 @@ mod test {
                     id: 1
                 },
                 premium: Some(false),
-                source_code_link: Some("https://gitlab.com/bob/bar".to_string())
+                source_code_link: Some("https://gitlab.com/bob/bar".to_string()),
+                category: Some(IncomingSpigotResourceNestedCategory {
+                    id: 3
+                })
             }
         ]
     }
@@ -0,0 +1,89 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+static MINECRAFT_VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+\.\d+(\.\d+)?$").unwrap());
+/// Ranges with more major versions than this, or ending beyond the highest plausible minor number, are ignored rather than expanded.
+const MAX_RANGE_MAJOR_VERSIONS: u32 = 50;
+const MAX_RANGE_MINOR_NUMBER: u32 = 99;
+
+static MINECRAFT_VERSION_RANGE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+)\.(\d+)(?:\.\d+)?\s*[-~]\s*(\d+)\.(\d+)(?:\.\d+)?$").unwrap());
+
+/*
+    Parses the "testedVersions" of a Spigot resource into the full set of Minecraft versions that it supports.
+
+    Entries are usually single major versions like "1.20", but some are ranges like "1.8-1.20".
+    Ranges are expanded to every major version in between, so "1.8-1.20" becomes "1.8", "1.9", ..., "1.20".
+    Minor versions at either end of a range are dropped, since Spigot only tracks major versions.
+    Implausibly wide ranges like "1.0-1.999" are ignored.
+    Anything else that does not look like a version is ignored.
+
+    The returned versions are sorted in numerical order, with duplicates removed.
+ */
+pub fn parse_spigot_tested_versions(tested_versions: &[String]) -> Vec<String> {
+    let mut versions = Vec::new();
+
+    for tested_version in tested_versions {
+        let tested_version = tested_version.trim();
+
+        if MINECRAFT_VERSION_REGEX.is_match(tested_version) {
+            versions.push(tested_version.to_string());
+        } else if let Some(caps) = MINECRAFT_VERSION_RANGE_REGEX.captures(tested_version) {
+            let start_major = &caps[1];
+            let end_major = &caps[3];
+
+            // Ranges spanning different leading version numbers (e.g. "1.20-2.0") cannot be expanded reliably.
+            if start_major != end_major {
+                continue;
+            }
+
+            if let (Ok(start_minor), Ok(end_minor)) = (caps[2].parse::<u32>(), caps[4].parse::<u32>()) {
+                let (low_minor, high_minor) = (start_minor.min(end_minor), start_minor.max(end_minor));
+
+                if high_minor > MAX_RANGE_MINOR_NUMBER || high_minor - low_minor >= MAX_RANGE_MAJOR_VERSIONS {
+                    continue;
+                }
+
+                for minor in low_minor..=high_minor {
+                    versions.push(format!("{}.{}", start_major, minor));
+                }
+            }
+        }
+    }
+
+    numeric_sort::sort(&mut versions);
+    versions.dedup();
+    versions
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rstest::*;
+    use speculoos::prelude::*;
+
+    #[rstest]
+    #[case::single_versions(vec!["1.20", "1.20.6", "1.21"], vec!["1.20", "1.20.6", "1.21"])]
+    #[case::unordered_versions(vec!["1.21", "1.8", "1.16"], vec!["1.8", "1.16", "1.21"])]
+    #[case::duplicate_versions(vec!["1.20", "1.20"], vec!["1.20"])]
+    #[case::range(vec!["1.8-1.12"], vec!["1.8", "1.9", "1.10", "1.11", "1.12"])]
+    #[case::range_with_whitespace(vec!["1.18 - 1.20"], vec!["1.18", "1.19", "1.20"])]
+    #[case::range_with_tilde(vec!["1.18~1.20"], vec!["1.18", "1.19", "1.20"])]
+    #[case::range_with_minor_versions(vec!["1.19.4-1.20.6"], vec!["1.19", "1.20"])]
+    #[case::reversed_range(vec!["1.20-1.18"], vec!["1.18", "1.19", "1.20"])]
+    #[case::overlapping_range_and_versions(vec!["1.8", "1.8-1.10", "1.21"], vec!["1.8", "1.9", "1.10", "1.21"])]
+    #[case::range_too_wide(vec!["1.0-1.999999999", "1.20"], vec!["1.20"])]
+    #[case::range_beyond_highest_minor(vec!["1.100-1.101"], vec![])]
+    #[case::range_across_leading_versions(vec!["1.20-2.0"], vec![])]
+    #[case::invalid(vec!["Legacy", "1.13+", ""], vec![])]
+    fn should_parse_spigot_tested_versions(#[case] tested_versions: Vec<&str>, #[case] expected_versions: Vec<&str>) {
+        // Arrange
+        let tested_versions: Vec<String> = tested_versions.into_iter().map(|x| x.to_string()).collect();
+
+        // Act
+        let versions = parse_spigot_tested_versions(&tested_versions);
+
+        // Assert
+        assert_that(&versions).is_equal_to(expected_versions.into_iter().map(|x| x.to_string()).collect::<Vec<String>>());
+    }
+}
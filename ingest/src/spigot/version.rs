@@ -1,5 +1,7 @@
 use crate::HttpServer;
+use crate::retry::send_with_retry;
 use crate::spigot::SpigotClient;
+use crate::metrics::{ItemOutcome, record_item, time_db_upsert, time_rate_limiter_wait, UpstreamServer};
 use mc_plugin_finder::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
 use mc_plugin_finder::database::spigot::resource::{SpigotResource, upsert_spigot_resource, get_spigot_resources};
 
@@ -45,6 +47,7 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
         let date_started = OffsetDateTime::now_utc();
 
         let resources = get_spigot_resources(db_pool).await?;
+
         let resource_stream = stream::iter(resources);
 
         let result = resource_stream
@@ -64,7 +67,9 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
             items_processed: items_processed.try_into()?,
             success: result.is_ok()
         };
-        insert_ingest_log(db_pool, &ingest_log).await?;
+        if self.dry_run.is_none() {
+            insert_ingest_log(db_pool, &ingest_log).await?;
+        }
 
         info!("Spigot versions populated: {}", items_processed);
 
@@ -78,16 +83,26 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
             Ok(version_name) => {
                 let mut new_resource = resource.clone();
                 new_resource.version_name = Some(version_name);
-                let db_result = upsert_spigot_resource(db_pool, &new_resource).await;
+                let db_result = match &self.dry_run {
+                    Some(dry_run) => dry_run.report_against(Some(&resource), &new_resource),
+                    None => time_db_upsert("spigot_resource", upsert_spigot_resource(db_pool, &new_resource)).await
+                };
 
                 match db_result {
                     Ok(_) => {
                         count.fetch_add(1, Ordering::Relaxed);
+                        record_item(IngestLogRepository::Spigot, IngestLogItem::Version, ItemOutcome::Processed);
                     },
-                    Err(err) => warn!("{}", err)
+                    Err(err) => {
+                        record_item(IngestLogRepository::Spigot, IngestLogItem::Version, ItemOutcome::Failed);
+                        warn!("{}", err)
+                    }
                 }
             }
-            Err(err) => warn!("{}", err)
+            Err(err) => {
+                record_item(IngestLogRepository::Spigot, IngestLogItem::Version, ItemOutcome::Failed);
+                warn!("{}", err)
+            }
         }
 
         Ok(())
@@ -97,14 +112,15 @@ impl<T> SpigotClient<T> where T: HttpServer + Send + Sync {
         skip(self)
     )]
     pub async fn get_latest_spigot_resource_version_from_api(&self, resource_id: i32) -> Result<String> {
-        self.rate_limiter.until_ready().await;
-
         let path = &["resources/", resource_id.to_string().as_str(), "/versions/latest"].concat();
         let url = self.http_server.base_url().join(path)?;
 
-        let raw_response = self.api_client.get(url)
-            .send()
-            .await?;
+        let raw_response = send_with_retry(
+            self.api_client.get(url),
+            &self.retry_policy,
+            UpstreamServer::Spigot,
+            || time_rate_limiter_wait(IngestLogRepository::Spigot, self.rate_limiter.until_ready())
+        ).await?;
 
         let status = raw_response.status();
         match status {
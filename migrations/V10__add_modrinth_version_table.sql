@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS modrinth_version (
+  id text PRIMARY KEY,
+  project_id text NOT NULL REFERENCES modrinth_project ON DELETE CASCADE,
+  name text NOT NULL,
+  version_number text NOT NULL,
+  version_type text NOT NULL,
+  loaders text[] NOT NULL,
+  game_versions text[] NOT NULL,
+  date_published timestamptz NOT NULL,
+  downloads integer NOT NULL,
+  file_sha1_hashes text[] NOT NULL,
+  file_sha512_hashes text[] NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS modrinth_version_project_id_index
+ON modrinth_version (project_id);
@@ -0,0 +1,291 @@
+-- Add Polymart as a new upstream repository
+ALTER TYPE ingest_log_repository ADD VALUE IF NOT EXISTS 'Polymart' BEFORE 'Common';
+
+-- Polymart
+CREATE TABLE IF NOT EXISTS polymart_resource (
+  id integer PRIMARY KEY,
+  name text NOT NULL,
+  description text NOT NULL,
+  author text NOT NULL,
+  date_created timestamptz NOT NULL,
+  date_updated timestamptz NOT NULL,
+  latest_minecraft_version text COLLATE en_natural,
+  latest_minecraft_version_sort_key integer[],
+  downloads integer NOT NULL,
+  premium boolean NOT NULL,
+  icon_url text,
+  version_name text,
+  source_url text,
+  source_repository_host text,
+  source_repository_owner text,
+  source_repository_name text,
+  source_repository_id text,
+  date_last_seen timestamptz,
+  date_deleted timestamptz,
+  platforms server_platform[] NOT NULL DEFAULT '{}',
+  minecraft_versions text[] NOT NULL DEFAULT '{}'
+);
+
+DROP MATERIALIZED VIEW common_project;
+
+CREATE MATERIALIZED VIEW common_project AS
+SELECT
+  s.id AS spigot_id,
+  s.slug AS spigot_slug,
+  s.parsed_name AS spigot_name,
+  s.description AS spigot_description,
+  a.name AS spigot_author,
+  s.version_name AS spigot_version,
+  s.premium AS spigot_premium,
+  s.abandoned AS spigot_abandoned,
+  s.icon_data AS spigot_icon_data,
+  s.date_created AS spigot_date_created,
+  s.date_updated AS spigot_date_updated,
+  s.latest_minecraft_version AS spigot_latest_minecraft_version,
+  s.latest_minecraft_version_sort_key AS spigot_latest_minecraft_version_sort_key,
+  s.downloads AS spigot_downloads,
+  s.likes AS spigot_likes,
+  s.platforms AS spigot_platforms,
+  s.categories AS spigot_categories,
+  s.minecraft_versions AS spigot_minecraft_versions,
+
+  m.id AS modrinth_id,
+  m.slug AS modrinth_slug,
+  m.name AS modrinth_name,
+  m.description AS modrinth_description,
+  m.author AS modrinth_author,
+  m.version_name AS modrinth_version,
+  m.status AS modrinth_status,
+  m.icon_url AS modrinth_icon_url,
+  m.date_created AS modrinth_date_created,
+  m.date_updated AS modrinth_date_updated,
+  m.latest_minecraft_version AS modrinth_latest_minecraft_version,
+  m.latest_minecraft_version_sort_key AS modrinth_latest_minecraft_version_sort_key,
+  m.downloads AS modrinth_downloads,
+  m.follows AS modrinth_follows,
+  m.platforms AS modrinth_platforms,
+  m.categories AS modrinth_categories,
+  m.minecraft_versions AS modrinth_minecraft_versions,
+
+  h.slug AS hangar_slug,
+  h.name AS hangar_name,
+  h.description AS hangar_description,
+  h.author AS hangar_author,
+  h.version_name AS hangar_version,
+  h.icon_url AS hangar_icon_url,
+  h.date_created AS hangar_date_created,
+  h.date_updated AS hangar_date_updated,
+  h.latest_minecraft_version AS hangar_latest_minecraft_version,
+  h.latest_minecraft_version_sort_key AS hangar_latest_minecraft_version_sort_key,
+  h.downloads AS hangar_downloads,
+  h.stars AS hangar_stars,
+  h.watchers AS hangar_watchers,
+  h.paper_versions AS hangar_paper_versions,
+  h.velocity_versions AS hangar_velocity_versions,
+  h.waterfall_versions AS hangar_waterfall_versions,
+  h.platforms AS hangar_platforms,
+  h.categories AS hangar_categories,
+  h.minecraft_versions AS hangar_minecraft_versions,
+
+  c.id AS curseforge_id,
+  c.slug AS curseforge_slug,
+  c.name AS curseforge_name,
+  c.description AS curseforge_description,
+  c.author AS curseforge_author,
+  c.version_name AS curseforge_version,
+  c.icon_url AS curseforge_icon_url,
+  c.date_created AS curseforge_date_created,
+  c.date_updated AS curseforge_date_updated,
+  c.latest_minecraft_version AS curseforge_latest_minecraft_version,
+  c.latest_minecraft_version_sort_key AS curseforge_latest_minecraft_version_sort_key,
+  c.downloads AS curseforge_downloads,
+  c.likes AS curseforge_likes,
+  c.platforms AS curseforge_platforms,
+  c.categories AS curseforge_categories,
+  c.minecraft_versions AS curseforge_minecraft_versions,
+
+  p.id AS polymart_id,
+  p.name AS polymart_name,
+  p.description AS polymart_description,
+  p.author AS polymart_author,
+  p.version_name AS polymart_version,
+  p.premium AS polymart_premium,
+  p.icon_url AS polymart_icon_url,
+  p.date_created AS polymart_date_created,
+  p.date_updated AS polymart_date_updated,
+  p.latest_minecraft_version AS polymart_latest_minecraft_version,
+  p.latest_minecraft_version_sort_key AS polymart_latest_minecraft_version_sort_key,
+  p.downloads AS polymart_downloads,
+  p.platforms AS polymart_platforms,
+  p.minecraft_versions AS polymart_minecraft_versions,
+
+  COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host, c.source_repository_host, p.source_repository_host) AS source_repository_host,
+  COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner, c.source_repository_owner, p.source_repository_owner) AS source_repository_owner,
+  COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name, c.source_repository_name, p.source_repository_name) AS source_repository_name,
+  COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id, c.source_repository_id, p.source_repository_id) AS source_repository_id
+FROM
+  (SELECT * FROM spigot_resource WHERE date_deleted IS NULL) s
+  INNER JOIN spigot_author a
+  ON  s.author_id = a.id
+
+  FULL JOIN (SELECT * FROM modrinth_project WHERE date_deleted IS NULL) m
+  ON  LOWER(s.source_repository_host) = LOWER(m.source_repository_host)
+  AND LOWER(s.source_repository_owner) = LOWER(m.source_repository_owner)
+  AND LOWER(s.source_repository_name) = LOWER(m.source_repository_name)
+  AND LOWER(s.source_repository_id) IS NOT DISTINCT FROM LOWER(m.source_repository_id)
+
+  FULL JOIN (SELECT * FROM hangar_project WHERE date_deleted IS NULL) h
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host)) = LOWER(h.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner)) = LOWER(h.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name)) = LOWER(h.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id)) IS NOT DISTINCT FROM LOWER(h.source_repository_id)
+
+  FULL JOIN (SELECT * FROM curseforge_project WHERE date_deleted IS NULL) c
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host)) = LOWER(c.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner)) = LOWER(c.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name)) = LOWER(c.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id)) IS NOT DISTINCT FROM LOWER(c.source_repository_id)
+
+  FULL JOIN (SELECT * FROM polymart_resource WHERE date_deleted IS NULL) p
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host, c.source_repository_host)) = LOWER(p.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner, c.source_repository_owner)) = LOWER(p.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name, c.source_repository_name)) = LOWER(p.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id, c.source_repository_id)) IS NOT DISTINCT FROM LOWER(p.source_repository_id);
+
+-- Indexes
+
+-- B-tree indexes for ordering by date_created
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_date_created_index
+ON common_project (GREATEST(spigot_date_created, modrinth_date_created, hangar_date_created) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_date_created_index
+ON common_project (GREATEST(spigot_date_created, modrinth_date_created, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_date_created_index
+ON common_project (GREATEST(spigot_date_created, NULL, hangar_date_created) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_date_created_index
+ON common_project (GREATEST(NULL, modrinth_date_created, hangar_date_created) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_date_created_index
+ON common_project (GREATEST(spigot_date_created, NULL, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_date_created_index
+ON common_project (GREATEST(NULL, modrinth_date_created, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_date_created_index
+ON common_project (GREATEST(NULL, NULL, hangar_date_created) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_date_created_index
+ON common_project (curseforge_date_created DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_date_created_index
+ON common_project (polymart_date_created DESC NULLS LAST);
+
+-- B-tree indexes for ordering by date_updated
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, modrinth_date_updated, hangar_date_updated) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, modrinth_date_updated, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, NULL, hangar_date_updated) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_date_updated_index
+ON common_project (GREATEST(NULL, modrinth_date_updated, hangar_date_updated) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, NULL, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_date_updated_index
+ON common_project (GREATEST(NULL, modrinth_date_updated, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_date_updated_index
+ON common_project (GREATEST(NULL, NULL, hangar_date_updated) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_date_updated_index
+ON common_project (curseforge_date_updated DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_date_updated_index
+ON common_project (polymart_date_updated DESC NULLS LAST);
+
+-- B-tree indexes for ordering by latest_minecraft_version_sort_key
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, modrinth_latest_minecraft_version_sort_key, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, modrinth_latest_minecraft_version_sort_key, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, NULL, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(NULL, modrinth_latest_minecraft_version_sort_key, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, NULL, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(NULL, modrinth_latest_minecraft_version_sort_key, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(NULL, NULL, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_latest_minecraft_version_sort_key_index
+ON common_project (curseforge_latest_minecraft_version_sort_key DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_latest_minecraft_version_sort_key_index
+ON common_project (polymart_latest_minecraft_version_sort_key DESC NULLS LAST);
+
+-- B-tree indexes for ordering by downloads
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + COALESCE(modrinth_downloads, 0) + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + COALESCE(modrinth_downloads, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + 0 + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_downloads_index
+ON common_project ((0 + COALESCE(modrinth_downloads, 0) + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + 0 + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_downloads_index
+ON common_project ((0 + COALESCE(modrinth_downloads, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_downloads_index
+ON common_project ((0 + 0 + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_downloads_index
+ON common_project (COALESCE(curseforge_downloads, 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_downloads_index
+ON common_project (COALESCE(polymart_downloads, 0) DESC NULLS LAST);
+
+-- B-tree indexes for ordering by likes and stars
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_likes_and_stars_index
+ON common_project ((COALESCE(spigot_likes, 0) + COALESCE(hangar_stars, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_likes_index
+ON common_project ((COALESCE(spigot_likes, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_stars_index
+ON common_project ((0 + COALESCE(hangar_stars, 0)) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_likes_index
+ON common_project (COALESCE(curseforge_likes, 0) DESC NULLS LAST);
+
+-- B-tree indexes for ordering by follows and watchers
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_follows_and_watchers_index
+ON common_project ((COALESCE(modrinth_follows, 0) + COALESCE(hangar_watchers, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_modrinth_follows_index
+ON common_project ((COALESCE(modrinth_follows, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_watchers_index
+ON common_project ((0 + COALESCE(hangar_watchers, 0)) DESC NULLS LAST);
+
+-- Trigram indexes for text search on name, description, and author
+CREATE INDEX IF NOT EXISTS common_project_name_index
+ON common_project
+USING gin (spigot_name gin_trgm_ops, modrinth_name gin_trgm_ops, hangar_name gin_trgm_ops, curseforge_name gin_trgm_ops, polymart_name gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS common_project_description_index
+ON common_project
+USING gin (spigot_description gin_trgm_ops, modrinth_description gin_trgm_ops, hangar_description gin_trgm_ops, curseforge_description gin_trgm_ops, polymart_description gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS common_project_author_index
+ON common_project
+USING gin (spigot_author gin_trgm_ops, modrinth_author gin_trgm_ops, hangar_author gin_trgm_ops, curseforge_author gin_trgm_ops, polymart_author gin_trgm_ops);
+
+-- GIN indexes for filtering by server platform
+CREATE INDEX IF NOT EXISTS common_project_platforms_index
+ON common_project
+USING gin (spigot_platforms, modrinth_platforms, hangar_platforms, curseforge_platforms, polymart_platforms);
+
+-- GIN indexes for filtering by category
+CREATE INDEX IF NOT EXISTS common_project_categories_index
+ON common_project
+USING gin (spigot_categories, modrinth_categories, hangar_categories, curseforge_categories);
+
+-- GIN indexes for filtering by supported Minecraft version
+CREATE INDEX IF NOT EXISTS common_project_minecraft_versions_index
+ON common_project
+USING gin (spigot_minecraft_versions, modrinth_minecraft_versions, hangar_minecraft_versions, curseforge_minecraft_versions, polymart_minecraft_versions);
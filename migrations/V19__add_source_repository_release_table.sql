@@ -0,0 +1,17 @@
+-- Track the latest release of each linked source repository
+ALTER TYPE ingest_log_repository ADD VALUE IF NOT EXISTS 'SourceRepository' BEFORE 'Common';
+ALTER TYPE ingest_log_item ADD VALUE IF NOT EXISTS 'Release';
+
+-- Source Repository Releases
+-- Host, owner, and name are stored in lowercase so that they can be matched case-insensitively against common projects.
+CREATE TABLE IF NOT EXISTS source_repository_release (
+  host text NOT NULL,
+  owner text NOT NULL,
+  name text NOT NULL,
+  tag_name text NOT NULL,
+  release_name text,
+  url text,
+  date_published timestamptz NOT NULL,
+  date_last_checked timestamptz NOT NULL,
+  PRIMARY KEY (host, owner, name)
+);
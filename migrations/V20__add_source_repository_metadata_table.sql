@@ -0,0 +1,17 @@
+-- Track forge metadata of each linked source repository
+ALTER TYPE ingest_log_item ADD VALUE IF NOT EXISTS 'Metadata';
+
+-- Source Repository Metadata
+-- Host, owner, and name are stored in lowercase so that they can be matched case-insensitively against common projects.
+CREATE TABLE IF NOT EXISTS source_repository_metadata (
+  host text NOT NULL,
+  owner text NOT NULL,
+  name text NOT NULL,
+  stars integer NOT NULL,
+  archived boolean NOT NULL,
+  license text,
+  open_issues integer,
+  date_last_commit timestamptz,
+  date_last_checked timestamptz NOT NULL,
+  PRIMARY KEY (host, owner, name)
+);
@@ -0,0 +1,17 @@
+-- Project Stats Snapshots
+-- Popularity stats of each upstream project, recorded at most once per day so that they can be compared over time.
+-- Stats that an upstream repository does not report are left null.
+CREATE TABLE IF NOT EXISTS project_stats_snapshot (
+  repository ingest_log_repository NOT NULL,
+  project_id text NOT NULL,
+  date date NOT NULL,
+  downloads integer NOT NULL,
+  likes integer,
+  follows integer,
+  stars integer,
+  watchers integer,
+  PRIMARY KEY (repository, project_id, date)
+);
+
+CREATE INDEX IF NOT EXISTS project_stats_snapshot_date_index
+ON project_stats_snapshot (date);
@@ -0,0 +1,13 @@
+-- Ingest Failures
+-- Upstream items that could not be converted or written during a populate/update operation, so that they can be retried later.
+-- The upstream ID is the ID as text, except for Hangar which uses the slug. The payload is the raw upstream item as JSON.
+CREATE TABLE IF NOT EXISTS ingest_failure (
+  repository ingest_log_repository NOT NULL,
+  item ingest_log_item NOT NULL,
+  upstream_id text NOT NULL,
+  error_kind text NOT NULL,
+  message text NOT NULL,
+  payload text NOT NULL,
+  date_failed timestamptz NOT NULL,
+  PRIMARY KEY (repository, item, upstream_id)
+);
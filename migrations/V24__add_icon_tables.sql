@@ -0,0 +1,363 @@
+-- Cache project icons, and add the hash of each project icon to the common projects
+
+-- Icons
+-- Project icons downloaded from each upstream repository, resized and stored in both WebP and PNG formats.
+-- Icons are identified by the SHA-256 hash of the original image, so that identical icons are only stored once.
+CREATE TABLE IF NOT EXISTS icon (
+  hash text PRIMARY KEY,
+  webp bytea NOT NULL,
+  png bytea NOT NULL,
+  date_created timestamptz NOT NULL
+);
+
+-- Project Icons
+-- The icon of each upstream project, along with the upstream icon URL it was created from, so that it is only downloaded again when that URL changes.
+-- The project ID is the ID as text, except for Hangar which uses the slug.
+CREATE TABLE IF NOT EXISTS project_icon (
+  repository ingest_log_repository NOT NULL,
+  project_id text NOT NULL,
+  source_url text NOT NULL,
+  hash text NOT NULL REFERENCES icon (hash),
+  date_updated timestamptz NOT NULL,
+  PRIMARY KEY (repository, project_id)
+);
+
+DROP MATERIALIZED VIEW common_project;
+
+-- Common
+-- Trending scores are the growth in downloads since the baseline snapshot, with each new like, star, follow, or watch counting as ten downloads.
+CREATE MATERIALIZED VIEW common_project AS
+SELECT
+  s.id AS spigot_id,
+  s.slug AS spigot_slug,
+  s.parsed_name AS spigot_name,
+  s.description AS spigot_description,
+  a.name AS spigot_author,
+  s.version_name AS spigot_version,
+  s.premium AS spigot_premium,
+  s.abandoned AS spigot_abandoned,
+  si.hash AS spigot_icon_hash,
+  s.date_created AS spigot_date_created,
+  s.date_updated AS spigot_date_updated,
+  s.latest_minecraft_version AS spigot_latest_minecraft_version,
+  s.latest_minecraft_version_sort_key AS spigot_latest_minecraft_version_sort_key,
+  s.downloads AS spigot_downloads,
+  s.likes AS spigot_likes,
+  (s.downloads - sb.downloads) + 10 * (s.likes - sb.likes) AS spigot_trending_score,
+  s.platforms AS spigot_platforms,
+  s.categories AS spigot_categories,
+  s.minecraft_versions AS spigot_minecraft_versions,
+
+  m.id AS modrinth_id,
+  m.slug AS modrinth_slug,
+  m.name AS modrinth_name,
+  m.description AS modrinth_description,
+  m.author AS modrinth_author,
+  m.version_name AS modrinth_version,
+  m.status AS modrinth_status,
+  m.icon_url AS modrinth_icon_url,
+  mi.hash AS modrinth_icon_hash,
+  m.date_created AS modrinth_date_created,
+  m.date_updated AS modrinth_date_updated,
+  m.latest_minecraft_version AS modrinth_latest_minecraft_version,
+  m.latest_minecraft_version_sort_key AS modrinth_latest_minecraft_version_sort_key,
+  m.downloads AS modrinth_downloads,
+  m.follows AS modrinth_follows,
+  (m.downloads - mb.downloads) + 10 * (m.follows - mb.follows) AS modrinth_trending_score,
+  m.platforms AS modrinth_platforms,
+  m.categories AS modrinth_categories,
+  m.minecraft_versions AS modrinth_minecraft_versions,
+
+  h.slug AS hangar_slug,
+  h.name AS hangar_name,
+  h.description AS hangar_description,
+  h.author AS hangar_author,
+  h.version_name AS hangar_version,
+  h.icon_url AS hangar_icon_url,
+  hi.hash AS hangar_icon_hash,
+  h.date_created AS hangar_date_created,
+  h.date_updated AS hangar_date_updated,
+  h.latest_minecraft_version AS hangar_latest_minecraft_version,
+  h.latest_minecraft_version_sort_key AS hangar_latest_minecraft_version_sort_key,
+  h.downloads AS hangar_downloads,
+  h.stars AS hangar_stars,
+  h.watchers AS hangar_watchers,
+  (h.downloads - hb.downloads) + 10 * ((h.stars - hb.stars) + (h.watchers - hb.watchers)) AS hangar_trending_score,
+  h.paper_versions AS hangar_paper_versions,
+  h.velocity_versions AS hangar_velocity_versions,
+  h.waterfall_versions AS hangar_waterfall_versions,
+  h.platforms AS hangar_platforms,
+  h.categories AS hangar_categories,
+  h.minecraft_versions AS hangar_minecraft_versions,
+
+  c.id AS curseforge_id,
+  c.slug AS curseforge_slug,
+  c.name AS curseforge_name,
+  c.description AS curseforge_description,
+  c.author AS curseforge_author,
+  c.version_name AS curseforge_version,
+  c.icon_url AS curseforge_icon_url,
+  ci.hash AS curseforge_icon_hash,
+  c.date_created AS curseforge_date_created,
+  c.date_updated AS curseforge_date_updated,
+  c.latest_minecraft_version AS curseforge_latest_minecraft_version,
+  c.latest_minecraft_version_sort_key AS curseforge_latest_minecraft_version_sort_key,
+  c.downloads AS curseforge_downloads,
+  c.likes AS curseforge_likes,
+  (c.downloads - cb.downloads) + 10 * (c.likes - cb.likes) AS curseforge_trending_score,
+  c.platforms AS curseforge_platforms,
+  c.categories AS curseforge_categories,
+  c.minecraft_versions AS curseforge_minecraft_versions,
+
+  p.id AS polymart_id,
+  p.name AS polymart_name,
+  p.description AS polymart_description,
+  p.author AS polymart_author,
+  p.version_name AS polymart_version,
+  p.premium AS polymart_premium,
+  p.icon_url AS polymart_icon_url,
+  pi.hash AS polymart_icon_hash,
+  p.date_created AS polymart_date_created,
+  p.date_updated AS polymart_date_updated,
+  p.latest_minecraft_version AS polymart_latest_minecraft_version,
+  p.latest_minecraft_version_sort_key AS polymart_latest_minecraft_version_sort_key,
+  p.downloads AS polymart_downloads,
+  p.downloads - pb.downloads AS polymart_trending_score,
+  p.platforms AS polymart_platforms,
+  p.minecraft_versions AS polymart_minecraft_versions,
+
+  b.id AS builtbybit_id,
+  b.name AS builtbybit_name,
+  b.description AS builtbybit_description,
+  b.author AS builtbybit_author,
+  b.version_name AS builtbybit_version,
+  b.price AS builtbybit_price,
+  b.currency AS builtbybit_currency,
+  b.date_created AS builtbybit_date_created,
+  b.date_updated AS builtbybit_date_updated,
+  b.downloads AS builtbybit_downloads,
+  b.downloads - bb.downloads AS builtbybit_trending_score,
+
+  COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host, c.source_repository_host, p.source_repository_host, b.source_repository_host) AS source_repository_host,
+  COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner, c.source_repository_owner, p.source_repository_owner, b.source_repository_owner) AS source_repository_owner,
+  COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name, c.source_repository_name, p.source_repository_name, b.source_repository_name) AS source_repository_name,
+  COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id, c.source_repository_id, p.source_repository_id, b.source_repository_id) AS source_repository_id
+FROM
+  (SELECT * FROM spigot_resource WHERE date_deleted IS NULL) s
+  INNER JOIN spigot_author a
+  ON  s.author_id = a.id
+  LEFT JOIN project_stats_baseline sb
+  ON  sb.repository = 'Spigot'
+  AND sb.project_id = s.id::text
+  LEFT JOIN project_icon si
+  ON  si.repository = 'Spigot'
+  AND si.project_id = s.id::text
+
+  FULL JOIN (SELECT * FROM modrinth_project WHERE date_deleted IS NULL) m
+  ON  LOWER(s.source_repository_host) = LOWER(m.source_repository_host)
+  AND LOWER(s.source_repository_owner) = LOWER(m.source_repository_owner)
+  AND LOWER(s.source_repository_name) = LOWER(m.source_repository_name)
+  AND LOWER(s.source_repository_id) IS NOT DISTINCT FROM LOWER(m.source_repository_id)
+  LEFT JOIN project_stats_baseline mb
+  ON  mb.repository = 'Modrinth'
+  AND mb.project_id = m.id
+  LEFT JOIN project_icon mi
+  ON  mi.repository = 'Modrinth'
+  AND mi.project_id = m.id
+
+  FULL JOIN (SELECT * FROM hangar_project WHERE date_deleted IS NULL) h
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host)) = LOWER(h.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner)) = LOWER(h.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name)) = LOWER(h.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id)) IS NOT DISTINCT FROM LOWER(h.source_repository_id)
+  LEFT JOIN project_stats_baseline hb
+  ON  hb.repository = 'Hangar'
+  AND hb.project_id = h.slug
+  LEFT JOIN project_icon hi
+  ON  hi.repository = 'Hangar'
+  AND hi.project_id = h.slug
+
+  FULL JOIN (SELECT * FROM curseforge_project WHERE date_deleted IS NULL) c
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host)) = LOWER(c.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner)) = LOWER(c.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name)) = LOWER(c.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id)) IS NOT DISTINCT FROM LOWER(c.source_repository_id)
+  LEFT JOIN project_stats_baseline cb
+  ON  cb.repository = 'CurseForge'
+  AND cb.project_id = c.id::text
+  LEFT JOIN project_icon ci
+  ON  ci.repository = 'CurseForge'
+  AND ci.project_id = c.id::text
+
+  FULL JOIN (SELECT * FROM polymart_resource WHERE date_deleted IS NULL) p
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host, c.source_repository_host)) = LOWER(p.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner, c.source_repository_owner)) = LOWER(p.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name, c.source_repository_name)) = LOWER(p.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id, c.source_repository_id)) IS NOT DISTINCT FROM LOWER(p.source_repository_id)
+  LEFT JOIN project_stats_baseline pb
+  ON  pb.repository = 'Polymart'
+  AND pb.project_id = p.id::text
+  LEFT JOIN project_icon pi
+  ON  pi.repository = 'Polymart'
+  AND pi.project_id = p.id::text
+
+  FULL JOIN (SELECT * FROM builtbybit_resource WHERE date_deleted IS NULL) b
+  ON  LOWER(COALESCE(s.source_repository_host, m.source_repository_host, h.source_repository_host, c.source_repository_host, p.source_repository_host)) = LOWER(b.source_repository_host)
+  AND LOWER(COALESCE(s.source_repository_owner, m.source_repository_owner, h.source_repository_owner, c.source_repository_owner, p.source_repository_owner)) = LOWER(b.source_repository_owner)
+  AND LOWER(COALESCE(s.source_repository_name, m.source_repository_name, h.source_repository_name, c.source_repository_name, p.source_repository_name)) = LOWER(b.source_repository_name)
+  AND LOWER(COALESCE(s.source_repository_id, m.source_repository_id, h.source_repository_id, c.source_repository_id, p.source_repository_id)) IS NOT DISTINCT FROM LOWER(b.source_repository_id)
+  LEFT JOIN project_stats_baseline bb
+  ON  bb.repository = 'BuiltByBit'
+  AND bb.project_id = b.id::text;
+
+-- Indexes
+
+-- B-tree indexes for ordering by date_created
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_date_created_index
+ON common_project (GREATEST(spigot_date_created, modrinth_date_created, hangar_date_created) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_date_created_index
+ON common_project (GREATEST(spigot_date_created, modrinth_date_created, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_date_created_index
+ON common_project (GREATEST(spigot_date_created, NULL, hangar_date_created) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_date_created_index
+ON common_project (GREATEST(NULL, modrinth_date_created, hangar_date_created) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_date_created_index
+ON common_project (GREATEST(spigot_date_created, NULL, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_date_created_index
+ON common_project (GREATEST(NULL, modrinth_date_created, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_date_created_index
+ON common_project (GREATEST(NULL, NULL, hangar_date_created) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_date_created_index
+ON common_project (curseforge_date_created DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_date_created_index
+ON common_project (polymart_date_created DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_builtbybit_date_created_index
+ON common_project (builtbybit_date_created DESC NULLS LAST);
+
+-- B-tree indexes for ordering by date_updated
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, modrinth_date_updated, hangar_date_updated) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, modrinth_date_updated, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, NULL, hangar_date_updated) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_date_updated_index
+ON common_project (GREATEST(NULL, modrinth_date_updated, hangar_date_updated) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_date_updated_index
+ON common_project (GREATEST(spigot_date_updated, NULL, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_date_updated_index
+ON common_project (GREATEST(NULL, modrinth_date_updated, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_date_updated_index
+ON common_project (GREATEST(NULL, NULL, hangar_date_updated) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_date_updated_index
+ON common_project (curseforge_date_updated DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_date_updated_index
+ON common_project (polymart_date_updated DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_builtbybit_date_updated_index
+ON common_project (builtbybit_date_updated DESC NULLS LAST);
+
+-- B-tree indexes for ordering by latest_minecraft_version_sort_key
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, modrinth_latest_minecraft_version_sort_key, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, modrinth_latest_minecraft_version_sort_key, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, NULL, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(NULL, modrinth_latest_minecraft_version_sort_key, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(spigot_latest_minecraft_version_sort_key, NULL, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(NULL, modrinth_latest_minecraft_version_sort_key, NULL) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_latest_minecraft_version_sort_key_index
+ON common_project (GREATEST(NULL, NULL, hangar_latest_minecraft_version_sort_key) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_latest_minecraft_version_sort_key_index
+ON common_project (curseforge_latest_minecraft_version_sort_key DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_latest_minecraft_version_sort_key_index
+ON common_project (polymart_latest_minecraft_version_sort_key DESC NULLS LAST);
+
+-- B-tree indexes for ordering by downloads
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_hangar_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + COALESCE(modrinth_downloads, 0) + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_modrinth_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + COALESCE(modrinth_downloads, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + 0 + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_downloads_index
+ON common_project ((0 + COALESCE(modrinth_downloads, 0) + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_downloads_index
+ON common_project ((COALESCE(spigot_downloads, 0) + 0 + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_modrinth_downloads_index
+ON common_project ((0 + COALESCE(modrinth_downloads, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_downloads_index
+ON common_project ((0 + 0 + COALESCE(hangar_downloads, 0)) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_downloads_index
+ON common_project (COALESCE(curseforge_downloads, 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_polymart_downloads_index
+ON common_project (COALESCE(polymart_downloads, 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_builtbybit_downloads_index
+ON common_project (COALESCE(builtbybit_downloads, 0) DESC NULLS LAST);
+
+-- B-tree indexes for ordering by likes and stars
+CREATE INDEX IF NOT EXISTS common_project_spigot_hangar_likes_and_stars_index
+ON common_project ((COALESCE(spigot_likes, 0) + COALESCE(hangar_stars, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_spigot_likes_index
+ON common_project ((COALESCE(spigot_likes, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_stars_index
+ON common_project ((0 + COALESCE(hangar_stars, 0)) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_curseforge_likes_index
+ON common_project (COALESCE(curseforge_likes, 0) DESC NULLS LAST);
+
+-- B-tree index for ordering by trending score
+CREATE INDEX IF NOT EXISTS common_project_trending_score_index
+ON common_project ((COALESCE(spigot_trending_score, 0) + COALESCE(modrinth_trending_score, 0) + COALESCE(hangar_trending_score, 0) + COALESCE(curseforge_trending_score, 0) + COALESCE(polymart_trending_score, 0) + COALESCE(builtbybit_trending_score, 0)) DESC NULLS LAST);
+
+-- B-tree indexes for ordering by follows and watchers
+CREATE INDEX IF NOT EXISTS common_project_modrinth_hangar_follows_and_watchers_index
+ON common_project ((COALESCE(modrinth_follows, 0) + COALESCE(hangar_watchers, 0)) DESC NULLS LAST);
+
+CREATE INDEX IF NOT EXISTS common_project_modrinth_follows_index
+ON common_project ((COALESCE(modrinth_follows, 0) + 0) DESC NULLS LAST);
+CREATE INDEX IF NOT EXISTS common_project_hangar_watchers_index
+ON common_project ((0 + COALESCE(hangar_watchers, 0)) DESC NULLS LAST);
+
+-- Trigram indexes for text search on name, description, and author
+CREATE INDEX IF NOT EXISTS common_project_name_index
+ON common_project
+USING gin (spigot_name gin_trgm_ops, modrinth_name gin_trgm_ops, hangar_name gin_trgm_ops, curseforge_name gin_trgm_ops, polymart_name gin_trgm_ops, builtbybit_name gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS common_project_description_index
+ON common_project
+USING gin (spigot_description gin_trgm_ops, modrinth_description gin_trgm_ops, hangar_description gin_trgm_ops, curseforge_description gin_trgm_ops, polymart_description gin_trgm_ops, builtbybit_description gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS common_project_author_index
+ON common_project
+USING gin (spigot_author gin_trgm_ops, modrinth_author gin_trgm_ops, hangar_author gin_trgm_ops, curseforge_author gin_trgm_ops, polymart_author gin_trgm_ops, builtbybit_author gin_trgm_ops);
+
+-- GIN indexes for filtering by server platform
+CREATE INDEX IF NOT EXISTS common_project_platforms_index
+ON common_project
+USING gin (spigot_platforms, modrinth_platforms, hangar_platforms, curseforge_platforms, polymart_platforms);
+
+-- GIN indexes for filtering by category
+CREATE INDEX IF NOT EXISTS common_project_categories_index
+ON common_project
+USING gin (spigot_categories, modrinth_categories, hangar_categories, curseforge_categories);
+
+-- GIN indexes for filtering by supported Minecraft version
+CREATE INDEX IF NOT EXISTS common_project_minecraft_versions_index
+ON common_project
+USING gin (spigot_minecraft_versions, modrinth_minecraft_versions, hangar_minecraft_versions, curseforge_minecraft_versions, polymart_minecraft_versions);
+
+-- B-tree index for finding icons that are no longer used by any project
+CREATE INDEX IF NOT EXISTS project_icon_hash_index
+ON project_icon (hash);
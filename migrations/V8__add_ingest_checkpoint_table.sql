@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS ingest_checkpoint (
+  repository ingest_log_repository NOT NULL,
+  item ingest_log_item NOT NULL,
+  position integer NOT NULL,
+  date_started timestamptz NOT NULL,
+  date_updated timestamptz NOT NULL,
+  PRIMARY KEY (repository, item)
+);
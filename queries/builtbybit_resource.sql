@@ -0,0 +1,43 @@
+--: BuiltbybitResourceEntity(currency?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, date_last_seen?, date_deleted?)
+
+--! upsert_builtbybit_resource (currency?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?)
+INSERT INTO builtbybit_resource (id, name, description, author, date_created, date_updated, downloads, price, currency, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, date_last_seen)
+  VALUES (:id, :name, :description, :author, :date_created, :date_updated, :downloads, :price, :currency, :version_name, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name, NOW())
+  ON CONFLICT (id)
+  DO UPDATE SET
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    downloads = EXCLUDED.downloads,
+    price = EXCLUDED.price,
+    currency = EXCLUDED.currency,
+    version_name = EXCLUDED.version_name,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL;
+
+--! get_builtbybit_resources : BuiltbybitResourceEntity
+SELECT * FROM builtbybit_resource
+WHERE date_deleted IS NULL;
+
+--! get_builtbybit_resource_by_id : BuiltbybitResourceEntity
+SELECT * FROM builtbybit_resource
+WHERE id = :id AND date_deleted IS NULL;
+
+--! get_latest_builtbybit_resource_update_date
+SELECT max(date_updated) FROM builtbybit_resource;
+
+--! mark_unseen_builtbybit_resources_as_deleted
+UPDATE builtbybit_resource
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < :date_started)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'BuiltByBit' AND item = 'Resource' AND upstream_id = builtbybit_resource.id::text
+  );
@@ -1,4 +1,4 @@
---: CommonProjectEntity(spigot_id?, spigot_slug?, spigot_name?, spigot_description?, spigot_author?, spigot_version?, spigot_premium?, spigot_abandoned?, spigot_icon_data?, spigot_date_created?, spigot_date_updated?, spigot_latest_minecraft_version?, spigot_downloads?, spigot_likes?, modrinth_id?, modrinth_slug?, modrinth_name?, modrinth_description?, modrinth_author?, modrinth_version?, modrinth_status?, modrinth_icon_url?, modrinth_date_created?, modrinth_date_updated?, modrinth_latest_minecraft_version?, modrinth_downloads?, modrinth_follows?, hangar_slug?, hangar_name?, hangar_description?, hangar_author?, hangar_version?, hangar_icon_url?, hangar_date_created?, hangar_date_updated?, hangar_latest_minecraft_version?, hangar_downloads?, hangar_stars?, hangar_watchers?, source_repository_host?, source_repository_owner?, source_repository_name?)
+--: CommonProjectEntity(spigot_id?, spigot_slug?, spigot_name?, spigot_description?, spigot_author?, spigot_version?, spigot_premium?, spigot_abandoned?, spigot_icon_hash?, spigot_date_created?, spigot_date_updated?, spigot_latest_minecraft_version?, spigot_downloads?, spigot_likes?, spigot_platforms?, spigot_categories?, spigot_minecraft_versions?, modrinth_id?, modrinth_slug?, modrinth_name?, modrinth_description?, modrinth_author?, modrinth_version?, modrinth_status?, modrinth_icon_url?, modrinth_icon_hash?, modrinth_date_created?, modrinth_date_updated?, modrinth_latest_minecraft_version?, modrinth_downloads?, modrinth_follows?, modrinth_platforms?, modrinth_categories?, modrinth_minecraft_versions?, hangar_slug?, hangar_name?, hangar_description?, hangar_author?, hangar_version?, hangar_icon_url?, hangar_icon_hash?, hangar_date_created?, hangar_date_updated?, hangar_latest_minecraft_version?, hangar_downloads?, hangar_stars?, hangar_watchers?, hangar_paper_versions?, hangar_velocity_versions?, hangar_waterfall_versions?, hangar_platforms?, hangar_categories?, hangar_minecraft_versions?, curseforge_id?, curseforge_slug?, curseforge_name?, curseforge_description?, curseforge_author?, curseforge_version?, curseforge_icon_url?, curseforge_icon_hash?, curseforge_date_created?, curseforge_date_updated?, curseforge_latest_minecraft_version?, curseforge_downloads?, curseforge_likes?, curseforge_platforms?, curseforge_categories?, curseforge_minecraft_versions?, polymart_id?, polymart_name?, polymart_description?, polymart_author?, polymart_version?, polymart_premium?, polymart_icon_url?, polymart_icon_hash?, polymart_date_created?, polymart_date_updated?, polymart_latest_minecraft_version?, polymart_downloads?, polymart_platforms?, polymart_categories?, polymart_minecraft_versions?, builtbybit_id?, builtbybit_name?, builtbybit_description?, builtbybit_author?, builtbybit_version?, builtbybit_price?, builtbybit_currency?, builtbybit_date_created?, builtbybit_date_updated?, builtbybit_downloads?, source_repository_host?, source_repository_owner?, source_repository_name?)
 
 --! refresh_common_projects
 REFRESH MATERIALIZED VIEW common_project;
@@ -16,12 +16,15 @@ SELECT
   spigot_version,
   spigot_premium,
   spigot_abandoned,
-  spigot_icon_data,
+  spigot_icon_hash,
   spigot_date_created,
   spigot_date_updated,
   spigot_latest_minecraft_version,
   spigot_downloads,
   spigot_likes,
+  spigot_platforms,
+  spigot_categories,
+  spigot_minecraft_versions,
 
   modrinth_id,
   modrinth_slug,
@@ -31,11 +34,15 @@ SELECT
   modrinth_version,
   modrinth_status,
   modrinth_icon_url,
+  modrinth_icon_hash,
   modrinth_date_created,
   modrinth_date_updated,
   modrinth_latest_minecraft_version,
   modrinth_downloads,
   modrinth_follows,
+  modrinth_platforms,
+  modrinth_categories,
+  modrinth_minecraft_versions,
 
   hangar_slug,
   hangar_name,
@@ -43,12 +50,63 @@ SELECT
   hangar_author,
   hangar_version,
   hangar_icon_url,
+  hangar_icon_hash,
   hangar_date_created,
   hangar_date_updated,
   hangar_latest_minecraft_version,
   hangar_downloads,
   hangar_stars,
   hangar_watchers,
+  hangar_paper_versions,
+  hangar_velocity_versions,
+  hangar_waterfall_versions,
+  hangar_platforms,
+  hangar_categories,
+  hangar_minecraft_versions,
+
+  curseforge_id,
+  curseforge_slug,
+  curseforge_name,
+  curseforge_description,
+  curseforge_author,
+  curseforge_version,
+  curseforge_icon_url,
+  curseforge_icon_hash,
+  curseforge_date_created,
+  curseforge_date_updated,
+  curseforge_latest_minecraft_version,
+  curseforge_downloads,
+  curseforge_likes,
+  curseforge_platforms,
+  curseforge_categories,
+  curseforge_minecraft_versions,
+
+  polymart_id,
+  polymart_name,
+  polymart_description,
+  polymart_author,
+  polymart_version,
+  polymart_premium,
+  polymart_icon_url,
+  polymart_icon_hash,
+  polymart_date_created,
+  polymart_date_updated,
+  polymart_latest_minecraft_version,
+  polymart_downloads,
+  polymart_platforms,
+  polymart_categories,
+  polymart_minecraft_versions,
+
+  builtbybit_id,
+  builtbybit_name,
+  builtbybit_description,
+  builtbybit_author,
+  builtbybit_version,
+  builtbybit_price,
+  builtbybit_currency,
+  builtbybit_date_created,
+  builtbybit_date_updated,
+  builtbybit_downloads,
 
   source_repository_host,
   source_repository_name,
@@ -0,0 +1,49 @@
+--: CurseForgeProjectEntity(latest_minecraft_version?, latest_minecraft_version_sort_key?, icon_url?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, date_last_seen?, date_deleted?)
+
+--! upsert_curseforge_project (latest_minecraft_version?, latest_minecraft_version_sort_key?, icon_url?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?)
+INSERT INTO curseforge_project (id, slug, name, description, author, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, likes, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, categories, minecraft_versions, date_last_seen)
+  VALUES (:id, :slug, :name, :description, :author, :date_created, :date_updated, :latest_minecraft_version, :latest_minecraft_version_sort_key, :downloads, :likes, :icon_url, :version_name, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name, :platforms, :categories, :minecraft_versions, NOW())
+  ON CONFLICT (id)
+  DO UPDATE SET
+    slug = EXCLUDED.slug,
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
+    downloads = EXCLUDED.downloads,
+    likes = EXCLUDED.likes,
+    icon_url = EXCLUDED.icon_url,
+    version_name = EXCLUDED.version_name,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL;
+
+--! get_curseforge_projects : CurseForgeProjectEntity
+SELECT * FROM curseforge_project
+WHERE date_deleted IS NULL;
+
+--! get_curseforge_project_by_id : CurseForgeProjectEntity
+SELECT * FROM curseforge_project
+WHERE id = :id AND date_deleted IS NULL;
+
+--! get_latest_curseforge_project_update_date
+SELECT max(date_updated) FROM curseforge_project;
+
+--! mark_unseen_curseforge_projects_as_deleted
+UPDATE curseforge_project
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < :date_started)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'CurseForge' AND item = 'Project' AND upstream_id = curseforge_project.id::text
+  );
@@ -1,8 +1,8 @@
---: HangarProjectEntity(latest_minecraft_version?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?)
+--: HangarProjectEntity(latest_minecraft_version?, latest_minecraft_version_sort_key?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, date_last_seen?, date_deleted?, full_description?)
 
---! upsert_hangar_project (latest_minecraft_version?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?)
-INSERT INTO hangar_project (slug, author, name, description, date_created, date_updated, latest_minecraft_version, downloads, stars, watchers, visibility, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name)
-  VALUES (:slug, :author, :name, :description, :date_created, :date_updated, :latest_minecraft_version, :downloads, :stars, :watchers, :visibility, :icon_url, :version_name, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name)
+--! upsert_hangar_project (latest_minecraft_version?, latest_minecraft_version_sort_key?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, full_description?)
+INSERT INTO hangar_project (slug, author, name, description, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, stars, watchers, visibility, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, paper_versions, velocity_versions, waterfall_versions, platforms, categories, minecraft_versions, full_description, date_last_seen)
+  VALUES (:slug, :author, :name, :description, :date_created, :date_updated, :latest_minecraft_version, :latest_minecraft_version_sort_key, :downloads, :stars, :watchers, :visibility, :icon_url, :version_name, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name, :paper_versions, :velocity_versions, :waterfall_versions, :platforms, :categories, :minecraft_versions, :full_description, NOW())
   ON CONFLICT (slug)
   DO UPDATE SET
     author = EXCLUDED.author,
@@ -11,6 +11,7 @@ INSERT INTO hangar_project (slug, author, name, description, date_created, date_
     date_created = EXCLUDED.date_created,
     date_updated = EXCLUDED.date_updated,
     latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
     downloads = EXCLUDED.downloads,
     stars = EXCLUDED.stars,
     watchers = EXCLUDED.watchers,
@@ -20,10 +21,34 @@ INSERT INTO hangar_project (slug, author, name, description, date_created, date_
     source_url = EXCLUDED.source_url,
     source_repository_host = EXCLUDED.source_repository_host,
     source_repository_owner = EXCLUDED.source_repository_owner,
-    source_repository_name = EXCLUDED.source_repository_name;
+    source_repository_name = EXCLUDED.source_repository_name,
+    paper_versions = EXCLUDED.paper_versions,
+    velocity_versions = EXCLUDED.velocity_versions,
+    waterfall_versions = EXCLUDED.waterfall_versions,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    full_description = EXCLUDED.full_description,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL;
 
 --! get_hangar_projects : HangarProjectEntity
-SELECT * FROM hangar_project;
+SELECT * FROM hangar_project
+WHERE date_deleted IS NULL;
+
+--! get_hangar_project_by_slug : HangarProjectEntity
+SELECT * FROM hangar_project
+WHERE slug = :slug AND date_deleted IS NULL;
 
 --! get_latest_hangar_project_update_date
-SELECT max(date_updated) FROM hangar_project;
\ No newline at end of file
+SELECT max(date_updated) FROM hangar_project;
+
+--! mark_unseen_hangar_projects_as_deleted
+UPDATE hangar_project
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < :date_started)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Hangar' AND item = 'Project' AND upstream_id = hangar_project.slug
+  );
@@ -0,0 +1,73 @@
+--: IconEntity()
+
+--: ProjectIconSourceEntity(icon_data?)
+
+--! get_outdated_project_icon_sources : ProjectIconSourceEntity
+SELECT source.repository, source.project_id, source.icon_url, source.icon_data
+FROM (
+    SELECT 'Spigot'::ingest_log_repository AS repository, id::text AS project_id, icon_url, icon_data
+    FROM spigot_resource
+    WHERE date_deleted IS NULL AND icon_data <> ''
+  UNION ALL
+    SELECT 'Modrinth', id, icon_url, NULL
+    FROM modrinth_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Hangar', slug, icon_url, NULL
+    FROM hangar_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'CurseForge', id::text, icon_url, NULL
+    FROM curseforge_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Polymart', id::text, icon_url, NULL
+    FROM polymart_resource
+    WHERE date_deleted IS NULL
+) AS source
+LEFT JOIN project_icon
+ON  project_icon.repository = source.repository
+AND project_icon.project_id = source.project_id
+WHERE source.icon_url <> ''
+  AND source.icon_url IS DISTINCT FROM project_icon.source_url
+ORDER BY source.repository, source.project_id;
+
+--! icon_exists
+SELECT EXISTS (
+  SELECT 1
+  FROM icon
+  WHERE hash = :hash
+);
+
+--! insert_icon
+INSERT INTO icon (hash, webp, png, date_created)
+  VALUES (:hash, :webp, :png, NOW())
+  ON CONFLICT (hash)
+  DO NOTHING;
+
+--! get_icon : IconEntity
+SELECT hash, webp, png
+FROM icon
+WHERE hash = :hash;
+
+--! upsert_project_icon
+INSERT INTO project_icon (repository, project_id, source_url, hash, date_updated)
+  VALUES (:repository, :project_id, :source_url, :hash, NOW())
+  ON CONFLICT (repository, project_id)
+  DO UPDATE SET
+    source_url = EXCLUDED.source_url,
+    hash = EXCLUDED.hash,
+    date_updated = EXCLUDED.date_updated;
+
+--! get_project_icon_hash
+SELECT hash
+FROM project_icon
+WHERE repository = :repository AND project_id = :project_id;
+
+--! delete_unused_icons
+DELETE FROM icon
+WHERE NOT EXISTS (
+  SELECT 1
+  FROM project_icon
+  WHERE project_icon.hash = icon.hash
+);
@@ -0,0 +1,22 @@
+--: IngestCheckpointEntity()
+
+--! upsert_ingest_checkpoint ()
+INSERT INTO ingest_checkpoint (repository, item, position, date_started, date_updated)
+  VALUES (:repository, :item, :position, :date_started, :date_updated)
+  ON CONFLICT (repository, item)
+  DO UPDATE SET
+    position = EXCLUDED.position,
+    date_started = EXCLUDED.date_started,
+    date_updated = EXCLUDED.date_updated;
+
+--! get_ingest_checkpoint : IngestCheckpointEntity
+SELECT *
+FROM ingest_checkpoint
+WHERE repository = :repository AND item = :item;
+
+--! delete_ingest_checkpoint
+DELETE FROM ingest_checkpoint
+WHERE repository = :repository AND item = :item;
+
+--! get_checkpoint_start_date
+SELECT NOW();
@@ -0,0 +1,26 @@
+--: IngestFailureEntity()
+
+--! upsert_ingest_failure ()
+INSERT INTO ingest_failure (repository, item, upstream_id, error_kind, message, payload, date_failed)
+  VALUES (:repository, :item, :upstream_id, :error_kind, :message, :payload, :date_failed)
+  ON CONFLICT (repository, item, upstream_id)
+  DO UPDATE SET
+    error_kind = EXCLUDED.error_kind,
+    message = EXCLUDED.message,
+    payload = EXCLUDED.payload,
+    date_failed = EXCLUDED.date_failed;
+
+--! get_ingest_failures : IngestFailureEntity
+SELECT *
+FROM ingest_failure
+WHERE repository = :repository AND item = :item
+ORDER BY date_failed;
+
+--! delete_ingest_failure
+DELETE FROM ingest_failure
+WHERE repository = :repository AND item = :item AND upstream_id = :upstream_id;
+
+--! get_ingest_failure_upstream_ids
+SELECT upstream_id
+FROM ingest_failure
+WHERE repository = :repository AND item = :item;
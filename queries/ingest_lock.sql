@@ -0,0 +1,5 @@
+--! try_acquire_ingest_lock
+SELECT pg_try_advisory_lock(:key) AS acquired;
+
+--! release_ingest_lock
+SELECT pg_advisory_unlock(:key) AS released;
@@ -1,8 +1,8 @@
---: ModrinthProjectEntity(latest_minecraft_version?, version_id?, version_name?, icon_url?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?)
+--: ModrinthProjectEntity(latest_minecraft_version?, latest_minecraft_version_sort_key?, version_id?, version_name?, icon_url?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, date_last_seen?, date_deleted?, full_description?)
 
---! upsert_modrinth_project (latest_minecraft_version?, version_id?, version_name?, icon_url?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?)
-INSERT INTO modrinth_project (id, slug, name, description, author, date_created, date_updated, latest_minecraft_version, downloads, follows, version_id, version_name, status, icon_url, source_url, source_repository_host, source_repository_owner, source_repository_name)
-  VALUES (:id, :slug, :name, :description, :author, :date_created, :date_updated, :latest_minecraft_version, :downloads, :follows, :version_id, :version_name, :status, :icon_url, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name)
+--! upsert_modrinth_project (latest_minecraft_version?, latest_minecraft_version_sort_key?, version_id?, version_name?, icon_url?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, full_description?)
+INSERT INTO modrinth_project (id, slug, name, description, author, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, follows, version_id, version_name, status, icon_url, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, categories, minecraft_versions, full_description, date_last_seen)
+  VALUES (:id, :slug, :name, :description, :author, :date_created, :date_updated, :latest_minecraft_version, :latest_minecraft_version_sort_key, :downloads, :follows, :version_id, :version_name, :status, :icon_url, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name, :platforms, :categories, :minecraft_versions, :full_description, NOW())
   ON CONFLICT(id)
   DO UPDATE SET
     id = EXCLUDED.id,
@@ -13,6 +13,7 @@ INSERT INTO modrinth_project (id, slug, name, description, author, date_created,
     date_created = EXCLUDED.date_created,
     date_updated = EXCLUDED.date_updated,
     latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
     downloads = EXCLUDED.downloads,
     follows = EXCLUDED.follows,
     version_id = EXCLUDED.version_id,
@@ -22,10 +23,31 @@ INSERT INTO modrinth_project (id, slug, name, description, author, date_created,
     source_url = EXCLUDED.source_url,
     source_repository_host = EXCLUDED.source_repository_host,
     source_repository_owner = EXCLUDED.source_repository_owner,
-    source_repository_name = EXCLUDED.source_repository_name;
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    full_description = EXCLUDED.full_description,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL;
 
 --! get_modrinth_projects : ModrinthProjectEntity
-SELECT * FROM modrinth_project;
+SELECT * FROM modrinth_project
+WHERE date_deleted IS NULL;
+
+--! get_modrinth_project_by_id : ModrinthProjectEntity
+SELECT * FROM modrinth_project
+WHERE id = :id AND date_deleted IS NULL;
 
 --! get_latest_modrinth_project_update_date
-SELECT max(date_updated) FROM modrinth_project;
\ No newline at end of file
+SELECT max(date_updated) FROM modrinth_project;
+
+--! mark_unseen_modrinth_projects_as_deleted
+UPDATE modrinth_project
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < :date_started)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Modrinth' AND item = 'Project' AND upstream_id = modrinth_project.id
+  );
@@ -0,0 +1,29 @@
+--: ModrinthVersionEntity()
+
+--! upsert_modrinth_version
+INSERT INTO modrinth_version (id, project_id, name, version_number, version_type, loaders, game_versions, date_published, downloads, file_sha1_hashes, file_sha512_hashes)
+  VALUES (:id, :project_id, :name, :version_number, :version_type, :loaders, :game_versions, :date_published, :downloads, :file_sha1_hashes, :file_sha512_hashes)
+  ON CONFLICT (id)
+  DO UPDATE SET
+    project_id = EXCLUDED.project_id,
+    name = EXCLUDED.name,
+    version_number = EXCLUDED.version_number,
+    version_type = EXCLUDED.version_type,
+    loaders = EXCLUDED.loaders,
+    game_versions = EXCLUDED.game_versions,
+    date_published = EXCLUDED.date_published,
+    downloads = EXCLUDED.downloads,
+    file_sha1_hashes = EXCLUDED.file_sha1_hashes,
+    file_sha512_hashes = EXCLUDED.file_sha512_hashes;
+
+--! get_modrinth_versions : ModrinthVersionEntity
+SELECT * FROM modrinth_version;
+
+--! get_modrinth_version_by_id : ModrinthVersionEntity
+SELECT * FROM modrinth_version
+WHERE id = :id;
+
+--! get_modrinth_versions_for_project : ModrinthVersionEntity
+SELECT * FROM modrinth_version
+WHERE project_id = :project_id
+ORDER BY date_published DESC;
@@ -0,0 +1,48 @@
+--: PolymartResourceEntity(latest_minecraft_version?, latest_minecraft_version_sort_key?, icon_url?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, date_last_seen?, date_deleted?)
+
+--! upsert_polymart_resource (latest_minecraft_version?, latest_minecraft_version_sort_key?, icon_url?, version_name?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?)
+INSERT INTO polymart_resource (id, name, description, author, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, premium, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, minecraft_versions, categories, date_last_seen)
+  VALUES (:id, :name, :description, :author, :date_created, :date_updated, :latest_minecraft_version, :latest_minecraft_version_sort_key, :downloads, :premium, :icon_url, :version_name, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name, :platforms, :minecraft_versions, :categories, NOW())
+  ON CONFLICT (id)
+  DO UPDATE SET
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
+    downloads = EXCLUDED.downloads,
+    premium = EXCLUDED.premium,
+    icon_url = EXCLUDED.icon_url,
+    version_name = EXCLUDED.version_name,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    categories = EXCLUDED.categories,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL;
+
+--! get_polymart_resources : PolymartResourceEntity
+SELECT * FROM polymart_resource
+WHERE date_deleted IS NULL;
+
+--! get_polymart_resource_by_id : PolymartResourceEntity
+SELECT * FROM polymart_resource
+WHERE id = :id AND date_deleted IS NULL;
+
+--! get_latest_polymart_resource_update_date
+SELECT max(date_updated) FROM polymart_resource;
+
+--! mark_unseen_polymart_resources_as_deleted
+UPDATE polymart_resource
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < :date_started)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Polymart' AND item = 'Resource' AND upstream_id = polymart_resource.id::text
+  );
@@ -0,0 +1,16 @@
+--: ProjectDescriptionEntity(full_description?)
+
+--! get_spigot_resource_description : ProjectDescriptionEntity
+SELECT COALESCE(parsed_name, name) AS name, description, full_description
+FROM spigot_resource
+WHERE id = :id AND date_deleted IS NULL;
+
+--! get_modrinth_project_description : ProjectDescriptionEntity
+SELECT name, description, full_description
+FROM modrinth_project
+WHERE id = :id AND date_deleted IS NULL;
+
+--! get_hangar_project_description : ProjectDescriptionEntity
+SELECT name, description, full_description
+FROM hangar_project
+WHERE slug = :slug AND date_deleted IS NULL;
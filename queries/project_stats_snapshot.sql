@@ -0,0 +1,68 @@
+--: ProjectStatsSnapshotEntity(likes?, follows?, stars?, watchers?)
+
+--! insert_project_stats_snapshots
+INSERT INTO project_stats_snapshot (repository, project_id, date, downloads, likes, follows, stars, watchers)
+SELECT repository, project_id, :date, downloads, likes, follows, stars, watchers
+FROM (
+    SELECT 'Spigot'::ingest_log_repository AS repository, id::text AS project_id, downloads, likes, NULL::integer AS follows, NULL::integer AS stars, NULL::integer AS watchers
+    FROM spigot_resource
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Modrinth', id, downloads, NULL, follows, NULL, NULL
+    FROM modrinth_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Hangar', slug, downloads, NULL, NULL, stars, watchers
+    FROM hangar_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'CurseForge', id::text, downloads, likes, NULL, NULL, NULL
+    FROM curseforge_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Polymart', id::text, downloads, NULL, NULL, NULL, NULL
+    FROM polymart_resource
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'BuiltByBit', id::text, downloads, NULL, NULL, NULL, NULL
+    FROM builtbybit_resource
+    WHERE date_deleted IS NULL
+) AS stats
+ON CONFLICT (repository, project_id, date)
+DO UPDATE SET
+  downloads = EXCLUDED.downloads,
+  likes = EXCLUDED.likes,
+  follows = EXCLUDED.follows,
+  stars = EXCLUDED.stars,
+  watchers = EXCLUDED.watchers;
+
+--! get_project_stats_snapshots : ProjectStatsSnapshotEntity
+SELECT *
+FROM project_stats_snapshot
+WHERE repository = :repository AND project_id = :project_id
+ORDER BY date;
+
+--! downsample_project_stats_snapshots
+DELETE FROM project_stats_snapshot s
+WHERE s.date < :daily_cutoff
+  AND EXISTS (
+    SELECT 1
+    FROM project_stats_snapshot t
+    WHERE t.repository = s.repository
+      AND t.project_id = s.project_id
+      AND date_trunc('week', t.date) = date_trunc('week', s.date)
+      AND t.date > s.date
+  );
+
+--! delete_expired_project_stats_snapshots
+DELETE FROM project_stats_snapshot
+WHERE date < :retention_cutoff;
+
+--! get_all_project_stats_snapshots : ProjectStatsSnapshotEntity
+SELECT *
+FROM project_stats_snapshot
+ORDER BY repository, project_id, date;
+
+--! insert_project_stats_snapshot (likes?, follows?, stars?, watchers?)
+INSERT INTO project_stats_snapshot (repository, project_id, date, downloads, likes, follows, stars, watchers)
+  VALUES (:repository, :project_id, :date, :downloads, :likes, :follows, :stars, :watchers);
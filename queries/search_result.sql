@@ -1,34 +1,53 @@
---: SearchResultEntity(latest_minecraft_version?, spigot_id?, spigot_slug?, spigot_name?, spigot_description?, spigot_author?, spigot_version?, spigot_premium?, spigot_abandoned?, spigot_icon_data?, modrinth_id?, modrinth_slug?, modrinth_name?, modrinth_description?, modrinth_author?, modrinth_version?, modrinth_status?, modrinth_icon_url?, hangar_slug?, hangar_name?, hangar_description?, hangar_author?, hangar_version?, hangar_icon_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?)
+--: SearchResultEntity(latest_minecraft_version?, spigot_id?, spigot_slug?, spigot_name?, spigot_description?, spigot_author?, spigot_version?, spigot_premium?, spigot_abandoned?, spigot_icon_hash?, modrinth_id?, modrinth_slug?, modrinth_name?, modrinth_description?, modrinth_author?, modrinth_version?, modrinth_status?, modrinth_icon_url?, modrinth_icon_hash?, hangar_slug?, hangar_name?, hangar_description?, hangar_author?, hangar_version?, hangar_icon_url?, hangar_icon_hash?, hangar_paper_versions?, hangar_velocity_versions?, hangar_waterfall_versions?, curseforge_id?, curseforge_slug?, curseforge_name?, curseforge_description?, curseforge_author?, curseforge_version?, curseforge_icon_url?, curseforge_icon_hash?, polymart_id?, polymart_name?, polymart_description?, polymart_author?, polymart_version?, polymart_premium?, polymart_icon_url?, polymart_icon_hash?, builtbybit_id?, builtbybit_name?, builtbybit_description?, builtbybit_author?, builtbybit_version?, builtbybit_price?, builtbybit_currency?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, release_tag_name?, release_name?, release_url?, release_date_published?, repository_stars?, repository_archived?, repository_license?, repository_open_issues?, repository_date_last_commit?)
 
---! search_projects (query, spigot, modrinth, hangar, name, description, author, sort, limit, offset) : SearchResultEntity
+--! search_projects (query, text_query, spigot, modrinth, hangar, curseforge, polymart, builtbybit, name, description, full_description, author, name_weight, categories_weight, description_weight, author_weight, full_text_weight, trigram_weight, platforms, categories, minecraft_versions, minecraft_version_family?, exclude_archived, sort, limit, offset) : SearchResultEntity
 SELECT
   COUNT(*) OVER() AS full_count,
 
   GREATEST(
     CASE WHEN :spigot IS TRUE THEN spigot_date_created ELSE NULL END,
     CASE WHEN :modrinth IS TRUE THEN modrinth_date_created ELSE NULL END,
-    CASE WHEN :hangar IS TRUE THEN hangar_date_created ELSE NULL END
+    CASE WHEN :hangar IS TRUE THEN hangar_date_created ELSE NULL END,
+    CASE WHEN :curseforge IS TRUE THEN curseforge_date_created ELSE NULL END,
+    CASE WHEN :polymart IS TRUE THEN polymart_date_created ELSE NULL END,
+    CASE WHEN :builtbybit IS TRUE THEN builtbybit_date_created ELSE NULL END
   ) AS date_created,
 
   GREATEST(
     CASE WHEN :spigot IS TRUE THEN spigot_date_updated ELSE NULL END,
     CASE WHEN :modrinth IS TRUE THEN modrinth_date_updated ELSE NULL END,
-    CASE WHEN :hangar IS TRUE THEN hangar_date_updated ELSE NULL END
+    CASE WHEN :hangar IS TRUE THEN hangar_date_updated ELSE NULL END,
+    CASE WHEN :curseforge IS TRUE THEN curseforge_date_updated ELSE NULL END,
+    CASE WHEN :polymart IS TRUE THEN polymart_date_updated ELSE NULL END,
+    CASE WHEN :builtbybit IS TRUE THEN builtbybit_date_updated ELSE NULL END
   ) AS date_updated,
 
-  GREATEST(
-    CASE WHEN :spigot IS TRUE THEN spigot_latest_minecraft_version ELSE NULL END,
-    CASE WHEN :modrinth IS TRUE THEN modrinth_latest_minecraft_version ELSE NULL END,
-    CASE WHEN :hangar IS TRUE THEN hangar_latest_minecraft_version ELSE NULL END
+  (
+    SELECT version
+    FROM (
+      VALUES
+        (CASE WHEN :spigot IS TRUE THEN spigot_latest_minecraft_version ELSE NULL END, CASE WHEN :spigot IS TRUE THEN spigot_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN :modrinth IS TRUE THEN modrinth_latest_minecraft_version ELSE NULL END, CASE WHEN :modrinth IS TRUE THEN modrinth_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN :hangar IS TRUE THEN hangar_latest_minecraft_version ELSE NULL END, CASE WHEN :hangar IS TRUE THEN hangar_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN :curseforge IS TRUE THEN curseforge_latest_minecraft_version ELSE NULL END, CASE WHEN :curseforge IS TRUE THEN curseforge_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN :polymart IS TRUE THEN polymart_latest_minecraft_version ELSE NULL END, CASE WHEN :polymart IS TRUE THEN polymart_latest_minecraft_version_sort_key ELSE NULL END)
+    ) AS latest(version, sort_key)
+    WHERE version IS NOT NULL
+    ORDER BY sort_key DESC NULLS LAST
+    LIMIT 1
   ) AS latest_minecraft_version,
 
   CASE WHEN :spigot IS TRUE THEN COALESCE(spigot_downloads, 0) ELSE 0 END +
   CASE WHEN :modrinth IS TRUE THEN COALESCE(modrinth_downloads, 0) ELSE 0 END +
-  CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END
+  CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END +
+  CASE WHEN :curseforge IS TRUE THEN COALESCE(curseforge_downloads, 0) ELSE 0 END +
+  CASE WHEN :polymart IS TRUE THEN COALESCE(polymart_downloads, 0) ELSE 0 END +
+  CASE WHEN :builtbybit IS TRUE THEN COALESCE(builtbybit_downloads, 0) ELSE 0 END
   AS downloads,
 
   CASE WHEN :spigot IS TRUE THEN COALESCE(spigot_likes, 0) ELSE 0 END +
-  CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END
+  CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END +
+  CASE WHEN :curseforge IS TRUE THEN COALESCE(curseforge_likes, 0) ELSE 0 END
   AS likes_and_stars,
 
   CASE WHEN :modrinth IS TRUE THEN COALESCE(modrinth_follows, 0) ELSE 0 END +
@@ -43,7 +62,7 @@ SELECT
   (CASE WHEN :spigot IS TRUE THEN spigot_version ELSE NULL END) AS spigot_version,
   (CASE WHEN :spigot IS TRUE THEN spigot_premium ELSE NULL END) AS spigot_premium,
   (CASE WHEN :spigot IS TRUE THEN spigot_abandoned ELSE NULL END) AS spigot_abandoned,
-  (CASE WHEN :spigot IS TRUE THEN spigot_icon_data ELSE NULL END) AS spigot_icon_data,
+  (CASE WHEN :spigot IS TRUE THEN spigot_icon_hash ELSE NULL END) AS spigot_icon_hash,
 
   (CASE WHEN :modrinth IS TRUE THEN modrinth_id ELSE NULL END) AS modrinth_id,
   (CASE WHEN :modrinth IS TRUE THEN modrinth_slug ELSE NULL END) AS modrinth_slug,
@@ -53,6 +72,7 @@ SELECT
   (CASE WHEN :modrinth IS TRUE THEN modrinth_version ELSE NULL END) AS modrinth_version,
   (CASE WHEN :modrinth IS TRUE THEN modrinth_status ELSE NULL END) AS modrinth_status,
   (CASE WHEN :modrinth IS TRUE THEN modrinth_icon_url ELSE NULL END) AS modrinth_icon_url,
+  (CASE WHEN :modrinth IS TRUE THEN modrinth_icon_hash ELSE NULL END) AS modrinth_icon_hash,
 
   (CASE WHEN :hangar IS TRUE THEN hangar_slug ELSE NULL END) AS hangar_slug,
   (CASE WHEN :hangar IS TRUE THEN hangar_name ELSE NULL END) AS hangar_name,
@@ -60,124 +80,417 @@ SELECT
   (CASE WHEN :hangar IS TRUE THEN hangar_author ELSE NULL END) AS hangar_author,
   (CASE WHEN :hangar IS TRUE THEN hangar_version ELSE NULL END) AS hangar_version,
   (CASE WHEN :hangar IS TRUE THEN hangar_icon_url ELSE NULL END) AS hangar_icon_url,
+  (CASE WHEN :hangar IS TRUE THEN hangar_icon_hash ELSE NULL END) AS hangar_icon_hash,
+  (CASE WHEN :hangar IS TRUE THEN hangar_paper_versions ELSE NULL END) AS hangar_paper_versions,
+  (CASE WHEN :hangar IS TRUE THEN hangar_velocity_versions ELSE NULL END) AS hangar_velocity_versions,
+  (CASE WHEN :hangar IS TRUE THEN hangar_waterfall_versions ELSE NULL END) AS hangar_waterfall_versions,
+
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_id ELSE NULL END) AS curseforge_id,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_slug ELSE NULL END) AS curseforge_slug,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_name ELSE NULL END) AS curseforge_name,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_description ELSE NULL END) AS curseforge_description,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_author ELSE NULL END) AS curseforge_author,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_version ELSE NULL END) AS curseforge_version,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_icon_url ELSE NULL END) AS curseforge_icon_url,
+  (CASE WHEN :curseforge IS TRUE THEN curseforge_icon_hash ELSE NULL END) AS curseforge_icon_hash,
+
+  (CASE WHEN :polymart IS TRUE THEN polymart_id ELSE NULL END) AS polymart_id,
+  (CASE WHEN :polymart IS TRUE THEN polymart_name ELSE NULL END) AS polymart_name,
+  (CASE WHEN :polymart IS TRUE THEN polymart_description ELSE NULL END) AS polymart_description,
+  (CASE WHEN :polymart IS TRUE THEN polymart_author ELSE NULL END) AS polymart_author,
+  (CASE WHEN :polymart IS TRUE THEN polymart_version ELSE NULL END) AS polymart_version,
+  (CASE WHEN :polymart IS TRUE THEN polymart_premium ELSE NULL END) AS polymart_premium,
+  (CASE WHEN :polymart IS TRUE THEN polymart_icon_url ELSE NULL END) AS polymart_icon_url,
+  (CASE WHEN :polymart IS TRUE THEN polymart_icon_hash ELSE NULL END) AS polymart_icon_hash,
+
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_id ELSE NULL END) AS builtbybit_id,
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_name ELSE NULL END) AS builtbybit_name,
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_description ELSE NULL END) AS builtbybit_description,
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_author ELSE NULL END) AS builtbybit_author,
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_version ELSE NULL END) AS builtbybit_version,
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_price ELSE NULL END) AS builtbybit_price,
+  (CASE WHEN :builtbybit IS TRUE THEN builtbybit_currency ELSE NULL END) AS builtbybit_currency,
 
   source_repository_host,
   source_repository_owner,
   source_repository_name,
-  source_repository_id
+  source_repository_id,
+
+  r.tag_name AS release_tag_name,
+  r.release_name AS release_name,
+  r.url AS release_url,
+  r.date_published AS release_date_published,
+
+  meta.stars AS repository_stars,
+  meta.archived AS repository_archived,
+  meta.license AS repository_license,
+  meta.open_issues AS repository_open_issues,
+  meta.date_last_commit AS repository_date_last_commit
 FROM
   common_project
+  LEFT JOIN source_repository_release r
+  ON  LOWER(source_repository_host) = r.host
+  AND LOWER(source_repository_owner) = r.owner
+  AND LOWER(source_repository_name) = r.name
+  LEFT JOIN source_repository_metadata meta
+  ON  LOWER(source_repository_host) = meta.host
+  AND LOWER(source_repository_owner) = meta.owner
+  AND LOWER(source_repository_name) = meta.name
 WHERE
-  CASE :spigot IS TRUE AND :query = ''
-    WHEN TRUE THEN spigot_id IS NOT NULL
-    ELSE FALSE
-  END
+  (
+    CASE :spigot IS TRUE AND :query = ''
+      WHEN TRUE THEN spigot_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :spigot IS TRUE AND :text_query != ''
+      WHEN TRUE THEN spigot_search_vector @@ to_tsquery('english', :text_query)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :spigot IS TRUE AND :name IS TRUE
+      WHEN TRUE THEN :query <% spigot_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :spigot IS TRUE AND :description IS TRUE
+      WHEN TRUE THEN :query <% spigot_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :spigot IS TRUE AND :full_description IS TRUE
+      WHEN TRUE THEN :query <% spigot_full_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :spigot IS TRUE AND :author IS TRUE
+      WHEN TRUE THEN :query <% spigot_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :modrinth IS TRUE AND :query = ''
+      WHEN TRUE THEN modrinth_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :modrinth IS TRUE AND :text_query != ''
+      WHEN TRUE THEN modrinth_search_vector @@ to_tsquery('english', :text_query)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :modrinth IS TRUE AND :name IS TRUE
+      WHEN TRUE THEN :query <% modrinth_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :modrinth IS TRUE AND :description IS TRUE
+      WHEN TRUE THEN :query <% modrinth_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :modrinth IS TRUE AND :full_description IS TRUE
+      WHEN TRUE THEN :query <% modrinth_full_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :modrinth IS TRUE AND :author IS TRUE
+      WHEN TRUE THEN :query <% modrinth_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :hangar IS TRUE AND :query = ''
+      WHEN TRUE THEN hangar_slug IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :hangar IS TRUE AND :text_query != ''
+      WHEN TRUE THEN hangar_search_vector @@ to_tsquery('english', :text_query)
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :spigot IS TRUE AND :name IS TRUE
-    WHEN TRUE THEN :query <% spigot_name
-    ELSE FALSE
-  END
+    CASE :hangar IS TRUE AND :name IS TRUE
+      WHEN TRUE THEN :query <% hangar_name
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :spigot IS TRUE AND :description IS TRUE
-    WHEN TRUE THEN :query <% spigot_description
-    ELSE FALSE
-  END
+    CASE :hangar IS TRUE AND :description IS TRUE
+      WHEN TRUE THEN :query <% hangar_description
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :spigot IS TRUE AND :author IS TRUE
-    WHEN TRUE THEN :query <% spigot_author
-    ELSE FALSE
-  END
+    CASE :hangar IS TRUE AND :full_description IS TRUE
+      WHEN TRUE THEN :query <% hangar_full_description
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :modrinth IS TRUE AND :query = ''
-    WHEN TRUE THEN modrinth_id IS NOT NULL
-    ELSE FALSE
-  END
+    CASE :hangar IS TRUE AND :author IS TRUE
+      WHEN TRUE THEN :query <% hangar_author
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :modrinth IS TRUE AND :name IS TRUE
-    WHEN TRUE THEN :query <% modrinth_name
-    ELSE FALSE
-  END
+    CASE :curseforge IS TRUE AND :query = ''
+      WHEN TRUE THEN curseforge_id IS NOT NULL
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :modrinth IS TRUE AND :description IS TRUE
-    WHEN TRUE THEN :query <% modrinth_description
-    ELSE FALSE
-  END
+    CASE :curseforge IS TRUE AND :text_query != ''
+      WHEN TRUE THEN curseforge_search_vector @@ to_tsquery('english', :text_query)
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :modrinth IS TRUE AND :author IS TRUE
-    WHEN TRUE THEN :query <% modrinth_author
-    ELSE FALSE
-  END
+    CASE :curseforge IS TRUE AND :name IS TRUE
+      WHEN TRUE THEN :query <% curseforge_name
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :hangar IS TRUE AND :query = ''
-    WHEN TRUE THEN hangar_slug IS NOT NULL
-    ELSE FALSE
-  END
+    CASE :curseforge IS TRUE AND :description IS TRUE
+      WHEN TRUE THEN :query <% curseforge_description
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :hangar IS TRUE AND :name IS TRUE
-    WHEN TRUE THEN :query <% hangar_name
-    ELSE FALSE
-  END
+    CASE :curseforge IS TRUE AND :author IS TRUE
+      WHEN TRUE THEN :query <% curseforge_author
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :hangar IS TRUE AND :description IS TRUE
-    WHEN TRUE THEN :query <% hangar_description
-    ELSE FALSE
-  END
+    CASE :polymart IS TRUE AND :query = ''
+      WHEN TRUE THEN polymart_id IS NOT NULL
+      ELSE FALSE
+    END
 
-  OR
+    OR
 
-  CASE :hangar IS TRUE AND :author IS TRUE
-    WHEN TRUE THEN :query <% hangar_author
-    ELSE FALSE
-  END
+    CASE :polymart IS TRUE AND :text_query != ''
+      WHEN TRUE THEN polymart_search_vector @@ to_tsquery('english', :text_query)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :polymart IS TRUE AND :name IS TRUE
+      WHEN TRUE THEN :query <% polymart_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :polymart IS TRUE AND :description IS TRUE
+      WHEN TRUE THEN :query <% polymart_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :polymart IS TRUE AND :author IS TRUE
+      WHEN TRUE THEN :query <% polymart_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :builtbybit IS TRUE AND :query = ''
+      WHEN TRUE THEN builtbybit_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :builtbybit IS TRUE AND :text_query != ''
+      WHEN TRUE THEN builtbybit_search_vector @@ to_tsquery('english', :text_query)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :builtbybit IS TRUE AND :name IS TRUE
+      WHEN TRUE THEN :query <% builtbybit_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :builtbybit IS TRUE AND :description IS TRUE
+      WHEN TRUE THEN :query <% builtbybit_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE :builtbybit IS TRUE AND :author IS TRUE
+      WHEN TRUE THEN :query <% builtbybit_author
+      ELSE FALSE
+    END
+  )
+
+  AND
+
+  -- Only include projects that support at least one of the given platforms, if any are given
+  (
+    cardinality(:platforms::server_platform[]) = 0
+    OR (:spigot IS TRUE AND spigot_platforms && :platforms)
+    OR (:modrinth IS TRUE AND modrinth_platforms && :platforms)
+    OR (:hangar IS TRUE AND hangar_platforms && :platforms)
+    OR (:curseforge IS TRUE AND curseforge_platforms && :platforms)
+    OR (:polymart IS TRUE AND polymart_platforms && :platforms)
+  )
+
+  AND
+
+  -- Only include projects in at least one of the given categories, if any are given
+  (
+    cardinality(:categories::project_category[]) = 0
+    OR (:spigot IS TRUE AND spigot_categories && :categories)
+    OR (:modrinth IS TRUE AND modrinth_categories && :categories)
+    OR (:hangar IS TRUE AND hangar_categories && :categories)
+    OR (:curseforge IS TRUE AND curseforge_categories && :categories)
+    OR (:polymart IS TRUE AND polymart_categories && :categories)
+  )
+
+  AND
+
+  -- Only include projects that support the given Minecraft version, if one is given.
+  -- The version is given in each of its spellings, such as "1.21" and "1.21.0", since upstream versions are stored as listed.
+  -- Spigot only lists major versions such as "1.20", which are assumed to cover all versions in that family such as "1.20.4".
+  (
+    cardinality(:minecraft_versions::text[]) = 0
+    OR (:spigot IS TRUE AND spigot_minecraft_versions && (:minecraft_versions || :minecraft_version_family::text))
+    OR (:modrinth IS TRUE AND modrinth_minecraft_versions && :minecraft_versions)
+    OR (:hangar IS TRUE AND hangar_minecraft_versions && :minecraft_versions)
+    OR (:curseforge IS TRUE AND curseforge_minecraft_versions && :minecraft_versions)
+    OR (:polymart IS TRUE AND polymart_minecraft_versions && :minecraft_versions)
+  )
+
+  AND
+
+  -- Exclude projects whose source repository has been archived, if specified
+  (
+    :exclude_archived IS FALSE
+    OR meta.archived IS NOT TRUE
+  )
 
   ORDER BY
     -- Sorts on 'real' type
+    -- Relevance combines the weighted full-text rank of each project with the trigram word similarity of its best matching field.
     CASE
       WHEN :sort = 'relevance' AND :query != '' THEN
         GREATEST(
           CASE WHEN :spigot IS TRUE THEN
-            GREATEST(
-              CASE WHEN :name IS TRUE THEN :query <<-> spigot_name ELSE NULL END,
-              CASE WHEN :description IS TRUE THEN :query <<-> spigot_description ELSE NULL END,
-              CASE WHEN :author IS TRUE THEN :query <<-> spigot_author ELSE NULL END
-            )
+            CASE WHEN :text_query != '' AND spigot_search_vector @@ to_tsquery('english', :text_query)
+              THEN :full_text_weight::real * ts_rank(ARRAY[:author_weight::real, :description_weight::real, :categories_weight::real, :name_weight::real], spigot_search_vector, to_tsquery('english', :text_query))
+              ELSE 0
+            END +
+            :trigram_weight::real * COALESCE(1 - LEAST(
+                CASE WHEN :name IS TRUE THEN :query <<-> spigot_name ELSE NULL END,
+                CASE WHEN :description IS TRUE THEN :query <<-> spigot_description ELSE NULL END,
+                CASE WHEN :full_description IS TRUE THEN :query <<-> spigot_full_description ELSE NULL END,
+                CASE WHEN :author IS TRUE THEN :query <<-> spigot_author ELSE NULL END
+            ), 0)
           ELSE NULL END,
           CASE WHEN :modrinth IS TRUE THEN
-            GREATEST(
-              CASE WHEN :name IS TRUE THEN :query <<-> modrinth_name ELSE NULL END,
-              CASE WHEN :description IS TRUE THEN :query <<-> modrinth_description ELSE NULL END,
-              CASE WHEN :author IS TRUE THEN :query <<-> modrinth_author ELSE NULL END
-            )
+            CASE WHEN :text_query != '' AND modrinth_search_vector @@ to_tsquery('english', :text_query)
+              THEN :full_text_weight::real * ts_rank(ARRAY[:author_weight::real, :description_weight::real, :categories_weight::real, :name_weight::real], modrinth_search_vector, to_tsquery('english', :text_query))
+              ELSE 0
+            END +
+            :trigram_weight::real * COALESCE(1 - LEAST(
+                CASE WHEN :name IS TRUE THEN :query <<-> modrinth_name ELSE NULL END,
+                CASE WHEN :description IS TRUE THEN :query <<-> modrinth_description ELSE NULL END,
+                CASE WHEN :full_description IS TRUE THEN :query <<-> modrinth_full_description ELSE NULL END,
+                CASE WHEN :author IS TRUE THEN :query <<-> modrinth_author ELSE NULL END
+            ), 0)
           ELSE NULL END,
           CASE WHEN :hangar IS TRUE THEN
-            GREATEST(
-              CASE WHEN :name IS TRUE THEN :query <<-> hangar_name ELSE NULL END,
-              CASE WHEN :description IS TRUE THEN :query <<-> hangar_description ELSE NULL END,
-              CASE WHEN :author IS TRUE THEN :query <<-> hangar_author ELSE NULL END
-            )
+            CASE WHEN :text_query != '' AND hangar_search_vector @@ to_tsquery('english', :text_query)
+              THEN :full_text_weight::real * ts_rank(ARRAY[:author_weight::real, :description_weight::real, :categories_weight::real, :name_weight::real], hangar_search_vector, to_tsquery('english', :text_query))
+              ELSE 0
+            END +
+            :trigram_weight::real * COALESCE(1 - LEAST(
+                CASE WHEN :name IS TRUE THEN :query <<-> hangar_name ELSE NULL END,
+                CASE WHEN :description IS TRUE THEN :query <<-> hangar_description ELSE NULL END,
+                CASE WHEN :full_description IS TRUE THEN :query <<-> hangar_full_description ELSE NULL END,
+                CASE WHEN :author IS TRUE THEN :query <<-> hangar_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN :curseforge IS TRUE THEN
+            CASE WHEN :text_query != '' AND curseforge_search_vector @@ to_tsquery('english', :text_query)
+              THEN :full_text_weight::real * ts_rank(ARRAY[:author_weight::real, :description_weight::real, :categories_weight::real, :name_weight::real], curseforge_search_vector, to_tsquery('english', :text_query))
+              ELSE 0
+            END +
+            :trigram_weight::real * COALESCE(1 - LEAST(
+                CASE WHEN :name IS TRUE THEN :query <<-> curseforge_name ELSE NULL END,
+                CASE WHEN :description IS TRUE THEN :query <<-> curseforge_description ELSE NULL END,
+                CASE WHEN :author IS TRUE THEN :query <<-> curseforge_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN :polymart IS TRUE THEN
+            CASE WHEN :text_query != '' AND polymart_search_vector @@ to_tsquery('english', :text_query)
+              THEN :full_text_weight::real * ts_rank(ARRAY[:author_weight::real, :description_weight::real, :categories_weight::real, :name_weight::real], polymart_search_vector, to_tsquery('english', :text_query))
+              ELSE 0
+            END +
+            :trigram_weight::real * COALESCE(1 - LEAST(
+                CASE WHEN :name IS TRUE THEN :query <<-> polymart_name ELSE NULL END,
+                CASE WHEN :description IS TRUE THEN :query <<-> polymart_description ELSE NULL END,
+                CASE WHEN :author IS TRUE THEN :query <<-> polymart_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN :builtbybit IS TRUE THEN
+            CASE WHEN :text_query != '' AND builtbybit_search_vector @@ to_tsquery('english', :text_query)
+              THEN :full_text_weight::real * ts_rank(ARRAY[:author_weight::real, :description_weight::real, :categories_weight::real, :name_weight::real], builtbybit_search_vector, to_tsquery('english', :text_query))
+              ELSE 0
+            END +
+            :trigram_weight::real * COALESCE(1 - LEAST(
+                CASE WHEN :name IS TRUE THEN :query <<-> builtbybit_name ELSE NULL END,
+                CASE WHEN :description IS TRUE THEN :query <<-> builtbybit_description ELSE NULL END,
+                CASE WHEN :author IS TRUE THEN :query <<-> builtbybit_author ELSE NULL END
+            ), 0)
           ELSE NULL END
         )
-    END ASC NULLS LAST,
+    END DESC NULLS LAST,
 
     -- Sorts on 'timestamptz' type
     CASE
@@ -185,24 +498,35 @@ WHERE
         GREATEST(
           CASE WHEN :spigot IS TRUE THEN spigot_date_created ELSE NULL END,
           CASE WHEN :modrinth IS TRUE THEN modrinth_date_created ELSE NULL END,
-          CASE WHEN :hangar IS TRUE THEN hangar_date_created ELSE NULL END
+          CASE WHEN :hangar IS TRUE THEN hangar_date_created ELSE NULL END,
+          CASE WHEN :curseforge IS TRUE THEN curseforge_date_created ELSE NULL END,
+          CASE WHEN :polymart IS TRUE THEN polymart_date_created ELSE NULL END,
+          CASE WHEN :builtbybit IS TRUE THEN builtbybit_date_created ELSE NULL END
         )
 
       WHEN :sort = 'date_updated' THEN
         GREATEST(
           CASE WHEN :spigot IS TRUE THEN spigot_date_updated ELSE NULL END,
           CASE WHEN :modrinth IS TRUE THEN modrinth_date_updated ELSE NULL END,
-          CASE WHEN :hangar IS TRUE THEN hangar_date_updated ELSE NULL END
+          CASE WHEN :hangar IS TRUE THEN hangar_date_updated ELSE NULL END,
+          CASE WHEN :curseforge IS TRUE THEN curseforge_date_updated ELSE NULL END,
+          CASE WHEN :polymart IS TRUE THEN polymart_date_updated ELSE NULL END,
+          CASE WHEN :builtbybit IS TRUE THEN builtbybit_date_updated ELSE NULL END
         )
+
+      WHEN :sort = 'repository_activity' THEN
+        meta.date_last_commit
     END DESC NULLS LAST,
 
-    -- Sorts on 'text' type
+    -- Sorts on 'integer[]' type
     CASE
       WHEN :sort = 'latest_minecraft_version' THEN
         GREATEST(
-          CASE WHEN :spigot IS TRUE THEN spigot_latest_minecraft_version ELSE NULL END,
-          CASE WHEN :modrinth IS TRUE THEN modrinth_latest_minecraft_version ELSE NULL END,
-          CASE WHEN :hangar IS TRUE THEN hangar_latest_minecraft_version ELSE NULL END
+          CASE WHEN :spigot IS TRUE THEN spigot_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN :modrinth IS TRUE THEN modrinth_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN :hangar IS TRUE THEN hangar_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN :curseforge IS TRUE THEN curseforge_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN :polymart IS TRUE THEN polymart_latest_minecraft_version_sort_key ELSE NULL END
         )
     END DESC NULLS LAST,
 
@@ -210,17 +534,29 @@ WHERE
     CASE
       WHEN :sort = 'likes_and_stars' THEN
         CASE WHEN :spigot IS TRUE THEN COALESCE(spigot_likes, 0) ELSE 0 END +
-        CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END
+        CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END +
+        CASE WHEN :curseforge IS TRUE THEN COALESCE(curseforge_likes, 0) ELSE 0 END
 
       WHEN :sort = 'follows_and_watchers' THEN
         CASE WHEN :modrinth IS TRUE THEN COALESCE(modrinth_follows, 0) ELSE 0 END +
         CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_watchers, 0) ELSE 0 END
+
+      WHEN :sort = 'trending' THEN
+        CASE WHEN :spigot IS TRUE THEN COALESCE(spigot_trending_score, 0) ELSE 0 END +
+        CASE WHEN :modrinth IS TRUE THEN COALESCE(modrinth_trending_score, 0) ELSE 0 END +
+        CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_trending_score, 0) ELSE 0 END +
+        CASE WHEN :curseforge IS TRUE THEN COALESCE(curseforge_trending_score, 0) ELSE 0 END +
+        CASE WHEN :polymart IS TRUE THEN COALESCE(polymart_trending_score, 0) ELSE 0 END +
+        CASE WHEN :builtbybit IS TRUE THEN COALESCE(builtbybit_trending_score, 0) ELSE 0 END
     END DESC NULLS LAST,
 
     -- Fallback to sort by downloads when no sort is specified or as a secondary sort
     CASE WHEN :spigot IS TRUE THEN COALESCE(spigot_downloads, 0) ELSE 0 END +
     CASE WHEN :modrinth IS TRUE THEN COALESCE(modrinth_downloads, 0) ELSE 0 END +
-    CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END
+    CASE WHEN :hangar IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END +
+    CASE WHEN :curseforge IS TRUE THEN COALESCE(curseforge_downloads, 0) ELSE 0 END +
+    CASE WHEN :polymart IS TRUE THEN COALESCE(polymart_downloads, 0) ELSE 0 END +
+    CASE WHEN :builtbybit IS TRUE THEN COALESCE(builtbybit_downloads, 0) ELSE 0 END
     DESC NULLS LAST
 
 LIMIT :limit
@@ -0,0 +1,18 @@
+--! get_populated_snapshot_tables
+SELECT table_name
+FROM (
+  VALUES
+    ('spigot_author', EXISTS (SELECT 1 FROM spigot_author)),
+    ('spigot_resource', EXISTS (SELECT 1 FROM spigot_resource)),
+    ('modrinth_project', EXISTS (SELECT 1 FROM modrinth_project)),
+    ('modrinth_version', EXISTS (SELECT 1 FROM modrinth_version)),
+    ('hangar_project', EXISTS (SELECT 1 FROM hangar_project)),
+    ('curseforge_project', EXISTS (SELECT 1 FROM curseforge_project)),
+    ('polymart_resource', EXISTS (SELECT 1 FROM polymart_resource)),
+    ('builtbybit_resource', EXISTS (SELECT 1 FROM builtbybit_resource)),
+    ('source_repository_release', EXISTS (SELECT 1 FROM source_repository_release)),
+    ('source_repository_metadata', EXISTS (SELECT 1 FROM source_repository_metadata)),
+    ('project_stats_snapshot', EXISTS (SELECT 1 FROM project_stats_snapshot)),
+    ('ingest_log', EXISTS (SELECT 1 FROM ingest_log))
+) AS snapshot_table (table_name, populated)
+WHERE populated;
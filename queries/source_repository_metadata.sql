@@ -0,0 +1,20 @@
+--: SourceRepositoryMetadataEntity(license?, open_issues?, date_last_commit?)
+
+--! upsert_source_repository_metadata (license?, open_issues?, date_last_commit?)
+INSERT INTO source_repository_metadata (host, owner, name, stars, archived, license, open_issues, date_last_commit, date_last_checked)
+  VALUES (LOWER(:host), LOWER(:owner), LOWER(:name), :stars, :archived, :license, :open_issues, :date_last_commit, NOW())
+  ON CONFLICT (host, owner, name)
+  DO UPDATE SET
+    stars = EXCLUDED.stars,
+    archived = EXCLUDED.archived,
+    license = EXCLUDED.license,
+    open_issues = EXCLUDED.open_issues,
+    date_last_commit = EXCLUDED.date_last_commit,
+    date_last_checked = EXCLUDED.date_last_checked;
+
+--! get_source_repository_metadata : SourceRepositoryMetadataEntity
+SELECT * FROM source_repository_metadata;
+
+--! get_source_repository_metadata_by_repository : SourceRepositoryMetadataEntity
+SELECT * FROM source_repository_metadata
+WHERE host = :host AND owner = :owner AND name = :name;
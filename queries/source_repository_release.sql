@@ -0,0 +1,32 @@
+--: SourceRepositoryReleaseEntity(release_name?, url?)
+
+--: SourceRepositoryEntity()
+
+--! upsert_source_repository_release (release_name?, url?)
+INSERT INTO source_repository_release (host, owner, name, tag_name, release_name, url, date_published, date_last_checked)
+  VALUES (LOWER(:host), LOWER(:owner), LOWER(:name), :tag_name, :release_name, :url, :date_published, NOW())
+  ON CONFLICT (host, owner, name)
+  DO UPDATE SET
+    tag_name = EXCLUDED.tag_name,
+    release_name = EXCLUDED.release_name,
+    url = EXCLUDED.url,
+    date_published = EXCLUDED.date_published,
+    date_last_checked = EXCLUDED.date_last_checked;
+
+--! get_source_repository_releases : SourceRepositoryReleaseEntity
+SELECT * FROM source_repository_release;
+
+--! get_source_repository_release_by_repository : SourceRepositoryReleaseEntity
+SELECT * FROM source_repository_release
+WHERE host = :host AND owner = :owner AND name = :name;
+
+--! get_distinct_source_repositories : SourceRepositoryEntity
+SELECT DISTINCT
+  LOWER(source_repository_host) AS host,
+  LOWER(source_repository_owner) AS owner,
+  LOWER(source_repository_name) AS name
+FROM common_project
+WHERE source_repository_host IS NOT NULL
+  AND source_repository_owner IS NOT NULL
+  AND source_repository_name IS NOT NULL
+ORDER BY host, owner, name;
@@ -6,4 +6,8 @@ INSERT INTO spigot_author (id, name)
   ON CONFLICT DO NOTHING;
 
 --! get_spigot_authors : SpigotAuthorEntity
-SELECT id, name FROM spigot_author;
\ No newline at end of file
+SELECT id, name FROM spigot_author;
+
+--! get_spigot_author_by_id : SpigotAuthorEntity
+SELECT id, name FROM spigot_author
+WHERE id = :id;
\ No newline at end of file
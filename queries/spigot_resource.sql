@@ -1,8 +1,8 @@
---: SpigotResourceEntity(parsed_name?, latest_minecraft_version?, version_name?, icon_url?, icon_data?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?)
+--: SpigotResourceEntity(parsed_name?, latest_minecraft_version?, latest_minecraft_version_sort_key?, version_name?, icon_url?, icon_data?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, source_repository_id?, date_last_seen?, date_deleted?, full_description?)
 
---! upsert_spigot_resource (parsed_name?, latest_minecraft_version?, version_name?, icon_url?, icon_data?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?)
-INSERT INTO spigot_resource (id, name, parsed_name, description, slug, date_created, date_updated, latest_minecraft_version, downloads, likes, author_id, version_id, version_name, premium, abandoned, icon_url, icon_data, source_url, source_repository_host, source_repository_owner, source_repository_name)
-  VALUES (:id, :name, :parsed_name, :description, :slug, :date_created, :date_updated, :latest_minecraft_version, :downloads, :likes, :author_id, :version_id, :version_name, :premium, :abandoned, :icon_url, :icon_data, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name)
+--! upsert_spigot_resource (parsed_name?, latest_minecraft_version?, latest_minecraft_version_sort_key?, version_name?, icon_url?, icon_data?, source_url?, source_repository_host?, source_repository_owner?, source_repository_name?, full_description?)
+INSERT INTO spigot_resource (id, name, parsed_name, description, slug, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, likes, author_id, version_id, version_name, premium, abandoned, icon_url, icon_data, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, categories, minecraft_versions, full_description, date_last_seen)
+  VALUES (:id, :name, :parsed_name, :description, :slug, :date_created, :date_updated, :latest_minecraft_version, :latest_minecraft_version_sort_key, :downloads, :likes, :author_id, :version_id, :version_name, :premium, :abandoned, :icon_url, :icon_data, :source_url, :source_repository_host, :source_repository_owner, :source_repository_name, :platforms, :categories, :minecraft_versions, :full_description, NOW())
   ON CONFLICT (id)
   DO UPDATE SET
     name = EXCLUDED.name,
@@ -12,6 +12,7 @@ INSERT INTO spigot_resource (id, name, parsed_name, description, slug, date_crea
     date_created = EXCLUDED.date_created,
     date_updated = EXCLUDED.date_updated,
     latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
     downloads = EXCLUDED.downloads,
     likes = EXCLUDED.likes,
     author_id = EXCLUDED.author_id,
@@ -24,10 +25,31 @@ INSERT INTO spigot_resource (id, name, parsed_name, description, slug, date_crea
     source_url = EXCLUDED.source_url,
     source_repository_host = EXCLUDED.source_repository_host,
     source_repository_owner = EXCLUDED.source_repository_owner,
-    source_repository_name = EXCLUDED.source_repository_name;
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    full_description = EXCLUDED.full_description,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL;
 
 --! get_spigot_resources : SpigotResourceEntity
-SELECT * FROM spigot_resource;
+SELECT * FROM spigot_resource
+WHERE date_deleted IS NULL;
+
+--! get_spigot_resource_by_id : SpigotResourceEntity
+SELECT * FROM spigot_resource
+WHERE id = :id AND date_deleted IS NULL;
 
 --! get_latest_spigot_resource_update_date
 SELECT max(date_updated) FROM spigot_resource;
+
+--! mark_unseen_spigot_resources_as_deleted
+UPDATE spigot_resource
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < :date_started)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Spigot' AND item = 'Resource' AND upstream_id = spigot_resource.id::text
+  );
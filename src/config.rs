@@ -16,4 +16,10 @@ fn config() -> &'static config::Config {
 
 pub fn get_config_string(key: &str) -> String {
     config().get_string(key).unwrap_or_else(|_| panic!("config key '{}' is not set to a string", key))
+}
+pub fn get_optional_config_string(key: &str) -> Option<String> {
+    config().get_string(key).ok()
+}
+pub fn get_optional_config_float(key: &str) -> Option<f64> {
+    config().get_float(key).ok()
 }
\ No newline at end of file
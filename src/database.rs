@@ -1,10 +1,24 @@
 mod cornucopia;
+pub mod builtbybit;
 pub mod common;
+pub mod curseforge;
 pub mod fix_upstream_errors;
 pub mod hangar;
+pub mod icon;
+pub mod ingest_checkpoint;
+pub mod ingest_failure;
+pub mod ingest_lock;
 pub mod ingest_log;
 pub mod modrinth;
+pub mod polymart;
+pub mod project_category;
+pub mod project_description;
+pub mod project_stats_snapshot;
+pub mod server_platform;
+pub mod snapshot;
 pub mod source_repository;
+pub mod source_repository_metadata;
+pub mod source_repository_release;
 pub mod spigot;
 
 use crate::config::get_config_string;
@@ -61,7 +75,9 @@ pub fn get_db() -> Database {
 mod test {
     use super::*;
     use deadpool_postgres::Pool;
-    use std::fs::read_to_string;
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use std::fs::{read_dir, read_to_string};
 
     const DEFAULT_POSTGRES_DB_NAME: &str = "postgres";
 
@@ -73,6 +89,26 @@ mod test {
 
     impl DatabaseTestContext {
         pub async fn new(db_name: &str) -> Self {
+            let context = Self::new_empty(db_name).await;
+
+            Self::run_migration(&context.pool)
+                .await
+                .expect("could not run migration");
+
+            context
+        }
+
+        pub async fn new_from_versioned_migrations(db_name: &str) -> Self {
+            let context = Self::new_empty(db_name).await;
+
+            Self::run_versioned_migrations(&context.pool)
+                .await
+                .expect("could not run versioned migrations");
+
+            context
+        }
+
+        async fn new_empty(db_name: &str) -> Self {
             dotenvy::dotenv().expect("could not load environment variables from .env file");
 
             let db = get_db();
@@ -93,10 +129,6 @@ mod test {
                 .await
                 .expect("could not create database pool");
 
-            Self::run_migration(&pool)
-                .await
-                .expect("could not run migration");
-
             Self {
                 db_name: db_name.to_string(),
                 base_pool,
@@ -124,6 +156,30 @@ mod test {
             Ok(())
         }
 
+        // Applies the migrations in version order, as refinery does in production
+        async fn run_versioned_migrations(pool: &Pool) -> Result<()> {
+            let client = pool.get().await?;
+
+            let mut migrations = Vec::new();
+            for entry in read_dir("migrations")? {
+                let path = entry?.path();
+                let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+                if let Some(version) = file_name
+                    .strip_prefix('V')
+                    .and_then(|name| name.split_once("__"))
+                    .and_then(|(version, _)| version.parse::<u32>().ok()) {
+                    migrations.push((version, path));
+                }
+            }
+            migrations.sort();
+
+            for (_, path) in migrations {
+                client.batch_execute(&read_to_string(path)?).await?;
+            }
+
+            Ok(())
+        }
+
         async fn drop_database(pool: &Pool, db_name: &str) -> Result<()> {
             let client = pool.get().await?;
 
@@ -144,4 +200,46 @@ mod test {
             Ok(())
         }
     }
-}
\ No newline at end of file
+
+    async fn get_column_layout(pool: &Pool) -> Result<Vec<String>> {
+        let client = pool.get().await?;
+
+        let statement = "
+          SELECT c.relname || '.' || a.attname || ': ' || format_type(a.atttypid, a.atttypmod)
+          FROM pg_attribute a
+          INNER JOIN pg_class c ON c.oid = a.attrelid
+          INNER JOIN pg_namespace n ON n.oid = c.relnamespace
+          WHERE n.nspname = 'public'
+            AND c.relkind IN ('r', 'v', 'm')
+            AND a.attnum > 0
+            AND NOT a.attisdropped
+          ORDER BY c.relname, a.attnum;";
+
+        let rows = client.query(statement, &[]).await?;
+
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    // Generated queries map the columns of "SELECT *" by position, so the schema file must lay out columns in the same order as the migrations
+    #[tokio::test]
+    #[named]
+    async fn should_match_schema_file_column_order_to_migrations() -> Result<()> {
+        // Setup
+        let schema_context = DatabaseTestContext::new(&format!("{}_schema", function_name!())).await;
+        let migrations_context = DatabaseTestContext::new_from_versioned_migrations(&format!("{}_migrations", function_name!())).await;
+
+        // Act
+        let schema_layout = get_column_layout(&schema_context.pool).await?;
+        let migrations_layout = get_column_layout(&migrations_context.pool).await?;
+
+        // Assert
+        assert_that(&schema_layout).is_not_empty();
+        assert_that(&schema_layout).is_equal_to(&migrations_layout);
+
+        // Teardown
+        schema_context.drop().await?;
+        migrations_context.drop().await?;
+
+        Ok(())
+    }
+}
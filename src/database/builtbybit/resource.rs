@@ -0,0 +1,379 @@
+use crate::database::source_repository::SourceRepository;
+use crate::database::cornucopia::queries::builtbybit_resource::{self, BuiltbybitResourceEntity, UpsertBuiltbybitResourceParams};
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuiltByBitResource {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_updated: OffsetDateTime,
+    pub downloads: i32,
+    pub price: f64,
+    pub currency: Option<String>,
+    pub version_name: Option<String>,
+    pub source_url: Option<String>,
+    pub source_repository: Option<SourceRepository>
+}
+
+impl From<BuiltByBitResource> for UpsertBuiltbybitResourceParams<String, String, String, String, String, String, String, String, String> {
+    fn from(resource: BuiltByBitResource) -> Self {
+        let mut source_repository_host = None;
+        let mut source_repository_owner = None;
+        let mut source_repository_name = None;
+
+        if let Some(repo) = resource.source_repository {
+            source_repository_host = Some(repo.host);
+            source_repository_owner = Some(repo.owner);
+            source_repository_name = Some(repo.name);
+        }
+
+        UpsertBuiltbybitResourceParams {
+            id: resource.id,
+            name: resource.name,
+            description: resource.description,
+            author: resource.author,
+            date_created: resource.date_created,
+            date_updated: resource.date_updated,
+            downloads: resource.downloads,
+            price: resource.price,
+            currency: resource.currency,
+            version_name: resource.version_name,
+            source_url: resource.source_url,
+            source_repository_host,
+            source_repository_owner,
+            source_repository_name
+        }
+    }
+}
+
+impl From<BuiltbybitResourceEntity> for BuiltByBitResource {
+    fn from(entity: BuiltbybitResourceEntity) -> Self {
+        let mut source_repository = None;
+
+        if let (Some(host), Some(owner), Some(name)) = (entity.source_repository_host, entity.source_repository_owner, entity.source_repository_name) {
+            source_repository = Some(SourceRepository {
+                host,
+                owner,
+                name,
+                id: entity.source_repository_id
+            })
+        }
+
+        BuiltByBitResource {
+            id: entity.id,
+            name: entity.name,
+            description: entity.description,
+            author: entity.author,
+            date_created: entity.date_created,
+            date_updated: entity.date_updated,
+            downloads: entity.downloads,
+            price: entity.price,
+            currency: entity.currency,
+            version_name: entity.version_name,
+            source_url: entity.source_url,
+            source_repository
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum BuiltByBitResourceError {
+    #[error("Skipping resource ID {resource_id}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        resource_id: i32,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_builtbybit_resource(db_pool: &Pool, resource: &BuiltByBitResource) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = builtbybit_resource::upsert_builtbybit_resource()
+        .params(&db_client, &resource.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            BuiltByBitResourceError::DatabaseQueryFailed {
+                resource_id: resource.id,
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+pub async fn get_builtbybit_resources(db_pool: &Pool) -> Result<Vec<BuiltByBitResource>> {
+    let db_client = db_pool.get().await?;
+
+    let resources = builtbybit_resource::get_builtbybit_resources()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(resources)
+}
+
+/// Returns the resource with the given ID, or None if it does not exist or is deleted.
+pub async fn get_builtbybit_resource_by_id(db_pool: &Pool, id: i32) -> Result<Option<BuiltByBitResource>> {
+    let db_client = db_pool.get().await?;
+
+    let resource = builtbybit_resource::get_builtbybit_resource_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(resource)
+}
+
+pub async fn get_latest_builtbybit_resource_update_date(db_pool: &Pool) -> Result<OffsetDateTime> {
+    let db_client = db_pool.get().await?;
+
+    let date = builtbybit_resource::get_latest_builtbybit_resource_update_date()
+        .bind(&db_client)
+        .one()
+        .await?;
+
+    Ok(date)
+}
+
+/// Marks resources that have not been seen upstream since the given date as deleted, returning the number of resources marked.
+/// Deleted resources are excluded from common projects, and are restored the next time they are upserted.
+/// Quarantined resources are left alone, since they may have been seen but failed to process.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn mark_unseen_builtbybit_resources_as_deleted(db_pool: &Pool, date_started: OffsetDateTime) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = builtbybit_resource::mark_unseen_builtbybit_resources_as_deleted()
+        .bind(&db_client, &date_started)
+        .await?;
+
+    Ok(count)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_builtbybit_resource() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let resource = &create_test_builtbybit_resources()[0];
+
+        // Act
+        upsert_builtbybit_resource(&context.pool, resource).await?;
+
+        // Assert
+        let retrieved_resources = get_builtbybit_resources(&context.pool).await?;
+        let retrieved_resource = &retrieved_resources[0];
+
+        assert_that(&retrieved_resources).has_length(1);
+        assert_that(&retrieved_resource).is_equal_to(resource);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_builtbybit_resource() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _resource = populate_test_builtbybit_resource(&context.pool).await?;
+
+        let updated_resource = BuiltByBitResource {
+            id: 1,
+            name: "foo-updated".to_string(),
+            description: "foo-description-updated".to_string(),
+            author: "Frumple".to_string(),
+            date_created: datetime!(2020-01-01 0:00 UTC),
+            date_updated: datetime!(2021-07-01 0:00 UTC),
+            downloads: 100,
+            price: 12.5,
+            currency: Some("EUR".to_string()),
+            version_name: Some("2.3.4".to_string()),
+            source_url: Some("https://github.com/alice/foo-updated".to_string()),
+            source_repository: Some(SourceRepository {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo-updated".to_string(),
+                id: None
+            })
+        };
+
+        // Act
+        upsert_builtbybit_resource(&context.pool, &updated_resource).await?;
+
+        // Assert
+        let retrieved_resources = get_builtbybit_resources(&context.pool).await?;
+        let retrieved_resource = &retrieved_resources[0];
+
+        assert_that(&retrieved_resources).has_length(1);
+        assert_that(&retrieved_resource).is_equal_to(&updated_resource);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_latest_builtbybit_resource_update_date() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let resources = create_test_builtbybit_resources();
+        for resource in resources {
+            upsert_builtbybit_resource(&context.pool, &resource).await?;
+        }
+
+        // Act
+        let latest_update_date = get_latest_builtbybit_resource_update_date(&context.pool).await?;
+
+        // Assert
+        assert_that(&latest_update_date).is_equal_to(datetime!(2017-02-03 0:00 UTC));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_mark_unseen_builtbybit_resources_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let resources = populate_test_builtbybit_resources(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_builtbybit_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(resources.len() as u64);
+
+        let retrieved_resources = get_builtbybit_resources(&context.pool).await?;
+        assert_that(&retrieved_resources).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    pub async fn populate_test_builtbybit_resource(db_pool: &Pool) -> Result<BuiltByBitResource> {
+        let resource = &create_test_builtbybit_resources()[0];
+        upsert_builtbybit_resource(db_pool, resource).await?;
+        Ok(resource.clone())
+    }
+
+    pub async fn populate_test_builtbybit_resources(db_pool: &Pool) -> Result<Vec<BuiltByBitResource>> {
+        let resources = create_test_builtbybit_resources();
+        for resource in &resources {
+            upsert_builtbybit_resource(db_pool, resource).await?
+        }
+        Ok(resources)
+    }
+
+    fn create_test_builtbybit_resources() -> Vec<BuiltByBitResource> {
+        vec![
+            BuiltByBitResource {
+                id: 1,
+                name: "foo-builtbybit".to_string(),
+                description: "foo-builtbybit-description".to_string(),
+                author: "alice".to_string(),
+                date_created: datetime!(2017-01-01 0:00 UTC),
+                date_updated: datetime!(2017-02-03 0:00 UTC),
+                downloads: 100,
+                price: 0.0,
+                currency: None,
+                version_name: Some("1.2.3".to_string()),
+                source_url: Some("https://github.com/alice/foo".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "github.com".to_string(),
+                    owner: "alice".to_string(),
+                    name: "foo".to_string(),
+                    id: None
+                })
+            },
+            BuiltByBitResource {
+                id: 2,
+                name: "bar-builtbybit".to_string(),
+                description: "bar-builtbybit-description".to_string(),
+                author: "bob".to_string(),
+                date_created: datetime!(2017-01-02 0:00 UTC),
+                date_updated: datetime!(2017-02-02 0:00 UTC),
+                downloads: 300,
+                price: 9.99,
+                currency: Some("USD".to_string()),
+                version_name: Some("1.2.3".to_string()),
+                source_url: Some("https://gitlab.com/bob/bar".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "gitlab.com".to_string(),
+                    owner: "bob".to_string(),
+                    name: "bar".to_string(),
+                    id: None
+                })
+            },
+            BuiltByBitResource {
+                id: 3,
+                name: "baz-builtbybit".to_string(),
+                description: "baz-builtbybit-description".to_string(),
+                author: "eve".to_string(),
+                date_created: datetime!(2017-01-03 0:00 UTC),
+                date_updated: datetime!(2017-02-01 0:00 UTC),
+                downloads: 200,
+                price: 4.5,
+                currency: Some("GBP".to_string()),
+                version_name: None,
+                source_url: Some("https://bitbucket.org/eve/baz".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "bitbucket.org".to_string(),
+                    owner: "eve".to_string(),
+                    name: "baz".to_string(),
+                    id: None
+                })
+            }
+        ]
+    }
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
 use crate::database::cornucopia::queries::common_project::{self, CommonProjectEntity};
+use crate::database::project_category::{ProjectCategory, from_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, from_cornucopia_server_platforms};
 use crate::database::ingest_log::{IngestLog, IngestLogAction, IngestLogRepository, IngestLogItem, insert_ingest_log};
 
 use anyhow::Result;
@@ -10,7 +12,10 @@ use tracing::{info, instrument};
 pub struct CommonProject {
     pub spigot: Option<CommonProjectSpigot>,
     pub modrinth: Option<CommonProjectModrinth>,
-    pub hangar: Option<CommonProjectHangar>
+    pub hangar: Option<CommonProjectHangar>,
+    pub curseforge: Option<CommonProjectCurseForge>,
+    pub polymart: Option<CommonProjectPolymart>,
+    pub builtbybit: Option<CommonProjectBuiltByBit>
 }
 
 impl From<CommonProjectEntity> for CommonProject {
@@ -24,12 +29,15 @@ impl From<CommonProjectEntity> for CommonProject {
             version: entity.spigot_version,
             premium: entity.spigot_premium.unwrap(),
             abandoned: entity.spigot_abandoned.unwrap(),
-            icon_data: entity.spigot_icon_data,
+            icon_hash: entity.spigot_icon_hash,
             date_created: entity.spigot_date_created.unwrap(),
             date_updated: entity.spigot_date_updated.unwrap(),
             latest_minecraft_version: entity.spigot_latest_minecraft_version,
             downloads: entity.spigot_downloads.unwrap(),
-            likes: entity.spigot_likes.unwrap()
+            likes: entity.spigot_likes.unwrap(),
+            platforms: from_cornucopia_server_platforms(entity.spigot_platforms.unwrap_or_default()),
+            categories: from_cornucopia_project_categories(entity.spigot_categories.unwrap_or_default()),
+            minecraft_versions: entity.spigot_minecraft_versions.unwrap_or_default()
         });
 
         let modrinth = entity.modrinth_id.clone().map(|_| CommonProjectModrinth {
@@ -40,11 +48,15 @@ impl From<CommonProjectEntity> for CommonProject {
             author: entity.modrinth_author.unwrap(),
             version: entity.modrinth_version,
             icon_url: entity.modrinth_icon_url,
+            icon_hash: entity.modrinth_icon_hash,
             date_created: entity.modrinth_date_created.unwrap(),
             date_updated: entity.modrinth_date_updated.unwrap(),
             latest_minecraft_version: entity.modrinth_latest_minecraft_version,
             downloads: entity.modrinth_downloads.unwrap(),
-            follows: entity.modrinth_follows.unwrap()
+            follows: entity.modrinth_follows.unwrap(),
+            platforms: from_cornucopia_server_platforms(entity.modrinth_platforms.unwrap_or_default()),
+            categories: from_cornucopia_project_categories(entity.modrinth_categories.unwrap_or_default()),
+            minecraft_versions: entity.modrinth_minecraft_versions.unwrap_or_default()
         });
 
         let hangar = entity.hangar_slug.clone().map(|_| CommonProjectHangar {
@@ -54,18 +66,78 @@ impl From<CommonProjectEntity> for CommonProject {
             author: entity.hangar_author.unwrap(),
             version: entity.hangar_version,
             icon_url: entity.hangar_icon_url.unwrap(),
+            icon_hash: entity.hangar_icon_hash,
             date_created: entity.hangar_date_created.unwrap(),
             date_updated: entity.hangar_date_updated.unwrap(),
             latest_minecraft_version: entity.hangar_latest_minecraft_version,
             downloads: entity.hangar_downloads.unwrap(),
             stars: entity.hangar_stars.unwrap(),
-            watchers: entity.hangar_watchers.unwrap()
+            watchers: entity.hangar_watchers.unwrap(),
+            paper_versions: entity.hangar_paper_versions.unwrap_or_default(),
+            velocity_versions: entity.hangar_velocity_versions.unwrap_or_default(),
+            waterfall_versions: entity.hangar_waterfall_versions.unwrap_or_default(),
+            platforms: from_cornucopia_server_platforms(entity.hangar_platforms.unwrap_or_default()),
+            categories: from_cornucopia_project_categories(entity.hangar_categories.unwrap_or_default()),
+            minecraft_versions: entity.hangar_minecraft_versions.unwrap_or_default()
+        });
+
+        let curseforge = entity.curseforge_id.map(|_| CommonProjectCurseForge {
+            id: entity.curseforge_id.unwrap(),
+            slug: entity.curseforge_slug.unwrap(),
+            name: entity.curseforge_name.unwrap(),
+            description: entity.curseforge_description.unwrap(),
+            author: entity.curseforge_author.unwrap(),
+            version: entity.curseforge_version,
+            icon_url: entity.curseforge_icon_url,
+            icon_hash: entity.curseforge_icon_hash,
+            date_created: entity.curseforge_date_created.unwrap(),
+            date_updated: entity.curseforge_date_updated.unwrap(),
+            latest_minecraft_version: entity.curseforge_latest_minecraft_version,
+            downloads: entity.curseforge_downloads.unwrap(),
+            likes: entity.curseforge_likes.unwrap(),
+            platforms: from_cornucopia_server_platforms(entity.curseforge_platforms.unwrap_or_default()),
+            categories: from_cornucopia_project_categories(entity.curseforge_categories.unwrap_or_default()),
+            minecraft_versions: entity.curseforge_minecraft_versions.unwrap_or_default()
+        });
+
+        let polymart = entity.polymart_id.map(|_| CommonProjectPolymart {
+            id: entity.polymart_id.unwrap(),
+            name: entity.polymart_name.unwrap(),
+            description: entity.polymart_description.unwrap(),
+            author: entity.polymart_author.unwrap(),
+            version: entity.polymart_version,
+            premium: entity.polymart_premium.unwrap(),
+            icon_url: entity.polymart_icon_url,
+            icon_hash: entity.polymart_icon_hash,
+            date_created: entity.polymart_date_created.unwrap(),
+            date_updated: entity.polymart_date_updated.unwrap(),
+            latest_minecraft_version: entity.polymart_latest_minecraft_version,
+            downloads: entity.polymart_downloads.unwrap(),
+            platforms: from_cornucopia_server_platforms(entity.polymart_platforms.unwrap_or_default()),
+            categories: from_cornucopia_project_categories(entity.polymart_categories.unwrap_or_default()),
+            minecraft_versions: entity.polymart_minecraft_versions.unwrap_or_default()
+        });
+
+        let builtbybit = entity.builtbybit_id.map(|_| CommonProjectBuiltByBit {
+            id: entity.builtbybit_id.unwrap(),
+            name: entity.builtbybit_name.unwrap(),
+            description: entity.builtbybit_description.unwrap(),
+            author: entity.builtbybit_author.unwrap(),
+            version: entity.builtbybit_version,
+            price: entity.builtbybit_price.unwrap(),
+            currency: entity.builtbybit_currency,
+            date_created: entity.builtbybit_date_created.unwrap(),
+            date_updated: entity.builtbybit_date_updated.unwrap(),
+            downloads: entity.builtbybit_downloads.unwrap()
         });
 
         CommonProject {
             spigot,
             modrinth,
-            hangar
+            hangar,
+            curseforge,
+            polymart,
+            builtbybit
         }
     }
 }
@@ -80,12 +152,15 @@ pub struct CommonProjectSpigot {
     pub version: Option<String>,
     pub premium: bool,
     pub abandoned: bool,
-    pub icon_data: Option<String>,
+    pub icon_hash: Option<String>,
     pub date_created: OffsetDateTime,
     pub date_updated: OffsetDateTime,
     pub latest_minecraft_version: Option<String>,
     pub downloads: i32,
-    pub likes: i32
+    pub likes: i32,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -97,11 +172,15 @@ pub struct CommonProjectModrinth {
     pub author: String,
     pub version: Option<String>,
     pub icon_url: Option<String>,
+    pub icon_hash: Option<String>,
     pub date_created: OffsetDateTime,
     pub date_updated: OffsetDateTime,
     pub latest_minecraft_version: Option<String>,
     pub downloads: i32,
-    pub follows: i32
+    pub follows: i32,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -112,12 +191,72 @@ pub struct CommonProjectHangar {
     pub author: String,
     pub version: Option<String>,
     pub icon_url: String,
+    pub icon_hash: Option<String>,
     pub date_created: OffsetDateTime,
     pub date_updated: OffsetDateTime,
     pub latest_minecraft_version: Option<String>,
     pub downloads: i32,
     pub stars: i32,
-    pub watchers: i32
+    pub watchers: i32,
+    pub paper_versions: Vec<String>,
+    pub velocity_versions: Vec<String>,
+    pub waterfall_versions: Vec<String>,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommonProjectCurseForge {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>,
+    pub date_created: OffsetDateTime,
+    pub date_updated: OffsetDateTime,
+    pub latest_minecraft_version: Option<String>,
+    pub downloads: i32,
+    pub likes: i32,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommonProjectPolymart {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub premium: bool,
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>,
+    pub date_created: OffsetDateTime,
+    pub date_updated: OffsetDateTime,
+    pub latest_minecraft_version: Option<String>,
+    pub downloads: i32,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommonProjectBuiltByBit {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub price: f64,
+    pub currency: Option<String>,
+    pub date_created: OffsetDateTime,
+    pub date_updated: OffsetDateTime,
+    pub downloads: i32
 }
 
 #[instrument(
@@ -177,7 +316,7 @@ mod test {
     use super::*;
 
     use crate::database::spigot::author::SpigotAuthor;
-    use crate::database::spigot::resource::{SpigotResource, upsert_spigot_resource};
+    use crate::database::spigot::resource::{SpigotResource, upsert_spigot_resource, mark_unseen_spigot_resources_as_deleted};
     use crate::database::spigot::resource::test::populate_test_spigot_author_and_resource;
 
     use crate::database::modrinth::project::{ModrinthProject, upsert_modrinth_project};
@@ -186,6 +325,15 @@ mod test {
     use crate::database::hangar::project::{HangarProject, upsert_hangar_project};
     use crate::database::hangar::project::test::populate_test_hangar_project;
 
+    use crate::database::curseforge::project::{CurseForgeProject, upsert_curseforge_project};
+    use crate::database::curseforge::project::test::populate_test_curseforge_project;
+
+    use crate::database::polymart::resource::{PolymartResource, upsert_polymart_resource};
+    use crate::database::polymart::resource::test::populate_test_polymart_resource;
+
+    use crate::database::builtbybit::resource::{BuiltByBitResource, upsert_builtbybit_resource};
+    use crate::database::builtbybit::resource::test::populate_test_builtbybit_resource;
+
     use crate::database::test::DatabaseTestContext;
 
     use ::function_name::named;
@@ -558,6 +706,214 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_insert_and_update_spigot_and_curseforge() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (spigot_author, mut spigot_resource) = populate_test_spigot_author_and_resource(&context.pool).await?;
+        let mut curseforge_project = populate_test_curseforge_project(&context.pool).await?;
+
+        // Act 1 - Refresh common projects
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert 1 - Verify project in common projects
+        let inserted_projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&inserted_projects).has_length(1);
+
+        let inserted_project = &inserted_projects[0];
+
+        assert_spigot_fields_are_equal(inserted_project, &spigot_author, &spigot_resource);
+        assert_modrinth_fields_are_none(inserted_project);
+        assert_hangar_fields_are_none(inserted_project);
+        assert_curseforge_fields_are_equal(inserted_project, &curseforge_project);
+
+        // Act 2 - Update project
+        spigot_resource.parsed_name = Some("foo-updated".to_string());
+        spigot_resource.description = "foo-updated-description".to_string();
+        spigot_resource.date_updated = datetime!(2023-07-01 0:00 UTC);
+        upsert_spigot_resource(&context.pool, &spigot_resource).await?;
+
+        curseforge_project.name = "foo-updated".to_string();
+        curseforge_project.description = "foo-updated-description".to_string();
+        curseforge_project.date_updated = datetime!(2023-07-01 0:00 UTC);
+        upsert_curseforge_project(&context.pool, &curseforge_project).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert 2 - Verify project was updated
+        let updated_projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&updated_projects).has_length(1);
+
+        let updated_project = &updated_projects[0];
+
+        assert_spigot_fields_are_equal(updated_project, &spigot_author, &spigot_resource);
+        assert_modrinth_fields_are_none(updated_project);
+        assert_hangar_fields_are_none(updated_project);
+        assert_curseforge_fields_are_equal(updated_project, &curseforge_project);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_and_update_spigot_and_polymart() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (spigot_author, mut spigot_resource) = populate_test_spigot_author_and_resource(&context.pool).await?;
+        let mut polymart_resource = populate_test_polymart_resource(&context.pool).await?;
+
+        // Act 1 - Refresh common projects
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert 1 - Verify project in common projects
+        let inserted_projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&inserted_projects).has_length(1);
+
+        let inserted_project = &inserted_projects[0];
+
+        assert_spigot_fields_are_equal(inserted_project, &spigot_author, &spigot_resource);
+        assert_modrinth_fields_are_none(inserted_project);
+        assert_hangar_fields_are_none(inserted_project);
+        assert_curseforge_fields_are_none(inserted_project);
+        assert_polymart_fields_are_equal(inserted_project, &polymart_resource);
+
+        // Act 2 - Update project
+        spigot_resource.parsed_name = Some("foo-updated".to_string());
+        spigot_resource.description = "foo-updated-description".to_string();
+        spigot_resource.date_updated = datetime!(2023-07-01 0:00 UTC);
+        upsert_spigot_resource(&context.pool, &spigot_resource).await?;
+
+        polymart_resource.name = "foo-updated".to_string();
+        polymart_resource.description = "foo-updated-description".to_string();
+        polymart_resource.date_updated = datetime!(2023-07-01 0:00 UTC);
+        upsert_polymart_resource(&context.pool, &polymart_resource).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert 2 - Verify project was updated
+        let updated_projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&updated_projects).has_length(1);
+
+        let updated_project = &updated_projects[0];
+
+        assert_spigot_fields_are_equal(updated_project, &spigot_author, &spigot_resource);
+        assert_modrinth_fields_are_none(updated_project);
+        assert_hangar_fields_are_none(updated_project);
+        assert_curseforge_fields_are_none(updated_project);
+        assert_polymart_fields_are_equal(updated_project, &polymart_resource);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_and_update_spigot_and_builtbybit() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (spigot_author, mut spigot_resource) = populate_test_spigot_author_and_resource(&context.pool).await?;
+        let mut builtbybit_resource = populate_test_builtbybit_resource(&context.pool).await?;
+
+        // Act 1 - Refresh common projects
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert 1 - Verify project in common projects
+        let inserted_projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&inserted_projects).has_length(1);
+
+        let inserted_project = &inserted_projects[0];
+
+        assert_spigot_fields_are_equal(inserted_project, &spigot_author, &spigot_resource);
+        assert_modrinth_fields_are_none(inserted_project);
+        assert_hangar_fields_are_none(inserted_project);
+        assert_curseforge_fields_are_none(inserted_project);
+        assert_polymart_fields_are_none(inserted_project);
+        assert_builtbybit_fields_are_equal(inserted_project, &builtbybit_resource);
+
+        // Act 2 - Update project
+        spigot_resource.parsed_name = Some("foo-updated".to_string());
+        spigot_resource.description = "foo-updated-description".to_string();
+        spigot_resource.date_updated = datetime!(2023-07-01 0:00 UTC);
+        upsert_spigot_resource(&context.pool, &spigot_resource).await?;
+
+        builtbybit_resource.name = "foo-updated".to_string();
+        builtbybit_resource.description = "foo-updated-description".to_string();
+        builtbybit_resource.price = 14.99;
+        builtbybit_resource.currency = Some("USD".to_string());
+        builtbybit_resource.date_updated = datetime!(2023-07-01 0:00 UTC);
+        upsert_builtbybit_resource(&context.pool, &builtbybit_resource).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert 2 - Verify project was updated
+        let updated_projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&updated_projects).has_length(1);
+
+        let updated_project = &updated_projects[0];
+
+        assert_spigot_fields_are_equal(updated_project, &spigot_author, &spigot_resource);
+        assert_modrinth_fields_are_none(updated_project);
+        assert_hangar_fields_are_none(updated_project);
+        assert_curseforge_fields_are_none(updated_project);
+        assert_polymart_fields_are_none(updated_project);
+        assert_builtbybit_fields_are_equal(updated_project, &builtbybit_resource);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_exclude_deleted_spigot_resource_from_merged_project() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_author, _spigot_resource) = populate_test_spigot_author_and_resource(&context.pool).await?;
+        mark_unseen_spigot_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        let modrinth_project = populate_test_modrinth_project(&context.pool).await?;
+
+        // Act
+        refresh_common_projects(&context.pool).await?;
+
+        // Assert
+        let projects = get_common_projects(&context.pool).await?;
+
+        assert_that(&projects).has_length(1);
+
+        let project = &projects[0];
+
+        assert_spigot_fields_are_none(project);
+        assert_modrinth_fields_are_equal(project, &modrinth_project);
+        assert_hangar_fields_are_none(project);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     fn assert_spigot_fields_are_equal(common_project: &CommonProject, spigot_author: &SpigotAuthor, spigot_resource: &SpigotResource) {
         assert_that(&common_project.spigot).is_some();
 
@@ -566,6 +922,9 @@ mod test {
             assert_that(&spigot.name).is_equal_to(&spigot_resource.parsed_name);
             assert_that(&spigot.description).is_equal_to(&spigot_resource.description);
             assert_that(&spigot.author).is_equal_to(&spigot_author.name);
+            assert_that(&spigot.platforms).is_equal_to(&spigot_resource.platforms);
+            assert_that(&spigot.categories).is_equal_to(&spigot_resource.categories);
+            assert_that(&spigot.minecraft_versions).is_equal_to(&spigot_resource.minecraft_versions);
         }
     }
 
@@ -577,6 +936,9 @@ mod test {
             assert_that(&modrinth.name).is_equal_to(&modrinth_project.name);
             assert_that(&modrinth.description).is_equal_to(&modrinth_project.description);
             assert_that(&modrinth.author).is_equal_to(&modrinth_project.author);
+            assert_that(&modrinth.platforms).is_equal_to(&modrinth_project.platforms);
+            assert_that(&modrinth.categories).is_equal_to(&modrinth_project.categories);
+            assert_that(&modrinth.minecraft_versions).is_equal_to(&modrinth_project.minecraft_versions);
         }
     }
 
@@ -588,6 +950,53 @@ mod test {
             assert_that(&hangar.name).is_equal_to(&hangar_project.name);
             assert_that(&hangar.description).is_equal_to(&hangar_project.description);
             assert_that(&hangar.author).is_equal_to(&hangar_project.author);
+            assert_that(&hangar.paper_versions).is_equal_to(&hangar_project.paper_versions);
+            assert_that(&hangar.velocity_versions).is_equal_to(&hangar_project.velocity_versions);
+            assert_that(&hangar.waterfall_versions).is_equal_to(&hangar_project.waterfall_versions);
+            assert_that(&hangar.platforms).is_equal_to(&hangar_project.platforms);
+            assert_that(&hangar.categories).is_equal_to(&hangar_project.categories);
+            assert_that(&hangar.minecraft_versions).is_equal_to(&hangar_project.minecraft_versions);
+        }
+    }
+
+    fn assert_curseforge_fields_are_equal(common_project: &CommonProject, curseforge_project: &CurseForgeProject) {
+        assert_that(&common_project.curseforge).is_some();
+
+        if let Some(curseforge) = &common_project.curseforge {
+            assert_that(&curseforge.id).is_equal_to(curseforge_project.id);
+            assert_that(&curseforge.name).is_equal_to(&curseforge_project.name);
+            assert_that(&curseforge.description).is_equal_to(&curseforge_project.description);
+            assert_that(&curseforge.author).is_equal_to(&curseforge_project.author);
+            assert_that(&curseforge.platforms).is_equal_to(&curseforge_project.platforms);
+            assert_that(&curseforge.categories).is_equal_to(&curseforge_project.categories);
+            assert_that(&curseforge.minecraft_versions).is_equal_to(&curseforge_project.minecraft_versions);
+        }
+    }
+
+    fn assert_polymart_fields_are_equal(common_project: &CommonProject, polymart_resource: &PolymartResource) {
+        assert_that(&common_project.polymart).is_some();
+
+        if let Some(polymart) = &common_project.polymart {
+            assert_that(&polymart.id).is_equal_to(polymart_resource.id);
+            assert_that(&polymart.name).is_equal_to(&polymart_resource.name);
+            assert_that(&polymart.description).is_equal_to(&polymart_resource.description);
+            assert_that(&polymart.author).is_equal_to(&polymart_resource.author);
+            assert_that(&polymart.premium).is_equal_to(polymart_resource.premium);
+            assert_that(&polymart.platforms).is_equal_to(&polymart_resource.platforms);
+            assert_that(&polymart.minecraft_versions).is_equal_to(&polymart_resource.minecraft_versions);
+        }
+    }
+
+    fn assert_builtbybit_fields_are_equal(common_project: &CommonProject, builtbybit_resource: &BuiltByBitResource) {
+        assert_that(&common_project.builtbybit).is_some();
+
+        if let Some(builtbybit) = &common_project.builtbybit {
+            assert_that(&builtbybit.id).is_equal_to(builtbybit_resource.id);
+            assert_that(&builtbybit.name).is_equal_to(&builtbybit_resource.name);
+            assert_that(&builtbybit.description).is_equal_to(&builtbybit_resource.description);
+            assert_that(&builtbybit.author).is_equal_to(&builtbybit_resource.author);
+            assert_that(&builtbybit.price).is_equal_to(builtbybit_resource.price);
+            assert_that(&builtbybit.currency).is_equal_to(&builtbybit_resource.currency);
         }
     }
 
@@ -602,4 +1011,12 @@ mod test {
     fn assert_hangar_fields_are_none(common_project: &CommonProject) {
         assert_that(&common_project.hangar).is_none();
     }
+
+    fn assert_curseforge_fields_are_none(common_project: &CommonProject) {
+        assert_that(&common_project.curseforge).is_none();
+    }
+
+    fn assert_polymart_fields_are_none(common_project: &CommonProject) {
+        assert_that(&common_project.polymart).is_none();
+    }
 }
\ No newline at end of file
@@ -1,5 +1,11 @@
+use crate::config::get_optional_config_float;
 use crate::database::cornucopia::queries::search_result::{self, SearchResultEntity, SearchProjectsParams};
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+use crate::database::project_category::{ProjectCategory, to_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, to_cornucopia_server_platforms};
 use crate::database::source_repository::SourceRepository;
+use crate::minecraft_version::MinecraftVersion;
 
 use anyhow::Result;
 use cornucopia_async::Params;
@@ -7,16 +13,26 @@ use deadpool_postgres::Pool;
 use time::OffsetDateTime;
 use tracing::instrument;
 
+/// Projects that have been marked as deleted are always excluded from the search results, since they are left out of the common projects.
 #[derive(Clone, Debug, PartialEq)]
 pub struct SearchParams {
     pub query: String,
     pub spigot: bool,
     pub modrinth: bool,
     pub hangar: bool,
+    pub curseforge: bool,
+    pub polymart: bool,
+    pub builtbybit: bool,
     pub name: bool,
     pub description: bool,
+    pub full_description: bool,
     pub author: bool,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_version: Option<String>,
+    pub exclude_archived: bool,
     pub sort: SearchParamsSort,
+    pub weights: SearchWeights,
     pub limit: i64,
     pub offset: i64
 }
@@ -28,26 +44,66 @@ impl Default for SearchParams {
             spigot: bool::default(),
             modrinth: bool::default(),
             hangar: bool::default(),
+            curseforge: bool::default(),
+            polymart: bool::default(),
+            builtbybit: bool::default(),
             name: bool::default(),
             description: bool::default(),
+            full_description: bool::default(),
             author: bool::default(),
+            platforms: Vec::default(),
+            categories: Vec::default(),
+            minecraft_version: Option::default(),
+            exclude_archived: bool::default(),
             sort: SearchParamsSort::default(),
+            weights: SearchWeights::default(),
             limit: 25,
             offset: i64::default()
         }
     }
 }
 
-impl From<SearchParams> for SearchProjectsParams<String, String> {
+impl From<SearchParams> for SearchProjectsParams<String, String, Vec<CornucopiaServerPlatform>, Vec<CornucopiaProjectCategory>, String, Vec<String>, String, String> {
     fn from(params: SearchParams) -> Self {
+        let text_query = params.text_query();
+
+        let minecraft_version = params.minecraft_version
+            .as_deref()
+            .and_then(|x| x.parse::<MinecraftVersion>().ok());
+
+        // A version that cannot be parsed is still matched exactly as given
+        let minecraft_versions = match (&minecraft_version, params.minecraft_version) {
+            (Some(version), _) => version.spellings(),
+            (None, Some(text)) => vec![text],
+            (None, None) => vec![]
+        };
+
+        let minecraft_version_family = minecraft_version.map(|x| x.family());
+
         SearchProjectsParams {
             query: params.query,
+            text_query,
             spigot: params.spigot,
             modrinth: params.modrinth,
             hangar: params.hangar,
+            curseforge: params.curseforge,
+            polymart: params.polymart,
+            builtbybit: params.builtbybit,
             name: params.name,
             description: params.description,
+            full_description: params.full_description,
             author: params.author,
+            name_weight: params.weights.name,
+            categories_weight: params.weights.categories,
+            description_weight: params.weights.description,
+            author_weight: params.weights.author,
+            full_text_weight: params.weights.full_text,
+            trigram_weight: params.weights.trigram,
+            platforms: to_cornucopia_server_platforms(params.platforms),
+            categories: to_cornucopia_project_categories(params.categories),
+            minecraft_versions,
+            minecraft_version_family,
+            exclude_archived: params.exclude_archived,
             sort: params.sort.to_string(),
             limit: params.limit,
             offset: params.offset
@@ -55,6 +111,100 @@ impl From<SearchParams> for SearchProjectsParams<String, String> {
     }
 }
 
+impl SearchParams {
+    /// Converts the query into a full-text search query for to_tsquery(), restricted to the weights of the fields being searched.
+    /// Each word is matched as a prefix, and multiple words are also matched as a single compound word, so that "anti cheat" can find "anticheat".
+    /// Returns an empty string if there are no words to search, or if none of the weighted fields are being searched.
+    fn text_query(&self) -> String {
+        let mut labels = String::new();
+        if self.name {
+            labels.push('A');
+        }
+        if self.description {
+            // Categories are searched along with the description
+            labels.push_str("BC");
+        }
+        if self.author {
+            labels.push('D');
+        }
+
+        // Only letters and digits are kept, so that the query cannot contain any tsquery operators
+        let words: Vec<String> = self.query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|x| !x.is_empty())
+            .map(|x| x.to_lowercase())
+            .collect();
+
+        if labels.is_empty() || words.is_empty() {
+            return String::new();
+        }
+
+        let terms = words.iter()
+            .map(|x| format!("{}:*{}", x, labels))
+            .collect::<Vec<String>>()
+            .join(" & ");
+
+        if words.len() > 1 {
+            format!("({}) | {}:*{}", terms, words.concat(), labels)
+        } else {
+            terms
+        }
+    }
+}
+
+/// The weights used to rank search results by relevance.
+/// The field weights are applied to the full-text rank of each field, which is then combined with the trigram similarity of the best matching field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchWeights {
+    pub name: f32,
+    pub categories: f32,
+    pub description: f32,
+    pub author: f32,
+    pub full_text: f32,
+    pub trigram: f32
+}
+
+impl SearchWeights {
+    /// Returns the default weights, overridden by any weights that are set in the config.
+    /// Panics if a field weight is not between 0 and 1, since ts_rank() would reject it on every search.
+    pub fn from_config() -> Self {
+        let default = Self::default();
+        let get_weight = |key: &str, default: f32| get_optional_config_float(key).map_or(default, |x| x as f32);
+        let get_field_weight = |key: &str, default: f32| validate_field_weight(key, get_weight(key, default));
+
+        SearchWeights {
+            name: get_field_weight("search.weight.name", default.name),
+            categories: get_field_weight("search.weight.categories", default.categories),
+            description: get_field_weight("search.weight.description", default.description),
+            author: get_field_weight("search.weight.author", default.author),
+            full_text: get_weight("search.weight.fulltext", default.full_text),
+            trigram: get_weight("search.weight.trigram", default.trigram)
+        }
+    }
+}
+
+fn validate_field_weight(key: &str, weight: f32) -> f32 {
+    if !(0.0..=1.0).contains(&weight) {
+        panic!("config key '{}' must be between 0 and 1, but was {}", key, weight);
+    }
+
+    weight
+}
+
+// The field weights match the PostgreSQL defaults for ts_rank().
+impl Default for SearchWeights {
+    fn default() -> Self {
+        SearchWeights {
+            name: 1.0,
+            categories: 0.4,
+            description: 0.2,
+            author: 0.1,
+            full_text: 1.0,
+            trigram: 1.0
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum SearchParamsSort {
@@ -66,6 +216,8 @@ pub enum SearchParamsSort {
     Downloads,
     LikesAndStars,
     FollowsAndWatchers,
+    RepositoryActivity,
+    Trending,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -82,7 +234,12 @@ pub struct SearchResult {
     pub spigot: Option<SearchResultSpigot>,
     pub modrinth: Option<SearchResultModrinth>,
     pub hangar: Option<SearchResultHangar>,
-    pub source_repository: Option<SourceRepository>
+    pub curseforge: Option<SearchResultCurseForge>,
+    pub polymart: Option<SearchResultPolymart>,
+    pub builtbybit: Option<SearchResultBuiltByBit>,
+    pub source_repository: Option<SourceRepository>,
+    pub source_repository_release: Option<SearchResultSourceRepositoryRelease>,
+    pub source_repository_metadata: Option<SearchResultSourceRepositoryMetadata>
 }
 
 impl From<SearchResultEntity> for SearchResult {
@@ -96,7 +253,7 @@ impl From<SearchResultEntity> for SearchResult {
             version: entity.spigot_version,
             premium: entity.spigot_premium.expect("Spigot premium should not be None"),
             abandoned: entity.spigot_abandoned.expect("Spigot abandoned should not be None"),
-            icon_data: entity.spigot_icon_data
+            icon_hash: entity.spigot_icon_hash
         });
 
         let modrinth = entity.modrinth_id.clone().map(|_| SearchResultModrinth {
@@ -107,7 +264,8 @@ impl From<SearchResultEntity> for SearchResult {
             author: entity.modrinth_author.expect("Modrinth author should not be None"),
             version: entity.modrinth_version,
             status: entity.modrinth_status.expect("Modrinth status should not be None"),
-            icon_url: entity.modrinth_icon_url
+            icon_url: entity.modrinth_icon_url,
+            icon_hash: entity.modrinth_icon_hash
         });
 
         let hangar = entity.hangar_slug.clone().map(|_| SearchResultHangar {
@@ -116,7 +274,43 @@ impl From<SearchResultEntity> for SearchResult {
             description: entity.hangar_description.expect("Hangar description should not be None"),
             author: entity.hangar_author.expect("Hangar author should not be None"),
             version: entity.hangar_version,
-            icon_url: entity.hangar_icon_url.expect("Hangar icon url should not be None")
+            icon_url: entity.hangar_icon_url.expect("Hangar icon url should not be None"),
+            icon_hash: entity.hangar_icon_hash,
+            paper_versions: entity.hangar_paper_versions.unwrap_or_default(),
+            velocity_versions: entity.hangar_velocity_versions.unwrap_or_default(),
+            waterfall_versions: entity.hangar_waterfall_versions.unwrap_or_default()
+        });
+
+        let curseforge = entity.curseforge_id.map(|_| SearchResultCurseForge {
+            id: entity.curseforge_id.expect("CurseForge id should not be None"),
+            slug: entity.curseforge_slug.expect("CurseForge slug should not be None"),
+            name: entity.curseforge_name.expect("CurseForge name should not be None"),
+            description: entity.curseforge_description.expect("CurseForge description should not be None"),
+            author: entity.curseforge_author.expect("CurseForge author should not be None"),
+            version: entity.curseforge_version,
+            icon_url: entity.curseforge_icon_url,
+            icon_hash: entity.curseforge_icon_hash
+        });
+
+        let polymart = entity.polymart_id.map(|_| SearchResultPolymart {
+            id: entity.polymart_id.expect("Polymart id should not be None"),
+            name: entity.polymart_name.expect("Polymart name should not be None"),
+            description: entity.polymart_description.expect("Polymart description should not be None"),
+            author: entity.polymart_author.expect("Polymart author should not be None"),
+            version: entity.polymart_version,
+            premium: entity.polymart_premium.expect("Polymart premium should not be None"),
+            icon_url: entity.polymart_icon_url,
+            icon_hash: entity.polymart_icon_hash
+        });
+
+        let builtbybit = entity.builtbybit_id.map(|_| SearchResultBuiltByBit {
+            id: entity.builtbybit_id.expect("BuiltByBit id should not be None"),
+            name: entity.builtbybit_name.expect("BuiltByBit name should not be None"),
+            description: entity.builtbybit_description.expect("BuiltByBit description should not be None"),
+            author: entity.builtbybit_author.expect("BuiltByBit author should not be None"),
+            version: entity.builtbybit_version,
+            price: entity.builtbybit_price.expect("BuiltByBit price should not be None"),
+            currency: entity.builtbybit_currency
         });
 
         let mut source_repository = None;
@@ -132,6 +326,21 @@ impl From<SearchResultEntity> for SearchResult {
             })
         }
 
+        let source_repository_release = entity.release_tag_name.clone().map(|_| SearchResultSourceRepositoryRelease {
+            tag_name: entity.release_tag_name.expect("Release tag name should not be None"),
+            name: entity.release_name,
+            url: entity.release_url,
+            date_published: entity.release_date_published.expect("Release date published should not be None")
+        });
+
+        let source_repository_metadata = entity.repository_stars.map(|_| SearchResultSourceRepositoryMetadata {
+            stars: entity.repository_stars.expect("Repository stars should not be None"),
+            archived: entity.repository_archived.expect("Repository archived should not be None"),
+            license: entity.repository_license,
+            open_issues: entity.repository_open_issues,
+            date_last_commit: entity.repository_date_last_commit
+        });
+
         SearchResult {
             full_count: entity.full_count,
 
@@ -145,7 +354,12 @@ impl From<SearchResultEntity> for SearchResult {
             spigot,
             modrinth,
             hangar,
-            source_repository
+            curseforge,
+            polymart,
+            builtbybit,
+            source_repository,
+            source_repository_release,
+            source_repository_metadata
         }
     }
 }
@@ -160,7 +374,7 @@ pub struct SearchResultSpigot {
     pub version: Option<String>,
     pub premium: bool,
     pub abandoned: bool,
-    pub icon_data: Option<String>
+    pub icon_hash: Option<String>
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -173,6 +387,7 @@ pub struct SearchResultModrinth {
     pub version: Option<String>,
     pub status: String,
     pub icon_url: Option<String>,
+    pub icon_hash: Option<String>
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -182,7 +397,72 @@ pub struct SearchResultHangar {
     pub description: String,
     pub author: String,
     pub version: Option<String>,
-    pub icon_url: String
+    pub icon_url: String,
+    pub icon_hash: Option<String>,
+    pub paper_versions: Vec<String>,
+    pub velocity_versions: Vec<String>,
+    pub waterfall_versions: Vec<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResultCurseForge {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResultPolymart {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub premium: bool,
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResultBuiltByBit {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub price: f64,
+    pub currency: Option<String>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResultSourceRepositoryRelease {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub date_published: OffsetDateTime
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResultSourceRepositoryMetadata {
+    pub stars: i32,
+    pub archived: bool,
+    pub license: Option<String>,
+    pub open_issues: Option<i32>,
+    pub date_last_commit: Option<OffsetDateTime>
+}
+
+impl SearchResult {
+    /// Returns true if the source repository has a release that was published after every upload to the included repositories.
+    pub fn has_newer_source_repository_release(&self) -> bool {
+        self.source_repository_release
+            .as_ref()
+            .is_some_and(|x| x.date_published > self.date_updated)
+    }
 }
 
 #[instrument(
@@ -208,21 +488,39 @@ mod test {
     use super::*;
 
     use crate::database::spigot::author::SpigotAuthor;
-    use crate::database::spigot::resource::SpigotResource;
+    use crate::database::spigot::resource::{SpigotResource, upsert_spigot_resource};
     use crate::database::spigot::resource::test::populate_test_spigot_authors_and_resources;
 
     use crate::database::modrinth::project::ModrinthProject;
     use crate::database::modrinth::project::test::populate_test_modrinth_projects;
 
-    use crate::database::hangar::project::HangarProject;
+    use crate::database::hangar::project::{HangarProject, upsert_hangar_project};
     use crate::database::hangar::project::test::populate_test_hangar_projects;
 
+    use crate::database::curseforge::project::CurseForgeProject;
+    use crate::database::curseforge::project::test::populate_test_curseforge_projects;
+    use crate::database::polymart::resource::PolymartResource;
+    use crate::database::polymart::resource::test::populate_test_polymart_resources;
+
+    use crate::database::builtbybit::resource::BuiltByBitResource;
+    use crate::database::builtbybit::resource::test::populate_test_builtbybit_resources;
+
+    use crate::database::source_repository_metadata::test::populate_test_source_repository_metadata;
+    use crate::database::source_repository_release::test::populate_test_source_repository_releases;
+
+    use crate::database::project_stats_snapshot::insert_project_stats_snapshots;
+
+    use crate::database::icon::{ProjectIcon, insert_icon, upsert_project_icon};
+    use crate::database::icon::test::create_test_icon;
+    use crate::database::ingest_log::IngestLogRepository;
+
     use crate::database::common::project::refresh_common_projects;
 
     use crate::database::test::DatabaseTestContext;
 
     use ::function_name::named;
     use speculoos::prelude::*;
+    use time::{Duration, OffsetDateTime};
     use time::macros::datetime;
 
     #[tokio::test]
@@ -417,6 +715,213 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_search_curseforge_projects() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, _spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+        let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+        let curseforge_projects = populate_test_curseforge_projects(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Search by name
+        let params = SearchParams {
+            query: "foo".to_string(),
+            curseforge: true,
+            name: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1 - Verify search by name
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_curseforge_project(&search_results[0], &curseforge_projects[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(curseforge_projects[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(curseforge_projects[0].likes);
+        assert_curseforge_fields_are_equal(&search_results[0].curseforge, &curseforge_projects[0]);
+
+        // Act 2 - Search by description
+        let params = SearchParams {
+            query: "foo".to_string(),
+            curseforge: true,
+            description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2 - Verify search by description
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_curseforge_project(&search_results[0], &curseforge_projects[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(curseforge_projects[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(curseforge_projects[0].likes);
+        assert_curseforge_fields_are_equal(&search_results[0].curseforge, &curseforge_projects[0]);
+
+        // Act 3 - Search by author
+        let params = SearchParams {
+            query: "alice".to_string(),
+            curseforge: true,
+            author: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3 - Verify search by author
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_curseforge_project(&search_results[0], &curseforge_projects[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(curseforge_projects[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(curseforge_projects[0].likes);
+        assert_curseforge_fields_are_equal(&search_results[0].curseforge, &curseforge_projects[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_search_polymart_resources() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, _spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+        let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+        let _curseforge_projects = populate_test_curseforge_projects(&context.pool).await?;
+        let polymart_resources = populate_test_polymart_resources(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Search by name
+        let params = SearchParams {
+            query: "foo".to_string(),
+            polymart: true,
+            name: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1 - Verify search by name
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_polymart_resource(&search_results[0], &polymart_resources[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(polymart_resources[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(0);
+        assert_polymart_fields_are_equal(&search_results[0].polymart, &polymart_resources[0]);
+
+        // Act 2 - Search by description
+        let params = SearchParams {
+            query: "foo".to_string(),
+            polymart: true,
+            description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2 - Verify search by description
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_polymart_resource(&search_results[0], &polymart_resources[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(polymart_resources[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(0);
+        assert_polymart_fields_are_equal(&search_results[0].polymart, &polymart_resources[0]);
+
+        // Act 3 - Search by author
+        let params = SearchParams {
+            query: "alice".to_string(),
+            polymart: true,
+            author: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3 - Verify search by author
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_polymart_resource(&search_results[0], &polymart_resources[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(polymart_resources[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(0);
+        assert_polymart_fields_are_equal(&search_results[0].polymart, &polymart_resources[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_search_builtbybit_resources() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, _spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+        let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+        let _curseforge_projects = populate_test_curseforge_projects(&context.pool).await?;
+        let _polymart_resources = populate_test_polymart_resources(&context.pool).await?;
+        let builtbybit_resources = populate_test_builtbybit_resources(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Search by name
+        let params = SearchParams {
+            query: "foo".to_string(),
+            builtbybit: true,
+            name: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1 - Verify search by name
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_builtbybit_resource(&search_results[0], &builtbybit_resources[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(builtbybit_resources[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(0);
+        assert_builtbybit_fields_are_equal(&search_results[0].builtbybit, &builtbybit_resources[0]);
+
+        // Act 2 - Search by description
+        let params = SearchParams {
+            query: "foo".to_string(),
+            builtbybit: true,
+            description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2 - Verify search by description
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_builtbybit_resource(&search_results[0], &builtbybit_resources[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(builtbybit_resources[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(0);
+        assert_builtbybit_fields_are_equal(&search_results[0].builtbybit, &builtbybit_resources[0]);
+
+        // Act 3 - Search by author
+        let params = SearchParams {
+            query: "alice".to_string(),
+            builtbybit: true,
+            author: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3 - Verify search by author
+        assert_that(&search_results).has_length(1);
+        assert_dates_are_equal_to_builtbybit_resource(&search_results[0], &builtbybit_resources[0]);
+        assert_that(&search_results[0].downloads).is_equal_to(builtbybit_resources[0].downloads);
+        assert_that(&search_results[0].likes_and_stars).is_equal_to(0);
+        assert_builtbybit_fields_are_equal(&search_results[0].builtbybit, &builtbybit_resources[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[named]
     async fn should_search_spigot_resources_and_modrinth_projects() -> Result<()> {
@@ -705,35 +1210,254 @@ mod test {
 
     #[tokio::test]
     #[named]
-    async fn should_return_search_results_in_correct_order() -> Result<()> {
+    async fn should_filter_search_results_by_platforms() -> Result<()> {
         // Setup
         let context = DatabaseTestContext::new(function_name!()).await;
 
         // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
         let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
 
         refresh_common_projects(&context.pool).await?;
 
-        // Act 1 - Sort by date_created order
+        // Act 1 - Filter by a platform supported on only one project
         let params = SearchParams {
+            spigot: true,
+            modrinth: true,
             hangar: true,
-            name: true,
-            sort: SearchParamsSort::DateCreated,
+            platforms: vec![ServerPlatform::Folia],
             ..Default::default()
         };
         let search_results = search_projects(&context.pool, &params).await?;
 
-        // Assert 1 - Verify results are in date_created order
-        assert_that(&search_results).has_length(3);
-        assert_that(&search_results[0].date_created).is_equal_to(datetime!(2022-01-03 0:00 UTC));
-        assert_that(&search_results[1].date_created).is_equal_to(datetime!(2022-01-02 0:00 UTC));
-        assert_that(&search_results[2].date_created).is_equal_to(datetime!(2022-01-01 0:00 UTC));
+        // Assert 1
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].hangar.as_ref().map(|h| h.slug.as_str())).is_equal_to(Some("foo"));
 
-        // Act 2 - Sort by date_updated order
+        // Act 2 - Only platforms from the selected repositories are considered
         let params = SearchParams {
-            hangar: true,
-            name: true,
-            sort: SearchParamsSort::DateUpdated,
+            spigot: true,
+            platforms: vec![ServerPlatform::Folia],
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2
+        assert_that(&search_results).is_empty();
+
+        // Act 3 - Filter by multiple platforms, where any one of them may match
+        let params = SearchParams {
+            spigot: true,
+            modrinth: true,
+            hangar: true,
+            platforms: vec![ServerPlatform::Velocity, ServerPlatform::Waterfall],
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3
+        assert_that(&search_results).has_length(3);
+
+        // Act 4 - No platforms given
+        let params = SearchParams {
+            spigot: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 4
+        assert_that(&search_results).has_length(3);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_filter_search_results_by_categories() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+        let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+        let _polymart_resources = populate_test_polymart_resources(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Filter by a category shared across all repositories
+        let params = SearchParams {
+            spigot: true,
+            modrinth: true,
+            hangar: true,
+            categories: vec![ProjectCategory::Economy],
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].spigot.as_ref().map(|s| s.slug.as_str())).is_equal_to(Some("baz.3"));
+
+        // Act 2 - Only categories from the selected repositories are considered
+        let params = SearchParams {
+            spigot: true,
+            modrinth: true,
+            categories: vec![ProjectCategory::Protection],
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2
+        assert_that(&search_results).is_empty();
+
+        // Act 3 - Filter by multiple categories, where any one of them may match
+        let params = SearchParams {
+            spigot: true,
+            modrinth: true,
+            hangar: true,
+            categories: vec![ProjectCategory::Library, ProjectCategory::Protection],
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].hangar.as_ref().map(|h| h.slug.as_str())).is_equal_to(Some("bar"));
+
+        // Act 4 - Filter Polymart resources by category
+        let params = SearchParams {
+            polymart: true,
+            categories: vec![ProjectCategory::Chat],
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 4
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].polymart.as_ref().map(|p| p.name.as_str())).is_equal_to(Some("foo-polymart"));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_filter_search_results_by_minecraft_version() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+        let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Filter by a minor version across all repositories
+        let params = SearchParams {
+            spigot: true,
+            modrinth: true,
+            hangar: true,
+            minecraft_version: Some("1.20.4".to_string()),
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].modrinth.as_ref().map(|m| m.slug.as_str())).is_equal_to(Some("foo"));
+
+        // Act 2 - Spigot major versions cover all of their minor versions
+        let params = SearchParams {
+            spigot: true,
+            minecraft_version: Some("1.16.5".to_string()),
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].spigot.as_ref().map(|s| s.slug.as_str())).is_equal_to(Some("baz.3"));
+
+        // Act 3 - Modrinth and Hangar versions must match, not just their family
+        let params = SearchParams {
+            modrinth: true,
+            hangar: true,
+            minecraft_version: Some("1.21.1".to_string()),
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3
+        assert_that(&search_results).is_empty();
+
+        // Act 4 - Only versions from the selected repositories are considered
+        let params = SearchParams {
+            hangar: true,
+            minecraft_version: Some("1.20.4".to_string()),
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 4
+        assert_that(&search_results).is_empty();
+
+        // Act 5 - Versions match regardless of how a zero patch version is written
+        let params = SearchParams {
+            modrinth: true,
+            minecraft_version: Some("1.21.0".to_string()),
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 5
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].modrinth.as_ref().map(|m| m.slug.as_str())).is_equal_to(Some("foo"));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_return_search_results_in_correct_order() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Sort by date_created order
+        let params = SearchParams {
+            hangar: true,
+            name: true,
+            sort: SearchParamsSort::DateCreated,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1 - Verify results are in date_created order
+        assert_that(&search_results).has_length(3);
+        assert_that(&search_results[0].date_created).is_equal_to(datetime!(2022-01-03 0:00 UTC));
+        assert_that(&search_results[1].date_created).is_equal_to(datetime!(2022-01-02 0:00 UTC));
+        assert_that(&search_results[2].date_created).is_equal_to(datetime!(2022-01-01 0:00 UTC));
+
+        // Act 2 - Sort by date_updated order
+        let params = SearchParams {
+            hangar: true,
+            name: true,
+            sort: SearchParamsSort::DateUpdated,
             ..Default::default()
         };
         let search_results = search_projects(&context.pool, &params).await?;
@@ -810,6 +1534,456 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_sort_search_results_by_minecraft_version_stage() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+
+        for (project, version) in hangar_projects.into_iter().zip(["1.21.9", "24w46a", "1.21.10"]) {
+            let project = HangarProject {
+                latest_minecraft_version: Some(version.to_string()),
+                ..project
+            };
+            upsert_hangar_project(&context.pool, &project).await?;
+        }
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act
+        let params = SearchParams {
+            hangar: true,
+            name: true,
+            sort: SearchParamsSort::LatestMinecraftVersion,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert
+        assert_that(&search_results).has_length(3);
+        assert_that(&search_results[0].latest_minecraft_version).is_some().is_equal_to("1.21.10".to_string());
+        assert_that(&search_results[1].latest_minecraft_version).is_some().is_equal_to("1.21.9".to_string());
+        assert_that(&search_results[2].latest_minecraft_version).is_some().is_equal_to("24w46a".to_string());
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_include_source_repository_releases() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let releases = populate_test_source_repository_releases(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act
+        let params = SearchParams {
+            spigot: true,
+            sort: SearchParamsSort::DateUpdated,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert
+        assert_that(&search_results).has_length(3);
+
+        // foo has a release that is newer than its latest Spigot update
+        let foo_release = search_results[0].source_repository_release.as_ref();
+        assert_that(&foo_release.map(|x| x.tag_name.as_str())).is_equal_to(Some(releases[0].tag_name.as_str()));
+        assert_that(&foo_release.map(|x| x.date_published)).is_equal_to(Some(releases[0].date_published));
+        assert_that(&search_results[0].has_newer_source_repository_release()).is_true();
+
+        // bar has a release that is older than its latest Spigot update
+        let bar_release = search_results[1].source_repository_release.as_ref();
+        assert_that(&bar_release.map(|x| x.tag_name.as_str())).is_equal_to(Some(releases[1].tag_name.as_str()));
+        assert_that(&search_results[1].has_newer_source_repository_release()).is_false();
+
+        // baz has no release
+        assert_that(&search_results[2].source_repository_release).is_none();
+        assert_that(&search_results[2].has_newer_source_repository_release()).is_false();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_include_project_icon_hashes() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        insert_icon(&context.pool, &create_test_icon("aaaa")).await?;
+        upsert_project_icon(&context.pool, &ProjectIcon {
+            repository: IngestLogRepository::Spigot,
+            project_id: spigot_resources[0].id.to_string(),
+            source_url: spigot_resources[0].icon_url.clone().unwrap(),
+            hash: "aaaa".to_string()
+        }).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act
+        let params = SearchParams {
+            spigot: true,
+            sort: SearchParamsSort::DateUpdated,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert
+        assert_that(&search_results).has_length(3);
+
+        // foo has a cached icon
+        assert_that(&search_results[0].spigot.as_ref().and_then(|x| x.icon_hash.clone())).is_equal_to(Some("aaaa".to_string()));
+
+        // bar and baz have not had their icons cached yet
+        assert_that(&search_results[1].spigot.as_ref().and_then(|x| x.icon_hash.clone())).is_none();
+        assert_that(&search_results[2].spigot.as_ref().and_then(|x| x.icon_hash.clone())).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_search_full_descriptions() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        let foo = SpigotResource {
+            full_description: Some("<p>Protects your <strong>claims</strong> from griefers</p>".to_string()),
+            ..spigot_resources[0].clone()
+        };
+        upsert_spigot_resource(&context.pool, &foo).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Full descriptions are not searched by default
+        let params = SearchParams {
+            query: "griefers".to_string(),
+            spigot: true,
+            name: true,
+            description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1
+        assert_that(&search_results).is_empty();
+
+        // Act 2 - Search by full description
+        let params = SearchParams {
+            query: "griefers".to_string(),
+            spigot: true,
+            full_description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2
+        assert_that(&search_results).has_length(1);
+        assert_that(&search_results[0].spigot.as_ref().map(|x| x.id)).is_equal_to(Some(foo.id));
+
+        // Act 3 - HTML tags are not searched
+        let params = SearchParams {
+            query: "strong".to_string(),
+            spigot: true,
+            full_description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 3
+        assert_that(&search_results).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_search_compound_and_stemmed_words() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        let foo = SpigotResource {
+            parsed_name: Some("AntiCheat".to_string()),
+            ..spigot_resources[0].clone()
+        };
+        upsert_spigot_resource(&context.pool, &foo).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        for query in ["anticheat", "anti cheat", "cheating"] {
+            // Act
+            let params = SearchParams {
+                query: query.to_string(),
+                spigot: true,
+                name: true,
+                ..Default::default()
+            };
+            let search_results = search_projects(&context.pool, &params).await?;
+
+            // Assert
+            assert_that(&search_results).named(query).has_length(1);
+            assert_that(&search_results[0].spigot.as_ref().map(|x| x.id)).named(query).is_equal_to(Some(foo.id));
+        }
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_rank_search_results_by_weights() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        let foo = SpigotResource {
+            parsed_name: Some("Teleport".to_string()),
+            ..spigot_resources[0].clone()
+        };
+        upsert_spigot_resource(&context.pool, &foo).await?;
+
+        let bar = SpigotResource {
+            description: "Teleport to your warps".to_string(),
+            ..spigot_resources[1].clone()
+        };
+        upsert_spigot_resource(&context.pool, &bar).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Names are ranked above descriptions by default
+        let params = SearchParams {
+            query: "teleport".to_string(),
+            spigot: true,
+            name: true,
+            description: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1
+        let ids: Vec<Option<i32>> = search_results.iter().map(|x| x.spigot.as_ref().map(|x| x.id)).collect();
+        assert_that(&ids).is_equal_to(vec![Some(foo.id), Some(bar.id)]);
+
+        // Act 2 - Rank descriptions above names using only the full-text rank
+        let params = SearchParams {
+            weights: SearchWeights {
+                name: 0.1,
+                description: 1.0,
+                trigram: 0.0,
+                ..Default::default()
+            },
+            ..params
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2
+        let ids: Vec<Option<i32>> = search_results.iter().map(|x| x.spigot.as_ref().map(|x| x.id)).collect();
+        assert_that(&ids).is_equal_to(vec![Some(bar.id), Some(foo.id)]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_query_to_text_query() {
+        // Arrange
+        let params = SearchParams {
+            query: "Anti-Cheat & (foo".to_string(),
+            name: true,
+            author: true,
+            ..Default::default()
+        };
+
+        // Act
+        let text_query = params.text_query();
+
+        // Assert
+        assert_that(&text_query).is_equal_to("(anti:*AD & cheat:*AD & foo:*AD) | anticheatfoo:*AD".to_string());
+    }
+
+    #[test]
+    fn should_not_convert_query_without_weighted_fields_to_text_query() {
+        // Arrange
+        let params = SearchParams {
+            query: "foo".to_string(),
+            full_description: true,
+            ..Default::default()
+        };
+
+        // Act
+        let text_query = params.text_query();
+
+        // Assert
+        assert_that(&text_query).is_empty();
+    }
+
+    #[test]
+    fn should_accept_field_weight_between_zero_and_one() {
+        // Act
+        let weight = validate_field_weight("search.weight.name", 0.5);
+
+        // Assert
+        assert_that(&weight).is_equal_to(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "config key 'search.weight.name' must be between 0 and 1, but was 1.5")]
+    fn should_reject_field_weight_above_one() {
+        // Act
+        validate_field_weight("search.weight.name", 1.5);
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_exclude_archived_source_repositories() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let _metadata = populate_test_source_repository_metadata(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act 1 - Archived repositories are included by default
+        let params = SearchParams {
+            spigot: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 1
+        assert_that(&search_results).has_length(3);
+
+        // Act 2 - Exclude archived repositories
+        let params = SearchParams {
+            spigot: true,
+            exclude_archived: true,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert 2
+        assert_that(&search_results).has_length(2);
+        assert_that(&search_results.iter().all(|x| x.source_repository_metadata.as_ref().is_some_and(|m| !m.archived))).is_true();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_sort_search_results_by_repository_activity() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let metadata = populate_test_source_repository_metadata(&context.pool).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act
+        let params = SearchParams {
+            spigot: true,
+            sort: SearchParamsSort::RepositoryActivity,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert
+        let slugs: Vec<&str> = search_results.iter().map(|x| x.spigot.as_ref().unwrap().slug.as_str()).collect();
+        assert_that(&slugs).is_equal_to(vec!["baz.3", "foo.1", "bar.2"]);
+
+        let foo_metadata = search_results[1].source_repository_metadata.as_ref();
+        assert_that(&foo_metadata.map(|x| x.stars)).is_equal_to(Some(metadata[0].stars));
+        assert_that(&foo_metadata.and_then(|x| x.license.clone())).is_equal_to(metadata[0].license.clone());
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_sort_search_results_by_trending_score() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        insert_project_stats_snapshots(&context.pool, OffsetDateTime::now_utc().date() - Duration::days(3)).await?;
+
+        // Since the snapshot, foo gained 50 downloads and baz gained 10 likes.
+        let foo = SpigotResource {
+            downloads: spigot_resources[0].downloads + 50,
+            ..spigot_resources[0].clone()
+        };
+        let baz = SpigotResource {
+            likes: spigot_resources[2].likes + 10,
+            ..spigot_resources[2].clone()
+        };
+        upsert_spigot_resource(&context.pool, &foo).await?;
+        upsert_spigot_resource(&context.pool, &baz).await?;
+
+        refresh_common_projects(&context.pool).await?;
+
+        // Act
+        let params = SearchParams {
+            spigot: true,
+            sort: SearchParamsSort::Trending,
+            ..Default::default()
+        };
+        let search_results = search_projects(&context.pool, &params).await?;
+
+        // Assert
+        let slugs: Vec<&str> = search_results.iter().map(|x| x.spigot.as_ref().unwrap().slug.as_str()).collect();
+        assert_that(&slugs).is_equal_to(vec!["baz.3", "foo.1", "bar.2"]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     fn assert_dates_are_equal_to_spigot_resource(search_result: &SearchResult, spigot_resource: &SpigotResource) {
         assert_that(&search_result.date_created).is_equal_to(spigot_resource.date_created);
         assert_that(&search_result.date_updated).is_equal_to(spigot_resource.date_updated);
@@ -825,6 +1999,21 @@ mod test {
         assert_that(&search_result.date_updated).is_equal_to(hangar_project.date_updated);
     }
 
+    fn assert_dates_are_equal_to_curseforge_project(search_result: &SearchResult, curseforge_project: &CurseForgeProject) {
+        assert_that(&search_result.date_created).is_equal_to(curseforge_project.date_created);
+        assert_that(&search_result.date_updated).is_equal_to(curseforge_project.date_updated);
+    }
+
+    fn assert_dates_are_equal_to_polymart_resource(search_result: &SearchResult, polymart_resource: &PolymartResource) {
+        assert_that(&search_result.date_created).is_equal_to(polymart_resource.date_created);
+        assert_that(&search_result.date_updated).is_equal_to(polymart_resource.date_updated);
+    }
+
+    fn assert_dates_are_equal_to_builtbybit_resource(search_result: &SearchResult, builtbybit_resource: &BuiltByBitResource) {
+        assert_that(&search_result.date_created).is_equal_to(builtbybit_resource.date_created);
+        assert_that(&search_result.date_updated).is_equal_to(builtbybit_resource.date_updated);
+    }
+
     fn assert_stats_are_equal_to_projects(search_result: &SearchResult, spigot_resource: Option<&SpigotResource>, modrinth_project: Option<&ModrinthProject>, hangar_project: Option<&HangarProject>) {
         let mut expected_downloads = 0;
         let mut expected_likes_and_stars = 0;
@@ -862,7 +2051,6 @@ mod test {
         assert_that(&s.author).is_equal_to(&spigot_author.name);
         assert_that(&s.version).is_equal_to(&spigot_resource.version_name);
         assert_that(&s.premium).is_equal_to(spigot_resource.premium);
-        assert_that(&s.icon_data).is_equal_to(&spigot_resource.icon_data);
     }
 
     fn assert_modrinth_fields_are_equal(modrinth: &Option<SearchResultModrinth>, modrinth_project: &ModrinthProject) {
@@ -888,5 +2076,47 @@ mod test {
         assert_that(&h.author).is_equal_to(&hangar_project.author);
         assert_that(&h.version).is_equal_to(&hangar_project.version_name);
         assert_that(&h.icon_url).is_equal_to(&hangar_project.icon_url);
+        assert_that(&h.paper_versions).is_equal_to(&hangar_project.paper_versions);
+        assert_that(&h.velocity_versions).is_equal_to(&hangar_project.velocity_versions);
+        assert_that(&h.waterfall_versions).is_equal_to(&hangar_project.waterfall_versions);
+    }
+
+    fn assert_curseforge_fields_are_equal(curseforge: &Option<SearchResultCurseForge>, curseforge_project: &CurseForgeProject) {
+        assert_that(curseforge).is_some();
+
+        let c = curseforge.as_ref().unwrap();
+        assert_that(&c.id).is_equal_to(curseforge_project.id);
+        assert_that(&c.slug).is_equal_to(&curseforge_project.slug);
+        assert_that(&c.name).is_equal_to(&curseforge_project.name);
+        assert_that(&c.description).is_equal_to(&curseforge_project.description);
+        assert_that(&c.author).is_equal_to(&curseforge_project.author);
+        assert_that(&c.version).is_equal_to(&curseforge_project.version_name);
+        assert_that(&c.icon_url).is_equal_to(&curseforge_project.icon_url);
+    }
+
+    fn assert_polymart_fields_are_equal(polymart: &Option<SearchResultPolymart>, polymart_resource: &PolymartResource) {
+        assert_that(polymart).is_some();
+
+        let p = polymart.as_ref().unwrap();
+        assert_that(&p.id).is_equal_to(polymart_resource.id);
+        assert_that(&p.name).is_equal_to(&polymart_resource.name);
+        assert_that(&p.description).is_equal_to(&polymart_resource.description);
+        assert_that(&p.author).is_equal_to(&polymart_resource.author);
+        assert_that(&p.version).is_equal_to(&polymart_resource.version_name);
+        assert_that(&p.premium).is_equal_to(polymart_resource.premium);
+        assert_that(&p.icon_url).is_equal_to(&polymart_resource.icon_url);
+    }
+
+    fn assert_builtbybit_fields_are_equal(builtbybit: &Option<SearchResultBuiltByBit>, builtbybit_resource: &BuiltByBitResource) {
+        assert_that(builtbybit).is_some();
+
+        let b = builtbybit.as_ref().unwrap();
+        assert_that(&b.id).is_equal_to(builtbybit_resource.id);
+        assert_that(&b.name).is_equal_to(&builtbybit_resource.name);
+        assert_that(&b.description).is_equal_to(&builtbybit_resource.description);
+        assert_that(&b.author).is_equal_to(&builtbybit_resource.author);
+        assert_that(&b.version).is_equal_to(&builtbybit_resource.version_name);
+        assert_that(&b.price).is_equal_to(builtbybit_resource.price);
+        assert_that(&b.currency).is_equal_to(&builtbybit_resource.currency);
     }
 }
\ No newline at end of file
@@ -2,25 +2,25 @@
 
 #[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod types { pub mod public { #[derive( Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)] pub enum IngestLogAction { Populate,Update,Refresh,}impl<'a> postgres_types::ToSql for IngestLogAction
+#[allow(non_camel_case_types)] pub enum ServerPlatform { Bukkit,Spigot,Paper,Folia,Purpur,Velocity,BungeeCord,Waterfall,}impl<'a> postgres_types::ToSql for ServerPlatform
 {
     fn
     to_sql(&self, ty: &postgres_types::Type, buf: &mut
     postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
     Box<dyn std::error::Error + Sync + Send>,>
     {
-        let s = match *self { IngestLogAction::Populate => "Populate",IngestLogAction::Update => "Update",IngestLogAction::Refresh => "Refresh",};
+        let s = match *self { ServerPlatform::Bukkit => "Bukkit",ServerPlatform::Spigot => "Spigot",ServerPlatform::Paper => "Paper",ServerPlatform::Folia => "Folia",ServerPlatform::Purpur => "Purpur",ServerPlatform::Velocity => "Velocity",ServerPlatform::BungeeCord => "BungeeCord",ServerPlatform::Waterfall => "Waterfall",};
         buf.extend_from_slice(s.as_bytes());
         std::result::Result::Ok(postgres_types::IsNull::No)
     } fn accepts(ty: &postgres_types::Type) -> bool
     {
-        if ty.name() != "ingest_log_action" { return false; } match *ty.kind()
+        if ty.name() != "server_platform" { return false; } match *ty.kind()
         {
             postgres_types::Kind::Enum(ref variants) =>
             {
-                if variants.len() != 3 { return false; }
+                if variants.len() != 8 { return false; }
                 variants.iter().all(|v| match &**v
-                { "Populate" => true,"Update" => true,"Refresh" => true,_ => false, })
+                { "Bukkit" => true,"Spigot" => true,"Paper" => true,"Folia" => true,"Purpur" => true,"Velocity" => true,"BungeeCord" => true,"Waterfall" => true,_ => false, })
             } _ => false,
         }
     } fn
@@ -28,37 +28,86 @@
     postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
     Box<dyn std::error::Error + Sync + Send>>
     { postgres_types::__to_sql_checked(self, ty, out) }
-} impl<'a> postgres_types::FromSql<'a> for IngestLogAction
+} impl<'a> postgres_types::FromSql<'a> for ServerPlatform
 {
     fn from_sql(ty: &postgres_types::Type, buf: &'a [u8],) ->
-    Result<IngestLogAction, Box<dyn std::error::Error + Sync + Send>,>
+    Result<ServerPlatform, Box<dyn std::error::Error + Sync + Send>,>
     {
         match std::str::from_utf8(buf)?
         {
-            "Populate" => Ok(IngestLogAction::Populate),"Update" => Ok(IngestLogAction::Update),"Refresh" => Ok(IngestLogAction::Refresh),s =>
+            "Bukkit" => Ok(ServerPlatform::Bukkit),"Spigot" => Ok(ServerPlatform::Spigot),"Paper" => Ok(ServerPlatform::Paper),"Folia" => Ok(ServerPlatform::Folia),"Purpur" => Ok(ServerPlatform::Purpur),"Velocity" => Ok(ServerPlatform::Velocity),"BungeeCord" => Ok(ServerPlatform::BungeeCord),"Waterfall" => Ok(ServerPlatform::Waterfall),s =>
             Result::Err(Into::into(format!("invalid variant `{}`", s))),
         }
     } fn accepts(ty: &postgres_types::Type) -> bool
     {
-        if ty.name() != "ingest_log_action" { return false; } match *ty.kind()
+        if ty.name() != "server_platform" { return false; } match *ty.kind()
         {
             postgres_types::Kind::Enum(ref variants) =>
             {
-                if variants.len() != 3 { return false; }
+                if variants.len() != 8 { return false; }
                 variants.iter().all(|v| match &**v
-                { "Populate" => true,"Update" => true,"Refresh" => true,_ => false, })
+                { "Bukkit" => true,"Spigot" => true,"Paper" => true,"Folia" => true,"Purpur" => true,"Velocity" => true,"BungeeCord" => true,"Waterfall" => true,_ => false, })
+            } _ => false,
+        }
+    }
+}#[derive( Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)] pub enum ProjectCategory { AdminTools,Chat,Economy,Gameplay,Library,Minigames,Miscellaneous,Optimization,Protection,RolePlaying,Transportation,Utility,WorldManagement,}impl<'a> postgres_types::ToSql for ProjectCategory
+{
+    fn
+    to_sql(&self, ty: &postgres_types::Type, buf: &mut
+    postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
+    Box<dyn std::error::Error + Sync + Send>,>
+    {
+        let s = match *self { ProjectCategory::AdminTools => "AdminTools",ProjectCategory::Chat => "Chat",ProjectCategory::Economy => "Economy",ProjectCategory::Gameplay => "Gameplay",ProjectCategory::Library => "Library",ProjectCategory::Minigames => "Minigames",ProjectCategory::Miscellaneous => "Miscellaneous",ProjectCategory::Optimization => "Optimization",ProjectCategory::Protection => "Protection",ProjectCategory::RolePlaying => "RolePlaying",ProjectCategory::Transportation => "Transportation",ProjectCategory::Utility => "Utility",ProjectCategory::WorldManagement => "WorldManagement",};
+        buf.extend_from_slice(s.as_bytes());
+        std::result::Result::Ok(postgres_types::IsNull::No)
+    } fn accepts(ty: &postgres_types::Type) -> bool
+    {
+        if ty.name() != "project_category" { return false; } match *ty.kind()
+        {
+            postgres_types::Kind::Enum(ref variants) =>
+            {
+                if variants.len() != 13 { return false; }
+                variants.iter().all(|v| match &**v
+                { "AdminTools" => true,"Chat" => true,"Economy" => true,"Gameplay" => true,"Library" => true,"Minigames" => true,"Miscellaneous" => true,"Optimization" => true,"Protection" => true,"RolePlaying" => true,"Transportation" => true,"Utility" => true,"WorldManagement" => true,_ => false, })
+            } _ => false,
+        }
+    } fn
+    to_sql_checked(&self, ty: &postgres_types::Type, out: &mut
+    postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
+    Box<dyn std::error::Error + Sync + Send>>
+    { postgres_types::__to_sql_checked(self, ty, out) }
+} impl<'a> postgres_types::FromSql<'a> for ProjectCategory
+{
+    fn from_sql(ty: &postgres_types::Type, buf: &'a [u8],) ->
+    Result<ProjectCategory, Box<dyn std::error::Error + Sync + Send>,>
+    {
+        match std::str::from_utf8(buf)?
+        {
+            "AdminTools" => Ok(ProjectCategory::AdminTools),"Chat" => Ok(ProjectCategory::Chat),"Economy" => Ok(ProjectCategory::Economy),"Gameplay" => Ok(ProjectCategory::Gameplay),"Library" => Ok(ProjectCategory::Library),"Minigames" => Ok(ProjectCategory::Minigames),"Miscellaneous" => Ok(ProjectCategory::Miscellaneous),"Optimization" => Ok(ProjectCategory::Optimization),"Protection" => Ok(ProjectCategory::Protection),"RolePlaying" => Ok(ProjectCategory::RolePlaying),"Transportation" => Ok(ProjectCategory::Transportation),"Utility" => Ok(ProjectCategory::Utility),"WorldManagement" => Ok(ProjectCategory::WorldManagement),s =>
+            Result::Err(Into::into(format!("invalid variant `{}`", s))),
+        }
+    } fn accepts(ty: &postgres_types::Type) -> bool
+    {
+        if ty.name() != "project_category" { return false; } match *ty.kind()
+        {
+            postgres_types::Kind::Enum(ref variants) =>
+            {
+                if variants.len() != 13 { return false; }
+                variants.iter().all(|v| match &**v
+                { "AdminTools" => true,"Chat" => true,"Economy" => true,"Gameplay" => true,"Library" => true,"Minigames" => true,"Miscellaneous" => true,"Optimization" => true,"Protection" => true,"RolePlaying" => true,"Transportation" => true,"Utility" => true,"WorldManagement" => true,_ => false, })
             } _ => false,
         }
     }
 }#[derive( Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)] pub enum IngestLogRepository { Spigot,Modrinth,Hangar,Common,}impl<'a> postgres_types::ToSql for IngestLogRepository
+#[allow(non_camel_case_types)] pub enum IngestLogRepository { Spigot,Modrinth,Hangar,CurseForge,Polymart,BuiltByBit,SourceRepository,Common,}impl<'a> postgres_types::ToSql for IngestLogRepository
 {
     fn
     to_sql(&self, ty: &postgres_types::Type, buf: &mut
     postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
     Box<dyn std::error::Error + Sync + Send>,>
     {
-        let s = match *self { IngestLogRepository::Spigot => "Spigot",IngestLogRepository::Modrinth => "Modrinth",IngestLogRepository::Hangar => "Hangar",IngestLogRepository::Common => "Common",};
+        let s = match *self { IngestLogRepository::Spigot => "Spigot",IngestLogRepository::Modrinth => "Modrinth",IngestLogRepository::Hangar => "Hangar",IngestLogRepository::CurseForge => "CurseForge",IngestLogRepository::Polymart => "Polymart",IngestLogRepository::BuiltByBit => "BuiltByBit",IngestLogRepository::SourceRepository => "SourceRepository",IngestLogRepository::Common => "Common",};
         buf.extend_from_slice(s.as_bytes());
         std::result::Result::Ok(postgres_types::IsNull::No)
     } fn accepts(ty: &postgres_types::Type) -> bool
@@ -67,9 +116,9 @@
         {
             postgres_types::Kind::Enum(ref variants) =>
             {
-                if variants.len() != 4 { return false; }
+                if variants.len() != 8 { return false; }
                 variants.iter().all(|v| match &**v
-                { "Spigot" => true,"Modrinth" => true,"Hangar" => true,"Common" => true,_ => false, })
+                { "Spigot" => true,"Modrinth" => true,"Hangar" => true,"CurseForge" => true,"Polymart" => true,"BuiltByBit" => true,"SourceRepository" => true,"Common" => true,_ => false, })
             } _ => false,
         }
     } fn
@@ -84,7 +133,7 @@
     {
         match std::str::from_utf8(buf)?
         {
-            "Spigot" => Ok(IngestLogRepository::Spigot),"Modrinth" => Ok(IngestLogRepository::Modrinth),"Hangar" => Ok(IngestLogRepository::Hangar),"Common" => Ok(IngestLogRepository::Common),s =>
+            "Spigot" => Ok(IngestLogRepository::Spigot),"Modrinth" => Ok(IngestLogRepository::Modrinth),"Hangar" => Ok(IngestLogRepository::Hangar),"CurseForge" => Ok(IngestLogRepository::CurseForge),"Polymart" => Ok(IngestLogRepository::Polymart),"BuiltByBit" => Ok(IngestLogRepository::BuiltByBit),"SourceRepository" => Ok(IngestLogRepository::SourceRepository),"Common" => Ok(IngestLogRepository::Common),s =>
             Result::Err(Into::into(format!("invalid variant `{}`", s))),
         }
     } fn accepts(ty: &postgres_types::Type) -> bool
@@ -93,21 +142,21 @@
         {
             postgres_types::Kind::Enum(ref variants) =>
             {
-                if variants.len() != 4 { return false; }
+                if variants.len() != 8 { return false; }
                 variants.iter().all(|v| match &**v
-                { "Spigot" => true,"Modrinth" => true,"Hangar" => true,"Common" => true,_ => false, })
+                { "Spigot" => true,"Modrinth" => true,"Hangar" => true,"CurseForge" => true,"Polymart" => true,"BuiltByBit" => true,"SourceRepository" => true,"Common" => true,_ => false, })
             } _ => false,
         }
     }
 }#[derive( Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)] pub enum IngestLogItem { Author,Resource,Project,Version,}impl<'a> postgres_types::ToSql for IngestLogItem
+#[allow(non_camel_case_types)] pub enum IngestLogItem { Author,Resource,Project,Version,Release,Metadata,}impl<'a> postgres_types::ToSql for IngestLogItem
 {
     fn
     to_sql(&self, ty: &postgres_types::Type, buf: &mut
     postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
     Box<dyn std::error::Error + Sync + Send>,>
     {
-        let s = match *self { IngestLogItem::Author => "Author",IngestLogItem::Resource => "Resource",IngestLogItem::Project => "Project",IngestLogItem::Version => "Version",};
+        let s = match *self { IngestLogItem::Author => "Author",IngestLogItem::Resource => "Resource",IngestLogItem::Project => "Project",IngestLogItem::Version => "Version",IngestLogItem::Release => "Release",IngestLogItem::Metadata => "Metadata",};
         buf.extend_from_slice(s.as_bytes());
         std::result::Result::Ok(postgres_types::IsNull::No)
     } fn accepts(ty: &postgres_types::Type) -> bool
@@ -116,9 +165,9 @@
         {
             postgres_types::Kind::Enum(ref variants) =>
             {
-                if variants.len() != 4 { return false; }
+                if variants.len() != 6 { return false; }
                 variants.iter().all(|v| match &**v
-                { "Author" => true,"Resource" => true,"Project" => true,"Version" => true,_ => false, })
+                { "Author" => true,"Resource" => true,"Project" => true,"Version" => true,"Release" => true,"Metadata" => true,_ => false, })
             } _ => false,
         }
     } fn
@@ -133,7 +182,7 @@
     {
         match std::str::from_utf8(buf)?
         {
-            "Author" => Ok(IngestLogItem::Author),"Resource" => Ok(IngestLogItem::Resource),"Project" => Ok(IngestLogItem::Project),"Version" => Ok(IngestLogItem::Version),s =>
+            "Author" => Ok(IngestLogItem::Author),"Resource" => Ok(IngestLogItem::Resource),"Project" => Ok(IngestLogItem::Project),"Version" => Ok(IngestLogItem::Version),"Release" => Ok(IngestLogItem::Release),"Metadata" => Ok(IngestLogItem::Metadata),s =>
             Result::Err(Into::into(format!("invalid variant `{}`", s))),
         }
     } fn accepts(ty: &postgres_types::Type) -> bool
@@ -142,15 +191,251 @@
         {
             postgres_types::Kind::Enum(ref variants) =>
             {
-                if variants.len() != 4 { return false; }
+                if variants.len() != 6 { return false; }
+                variants.iter().all(|v| match &**v
+                { "Author" => true,"Resource" => true,"Project" => true,"Version" => true,"Release" => true,"Metadata" => true,_ => false, })
+            } _ => false,
+        }
+    }
+}#[derive( Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)] pub enum IngestLogAction { Populate,Update,Refresh,}impl<'a> postgres_types::ToSql for IngestLogAction
+{
+    fn
+    to_sql(&self, ty: &postgres_types::Type, buf: &mut
+    postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
+    Box<dyn std::error::Error + Sync + Send>,>
+    {
+        let s = match *self { IngestLogAction::Populate => "Populate",IngestLogAction::Update => "Update",IngestLogAction::Refresh => "Refresh",};
+        buf.extend_from_slice(s.as_bytes());
+        std::result::Result::Ok(postgres_types::IsNull::No)
+    } fn accepts(ty: &postgres_types::Type) -> bool
+    {
+        if ty.name() != "ingest_log_action" { return false; } match *ty.kind()
+        {
+            postgres_types::Kind::Enum(ref variants) =>
+            {
+                if variants.len() != 3 { return false; }
+                variants.iter().all(|v| match &**v
+                { "Populate" => true,"Update" => true,"Refresh" => true,_ => false, })
+            } _ => false,
+        }
+    } fn
+    to_sql_checked(&self, ty: &postgres_types::Type, out: &mut
+    postgres_types::private::BytesMut,) -> Result<postgres_types::IsNull,
+    Box<dyn std::error::Error + Sync + Send>>
+    { postgres_types::__to_sql_checked(self, ty, out) }
+} impl<'a> postgres_types::FromSql<'a> for IngestLogAction
+{
+    fn from_sql(ty: &postgres_types::Type, buf: &'a [u8],) ->
+    Result<IngestLogAction, Box<dyn std::error::Error + Sync + Send>,>
+    {
+        match std::str::from_utf8(buf)?
+        {
+            "Populate" => Ok(IngestLogAction::Populate),"Update" => Ok(IngestLogAction::Update),"Refresh" => Ok(IngestLogAction::Refresh),s =>
+            Result::Err(Into::into(format!("invalid variant `{}`", s))),
+        }
+    } fn accepts(ty: &postgres_types::Type) -> bool
+    {
+        if ty.name() != "ingest_log_action" { return false; } match *ty.kind()
+        {
+            postgres_types::Kind::Enum(ref variants) =>
+            {
+                if variants.len() != 3 { return false; }
                 variants.iter().all(|v| match &**v
-                { "Author" => true,"Resource" => true,"Project" => true,"Version" => true,_ => false, })
+                { "Populate" => true,"Update" => true,"Refresh" => true,_ => false, })
             } _ => false,
         }
     }
 } }}#[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod queries
-{ pub mod common_project
+{ pub mod builtbybit_resource
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertBuiltbybitResourceParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,> { pub id: i32,pub name: T1,pub description: T2,pub author: T3,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub downloads: i32,pub price: f64,pub currency: Option<T4>,pub version_name: Option<T5>,pub source_url: Option<T6>,pub source_repository_host: Option<T7>,pub source_repository_owner: Option<T8>,pub source_repository_name: Option<T9>,}#[derive( Debug, Clone, PartialEq,)] pub struct BuiltbybitResourceEntity
+{ pub id : i32,pub name : String,pub description : String,pub author : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub downloads : i32,pub price : f64,pub currency : Option<String>,pub version_name : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,}pub struct BuiltbybitResourceEntityBorrowed<'a> { pub id : i32,pub name : &'a str,pub description : &'a str,pub author : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub downloads : i32,pub price : f64,pub currency : Option<&'a str>,pub version_name : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,}
+impl<'a> From<BuiltbybitResourceEntityBorrowed<'a>> for BuiltbybitResourceEntity
+{
+    fn from(BuiltbybitResourceEntityBorrowed { id,name,description,author,date_created,date_updated,downloads,price,currency,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,date_last_seen,date_deleted,}: BuiltbybitResourceEntityBorrowed<'a>) -> Self
+    { Self { id,name: name.into(),description: description.into(),author: author.into(),date_created,date_updated,downloads,price,currency: currency.map(|v| v.into()),version_name: version_name.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),date_last_seen,date_deleted,} }
+}pub struct BuiltbybitResourceEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> BuiltbybitResourceEntityBorrowed,
+    mapper: fn(BuiltbybitResourceEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> BuiltbybitResourceEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(BuiltbybitResourceEntityBorrowed) -> R) ->
+    BuiltbybitResourceEntityQuery<'a,C,R,N>
+    {
+        BuiltbybitResourceEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct TimeOffsetDateTimeQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> time::OffsetDateTime,
+    mapper: fn(time::OffsetDateTime) -> T,
+} impl<'a, C, T:'a, const N: usize> TimeOffsetDateTimeQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(time::OffsetDateTime) -> R) ->
+    TimeOffsetDateTimeQuery<'a,C,R,N>
+    {
+        TimeOffsetDateTimeQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_builtbybit_resource() -> UpsertBuiltbybitResourceStmt
+{ UpsertBuiltbybitResourceStmt(cornucopia_async::private::Stmt::new("INSERT INTO builtbybit_resource (id, name, description, author, date_created, date_updated, downloads, price, currency, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, date_last_seen)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW())
+  ON CONFLICT (id)
+  DO UPDATE SET
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    downloads = EXCLUDED.downloads,
+    price = EXCLUDED.price,
+    currency = EXCLUDED.currency,
+    version_name = EXCLUDED.version_name,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL")) } pub struct
+UpsertBuiltbybitResourceStmt(cornucopia_async::private::Stmt); impl UpsertBuiltbybitResourceStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a i32,name: &'a T1,description: &'a T2,author: &'a T3,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,downloads: &'a i32,price: &'a f64,currency: &'a Option<T4>,version_name: &'a Option<T5>,source_url: &'a Option<T6>,source_repository_host: &'a Option<T7>,source_repository_owner: &'a Option<T8>,source_repository_name: &'a Option<T9>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,name,description,author,date_created,date_updated,downloads,price,currency,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertBuiltbybitResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertBuiltbybitResourceStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertBuiltbybitResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.name,&params.description,&params.author,&params.date_created,&params.date_updated,&params.downloads,&params.price,&params.currency,&params.version_name,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,)) }
+}pub fn get_builtbybit_resources() -> GetBuiltbybitResourcesStmt
+{ GetBuiltbybitResourcesStmt(cornucopia_async::private::Stmt::new("SELECT * FROM builtbybit_resource
+WHERE date_deleted IS NULL")) } pub struct
+GetBuiltbybitResourcesStmt(cornucopia_async::private::Stmt); impl GetBuiltbybitResourcesStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> BuiltbybitResourceEntityQuery<'a,C, BuiltbybitResourceEntity,
+0>
+{
+    BuiltbybitResourceEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { BuiltbybitResourceEntityBorrowed { id: row.get(0),name: row.get(1),description: row.get(2),author: row.get(3),date_created: row.get(4),date_updated: row.get(5),downloads: row.get(6),price: row.get(7),currency: row.get(8),version_name: row.get(9),source_url: row.get(10),source_repository_host: row.get(11),source_repository_owner: row.get(12),source_repository_name: row.get(13),source_repository_id: row.get(14),date_last_seen: row.get(15),date_deleted: row.get(16),} }, mapper: |it| { <BuiltbybitResourceEntity>::from(it) },
+    }
+} }pub fn get_builtbybit_resource_by_id() -> GetBuiltbybitResourceByIdStmt
+{ GetBuiltbybitResourceByIdStmt(cornucopia_async::private::Stmt::new("SELECT * FROM builtbybit_resource
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetBuiltbybitResourceByIdStmt(cornucopia_async::private::Stmt); impl GetBuiltbybitResourceByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+id: &'a i32,) -> BuiltbybitResourceEntityQuery<'a,C, BuiltbybitResourceEntity,
+1>
+{
+    BuiltbybitResourceEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { BuiltbybitResourceEntityBorrowed { id: row.get(0),name: row.get(1),description: row.get(2),author: row.get(3),date_created: row.get(4),date_updated: row.get(5),downloads: row.get(6),price: row.get(7),currency: row.get(8),version_name: row.get(9),source_url: row.get(10),source_repository_host: row.get(11),source_repository_owner: row.get(12),source_repository_name: row.get(13),source_repository_id: row.get(14),date_last_seen: row.get(15),date_deleted: row.get(16),} }, mapper: |it| { <BuiltbybitResourceEntity>::from(it) },
+    }
+} }pub fn get_latest_builtbybit_resource_update_date() -> GetLatestBuiltbybitResourceUpdateDateStmt
+{ GetLatestBuiltbybitResourceUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM builtbybit_resource")) } pub struct
+GetLatestBuiltbybitResourceUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestBuiltbybitResourceUpdateDateStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
+{
+    TimeOffsetDateTimeQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }pub fn mark_unseen_builtbybit_resources_as_deleted() -> MarkUnseenBuiltbybitResourcesAsDeletedStmt
+{ MarkUnseenBuiltbybitResourcesAsDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE builtbybit_resource
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < $1)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'BuiltByBit' AND item = 'Resource' AND upstream_id = builtbybit_resource.id::text
+  )")) } pub struct
+MarkUnseenBuiltbybitResourcesAsDeletedStmt(cornucopia_async::private::Stmt); impl MarkUnseenBuiltbybitResourcesAsDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date_started: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date_started,]).await
+} }}pub mod common_project
 { use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct I64Query<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
@@ -191,11 +476,11 @@ GenericClient
         Ok(it)
     }
 }#[derive( Debug, Clone, PartialEq,)] pub struct CommonProjectEntity
-{ pub spigot_id : Option<i32>,pub spigot_slug : Option<String>,pub spigot_name : Option<String>,pub spigot_description : Option<String>,pub spigot_author : Option<String>,pub spigot_version : Option<String>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_data : Option<String>,pub spigot_date_created : Option<time::OffsetDateTime>,pub spigot_date_updated : Option<time::OffsetDateTime>,pub spigot_latest_minecraft_version : Option<String>,pub spigot_downloads : Option<i32>,pub spigot_likes : Option<i32>,pub modrinth_id : Option<String>,pub modrinth_slug : Option<String>,pub modrinth_name : Option<String>,pub modrinth_description : Option<String>,pub modrinth_author : Option<String>,pub modrinth_version : Option<String>,pub modrinth_status : Option<String>,pub modrinth_icon_url : Option<String>,pub modrinth_date_created : Option<time::OffsetDateTime>,pub modrinth_date_updated : Option<time::OffsetDateTime>,pub modrinth_latest_minecraft_version : Option<String>,pub modrinth_downloads : Option<i32>,pub modrinth_follows : Option<i32>,pub hangar_slug : Option<String>,pub hangar_name : Option<String>,pub hangar_description : Option<String>,pub hangar_author : Option<String>,pub hangar_version : Option<String>,pub hangar_icon_url : Option<String>,pub hangar_date_created : Option<time::OffsetDateTime>,pub hangar_date_updated : Option<time::OffsetDateTime>,pub hangar_latest_minecraft_version : Option<String>,pub hangar_downloads : Option<i32>,pub hangar_stars : Option<i32>,pub hangar_watchers : Option<i32>,pub source_repository_host : Option<String>,pub source_repository_name : Option<String>,pub source_repository_owner : Option<String>,}pub struct CommonProjectEntityBorrowed<'a> { pub spigot_id : Option<i32>,pub spigot_slug : Option<&'a str>,pub spigot_name : Option<&'a str>,pub spigot_description : Option<&'a str>,pub spigot_author : Option<&'a str>,pub spigot_version : Option<&'a str>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_data : Option<&'a str>,pub spigot_date_created : Option<time::OffsetDateTime>,pub spigot_date_updated : Option<time::OffsetDateTime>,pub spigot_latest_minecraft_version : Option<&'a str>,pub spigot_downloads : Option<i32>,pub spigot_likes : Option<i32>,pub modrinth_id : Option<&'a str>,pub modrinth_slug : Option<&'a str>,pub modrinth_name : Option<&'a str>,pub modrinth_description : Option<&'a str>,pub modrinth_author : Option<&'a str>,pub modrinth_version : Option<&'a str>,pub modrinth_status : Option<&'a str>,pub modrinth_icon_url : Option<&'a str>,pub modrinth_date_created : Option<time::OffsetDateTime>,pub modrinth_date_updated : Option<time::OffsetDateTime>,pub modrinth_latest_minecraft_version : Option<&'a str>,pub modrinth_downloads : Option<i32>,pub modrinth_follows : Option<i32>,pub hangar_slug : Option<&'a str>,pub hangar_name : Option<&'a str>,pub hangar_description : Option<&'a str>,pub hangar_author : Option<&'a str>,pub hangar_version : Option<&'a str>,pub hangar_icon_url : Option<&'a str>,pub hangar_date_created : Option<time::OffsetDateTime>,pub hangar_date_updated : Option<time::OffsetDateTime>,pub hangar_latest_minecraft_version : Option<&'a str>,pub hangar_downloads : Option<i32>,pub hangar_stars : Option<i32>,pub hangar_watchers : Option<i32>,pub source_repository_host : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_owner : Option<&'a str>,}
+{ pub spigot_id : Option<i32>,pub spigot_slug : Option<String>,pub spigot_name : Option<String>,pub spigot_description : Option<String>,pub spigot_author : Option<String>,pub spigot_version : Option<String>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_hash : Option<String>,pub spigot_date_created : Option<time::OffsetDateTime>,pub spigot_date_updated : Option<time::OffsetDateTime>,pub spigot_latest_minecraft_version : Option<String>,pub spigot_downloads : Option<i32>,pub spigot_likes : Option<i32>,pub spigot_platforms : Option<Vec<super::super::types::public::ServerPlatform>>,pub spigot_categories : Option<Vec<super::super::types::public::ProjectCategory>>,pub spigot_minecraft_versions : Option<Vec<String>>,pub modrinth_id : Option<String>,pub modrinth_slug : Option<String>,pub modrinth_name : Option<String>,pub modrinth_description : Option<String>,pub modrinth_author : Option<String>,pub modrinth_version : Option<String>,pub modrinth_status : Option<String>,pub modrinth_icon_url : Option<String>,pub modrinth_icon_hash : Option<String>,pub modrinth_date_created : Option<time::OffsetDateTime>,pub modrinth_date_updated : Option<time::OffsetDateTime>,pub modrinth_latest_minecraft_version : Option<String>,pub modrinth_downloads : Option<i32>,pub modrinth_follows : Option<i32>,pub modrinth_platforms : Option<Vec<super::super::types::public::ServerPlatform>>,pub modrinth_categories : Option<Vec<super::super::types::public::ProjectCategory>>,pub modrinth_minecraft_versions : Option<Vec<String>>,pub hangar_slug : Option<String>,pub hangar_name : Option<String>,pub hangar_description : Option<String>,pub hangar_author : Option<String>,pub hangar_version : Option<String>,pub hangar_icon_url : Option<String>,pub hangar_icon_hash : Option<String>,pub hangar_date_created : Option<time::OffsetDateTime>,pub hangar_date_updated : Option<time::OffsetDateTime>,pub hangar_latest_minecraft_version : Option<String>,pub hangar_downloads : Option<i32>,pub hangar_stars : Option<i32>,pub hangar_watchers : Option<i32>,pub hangar_paper_versions : Option<Vec<String>>,pub hangar_velocity_versions : Option<Vec<String>>,pub hangar_waterfall_versions : Option<Vec<String>>,pub hangar_platforms : Option<Vec<super::super::types::public::ServerPlatform>>,pub hangar_categories : Option<Vec<super::super::types::public::ProjectCategory>>,pub hangar_minecraft_versions : Option<Vec<String>>,pub curseforge_id : Option<i32>,pub curseforge_slug : Option<String>,pub curseforge_name : Option<String>,pub curseforge_description : Option<String>,pub curseforge_author : Option<String>,pub curseforge_version : Option<String>,pub curseforge_icon_url : Option<String>,pub curseforge_icon_hash : Option<String>,pub curseforge_date_created : Option<time::OffsetDateTime>,pub curseforge_date_updated : Option<time::OffsetDateTime>,pub curseforge_latest_minecraft_version : Option<String>,pub curseforge_downloads : Option<i32>,pub curseforge_likes : Option<i32>,pub curseforge_platforms : Option<Vec<super::super::types::public::ServerPlatform>>,pub curseforge_categories : Option<Vec<super::super::types::public::ProjectCategory>>,pub curseforge_minecraft_versions : Option<Vec<String>>,pub polymart_id : Option<i32>,pub polymart_name : Option<String>,pub polymart_description : Option<String>,pub polymart_author : Option<String>,pub polymart_version : Option<String>,pub polymart_premium : Option<bool>,pub polymart_icon_url : Option<String>,pub polymart_icon_hash : Option<String>,pub polymart_date_created : Option<time::OffsetDateTime>,pub polymart_date_updated : Option<time::OffsetDateTime>,pub polymart_latest_minecraft_version : Option<String>,pub polymart_downloads : Option<i32>,pub polymart_platforms : Option<Vec<super::super::types::public::ServerPlatform>>,pub polymart_categories : Option<Vec<super::super::types::public::ProjectCategory>>,pub polymart_minecraft_versions : Option<Vec<String>>,pub builtbybit_id : Option<i32>,pub builtbybit_name : Option<String>,pub builtbybit_description : Option<String>,pub builtbybit_author : Option<String>,pub builtbybit_version : Option<String>,pub builtbybit_price : Option<f64>,pub builtbybit_currency : Option<String>,pub builtbybit_date_created : Option<time::OffsetDateTime>,pub builtbybit_date_updated : Option<time::OffsetDateTime>,pub builtbybit_downloads : Option<i32>,pub source_repository_host : Option<String>,pub source_repository_name : Option<String>,pub source_repository_owner : Option<String>,}pub struct CommonProjectEntityBorrowed<'a> { pub spigot_id : Option<i32>,pub spigot_slug : Option<&'a str>,pub spigot_name : Option<&'a str>,pub spigot_description : Option<&'a str>,pub spigot_author : Option<&'a str>,pub spigot_version : Option<&'a str>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_hash : Option<&'a str>,pub spigot_date_created : Option<time::OffsetDateTime>,pub spigot_date_updated : Option<time::OffsetDateTime>,pub spigot_latest_minecraft_version : Option<&'a str>,pub spigot_downloads : Option<i32>,pub spigot_likes : Option<i32>,pub spigot_platforms : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>>,pub spigot_categories : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>>,pub spigot_minecraft_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub modrinth_id : Option<&'a str>,pub modrinth_slug : Option<&'a str>,pub modrinth_name : Option<&'a str>,pub modrinth_description : Option<&'a str>,pub modrinth_author : Option<&'a str>,pub modrinth_version : Option<&'a str>,pub modrinth_status : Option<&'a str>,pub modrinth_icon_url : Option<&'a str>,pub modrinth_icon_hash : Option<&'a str>,pub modrinth_date_created : Option<time::OffsetDateTime>,pub modrinth_date_updated : Option<time::OffsetDateTime>,pub modrinth_latest_minecraft_version : Option<&'a str>,pub modrinth_downloads : Option<i32>,pub modrinth_follows : Option<i32>,pub modrinth_platforms : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>>,pub modrinth_categories : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>>,pub modrinth_minecraft_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub hangar_slug : Option<&'a str>,pub hangar_name : Option<&'a str>,pub hangar_description : Option<&'a str>,pub hangar_author : Option<&'a str>,pub hangar_version : Option<&'a str>,pub hangar_icon_url : Option<&'a str>,pub hangar_icon_hash : Option<&'a str>,pub hangar_date_created : Option<time::OffsetDateTime>,pub hangar_date_updated : Option<time::OffsetDateTime>,pub hangar_latest_minecraft_version : Option<&'a str>,pub hangar_downloads : Option<i32>,pub hangar_stars : Option<i32>,pub hangar_watchers : Option<i32>,pub hangar_paper_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub hangar_velocity_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub hangar_waterfall_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub hangar_platforms : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>>,pub hangar_categories : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>>,pub hangar_minecraft_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub curseforge_id : Option<i32>,pub curseforge_slug : Option<&'a str>,pub curseforge_name : Option<&'a str>,pub curseforge_description : Option<&'a str>,pub curseforge_author : Option<&'a str>,pub curseforge_version : Option<&'a str>,pub curseforge_icon_url : Option<&'a str>,pub curseforge_icon_hash : Option<&'a str>,pub curseforge_date_created : Option<time::OffsetDateTime>,pub curseforge_date_updated : Option<time::OffsetDateTime>,pub curseforge_latest_minecraft_version : Option<&'a str>,pub curseforge_downloads : Option<i32>,pub curseforge_likes : Option<i32>,pub curseforge_platforms : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>>,pub curseforge_categories : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>>,pub curseforge_minecraft_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub polymart_id : Option<i32>,pub polymart_name : Option<&'a str>,pub polymart_description : Option<&'a str>,pub polymart_author : Option<&'a str>,pub polymart_version : Option<&'a str>,pub polymart_premium : Option<bool>,pub polymart_icon_url : Option<&'a str>,pub polymart_icon_hash : Option<&'a str>,pub polymart_date_created : Option<time::OffsetDateTime>,pub polymart_date_updated : Option<time::OffsetDateTime>,pub polymart_latest_minecraft_version : Option<&'a str>,pub polymart_downloads : Option<i32>,pub polymart_platforms : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>>,pub polymart_categories : Option<cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>>,pub polymart_minecraft_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub builtbybit_id : Option<i32>,pub builtbybit_name : Option<&'a str>,pub builtbybit_description : Option<&'a str>,pub builtbybit_author : Option<&'a str>,pub builtbybit_version : Option<&'a str>,pub builtbybit_price : Option<f64>,pub builtbybit_currency : Option<&'a str>,pub builtbybit_date_created : Option<time::OffsetDateTime>,pub builtbybit_date_updated : Option<time::OffsetDateTime>,pub builtbybit_downloads : Option<i32>,pub source_repository_host : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_owner : Option<&'a str>,}
 impl<'a> From<CommonProjectEntityBorrowed<'a>> for CommonProjectEntity
 {
-    fn from(CommonProjectEntityBorrowed { spigot_id,spigot_slug,spigot_name,spigot_description,spigot_author,spigot_version,spigot_premium,spigot_abandoned,spigot_icon_data,spigot_date_created,spigot_date_updated,spigot_latest_minecraft_version,spigot_downloads,spigot_likes,modrinth_id,modrinth_slug,modrinth_name,modrinth_description,modrinth_author,modrinth_version,modrinth_status,modrinth_icon_url,modrinth_date_created,modrinth_date_updated,modrinth_latest_minecraft_version,modrinth_downloads,modrinth_follows,hangar_slug,hangar_name,hangar_description,hangar_author,hangar_version,hangar_icon_url,hangar_date_created,hangar_date_updated,hangar_latest_minecraft_version,hangar_downloads,hangar_stars,hangar_watchers,source_repository_host,source_repository_name,source_repository_owner,}: CommonProjectEntityBorrowed<'a>) ->
-    Self { Self { spigot_id,spigot_slug: spigot_slug.map(|v| v.into()),spigot_name: spigot_name.map(|v| v.into()),spigot_description: spigot_description.map(|v| v.into()),spigot_author: spigot_author.map(|v| v.into()),spigot_version: spigot_version.map(|v| v.into()),spigot_premium,spigot_abandoned,spigot_icon_data: spigot_icon_data.map(|v| v.into()),spigot_date_created,spigot_date_updated,spigot_latest_minecraft_version: spigot_latest_minecraft_version.map(|v| v.into()),spigot_downloads,spigot_likes,modrinth_id: modrinth_id.map(|v| v.into()),modrinth_slug: modrinth_slug.map(|v| v.into()),modrinth_name: modrinth_name.map(|v| v.into()),modrinth_description: modrinth_description.map(|v| v.into()),modrinth_author: modrinth_author.map(|v| v.into()),modrinth_version: modrinth_version.map(|v| v.into()),modrinth_status: modrinth_status.map(|v| v.into()),modrinth_icon_url: modrinth_icon_url.map(|v| v.into()),modrinth_date_created,modrinth_date_updated,modrinth_latest_minecraft_version: modrinth_latest_minecraft_version.map(|v| v.into()),modrinth_downloads,modrinth_follows,hangar_slug: hangar_slug.map(|v| v.into()),hangar_name: hangar_name.map(|v| v.into()),hangar_description: hangar_description.map(|v| v.into()),hangar_author: hangar_author.map(|v| v.into()),hangar_version: hangar_version.map(|v| v.into()),hangar_icon_url: hangar_icon_url.map(|v| v.into()),hangar_date_created,hangar_date_updated,hangar_latest_minecraft_version: hangar_latest_minecraft_version.map(|v| v.into()),hangar_downloads,hangar_stars,hangar_watchers,source_repository_host: source_repository_host.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),} }
+    fn from(CommonProjectEntityBorrowed { spigot_id,spigot_slug,spigot_name,spigot_description,spigot_author,spigot_version,spigot_premium,spigot_abandoned,spigot_icon_hash,spigot_date_created,spigot_date_updated,spigot_latest_minecraft_version,spigot_downloads,spigot_likes,spigot_platforms,spigot_categories,spigot_minecraft_versions,modrinth_id,modrinth_slug,modrinth_name,modrinth_description,modrinth_author,modrinth_version,modrinth_status,modrinth_icon_url,modrinth_icon_hash,modrinth_date_created,modrinth_date_updated,modrinth_latest_minecraft_version,modrinth_downloads,modrinth_follows,modrinth_platforms,modrinth_categories,modrinth_minecraft_versions,hangar_slug,hangar_name,hangar_description,hangar_author,hangar_version,hangar_icon_url,hangar_icon_hash,hangar_date_created,hangar_date_updated,hangar_latest_minecraft_version,hangar_downloads,hangar_stars,hangar_watchers,hangar_paper_versions,hangar_velocity_versions,hangar_waterfall_versions,hangar_platforms,hangar_categories,hangar_minecraft_versions,curseforge_id,curseforge_slug,curseforge_name,curseforge_description,curseforge_author,curseforge_version,curseforge_icon_url,curseforge_icon_hash,curseforge_date_created,curseforge_date_updated,curseforge_latest_minecraft_version,curseforge_downloads,curseforge_likes,curseforge_platforms,curseforge_categories,curseforge_minecraft_versions,polymart_id,polymart_name,polymart_description,polymart_author,polymart_version,polymart_premium,polymart_icon_url,polymart_icon_hash,polymart_date_created,polymart_date_updated,polymart_latest_minecraft_version,polymart_downloads,polymart_platforms,polymart_categories,polymart_minecraft_versions,builtbybit_id,builtbybit_name,builtbybit_description,builtbybit_author,builtbybit_version,builtbybit_price,builtbybit_currency,builtbybit_date_created,builtbybit_date_updated,builtbybit_downloads,source_repository_host,source_repository_name,source_repository_owner,}: CommonProjectEntityBorrowed<'a>) -> Self
+    { Self { spigot_id,spigot_slug: spigot_slug.map(|v| v.into()),spigot_name: spigot_name.map(|v| v.into()),spigot_description: spigot_description.map(|v| v.into()),spigot_author: spigot_author.map(|v| v.into()),spigot_version: spigot_version.map(|v| v.into()),spigot_premium,spigot_abandoned,spigot_icon_hash: spigot_icon_hash.map(|v| v.into()),spigot_date_created,spigot_date_updated,spigot_latest_minecraft_version: spigot_latest_minecraft_version.map(|v| v.into()),spigot_downloads,spigot_likes,spigot_platforms: spigot_platforms.map(|v| v.map(|v| v).collect()),spigot_categories: spigot_categories.map(|v| v.map(|v| v).collect()),spigot_minecraft_versions: spigot_minecraft_versions.map(|v| v.map(|v| v.into()).collect()),modrinth_id: modrinth_id.map(|v| v.into()),modrinth_slug: modrinth_slug.map(|v| v.into()),modrinth_name: modrinth_name.map(|v| v.into()),modrinth_description: modrinth_description.map(|v| v.into()),modrinth_author: modrinth_author.map(|v| v.into()),modrinth_version: modrinth_version.map(|v| v.into()),modrinth_status: modrinth_status.map(|v| v.into()),modrinth_icon_url: modrinth_icon_url.map(|v| v.into()),modrinth_icon_hash: modrinth_icon_hash.map(|v| v.into()),modrinth_date_created,modrinth_date_updated,modrinth_latest_minecraft_version: modrinth_latest_minecraft_version.map(|v| v.into()),modrinth_downloads,modrinth_follows,modrinth_platforms: modrinth_platforms.map(|v| v.map(|v| v).collect()),modrinth_categories: modrinth_categories.map(|v| v.map(|v| v).collect()),modrinth_minecraft_versions: modrinth_minecraft_versions.map(|v| v.map(|v| v.into()).collect()),hangar_slug: hangar_slug.map(|v| v.into()),hangar_name: hangar_name.map(|v| v.into()),hangar_description: hangar_description.map(|v| v.into()),hangar_author: hangar_author.map(|v| v.into()),hangar_version: hangar_version.map(|v| v.into()),hangar_icon_url: hangar_icon_url.map(|v| v.into()),hangar_icon_hash: hangar_icon_hash.map(|v| v.into()),hangar_date_created,hangar_date_updated,hangar_latest_minecraft_version: hangar_latest_minecraft_version.map(|v| v.into()),hangar_downloads,hangar_stars,hangar_watchers,hangar_paper_versions: hangar_paper_versions.map(|v| v.map(|v| v.into()).collect()),hangar_velocity_versions: hangar_velocity_versions.map(|v| v.map(|v| v.into()).collect()),hangar_waterfall_versions: hangar_waterfall_versions.map(|v| v.map(|v| v.into()).collect()),hangar_platforms: hangar_platforms.map(|v| v.map(|v| v).collect()),hangar_categories: hangar_categories.map(|v| v.map(|v| v).collect()),hangar_minecraft_versions: hangar_minecraft_versions.map(|v| v.map(|v| v.into()).collect()),curseforge_id,curseforge_slug: curseforge_slug.map(|v| v.into()),curseforge_name: curseforge_name.map(|v| v.into()),curseforge_description: curseforge_description.map(|v| v.into()),curseforge_author: curseforge_author.map(|v| v.into()),curseforge_version: curseforge_version.map(|v| v.into()),curseforge_icon_url: curseforge_icon_url.map(|v| v.into()),curseforge_icon_hash: curseforge_icon_hash.map(|v| v.into()),curseforge_date_created,curseforge_date_updated,curseforge_latest_minecraft_version: curseforge_latest_minecraft_version.map(|v| v.into()),curseforge_downloads,curseforge_likes,curseforge_platforms: curseforge_platforms.map(|v| v.map(|v| v).collect()),curseforge_categories: curseforge_categories.map(|v| v.map(|v| v).collect()),curseforge_minecraft_versions: curseforge_minecraft_versions.map(|v| v.map(|v| v.into()).collect()),polymart_id,polymart_name: polymart_name.map(|v| v.into()),polymart_description: polymart_description.map(|v| v.into()),polymart_author: polymart_author.map(|v| v.into()),polymart_version: polymart_version.map(|v| v.into()),polymart_premium,polymart_icon_url: polymart_icon_url.map(|v| v.into()),polymart_icon_hash: polymart_icon_hash.map(|v| v.into()),polymart_date_created,polymart_date_updated,polymart_latest_minecraft_version: polymart_latest_minecraft_version.map(|v| v.into()),polymart_downloads,polymart_platforms: polymart_platforms.map(|v| v.map(|v| v).collect()),polymart_categories: polymart_categories.map(|v| v.map(|v| v).collect()),polymart_minecraft_versions: polymart_minecraft_versions.map(|v| v.map(|v| v.into()).collect()),builtbybit_id,builtbybit_name: builtbybit_name.map(|v| v.into()),builtbybit_description: builtbybit_description.map(|v| v.into()),builtbybit_author: builtbybit_author.map(|v| v.into()),builtbybit_version: builtbybit_version.map(|v| v.into()),builtbybit_price,builtbybit_currency: builtbybit_currency.map(|v| v.into()),builtbybit_date_created,builtbybit_date_updated,builtbybit_downloads,source_repository_host: source_repository_host.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),} }
 }pub struct CommonProjectEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
@@ -249,8 +534,8 @@ GenericClient,>(&'a mut self, client: &'a  C,
 GetCommonProjectCountStmt(cornucopia_async::private::Stmt); impl GetCommonProjectCountStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> I64Query<'a,C,
-i64, 0>
+) -> I64Query<'a,C, i64,
+0>
 {
     I64Query
     {
@@ -267,12 +552,15 @@ i64, 0>
   spigot_version,
   spigot_premium,
   spigot_abandoned,
-  spigot_icon_data,
+  spigot_icon_hash,
   spigot_date_created,
   spigot_date_updated,
   spigot_latest_minecraft_version,
   spigot_downloads,
   spigot_likes,
+  spigot_platforms,
+  spigot_categories,
+  spigot_minecraft_versions,
 
   modrinth_id,
   modrinth_slug,
@@ -282,11 +570,15 @@ i64, 0>
   modrinth_version,
   modrinth_status,
   modrinth_icon_url,
+  modrinth_icon_hash,
   modrinth_date_created,
   modrinth_date_updated,
   modrinth_latest_minecraft_version,
   modrinth_downloads,
   modrinth_follows,
+  modrinth_platforms,
+  modrinth_categories,
+  modrinth_minecraft_versions,
 
   hangar_slug,
   hangar_name,
@@ -294,12 +586,63 @@ i64, 0>
   hangar_author,
   hangar_version,
   hangar_icon_url,
+  hangar_icon_hash,
   hangar_date_created,
   hangar_date_updated,
   hangar_latest_minecraft_version,
   hangar_downloads,
   hangar_stars,
   hangar_watchers,
+  hangar_paper_versions,
+  hangar_velocity_versions,
+  hangar_waterfall_versions,
+  hangar_platforms,
+  hangar_categories,
+  hangar_minecraft_versions,
+
+  curseforge_id,
+  curseforge_slug,
+  curseforge_name,
+  curseforge_description,
+  curseforge_author,
+  curseforge_version,
+  curseforge_icon_url,
+  curseforge_icon_hash,
+  curseforge_date_created,
+  curseforge_date_updated,
+  curseforge_latest_minecraft_version,
+  curseforge_downloads,
+  curseforge_likes,
+  curseforge_platforms,
+  curseforge_categories,
+  curseforge_minecraft_versions,
+
+  polymart_id,
+  polymart_name,
+  polymart_description,
+  polymart_author,
+  polymart_version,
+  polymart_premium,
+  polymart_icon_url,
+  polymart_icon_hash,
+  polymart_date_created,
+  polymart_date_updated,
+  polymart_latest_minecraft_version,
+  polymart_downloads,
+  polymart_platforms,
+  polymart_categories,
+  polymart_minecraft_versions,
+
+  builtbybit_id,
+  builtbybit_name,
+  builtbybit_description,
+  builtbybit_author,
+  builtbybit_version,
+  builtbybit_price,
+  builtbybit_currency,
+  builtbybit_date_created,
+  builtbybit_date_updated,
+  builtbybit_downloads,
 
   source_repository_host,
   source_repository_name,
@@ -309,47 +652,247 @@ FROM
 GetCommonProjectsStmt(cornucopia_async::private::Stmt); impl GetCommonProjectsStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> CommonProjectEntityQuery<'a,C,
-CommonProjectEntity, 0>
+) -> CommonProjectEntityQuery<'a,C, CommonProjectEntity,
+0>
 {
     CommonProjectEntityQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
-        |row| { CommonProjectEntityBorrowed { spigot_id: row.get(0),spigot_slug: row.get(1),spigot_name: row.get(2),spigot_description: row.get(3),spigot_author: row.get(4),spigot_version: row.get(5),spigot_premium: row.get(6),spigot_abandoned: row.get(7),spigot_icon_data: row.get(8),spigot_date_created: row.get(9),spigot_date_updated: row.get(10),spigot_latest_minecraft_version: row.get(11),spigot_downloads: row.get(12),spigot_likes: row.get(13),modrinth_id: row.get(14),modrinth_slug: row.get(15),modrinth_name: row.get(16),modrinth_description: row.get(17),modrinth_author: row.get(18),modrinth_version: row.get(19),modrinth_status: row.get(20),modrinth_icon_url: row.get(21),modrinth_date_created: row.get(22),modrinth_date_updated: row.get(23),modrinth_latest_minecraft_version: row.get(24),modrinth_downloads: row.get(25),modrinth_follows: row.get(26),hangar_slug: row.get(27),hangar_name: row.get(28),hangar_description: row.get(29),hangar_author: row.get(30),hangar_version: row.get(31),hangar_icon_url: row.get(32),hangar_date_created: row.get(33),hangar_date_updated: row.get(34),hangar_latest_minecraft_version: row.get(35),hangar_downloads: row.get(36),hangar_stars: row.get(37),hangar_watchers: row.get(38),source_repository_host: row.get(39),source_repository_name: row.get(40),source_repository_owner: row.get(41),} }, mapper: |it| { <CommonProjectEntity>::from(it) },
+        |row| { CommonProjectEntityBorrowed { spigot_id: row.get(0),spigot_slug: row.get(1),spigot_name: row.get(2),spigot_description: row.get(3),spigot_author: row.get(4),spigot_version: row.get(5),spigot_premium: row.get(6),spigot_abandoned: row.get(7),spigot_icon_hash: row.get(8),spigot_date_created: row.get(9),spigot_date_updated: row.get(10),spigot_latest_minecraft_version: row.get(11),spigot_downloads: row.get(12),spigot_likes: row.get(13),spigot_platforms: row.get(14),spigot_categories: row.get(15),spigot_minecraft_versions: row.get(16),modrinth_id: row.get(17),modrinth_slug: row.get(18),modrinth_name: row.get(19),modrinth_description: row.get(20),modrinth_author: row.get(21),modrinth_version: row.get(22),modrinth_status: row.get(23),modrinth_icon_url: row.get(24),modrinth_icon_hash: row.get(25),modrinth_date_created: row.get(26),modrinth_date_updated: row.get(27),modrinth_latest_minecraft_version: row.get(28),modrinth_downloads: row.get(29),modrinth_follows: row.get(30),modrinth_platforms: row.get(31),modrinth_categories: row.get(32),modrinth_minecraft_versions: row.get(33),hangar_slug: row.get(34),hangar_name: row.get(35),hangar_description: row.get(36),hangar_author: row.get(37),hangar_version: row.get(38),hangar_icon_url: row.get(39),hangar_icon_hash: row.get(40),hangar_date_created: row.get(41),hangar_date_updated: row.get(42),hangar_latest_minecraft_version: row.get(43),hangar_downloads: row.get(44),hangar_stars: row.get(45),hangar_watchers: row.get(46),hangar_paper_versions: row.get(47),hangar_velocity_versions: row.get(48),hangar_waterfall_versions: row.get(49),hangar_platforms: row.get(50),hangar_categories: row.get(51),hangar_minecraft_versions: row.get(52),curseforge_id: row.get(53),curseforge_slug: row.get(54),curseforge_name: row.get(55),curseforge_description: row.get(56),curseforge_author: row.get(57),curseforge_version: row.get(58),curseforge_icon_url: row.get(59),curseforge_icon_hash: row.get(60),curseforge_date_created: row.get(61),curseforge_date_updated: row.get(62),curseforge_latest_minecraft_version: row.get(63),curseforge_downloads: row.get(64),curseforge_likes: row.get(65),curseforge_platforms: row.get(66),curseforge_categories: row.get(67),curseforge_minecraft_versions: row.get(68),polymart_id: row.get(69),polymart_name: row.get(70),polymart_description: row.get(71),polymart_author: row.get(72),polymart_version: row.get(73),polymart_premium: row.get(74),polymart_icon_url: row.get(75),polymart_icon_hash: row.get(76),polymart_date_created: row.get(77),polymart_date_updated: row.get(78),polymart_latest_minecraft_version: row.get(79),polymart_downloads: row.get(80),polymart_platforms: row.get(81),polymart_categories: row.get(82),polymart_minecraft_versions: row.get(83),builtbybit_id: row.get(84),builtbybit_name: row.get(85),builtbybit_description: row.get(86),builtbybit_author: row.get(87),builtbybit_version: row.get(88),builtbybit_price: row.get(89),builtbybit_currency: row.get(90),builtbybit_date_created: row.get(91),builtbybit_date_updated: row.get(92),builtbybit_downloads: row.get(93),source_repository_host: row.get(94),source_repository_name: row.get(95),source_repository_owner: row.get(96),} }, mapper: |it| { <CommonProjectEntity>::from(it) },
     }
-} }}pub mod fix_upstream_errors
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn remove_incorrect_source_repository_host_owner_and_name_from_spigot_resources() -> RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt
-{ RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt(cornucopia_async::private::Stmt::new("UPDATE spigot_resource
-SET source_repository_host = NULL, source_repository_owner = NULL, source_repository_name = NULL
-WHERE id IN (25773, 82123, 97659, 119724)")) } pub struct
-RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt(cornucopia_async::private::Stmt); impl RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt
-{ pub async fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-) -> Result<u64, tokio_postgres::Error>
-{
-    let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[]).await
-} }pub fn add_source_repository_id_to_noble_whitelist_discord_spigot_resource() -> AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt
-{ AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt(cornucopia_async::private::Stmt::new("UPDATE spigot_resource
-SET source_repository_id = 'noble-whitelist-discord'
-WHERE id = 113896")) } pub struct
-AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt(cornucopia_async::private::Stmt); impl AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt
-{ pub async fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-) -> Result<u64, tokio_postgres::Error>
+} }}pub mod curseforge_project
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertCurseforgeProjectParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = i32>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T14: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = T15>,> { pub id: i32,pub slug: T1,pub name: T2,pub description: T3,pub author: T4,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T5>,pub latest_minecraft_version_sort_key: Option<T6>,pub downloads: i32,pub likes: i32,pub icon_url: Option<T7>,pub version_name: Option<T8>,pub source_url: Option<T9>,pub source_repository_host: Option<T10>,pub source_repository_owner: Option<T11>,pub source_repository_name: Option<T12>,pub platforms: T13,pub categories: T14,pub minecraft_versions: T16,}#[derive( Debug, Clone, PartialEq,)] pub struct CurseForgeProjectEntity
+{ pub id : i32,pub slug : String,pub name : String,pub description : String,pub author : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub latest_minecraft_version_sort_key : Option<Vec<i32>>,pub downloads : i32,pub likes : i32,pub icon_url : Option<String>,pub version_name : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : Vec<super::super::types::public::ServerPlatform>,pub categories : Vec<super::super::types::public::ProjectCategory>,pub minecraft_versions : Vec<String>,}pub struct CurseForgeProjectEntityBorrowed<'a> { pub id : i32,pub slug : &'a str,pub name : &'a str,pub description : &'a str,pub author : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub latest_minecraft_version_sort_key : Option<cornucopia_async::ArrayIterator<'a, i32>>,pub downloads : i32,pub likes : i32,pub icon_url : Option<&'a str>,pub version_name : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>,pub categories : cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>,pub minecraft_versions : cornucopia_async::ArrayIterator<'a, &'a str>,}
+impl<'a> From<CurseForgeProjectEntityBorrowed<'a>> for CurseForgeProjectEntity
 {
-    let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[]).await
-} }pub fn add_source_repository_id_to_noble_whitelist_discord_modrinth_project() -> AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt
-{ AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt(cornucopia_async::private::Stmt::new("UPDATE modrinth_project
-SET source_repository_id = 'noble-whitelist-discord'
-WHERE id = 'WWbtvBwl'")) } pub struct
-AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt(cornucopia_async::private::Stmt); impl AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt
-{ pub async fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-) -> Result<u64, tokio_postgres::Error>
+    fn from(CurseForgeProjectEntityBorrowed { id,slug,name,description,author,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,likes,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,date_last_seen,date_deleted,platforms,categories,minecraft_versions,}: CurseForgeProjectEntityBorrowed<'a>) -> Self
+    { Self { id,slug: slug.into(),name: name.into(),description: description.into(),author: author.into(),date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),latest_minecraft_version_sort_key: latest_minecraft_version_sort_key.map(|v| v.map(|v| v).collect()),downloads,likes,icon_url: icon_url.map(|v| v.into()),version_name: version_name.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),date_last_seen,date_deleted,platforms: platforms.map(|v| v).collect(),categories: categories.map(|v| v).collect(),minecraft_versions: minecraft_versions.map(|v| v.into()).collect(),} }
+}pub struct CurseForgeProjectEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
-    let stmt = self.0.prepare(client).await?;
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CurseForgeProjectEntityBorrowed,
+    mapper: fn(CurseForgeProjectEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CurseForgeProjectEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CurseForgeProjectEntityBorrowed) -> R) ->
+    CurseForgeProjectEntityQuery<'a,C,R,N>
+    {
+        CurseForgeProjectEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct TimeOffsetDateTimeQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> time::OffsetDateTime,
+    mapper: fn(time::OffsetDateTime) -> T,
+} impl<'a, C, T:'a, const N: usize> TimeOffsetDateTimeQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(time::OffsetDateTime) -> R) ->
+    TimeOffsetDateTimeQuery<'a,C,R,N>
+    {
+        TimeOffsetDateTimeQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_curseforge_project() -> UpsertCurseforgeProjectStmt
+{ UpsertCurseforgeProjectStmt(cornucopia_async::private::Stmt::new("INSERT INTO curseforge_project (id, slug, name, description, author, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, likes, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, categories, minecraft_versions, date_last_seen)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, NOW())
+  ON CONFLICT (id)
+  DO UPDATE SET
+    slug = EXCLUDED.slug,
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
+    downloads = EXCLUDED.downloads,
+    likes = EXCLUDED.likes,
+    icon_url = EXCLUDED.icon_url,
+    version_name = EXCLUDED.version_name,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL")) } pub struct
+UpsertCurseforgeProjectStmt(cornucopia_async::private::Stmt); impl UpsertCurseforgeProjectStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = i32>,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::StringSql,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T14:
+cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T15:
+cornucopia_async::StringSql,T16:
+cornucopia_async::ArraySql<Item = T15>,>(&'a mut self, client: &'a  C,
+id: &'a i32,slug: &'a T1,name: &'a T2,description: &'a T3,author: &'a T4,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T5>,latest_minecraft_version_sort_key: &'a Option<T6>,downloads: &'a i32,likes: &'a i32,icon_url: &'a Option<T7>,version_name: &'a Option<T8>,source_url: &'a Option<T9>,source_repository_host: &'a Option<T10>,source_repository_owner: &'a Option<T11>,source_repository_name: &'a Option<T12>,platforms: &'a T13,categories: &'a T14,minecraft_versions: &'a T16,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,slug,name,description,author,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,likes,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,platforms,categories,minecraft_versions,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = i32>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T14: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = T15>,>
+cornucopia_async::Params<'a, UpsertCurseforgeProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertCurseforgeProjectStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertCurseforgeProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.slug,&params.name,&params.description,&params.author,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.latest_minecraft_version_sort_key,&params.downloads,&params.likes,&params.icon_url,&params.version_name,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,&params.platforms,&params.categories,&params.minecraft_versions,)) }
+}pub fn get_curseforge_projects() -> GetCurseforgeProjectsStmt
+{ GetCurseforgeProjectsStmt(cornucopia_async::private::Stmt::new("SELECT * FROM curseforge_project
+WHERE date_deleted IS NULL")) } pub struct
+GetCurseforgeProjectsStmt(cornucopia_async::private::Stmt); impl GetCurseforgeProjectsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> CurseForgeProjectEntityQuery<'a,C, CurseForgeProjectEntity,
+0>
+{
+    CurseForgeProjectEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { CurseForgeProjectEntityBorrowed { id: row.get(0),slug: row.get(1),name: row.get(2),description: row.get(3),author: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),latest_minecraft_version_sort_key: row.get(8),downloads: row.get(9),likes: row.get(10),icon_url: row.get(11),version_name: row.get(12),source_url: row.get(13),source_repository_host: row.get(14),source_repository_owner: row.get(15),source_repository_name: row.get(16),source_repository_id: row.get(17),date_last_seen: row.get(18),date_deleted: row.get(19),platforms: row.get(20),categories: row.get(21),minecraft_versions: row.get(22),} }, mapper: |it| { <CurseForgeProjectEntity>::from(it) },
+    }
+} }pub fn get_curseforge_project_by_id() -> GetCurseforgeProjectByIdStmt
+{ GetCurseforgeProjectByIdStmt(cornucopia_async::private::Stmt::new("SELECT * FROM curseforge_project
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetCurseforgeProjectByIdStmt(cornucopia_async::private::Stmt); impl GetCurseforgeProjectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+id: &'a i32,) -> CurseForgeProjectEntityQuery<'a,C, CurseForgeProjectEntity,
+1>
+{
+    CurseForgeProjectEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CurseForgeProjectEntityBorrowed { id: row.get(0),slug: row.get(1),name: row.get(2),description: row.get(3),author: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),latest_minecraft_version_sort_key: row.get(8),downloads: row.get(9),likes: row.get(10),icon_url: row.get(11),version_name: row.get(12),source_url: row.get(13),source_repository_host: row.get(14),source_repository_owner: row.get(15),source_repository_name: row.get(16),source_repository_id: row.get(17),date_last_seen: row.get(18),date_deleted: row.get(19),platforms: row.get(20),categories: row.get(21),minecraft_versions: row.get(22),} }, mapper: |it| { <CurseForgeProjectEntity>::from(it) },
+    }
+} }pub fn get_latest_curseforge_project_update_date() -> GetLatestCurseforgeProjectUpdateDateStmt
+{ GetLatestCurseforgeProjectUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM curseforge_project")) } pub struct
+GetLatestCurseforgeProjectUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestCurseforgeProjectUpdateDateStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
+{
+    TimeOffsetDateTimeQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }pub fn mark_unseen_curseforge_projects_as_deleted() -> MarkUnseenCurseforgeProjectsAsDeletedStmt
+{ MarkUnseenCurseforgeProjectsAsDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE curseforge_project
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < $1)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'CurseForge' AND item = 'Project' AND upstream_id = curseforge_project.id::text
+  )")) } pub struct
+MarkUnseenCurseforgeProjectsAsDeletedStmt(cornucopia_async::private::Stmt); impl MarkUnseenCurseforgeProjectsAsDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date_started: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date_started,]).await
+} }}pub mod fix_upstream_errors
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn remove_incorrect_source_repository_host_owner_and_name_from_spigot_resources() -> RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt
+{ RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt(cornucopia_async::private::Stmt::new("UPDATE spigot_resource
+SET source_repository_host = NULL, source_repository_owner = NULL, source_repository_name = NULL
+WHERE id IN (25773, 82123, 97659, 119724)")) } pub struct
+RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt(cornucopia_async::private::Stmt); impl RemoveIncorrectSourceRepositoryHostOwnerAndNameFromSpigotResourcesStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[]).await
+} }pub fn add_source_repository_id_to_noble_whitelist_discord_spigot_resource() -> AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt
+{ AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt(cornucopia_async::private::Stmt::new("UPDATE spigot_resource
+SET source_repository_id = 'noble-whitelist-discord'
+WHERE id = 113896")) } pub struct
+AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt(cornucopia_async::private::Stmt); impl AddSourceRepositoryIdToNobleWhitelistDiscordSpigotResourceStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[]).await
+} }pub fn add_source_repository_id_to_noble_whitelist_discord_modrinth_project() -> AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt
+{ AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt(cornucopia_async::private::Stmt::new("UPDATE modrinth_project
+SET source_repository_id = 'noble-whitelist-discord'
+WHERE id = 'WWbtvBwl'")) } pub struct
+AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt(cornucopia_async::private::Stmt); impl AddSourceRepositoryIdToNobleWhitelistDiscordModrinthProjectStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
     client.execute(stmt, &[]).await
 } }pub fn add_source_repository_id_to_noble_whitelist_discord_hangar_project() -> AddSourceRepositoryIdToNobleWhitelistDiscordHangarProjectStmt
 { AddSourceRepositoryIdToNobleWhitelistDiscordHangarProjectStmt(cornucopia_async::private::Stmt::new("UPDATE hangar_project
@@ -374,12 +917,12 @@ GenericClient,>(&'a mut self, client: &'a  C,
     let stmt = self.0.prepare(client).await?;
     client.execute(stmt, &[]).await
 } }}pub mod hangar_project
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertHangarProjectParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,> { pub slug: T1,pub author: T2,pub name: T3,pub description: T4,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T5>,pub downloads: i32,pub stars: i32,pub watchers: i32,pub visibility: T6,pub icon_url: T7,pub version_name: Option<T8>,pub source_url: Option<T9>,pub source_repository_host: Option<T10>,pub source_repository_owner: Option<T11>,pub source_repository_name: Option<T12>,}#[derive( Debug, Clone, PartialEq,)] pub struct HangarProjectEntity
-{ pub slug : String,pub author : String,pub name : String,pub description : String,pub latest_minecraft_version : Option<String>,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub downloads : i32,pub stars : i32,pub watchers : i32,pub visibility : String,pub icon_url : String,pub version_name : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,}pub struct HangarProjectEntityBorrowed<'a> { pub slug : &'a str,pub author : &'a str,pub name : &'a str,pub description : &'a str,pub latest_minecraft_version : Option<&'a str>,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub downloads : i32,pub stars : i32,pub watchers : i32,pub visibility : &'a str,pub icon_url : &'a str,pub version_name : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,}
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertHangarProjectParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = i32>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::StringSql,T15: cornucopia_async::ArraySql<Item = T14>,T16: cornucopia_async::StringSql,T17: cornucopia_async::ArraySql<Item = T16>,T18: cornucopia_async::StringSql,T19: cornucopia_async::ArraySql<Item = T18>,T20: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T21: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T22: cornucopia_async::StringSql,T23: cornucopia_async::ArraySql<Item = T22>,T24: cornucopia_async::StringSql,> { pub slug: T1,pub author: T2,pub name: T3,pub description: T4,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T5>,pub latest_minecraft_version_sort_key: Option<T6>,pub downloads: i32,pub stars: i32,pub watchers: i32,pub visibility: T7,pub icon_url: T8,pub version_name: Option<T9>,pub source_url: Option<T10>,pub source_repository_host: Option<T11>,pub source_repository_owner: Option<T12>,pub source_repository_name: Option<T13>,pub paper_versions: T15,pub velocity_versions: T17,pub waterfall_versions: T19,pub platforms: T20,pub categories: T21,pub minecraft_versions: T23,pub full_description: Option<T24>,}#[derive( Debug, Clone, PartialEq,)] pub struct HangarProjectEntity
+{ pub slug : String,pub author : String,pub name : String,pub description : String,pub latest_minecraft_version : Option<String>,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub downloads : i32,pub stars : i32,pub watchers : i32,pub visibility : String,pub icon_url : String,pub version_name : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub paper_versions : Vec<String>,pub velocity_versions : Vec<String>,pub waterfall_versions : Vec<String>,pub platforms : Vec<super::super::types::public::ServerPlatform>,pub minecraft_versions : Vec<String>,pub latest_minecraft_version_sort_key : Option<Vec<i32>>,pub categories : Vec<super::super::types::public::ProjectCategory>,pub full_description : Option<String>,}pub struct HangarProjectEntityBorrowed<'a> { pub slug : &'a str,pub author : &'a str,pub name : &'a str,pub description : &'a str,pub latest_minecraft_version : Option<&'a str>,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub downloads : i32,pub stars : i32,pub watchers : i32,pub visibility : &'a str,pub icon_url : &'a str,pub version_name : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub paper_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub velocity_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub waterfall_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub platforms : cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>,pub minecraft_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub latest_minecraft_version_sort_key : Option<cornucopia_async::ArrayIterator<'a, i32>>,pub categories : cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>,pub full_description : Option<&'a str>,}
 impl<'a> From<HangarProjectEntityBorrowed<'a>> for HangarProjectEntity
 {
-    fn from(HangarProjectEntityBorrowed { slug,author,name,description,latest_minecraft_version,date_created,date_updated,downloads,stars,watchers,visibility,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,}: HangarProjectEntityBorrowed<'a>) ->
-    Self { Self { slug: slug.into(),author: author.into(),name: name.into(),description: description.into(),latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),date_created,date_updated,downloads,stars,watchers,visibility: visibility.into(),icon_url: icon_url.into(),version_name: version_name.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),} }
+    fn from(HangarProjectEntityBorrowed { slug,author,name,description,latest_minecraft_version,date_created,date_updated,downloads,stars,watchers,visibility,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,date_last_seen,date_deleted,paper_versions,velocity_versions,waterfall_versions,platforms,minecraft_versions,latest_minecraft_version_sort_key,categories,full_description,}: HangarProjectEntityBorrowed<'a>) -> Self
+    { Self { slug: slug.into(),author: author.into(),name: name.into(),description: description.into(),latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),date_created,date_updated,downloads,stars,watchers,visibility: visibility.into(),icon_url: icon_url.into(),version_name: version_name.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),date_last_seen,date_deleted,paper_versions: paper_versions.map(|v| v.into()).collect(),velocity_versions: velocity_versions.map(|v| v.into()).collect(),waterfall_versions: waterfall_versions.map(|v| v.into()).collect(),platforms: platforms.map(|v| v).collect(),minecraft_versions: minecraft_versions.map(|v| v.into()).collect(),latest_minecraft_version_sort_key: latest_minecraft_version_sort_key.map(|v| v.map(|v| v).collect()),categories: categories.map(|v| v).collect(),full_description: full_description.map(|v| v.into()),} }
 }pub struct HangarProjectEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
@@ -459,8 +1002,8 @@ GenericClient
         Ok(it)
     }
 }pub fn upsert_hangar_project() -> UpsertHangarProjectStmt
-{ UpsertHangarProjectStmt(cornucopia_async::private::Stmt::new("INSERT INTO hangar_project (slug, author, name, description, date_created, date_updated, latest_minecraft_version, downloads, stars, watchers, visibility, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name)
-  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+{ UpsertHangarProjectStmt(cornucopia_async::private::Stmt::new("INSERT INTO hangar_project (slug, author, name, description, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, stars, watchers, visibility, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, paper_versions, velocity_versions, waterfall_versions, platforms, categories, minecraft_versions, full_description, date_last_seen)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, NOW())
   ON CONFLICT (slug)
   DO UPDATE SET
     author = EXCLUDED.author,
@@ -469,6 +1012,7 @@ GenericClient
     date_created = EXCLUDED.date_created,
     date_updated = EXCLUDED.date_updated,
     latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
     downloads = EXCLUDED.downloads,
     stars = EXCLUDED.stars,
     watchers = EXCLUDED.watchers,
@@ -478,7 +1022,16 @@ GenericClient
     source_url = EXCLUDED.source_url,
     source_repository_host = EXCLUDED.source_repository_host,
     source_repository_owner = EXCLUDED.source_repository_owner,
-    source_repository_name = EXCLUDED.source_repository_name")) } pub struct
+    source_repository_name = EXCLUDED.source_repository_name,
+    paper_versions = EXCLUDED.paper_versions,
+    velocity_versions = EXCLUDED.velocity_versions,
+    waterfall_versions = EXCLUDED.waterfall_versions,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    full_description = EXCLUDED.full_description,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL")) } pub struct
 UpsertHangarProjectStmt(cornucopia_async::private::Stmt); impl UpsertHangarProjectStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
@@ -487,67 +1040,116 @@ cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
 cornucopia_async::StringSql,T5:
 cornucopia_async::StringSql,T6:
-cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = i32>,T7:
 cornucopia_async::StringSql,T8:
 cornucopia_async::StringSql,T9:
 cornucopia_async::StringSql,T10:
 cornucopia_async::StringSql,T11:
 cornucopia_async::StringSql,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::StringSql,T14:
+cornucopia_async::StringSql,T15:
+cornucopia_async::ArraySql<Item = T14>,T16:
+cornucopia_async::StringSql,T17:
+cornucopia_async::ArraySql<Item = T16>,T18:
+cornucopia_async::StringSql,T19:
+cornucopia_async::ArraySql<Item = T18>,T20:
+cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T21:
+cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T22:
+cornucopia_async::StringSql,T23:
+cornucopia_async::ArraySql<Item = T22>,T24:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-slug: &'a T1,author: &'a T2,name: &'a T3,description: &'a T4,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T5>,downloads: &'a i32,stars: &'a i32,watchers: &'a i32,visibility: &'a T6,icon_url: &'a T7,version_name: &'a Option<T8>,source_url: &'a Option<T9>,source_repository_host: &'a Option<T10>,source_repository_owner: &'a Option<T11>,source_repository_name: &'a Option<T12>,) -> Result<u64, tokio_postgres::Error>
+slug: &'a T1,author: &'a T2,name: &'a T3,description: &'a T4,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T5>,latest_minecraft_version_sort_key: &'a Option<T6>,downloads: &'a i32,stars: &'a i32,watchers: &'a i32,visibility: &'a T7,icon_url: &'a T8,version_name: &'a Option<T9>,source_url: &'a Option<T10>,source_repository_host: &'a Option<T11>,source_repository_owner: &'a Option<T12>,source_repository_name: &'a Option<T13>,paper_versions: &'a T15,velocity_versions: &'a T17,waterfall_versions: &'a T19,platforms: &'a T20,categories: &'a T21,minecraft_versions: &'a T23,full_description: &'a Option<T24>,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[slug,author,name,description,date_created,date_updated,latest_minecraft_version,downloads,stars,watchers,visibility,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, UpsertHangarProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    client.execute(stmt, &[slug,author,name,description,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,stars,watchers,visibility,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,paper_versions,velocity_versions,waterfall_versions,platforms,categories,minecraft_versions,full_description,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = i32>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::StringSql,T15: cornucopia_async::ArraySql<Item = T14>,T16: cornucopia_async::StringSql,T17: cornucopia_async::ArraySql<Item = T16>,T18: cornucopia_async::StringSql,T19: cornucopia_async::ArraySql<Item = T18>,T20: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T21: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T22: cornucopia_async::StringSql,T23: cornucopia_async::ArraySql<Item = T22>,T24: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertHangarProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,T19,T20,T21,T22,T23,T24,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
 tokio_postgres::Error>> + Send + 'a>>, C> for UpsertHangarProjectStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    UpsertHangarProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    UpsertHangarProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,T19,T20,T21,T22,T23,T24,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.slug,&params.author,&params.name,&params.description,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.downloads,&params.stars,&params.watchers,&params.visibility,&params.icon_url,&params.version_name,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,)) }
+    { Box::pin(self.bind(client, &params.slug,&params.author,&params.name,&params.description,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.latest_minecraft_version_sort_key,&params.downloads,&params.stars,&params.watchers,&params.visibility,&params.icon_url,&params.version_name,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,&params.paper_versions,&params.velocity_versions,&params.waterfall_versions,&params.platforms,&params.categories,&params.minecraft_versions,&params.full_description,)) }
 }pub fn get_hangar_projects() -> GetHangarProjectsStmt
-{ GetHangarProjectsStmt(cornucopia_async::private::Stmt::new("SELECT * FROM hangar_project")) } pub struct
+{ GetHangarProjectsStmt(cornucopia_async::private::Stmt::new("SELECT * FROM hangar_project
+WHERE date_deleted IS NULL")) } pub struct
 GetHangarProjectsStmt(cornucopia_async::private::Stmt); impl GetHangarProjectsStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> HangarProjectEntityQuery<'a,C,
-HangarProjectEntity, 0>
+) -> HangarProjectEntityQuery<'a,C, HangarProjectEntity,
+0>
 {
     HangarProjectEntityQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
-        |row| { HangarProjectEntityBorrowed { slug: row.get(0),author: row.get(1),name: row.get(2),description: row.get(3),latest_minecraft_version: row.get(4),date_created: row.get(5),date_updated: row.get(6),downloads: row.get(7),stars: row.get(8),watchers: row.get(9),visibility: row.get(10),icon_url: row.get(11),version_name: row.get(12),source_url: row.get(13),source_repository_host: row.get(14),source_repository_owner: row.get(15),source_repository_name: row.get(16),source_repository_id: row.get(17),} }, mapper: |it| { <HangarProjectEntity>::from(it) },
+        |row| { HangarProjectEntityBorrowed { slug: row.get(0),author: row.get(1),name: row.get(2),description: row.get(3),latest_minecraft_version: row.get(4),date_created: row.get(5),date_updated: row.get(6),downloads: row.get(7),stars: row.get(8),watchers: row.get(9),visibility: row.get(10),icon_url: row.get(11),version_name: row.get(12),source_url: row.get(13),source_repository_host: row.get(14),source_repository_owner: row.get(15),source_repository_name: row.get(16),source_repository_id: row.get(17),date_last_seen: row.get(18),date_deleted: row.get(19),paper_versions: row.get(20),velocity_versions: row.get(21),waterfall_versions: row.get(22),platforms: row.get(23),minecraft_versions: row.get(24),latest_minecraft_version_sort_key: row.get(25),categories: row.get(26),full_description: row.get(27),} }, mapper: |it| { <HangarProjectEntity>::from(it) },
+    }
+} }pub fn get_hangar_project_by_slug() -> GetHangarProjectBySlugStmt
+{ GetHangarProjectBySlugStmt(cornucopia_async::private::Stmt::new("SELECT * FROM hangar_project
+WHERE slug = $1 AND date_deleted IS NULL")) } pub struct
+GetHangarProjectBySlugStmt(cornucopia_async::private::Stmt); impl GetHangarProjectBySlugStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+slug: &'a T1,) -> HangarProjectEntityQuery<'a,C, HangarProjectEntity,
+1>
+{
+    HangarProjectEntityQuery
+    {
+        client, params: [slug,], stmt: &mut self.0, extractor:
+        |row| { HangarProjectEntityBorrowed { slug: row.get(0),author: row.get(1),name: row.get(2),description: row.get(3),latest_minecraft_version: row.get(4),date_created: row.get(5),date_updated: row.get(6),downloads: row.get(7),stars: row.get(8),watchers: row.get(9),visibility: row.get(10),icon_url: row.get(11),version_name: row.get(12),source_url: row.get(13),source_repository_host: row.get(14),source_repository_owner: row.get(15),source_repository_name: row.get(16),source_repository_id: row.get(17),date_last_seen: row.get(18),date_deleted: row.get(19),paper_versions: row.get(20),velocity_versions: row.get(21),waterfall_versions: row.get(22),platforms: row.get(23),minecraft_versions: row.get(24),latest_minecraft_version_sort_key: row.get(25),categories: row.get(26),full_description: row.get(27),} }, mapper: |it| { <HangarProjectEntity>::from(it) },
     }
 } }pub fn get_latest_hangar_project_update_date() -> GetLatestHangarProjectUpdateDateStmt
 { GetLatestHangarProjectUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM hangar_project")) } pub struct
 GetLatestHangarProjectUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestHangarProjectUpdateDateStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> TimeOffsetDateTimeQuery<'a,C,
-time::OffsetDateTime, 0>
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
 {
     TimeOffsetDateTimeQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
         |row| { row.get(0) }, mapper: |it| { it },
     }
-} }}pub mod ingest_log
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive(Clone,Copy, Debug)] pub struct InsertIngestLogParams<> { pub action: super::super::types::public::IngestLogAction,pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,pub date_started: time::OffsetDateTime,pub date_finished: time::OffsetDateTime,pub items_processed: i32,pub success: bool,}#[derive( Debug, Clone, PartialEq,Copy)] pub struct IngestLogEntity
-{ pub id : i32,pub action : super::super::types::public::IngestLogAction,pub repository : super::super::types::public::IngestLogRepository,pub item : super::super::types::public::IngestLogItem,pub date_started : time::OffsetDateTime,pub date_finished : time::OffsetDateTime,pub items_processed : i32,pub success : bool,}pub struct IngestLogEntityQuery<'a, C: GenericClient, T, const N: usize>
+} }pub fn mark_unseen_hangar_projects_as_deleted() -> MarkUnseenHangarProjectsAsDeletedStmt
+{ MarkUnseenHangarProjectsAsDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE hangar_project
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < $1)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Hangar' AND item = 'Project' AND upstream_id = hangar_project.slug
+  )")) } pub struct
+MarkUnseenHangarProjectsAsDeletedStmt(cornucopia_async::private::Stmt); impl MarkUnseenHangarProjectsAsDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date_started: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date_started,]).await
+} }}pub mod icon
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InsertIconParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::BytesSql,T3: cornucopia_async::BytesSql,> { pub hash: T1,pub webp: T2,pub png: T3,}#[derive( Debug)] pub struct UpsertProjectIconParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub repository: super::super::types::public::IngestLogRepository,pub project_id: T1,pub source_url: T2,pub hash: T3,}#[derive( Debug)] pub struct GetProjectIconHashParams<T1: cornucopia_async::StringSql,> { pub repository: super::super::types::public::IngestLogRepository,pub project_id: T1,}#[derive( Debug, Clone, PartialEq,)] pub struct ProjectIconSourceEntity
+{ pub repository : super::super::types::public::IngestLogRepository,pub project_id : String,pub icon_url : String,pub icon_data : Option<String>,}pub struct ProjectIconSourceEntityBorrowed<'a> { pub repository : super::super::types::public::IngestLogRepository,pub project_id : &'a str,pub icon_url : &'a str,pub icon_data : Option<&'a str>,}
+impl<'a> From<ProjectIconSourceEntityBorrowed<'a>> for ProjectIconSourceEntity
+{
+    fn from(ProjectIconSourceEntityBorrowed { repository,project_id,icon_url,icon_data,}: ProjectIconSourceEntityBorrowed<'a>) -> Self
+    { Self { repository,project_id: project_id.into(),icon_url: icon_url.into(),icon_data: icon_data.map(|v| v.into()),} }
+}pub struct ProjectIconSourceEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> IngestLogEntity,
-    mapper: fn(IngestLogEntity) -> T,
-} impl<'a, C, T:'a, const N: usize> IngestLogEntityQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ProjectIconSourceEntityBorrowed,
+    mapper: fn(ProjectIconSourceEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ProjectIconSourceEntityQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(IngestLogEntity) -> R) ->
-    IngestLogEntityQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(ProjectIconSourceEntityBorrowed) -> R) ->
+    ProjectIconSourceEntityQuery<'a,C,R,N>
     {
-        IngestLogEntityQuery
+        ProjectIconSourceEntityQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -574,76 +1176,2444 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn insert_ingest_log() -> InsertIngestLogStmt
-{ InsertIngestLogStmt(cornucopia_async::private::Stmt::new("INSERT INTO ingest_log (action, repository, item, date_started, date_finished, items_processed, success)
-  VALUES ($1, $2, $3, $4, $5, $6, $7)")) } pub struct
-InsertIngestLogStmt(cornucopia_async::private::Stmt); impl InsertIngestLogStmt
-{ pub async fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-action: &'a super::super::types::public::IngestLogAction,repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,date_started: &'a time::OffsetDateTime,date_finished: &'a time::OffsetDateTime,items_processed: &'a i32,success: &'a bool,) -> Result<u64, tokio_postgres::Error>
+}pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
 {
-    let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[action,repository,item,date_started,date_finished,items_processed,success,]).await
-} }impl <'a, C: GenericClient + Send + Sync, >
-cornucopia_async::Params<'a, InsertIngestLogParams<>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for InsertIngestLogStmt
-{
-    fn
-    params(&'a mut self, client: &'a  C, params: &'a
-    InsertIngestLogParams<>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-    tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.action,&params.repository,&params.item,&params.date_started,&params.date_finished,&params.items_processed,&params.success,)) }
-}pub fn get_last_successful_ingest_log() -> GetLastSuccessfulIngestLogStmt
-{ GetLastSuccessfulIngestLogStmt(cornucopia_async::private::Stmt::new("SELECT *
-FROM ingest_log
-WHERE success = TRUE
-ORDER BY id DESC
-LIMIT 1")) } pub struct
-GetLastSuccessfulIngestLogStmt(cornucopia_async::private::Stmt); impl GetLastSuccessfulIngestLogStmt
-{ pub fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-) -> IngestLogEntityQuery<'a,C,
-IngestLogEntity, 0>
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
 {
-    IngestLogEntityQuery
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
     {
-        client, params: [], stmt: &mut self.0, extractor:
-        |row| { IngestLogEntity { id: row.get(0),action: row.get(1),repository: row.get(2),item: row.get(3),date_started: row.get(4),date_finished: row.get(5),items_processed: row.get(6),success: row.get(7),} }, mapper: |it| { <IngestLogEntity>::from(it) },
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
     }
-} }pub fn get_ingest_logs() -> GetIngestLogsStmt
-{ GetIngestLogsStmt(cornucopia_async::private::Stmt::new("SELECT *
-FROM ingest_log")) } pub struct
-GetIngestLogsStmt(cornucopia_async::private::Stmt); impl GetIngestLogsStmt
-{ pub fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-) -> IngestLogEntityQuery<'a,C,
-IngestLogEntity, 0>
+}#[derive( Debug, Clone, PartialEq,)] pub struct IconEntity
+{ pub hash : String,pub webp : Vec<u8>,pub png : Vec<u8>,}pub struct IconEntityBorrowed<'a> { pub hash : &'a str,pub webp : &'a [u8],pub png : &'a [u8],}
+impl<'a> From<IconEntityBorrowed<'a>> for IconEntity
 {
-    IngestLogEntityQuery
+    fn from(IconEntityBorrowed { hash,webp,png,}: IconEntityBorrowed<'a>) -> Self
+    { Self { hash: hash.into(),webp: webp.into(),png: png.into(),} }
+}pub struct IconEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> IconEntityBorrowed,
+    mapper: fn(IconEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> IconEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(IconEntityBorrowed) -> R) ->
+    IconEntityQuery<'a,C,R,N>
     {
-        client, params: [], stmt: &mut self.0, extractor:
+        IconEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> & str,
+    mapper: fn(& str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(& str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn get_outdated_project_icon_sources() -> GetOutdatedProjectIconSourcesStmt
+{ GetOutdatedProjectIconSourcesStmt(cornucopia_async::private::Stmt::new("SELECT source.repository, source.project_id, source.icon_url, source.icon_data
+FROM (
+    SELECT 'Spigot'::ingest_log_repository AS repository, id::text AS project_id, icon_url, icon_data
+    FROM spigot_resource
+    WHERE date_deleted IS NULL AND icon_data <> ''
+  UNION ALL
+    SELECT 'Modrinth', id, icon_url, NULL
+    FROM modrinth_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Hangar', slug, icon_url, NULL
+    FROM hangar_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'CurseForge', id::text, icon_url, NULL
+    FROM curseforge_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Polymart', id::text, icon_url, NULL
+    FROM polymart_resource
+    WHERE date_deleted IS NULL
+) AS source
+LEFT JOIN project_icon
+ON  project_icon.repository = source.repository
+AND project_icon.project_id = source.project_id
+WHERE source.icon_url <> ''
+  AND source.icon_url IS DISTINCT FROM project_icon.source_url
+ORDER BY source.repository, source.project_id")) } pub struct
+GetOutdatedProjectIconSourcesStmt(cornucopia_async::private::Stmt); impl GetOutdatedProjectIconSourcesStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> ProjectIconSourceEntityQuery<'a,C, ProjectIconSourceEntity,
+0>
+{
+    ProjectIconSourceEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { ProjectIconSourceEntityBorrowed { repository: row.get(0),project_id: row.get(1),icon_url: row.get(2),icon_data: row.get(3),} }, mapper: |it| { <ProjectIconSourceEntity>::from(it) },
+    }
+} }pub fn icon_exists() -> IconExistsStmt
+{ IconExistsStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+  SELECT 1
+  FROM icon
+  WHERE hash = $1
+)")) } pub struct
+IconExistsStmt(cornucopia_async::private::Stmt); impl IconExistsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+hash: &'a T1,) -> BoolQuery<'a,C, bool,
+1>
+{
+    BoolQuery
+    {
+        client, params: [hash,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }pub fn insert_icon() -> InsertIconStmt
+{ InsertIconStmt(cornucopia_async::private::Stmt::new("INSERT INTO icon (hash, webp, png, date_created)
+  VALUES ($1, $2, $3, NOW())
+  ON CONFLICT (hash)
+  DO NOTHING")) } pub struct
+InsertIconStmt(cornucopia_async::private::Stmt); impl InsertIconStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::BytesSql,T3:
+cornucopia_async::BytesSql,>(&'a mut self, client: &'a  C,
+hash: &'a T1,webp: &'a T2,png: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[hash,webp,png,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::BytesSql,T3: cornucopia_async::BytesSql,>
+cornucopia_async::Params<'a, InsertIconParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InsertIconStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InsertIconParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.hash,&params.webp,&params.png,)) }
+}pub fn get_icon() -> GetIconStmt
+{ GetIconStmt(cornucopia_async::private::Stmt::new("SELECT hash, webp, png
+FROM icon
+WHERE hash = $1")) } pub struct
+GetIconStmt(cornucopia_async::private::Stmt); impl GetIconStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+hash: &'a T1,) -> IconEntityQuery<'a,C, IconEntity,
+1>
+{
+    IconEntityQuery
+    {
+        client, params: [hash,], stmt: &mut self.0, extractor:
+        |row| { IconEntityBorrowed { hash: row.get(0),webp: row.get(1),png: row.get(2),} }, mapper: |it| { <IconEntity>::from(it) },
+    }
+} }pub fn upsert_project_icon() -> UpsertProjectIconStmt
+{ UpsertProjectIconStmt(cornucopia_async::private::Stmt::new("INSERT INTO project_icon (repository, project_id, source_url, hash, date_updated)
+  VALUES ($1, $2, $3, $4, NOW())
+  ON CONFLICT (repository, project_id)
+  DO UPDATE SET
+    source_url = EXCLUDED.source_url,
+    hash = EXCLUDED.hash,
+    date_updated = EXCLUDED.date_updated")) } pub struct
+UpsertProjectIconStmt(cornucopia_async::private::Stmt); impl UpsertProjectIconStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,project_id: &'a T1,source_url: &'a T2,hash: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[repository,project_id,source_url,hash,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertProjectIconParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertProjectIconStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertProjectIconParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.repository,&params.project_id,&params.source_url,&params.hash,)) }
+}pub fn get_project_icon_hash() -> GetProjectIconHashStmt
+{ GetProjectIconHashStmt(cornucopia_async::private::Stmt::new("SELECT hash
+FROM project_icon
+WHERE repository = $1 AND project_id = $2")) } pub struct
+GetProjectIconHashStmt(cornucopia_async::private::Stmt); impl GetProjectIconHashStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,project_id: &'a T1,) -> StringQuery<'a,C, String,
+2>
+{
+    StringQuery
+    {
+        client, params: [repository,project_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+GetProjectIconHashParams<T1,>, StringQuery<'a, C, String,
+2>, C> for GetProjectIconHashStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GetProjectIconHashParams<T1,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.repository,&params.project_id,) }
+}pub fn delete_unused_icons() -> DeleteUnusedIconsStmt
+{ DeleteUnusedIconsStmt(cornucopia_async::private::Stmt::new("DELETE FROM icon
+WHERE NOT EXISTS (
+  SELECT 1
+  FROM project_icon
+  WHERE project_icon.hash = icon.hash
+)")) } pub struct
+DeleteUnusedIconsStmt(cornucopia_async::private::Stmt); impl DeleteUnusedIconsStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[]).await
+} }}pub mod ingest_checkpoint
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive(Clone,Copy, Debug)] pub struct UpsertIngestCheckpointParams<> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,pub position: i32,pub date_started: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,}#[derive(Clone,Copy, Debug)] pub struct GetIngestCheckpointParams<> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,}#[derive(Clone,Copy, Debug)] pub struct DeleteIngestCheckpointParams<> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,}#[derive( Debug, Clone, PartialEq,Copy)] pub struct IngestCheckpointEntity
+{ pub repository : super::super::types::public::IngestLogRepository,pub item : super::super::types::public::IngestLogItem,pub position : i32,pub date_started : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,}pub struct IngestCheckpointEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> IngestCheckpointEntity,
+    mapper: fn(IngestCheckpointEntity) -> T,
+} impl<'a, C, T:'a, const N: usize> IngestCheckpointEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(IngestCheckpointEntity) -> R) ->
+    IngestCheckpointEntityQuery<'a,C,R,N>
+    {
+        IngestCheckpointEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct TimeOffsetDateTimeQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> time::OffsetDateTime,
+    mapper: fn(time::OffsetDateTime) -> T,
+} impl<'a, C, T:'a, const N: usize> TimeOffsetDateTimeQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(time::OffsetDateTime) -> R) ->
+    TimeOffsetDateTimeQuery<'a,C,R,N>
+    {
+        TimeOffsetDateTimeQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_ingest_checkpoint() -> UpsertIngestCheckpointStmt
+{ UpsertIngestCheckpointStmt(cornucopia_async::private::Stmt::new("INSERT INTO ingest_checkpoint (repository, item, position, date_started, date_updated)
+  VALUES ($1, $2, $3, $4, $5)
+  ON CONFLICT (repository, item)
+  DO UPDATE SET
+    position = EXCLUDED.position,
+    date_started = EXCLUDED.date_started,
+    date_updated = EXCLUDED.date_updated")) } pub struct
+UpsertIngestCheckpointStmt(cornucopia_async::private::Stmt); impl UpsertIngestCheckpointStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,position: &'a i32,date_started: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[repository,item,position,date_started,date_updated,]).await
+} }impl <'a, C: GenericClient + Send + Sync, >
+cornucopia_async::Params<'a, UpsertIngestCheckpointParams<>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertIngestCheckpointStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertIngestCheckpointParams<>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.repository,&params.item,&params.position,&params.date_started,&params.date_updated,)) }
+}pub fn get_ingest_checkpoint() -> GetIngestCheckpointStmt
+{ GetIngestCheckpointStmt(cornucopia_async::private::Stmt::new("SELECT *
+FROM ingest_checkpoint
+WHERE repository = $1 AND item = $2")) } pub struct
+GetIngestCheckpointStmt(cornucopia_async::private::Stmt); impl GetIngestCheckpointStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,) -> IngestCheckpointEntityQuery<'a,C, IngestCheckpointEntity,
+2>
+{
+    IngestCheckpointEntityQuery
+    {
+        client, params: [repository,item,], stmt: &mut self.0, extractor:
+        |row| { IngestCheckpointEntity { repository: row.get(0),item: row.get(1),position: row.get(2),date_started: row.get(3),date_updated: row.get(4),} }, mapper: |it| { <IngestCheckpointEntity>::from(it) },
+    }
+} }impl <'a, C: GenericClient,> cornucopia_async::Params<'a,
+GetIngestCheckpointParams<>, IngestCheckpointEntityQuery<'a, C, IngestCheckpointEntity,
+2>, C> for GetIngestCheckpointStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GetIngestCheckpointParams<>) -> IngestCheckpointEntityQuery<'a, C,
+    IngestCheckpointEntity, 2>
+    { self.bind(client, &params.repository,&params.item,) }
+}pub fn delete_ingest_checkpoint() -> DeleteIngestCheckpointStmt
+{ DeleteIngestCheckpointStmt(cornucopia_async::private::Stmt::new("DELETE FROM ingest_checkpoint
+WHERE repository = $1 AND item = $2")) } pub struct
+DeleteIngestCheckpointStmt(cornucopia_async::private::Stmt); impl DeleteIngestCheckpointStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[repository,item,]).await
+} }impl <'a, C: GenericClient + Send + Sync, >
+cornucopia_async::Params<'a, DeleteIngestCheckpointParams<>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for DeleteIngestCheckpointStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    DeleteIngestCheckpointParams<>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.repository,&params.item,)) }
+}pub fn get_checkpoint_start_date() -> GetCheckpointStartDateStmt
+{ GetCheckpointStartDateStmt(cornucopia_async::private::Stmt::new("SELECT NOW()")) } pub struct
+GetCheckpointStartDateStmt(cornucopia_async::private::Stmt); impl GetCheckpointStartDateStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
+{
+    TimeOffsetDateTimeQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod ingest_failure
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertIngestFailureParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,pub upstream_id: T1,pub error_kind: T2,pub message: T3,pub payload: T4,pub date_failed: time::OffsetDateTime,}#[derive(Clone,Copy, Debug)] pub struct GetIngestFailuresParams<> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,}#[derive( Debug)] pub struct DeleteIngestFailureParams<T1: cornucopia_async::StringSql,> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,pub upstream_id: T1,}#[derive(Clone,Copy, Debug)] pub struct GetIngestFailureUpstreamIdsParams<> { pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,}#[derive( Debug, Clone, PartialEq,)] pub struct IngestFailureEntity
+{ pub repository : super::super::types::public::IngestLogRepository,pub item : super::super::types::public::IngestLogItem,pub upstream_id : String,pub error_kind : String,pub message : String,pub payload : String,pub date_failed : time::OffsetDateTime,}pub struct IngestFailureEntityBorrowed<'a> { pub repository : super::super::types::public::IngestLogRepository,pub item : super::super::types::public::IngestLogItem,pub upstream_id : &'a str,pub error_kind : &'a str,pub message : &'a str,pub payload : &'a str,pub date_failed : time::OffsetDateTime,}
+impl<'a> From<IngestFailureEntityBorrowed<'a>> for IngestFailureEntity
+{
+    fn from(IngestFailureEntityBorrowed { repository,item,upstream_id,error_kind,message,payload,date_failed,}: IngestFailureEntityBorrowed<'a>) -> Self
+    { Self { repository,item,upstream_id: upstream_id.into(),error_kind: error_kind.into(),message: message.into(),payload: payload.into(),date_failed,} }
+}pub struct IngestFailureEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> IngestFailureEntityBorrowed,
+    mapper: fn(IngestFailureEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> IngestFailureEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(IngestFailureEntityBorrowed) -> R) ->
+    IngestFailureEntityQuery<'a,C,R,N>
+    {
+        IngestFailureEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> & str,
+    mapper: fn(& str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(& str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_ingest_failure() -> UpsertIngestFailureStmt
+{ UpsertIngestFailureStmt(cornucopia_async::private::Stmt::new("INSERT INTO ingest_failure (repository, item, upstream_id, error_kind, message, payload, date_failed)
+  VALUES ($1, $2, $3, $4, $5, $6, $7)
+  ON CONFLICT (repository, item, upstream_id)
+  DO UPDATE SET
+    error_kind = EXCLUDED.error_kind,
+    message = EXCLUDED.message,
+    payload = EXCLUDED.payload,
+    date_failed = EXCLUDED.date_failed")) } pub struct
+UpsertIngestFailureStmt(cornucopia_async::private::Stmt); impl UpsertIngestFailureStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,upstream_id: &'a T1,error_kind: &'a T2,message: &'a T3,payload: &'a T4,date_failed: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[repository,item,upstream_id,error_kind,message,payload,date_failed,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertIngestFailureParams<T1,T2,T3,T4,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertIngestFailureStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertIngestFailureParams<T1,T2,T3,T4,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.repository,&params.item,&params.upstream_id,&params.error_kind,&params.message,&params.payload,&params.date_failed,)) }
+}pub fn get_ingest_failures() -> GetIngestFailuresStmt
+{ GetIngestFailuresStmt(cornucopia_async::private::Stmt::new("SELECT *
+FROM ingest_failure
+WHERE repository = $1 AND item = $2
+ORDER BY date_failed")) } pub struct
+GetIngestFailuresStmt(cornucopia_async::private::Stmt); impl GetIngestFailuresStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,) -> IngestFailureEntityQuery<'a,C, IngestFailureEntity,
+2>
+{
+    IngestFailureEntityQuery
+    {
+        client, params: [repository,item,], stmt: &mut self.0, extractor:
+        |row| { IngestFailureEntityBorrowed { repository: row.get(0),item: row.get(1),upstream_id: row.get(2),error_kind: row.get(3),message: row.get(4),payload: row.get(5),date_failed: row.get(6),} }, mapper: |it| { <IngestFailureEntity>::from(it) },
+    }
+} }impl <'a, C: GenericClient,> cornucopia_async::Params<'a,
+GetIngestFailuresParams<>, IngestFailureEntityQuery<'a, C, IngestFailureEntity,
+2>, C> for GetIngestFailuresStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GetIngestFailuresParams<>) -> IngestFailureEntityQuery<'a, C,
+    IngestFailureEntity, 2>
+    { self.bind(client, &params.repository,&params.item,) }
+}pub fn delete_ingest_failure() -> DeleteIngestFailureStmt
+{ DeleteIngestFailureStmt(cornucopia_async::private::Stmt::new("DELETE FROM ingest_failure
+WHERE repository = $1 AND item = $2 AND upstream_id = $3")) } pub struct
+DeleteIngestFailureStmt(cornucopia_async::private::Stmt); impl DeleteIngestFailureStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,upstream_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[repository,item,upstream_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, DeleteIngestFailureParams<T1,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for DeleteIngestFailureStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    DeleteIngestFailureParams<T1,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.repository,&params.item,&params.upstream_id,)) }
+}pub fn get_ingest_failure_upstream_ids() -> GetIngestFailureUpstreamIdsStmt
+{ GetIngestFailureUpstreamIdsStmt(cornucopia_async::private::Stmt::new("SELECT upstream_id
+FROM ingest_failure
+WHERE repository = $1 AND item = $2")) } pub struct
+GetIngestFailureUpstreamIdsStmt(cornucopia_async::private::Stmt); impl GetIngestFailureUpstreamIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,) -> StringQuery<'a,C, String,
+2>
+{
+    StringQuery
+    {
+        client, params: [repository,item,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,> cornucopia_async::Params<'a,
+GetIngestFailureUpstreamIdsParams<>, StringQuery<'a, C, String,
+2>, C> for GetIngestFailureUpstreamIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GetIngestFailureUpstreamIdsParams<>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.repository,&params.item,) }
+}}pub mod ingest_lock
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn try_acquire_ingest_lock() -> TryAcquireIngestLockStmt
+{ TryAcquireIngestLockStmt(cornucopia_async::private::Stmt::new("SELECT pg_try_advisory_lock($1) AS acquired")) } pub struct
+TryAcquireIngestLockStmt(cornucopia_async::private::Stmt); impl TryAcquireIngestLockStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+key: &'a i64,) -> BoolQuery<'a,C, bool,
+1>
+{
+    BoolQuery
+    {
+        client, params: [key,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }pub fn release_ingest_lock() -> ReleaseIngestLockStmt
+{ ReleaseIngestLockStmt(cornucopia_async::private::Stmt::new("SELECT pg_advisory_unlock($1) AS released")) } pub struct
+ReleaseIngestLockStmt(cornucopia_async::private::Stmt); impl ReleaseIngestLockStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+key: &'a i64,) -> BoolQuery<'a,C, bool,
+1>
+{
+    BoolQuery
+    {
+        client, params: [key,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod ingest_log
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive(Clone,Copy, Debug)] pub struct InsertIngestLogParams<> { pub action: super::super::types::public::IngestLogAction,pub repository: super::super::types::public::IngestLogRepository,pub item: super::super::types::public::IngestLogItem,pub date_started: time::OffsetDateTime,pub date_finished: time::OffsetDateTime,pub items_processed: i32,pub success: bool,}#[derive( Debug, Clone, PartialEq,Copy)] pub struct IngestLogEntity
+{ pub id : i32,pub action : super::super::types::public::IngestLogAction,pub repository : super::super::types::public::IngestLogRepository,pub item : super::super::types::public::IngestLogItem,pub date_started : time::OffsetDateTime,pub date_finished : time::OffsetDateTime,pub items_processed : i32,pub success : bool,}pub struct IngestLogEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> IngestLogEntity,
+    mapper: fn(IngestLogEntity) -> T,
+} impl<'a, C, T:'a, const N: usize> IngestLogEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(IngestLogEntity) -> R) ->
+    IngestLogEntityQuery<'a,C,R,N>
+    {
+        IngestLogEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn insert_ingest_log() -> InsertIngestLogStmt
+{ InsertIngestLogStmt(cornucopia_async::private::Stmt::new("INSERT INTO ingest_log (action, repository, item, date_started, date_finished, items_processed, success)
+  VALUES ($1, $2, $3, $4, $5, $6, $7)")) } pub struct
+InsertIngestLogStmt(cornucopia_async::private::Stmt); impl InsertIngestLogStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+action: &'a super::super::types::public::IngestLogAction,repository: &'a super::super::types::public::IngestLogRepository,item: &'a super::super::types::public::IngestLogItem,date_started: &'a time::OffsetDateTime,date_finished: &'a time::OffsetDateTime,items_processed: &'a i32,success: &'a bool,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[action,repository,item,date_started,date_finished,items_processed,success,]).await
+} }impl <'a, C: GenericClient + Send + Sync, >
+cornucopia_async::Params<'a, InsertIngestLogParams<>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InsertIngestLogStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InsertIngestLogParams<>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.action,&params.repository,&params.item,&params.date_started,&params.date_finished,&params.items_processed,&params.success,)) }
+}pub fn get_last_successful_ingest_log() -> GetLastSuccessfulIngestLogStmt
+{ GetLastSuccessfulIngestLogStmt(cornucopia_async::private::Stmt::new("SELECT *
+FROM ingest_log
+WHERE success = TRUE
+ORDER BY id DESC
+LIMIT 1")) } pub struct
+GetLastSuccessfulIngestLogStmt(cornucopia_async::private::Stmt); impl GetLastSuccessfulIngestLogStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> IngestLogEntityQuery<'a,C, IngestLogEntity,
+0>
+{
+    IngestLogEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
         |row| { IngestLogEntity { id: row.get(0),action: row.get(1),repository: row.get(2),item: row.get(3),date_started: row.get(4),date_finished: row.get(5),items_processed: row.get(6),success: row.get(7),} }, mapper: |it| { <IngestLogEntity>::from(it) },
     }
-} }}pub mod modrinth_project
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertModrinthProjectParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::StringSql,> { pub id: T1,pub slug: T2,pub name: T3,pub description: T4,pub author: T5,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T6>,pub downloads: i32,pub follows: i32,pub version_id: Option<T7>,pub version_name: Option<T8>,pub status: T9,pub icon_url: Option<T10>,pub source_url: Option<T11>,pub source_repository_host: Option<T12>,pub source_repository_owner: Option<T13>,pub source_repository_name: Option<T14>,}#[derive( Debug, Clone, PartialEq,)] pub struct ModrinthProjectEntity
-{ pub id : String,pub slug : String,pub name : String,pub description : String,pub author : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub downloads : i32,pub follows : i32,pub version_id : Option<String>,pub version_name : Option<String>,pub status : String,pub icon_url : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,}pub struct ModrinthProjectEntityBorrowed<'a> { pub id : &'a str,pub slug : &'a str,pub name : &'a str,pub description : &'a str,pub author : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub downloads : i32,pub follows : i32,pub version_id : Option<&'a str>,pub version_name : Option<&'a str>,pub status : &'a str,pub icon_url : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,}
-impl<'a> From<ModrinthProjectEntityBorrowed<'a>> for ModrinthProjectEntity
+} }pub fn get_ingest_logs() -> GetIngestLogsStmt
+{ GetIngestLogsStmt(cornucopia_async::private::Stmt::new("SELECT *
+FROM ingest_log")) } pub struct
+GetIngestLogsStmt(cornucopia_async::private::Stmt); impl GetIngestLogsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> IngestLogEntityQuery<'a,C, IngestLogEntity,
+0>
+{
+    IngestLogEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { IngestLogEntity { id: row.get(0),action: row.get(1),repository: row.get(2),item: row.get(3),date_started: row.get(4),date_finished: row.get(5),items_processed: row.get(6),success: row.get(7),} }, mapper: |it| { <IngestLogEntity>::from(it) },
+    }
+} }}pub mod modrinth_project
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertModrinthProjectParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = i32>,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::StringSql,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T17: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T18: cornucopia_async::StringSql,T19: cornucopia_async::ArraySql<Item = T18>,T20: cornucopia_async::StringSql,> { pub id: T1,pub slug: T2,pub name: T3,pub description: T4,pub author: T5,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T6>,pub latest_minecraft_version_sort_key: Option<T7>,pub downloads: i32,pub follows: i32,pub version_id: Option<T8>,pub version_name: Option<T9>,pub status: T10,pub icon_url: Option<T11>,pub source_url: Option<T12>,pub source_repository_host: Option<T13>,pub source_repository_owner: Option<T14>,pub source_repository_name: Option<T15>,pub platforms: T16,pub categories: T17,pub minecraft_versions: T19,pub full_description: Option<T20>,}#[derive( Debug, Clone, PartialEq,)] pub struct ModrinthProjectEntity
+{ pub id : String,pub slug : String,pub name : String,pub description : String,pub author : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub downloads : i32,pub follows : i32,pub version_id : Option<String>,pub version_name : Option<String>,pub status : String,pub icon_url : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : Vec<super::super::types::public::ServerPlatform>,pub minecraft_versions : Vec<String>,pub latest_minecraft_version_sort_key : Option<Vec<i32>>,pub categories : Vec<super::super::types::public::ProjectCategory>,pub full_description : Option<String>,}pub struct ModrinthProjectEntityBorrowed<'a> { pub id : &'a str,pub slug : &'a str,pub name : &'a str,pub description : &'a str,pub author : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub downloads : i32,pub follows : i32,pub version_id : Option<&'a str>,pub version_name : Option<&'a str>,pub status : &'a str,pub icon_url : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>,pub minecraft_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub latest_minecraft_version_sort_key : Option<cornucopia_async::ArrayIterator<'a, i32>>,pub categories : cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>,pub full_description : Option<&'a str>,}
+impl<'a> From<ModrinthProjectEntityBorrowed<'a>> for ModrinthProjectEntity
+{
+    fn from(ModrinthProjectEntityBorrowed { id,slug,name,description,author,date_created,date_updated,latest_minecraft_version,downloads,follows,version_id,version_name,status,icon_url,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,date_last_seen,date_deleted,platforms,minecraft_versions,latest_minecraft_version_sort_key,categories,full_description,}: ModrinthProjectEntityBorrowed<'a>) -> Self
+    { Self { id: id.into(),slug: slug.into(),name: name.into(),description: description.into(),author: author.into(),date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),downloads,follows,version_id: version_id.map(|v| v.into()),version_name: version_name.map(|v| v.into()),status: status.into(),icon_url: icon_url.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),date_last_seen,date_deleted,platforms: platforms.map(|v| v).collect(),minecraft_versions: minecraft_versions.map(|v| v.into()).collect(),latest_minecraft_version_sort_key: latest_minecraft_version_sort_key.map(|v| v.map(|v| v).collect()),categories: categories.map(|v| v).collect(),full_description: full_description.map(|v| v.into()),} }
+}pub struct ModrinthProjectEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ModrinthProjectEntityBorrowed,
+    mapper: fn(ModrinthProjectEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ModrinthProjectEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(ModrinthProjectEntityBorrowed) -> R) ->
+    ModrinthProjectEntityQuery<'a,C,R,N>
+    {
+        ModrinthProjectEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct TimeOffsetDateTimeQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> time::OffsetDateTime,
+    mapper: fn(time::OffsetDateTime) -> T,
+} impl<'a, C, T:'a, const N: usize> TimeOffsetDateTimeQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(time::OffsetDateTime) -> R) ->
+    TimeOffsetDateTimeQuery<'a,C,R,N>
+    {
+        TimeOffsetDateTimeQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_modrinth_project() -> UpsertModrinthProjectStmt
+{ UpsertModrinthProjectStmt(cornucopia_async::private::Stmt::new("INSERT INTO modrinth_project (id, slug, name, description, author, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, follows, version_id, version_name, status, icon_url, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, categories, minecraft_versions, full_description, date_last_seen)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, NOW())
+  ON CONFLICT(id)
+  DO UPDATE SET
+    id = EXCLUDED.id,
+    slug = EXCLUDED.slug,
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
+    downloads = EXCLUDED.downloads,
+    follows = EXCLUDED.follows,
+    version_id = EXCLUDED.version_id,
+    version_name = EXCLUDED.version_name,
+    status = EXCLUDED.status,
+    icon_url = EXCLUDED.icon_url,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    full_description = EXCLUDED.full_description,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL")) } pub struct
+UpsertModrinthProjectStmt(cornucopia_async::private::Stmt); impl UpsertModrinthProjectStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = i32>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::StringSql,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::StringSql,T14:
+cornucopia_async::StringSql,T15:
+cornucopia_async::StringSql,T16:
+cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T17:
+cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T18:
+cornucopia_async::StringSql,T19:
+cornucopia_async::ArraySql<Item = T18>,T20:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,slug: &'a T2,name: &'a T3,description: &'a T4,author: &'a T5,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T6>,latest_minecraft_version_sort_key: &'a Option<T7>,downloads: &'a i32,follows: &'a i32,version_id: &'a Option<T8>,version_name: &'a Option<T9>,status: &'a T10,icon_url: &'a Option<T11>,source_url: &'a Option<T12>,source_repository_host: &'a Option<T13>,source_repository_owner: &'a Option<T14>,source_repository_name: &'a Option<T15>,platforms: &'a T16,categories: &'a T17,minecraft_versions: &'a T19,full_description: &'a Option<T20>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,slug,name,description,author,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,follows,version_id,version_name,status,icon_url,source_url,source_repository_host,source_repository_owner,source_repository_name,platforms,categories,minecraft_versions,full_description,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = i32>,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::StringSql,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T17: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T18: cornucopia_async::StringSql,T19: cornucopia_async::ArraySql<Item = T18>,T20: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertModrinthProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,T19,T20,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertModrinthProjectStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertModrinthProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,T19,T20,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.slug,&params.name,&params.description,&params.author,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.latest_minecraft_version_sort_key,&params.downloads,&params.follows,&params.version_id,&params.version_name,&params.status,&params.icon_url,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,&params.platforms,&params.categories,&params.minecraft_versions,&params.full_description,)) }
+}pub fn get_modrinth_projects() -> GetModrinthProjectsStmt
+{ GetModrinthProjectsStmt(cornucopia_async::private::Stmt::new("SELECT * FROM modrinth_project
+WHERE date_deleted IS NULL")) } pub struct
+GetModrinthProjectsStmt(cornucopia_async::private::Stmt); impl GetModrinthProjectsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> ModrinthProjectEntityQuery<'a,C, ModrinthProjectEntity,
+0>
+{
+    ModrinthProjectEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { ModrinthProjectEntityBorrowed { id: row.get(0),slug: row.get(1),name: row.get(2),description: row.get(3),author: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),downloads: row.get(8),follows: row.get(9),version_id: row.get(10),version_name: row.get(11),status: row.get(12),icon_url: row.get(13),source_url: row.get(14),source_repository_host: row.get(15),source_repository_owner: row.get(16),source_repository_name: row.get(17),source_repository_id: row.get(18),date_last_seen: row.get(19),date_deleted: row.get(20),platforms: row.get(21),minecraft_versions: row.get(22),latest_minecraft_version_sort_key: row.get(23),categories: row.get(24),full_description: row.get(25),} }, mapper: |it| { <ModrinthProjectEntity>::from(it) },
+    }
+} }pub fn get_modrinth_project_by_id() -> GetModrinthProjectByIdStmt
+{ GetModrinthProjectByIdStmt(cornucopia_async::private::Stmt::new("SELECT * FROM modrinth_project
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetModrinthProjectByIdStmt(cornucopia_async::private::Stmt); impl GetModrinthProjectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> ModrinthProjectEntityQuery<'a,C, ModrinthProjectEntity,
+1>
+{
+    ModrinthProjectEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { ModrinthProjectEntityBorrowed { id: row.get(0),slug: row.get(1),name: row.get(2),description: row.get(3),author: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),downloads: row.get(8),follows: row.get(9),version_id: row.get(10),version_name: row.get(11),status: row.get(12),icon_url: row.get(13),source_url: row.get(14),source_repository_host: row.get(15),source_repository_owner: row.get(16),source_repository_name: row.get(17),source_repository_id: row.get(18),date_last_seen: row.get(19),date_deleted: row.get(20),platforms: row.get(21),minecraft_versions: row.get(22),latest_minecraft_version_sort_key: row.get(23),categories: row.get(24),full_description: row.get(25),} }, mapper: |it| { <ModrinthProjectEntity>::from(it) },
+    }
+} }pub fn get_latest_modrinth_project_update_date() -> GetLatestModrinthProjectUpdateDateStmt
+{ GetLatestModrinthProjectUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM modrinth_project")) } pub struct
+GetLatestModrinthProjectUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestModrinthProjectUpdateDateStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
+{
+    TimeOffsetDateTimeQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }pub fn mark_unseen_modrinth_projects_as_deleted() -> MarkUnseenModrinthProjectsAsDeletedStmt
+{ MarkUnseenModrinthProjectsAsDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE modrinth_project
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < $1)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Modrinth' AND item = 'Project' AND upstream_id = modrinth_project.id
+  )")) } pub struct
+MarkUnseenModrinthProjectsAsDeletedStmt(cornucopia_async::private::Stmt); impl MarkUnseenModrinthProjectsAsDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date_started: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date_started,]).await
+} }}pub mod modrinth_version
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertModrinthVersionParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,> { pub id: T1,pub project_id: T2,pub name: T3,pub version_number: T4,pub version_type: T5,pub loaders: T7,pub game_versions: T9,pub date_published: time::OffsetDateTime,pub downloads: i32,pub file_sha1_hashes: T11,pub file_sha512_hashes: T13,}#[derive( Debug, Clone, PartialEq,)] pub struct ModrinthVersionEntity
+{ pub id : String,pub project_id : String,pub name : String,pub version_number : String,pub version_type : String,pub loaders : Vec<String>,pub game_versions : Vec<String>,pub date_published : time::OffsetDateTime,pub downloads : i32,pub file_sha1_hashes : Vec<String>,pub file_sha512_hashes : Vec<String>,}pub struct ModrinthVersionEntityBorrowed<'a> { pub id : &'a str,pub project_id : &'a str,pub name : &'a str,pub version_number : &'a str,pub version_type : &'a str,pub loaders : cornucopia_async::ArrayIterator<'a, &'a str>,pub game_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub date_published : time::OffsetDateTime,pub downloads : i32,pub file_sha1_hashes : cornucopia_async::ArrayIterator<'a, &'a str>,pub file_sha512_hashes : cornucopia_async::ArrayIterator<'a, &'a str>,}
+impl<'a> From<ModrinthVersionEntityBorrowed<'a>> for ModrinthVersionEntity
+{
+    fn from(ModrinthVersionEntityBorrowed { id,project_id,name,version_number,version_type,loaders,game_versions,date_published,downloads,file_sha1_hashes,file_sha512_hashes,}: ModrinthVersionEntityBorrowed<'a>) -> Self
+    { Self { id: id.into(),project_id: project_id.into(),name: name.into(),version_number: version_number.into(),version_type: version_type.into(),loaders: loaders.map(|v| v.into()).collect(),game_versions: game_versions.map(|v| v.into()).collect(),date_published,downloads,file_sha1_hashes: file_sha1_hashes.map(|v| v.into()).collect(),file_sha512_hashes: file_sha512_hashes.map(|v| v.into()).collect(),} }
+}pub struct ModrinthVersionEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ModrinthVersionEntityBorrowed,
+    mapper: fn(ModrinthVersionEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ModrinthVersionEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(ModrinthVersionEntityBorrowed) -> R) ->
+    ModrinthVersionEntityQuery<'a,C,R,N>
+    {
+        ModrinthVersionEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_modrinth_version() -> UpsertModrinthVersionStmt
+{ UpsertModrinthVersionStmt(cornucopia_async::private::Stmt::new("INSERT INTO modrinth_version (id, project_id, name, version_number, version_type, loaders, game_versions, date_published, downloads, file_sha1_hashes, file_sha512_hashes)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+  ON CONFLICT (id)
+  DO UPDATE SET
+    project_id = EXCLUDED.project_id,
+    name = EXCLUDED.name,
+    version_number = EXCLUDED.version_number,
+    version_type = EXCLUDED.version_type,
+    loaders = EXCLUDED.loaders,
+    game_versions = EXCLUDED.game_versions,
+    date_published = EXCLUDED.date_published,
+    downloads = EXCLUDED.downloads,
+    file_sha1_hashes = EXCLUDED.file_sha1_hashes,
+    file_sha512_hashes = EXCLUDED.file_sha512_hashes")) } pub struct
+UpsertModrinthVersionStmt(cornucopia_async::private::Stmt); impl UpsertModrinthVersionStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = T6>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::ArraySql<Item = T8>,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::ArraySql<Item = T10>,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::ArraySql<Item = T12>,>(&'a mut self, client: &'a  C,
+id: &'a T1,project_id: &'a T2,name: &'a T3,version_number: &'a T4,version_type: &'a T5,loaders: &'a T7,game_versions: &'a T9,date_published: &'a time::OffsetDateTime,downloads: &'a i32,file_sha1_hashes: &'a T11,file_sha512_hashes: &'a T13,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,project_id,name,version_number,version_type,loaders,game_versions,date_published,downloads,file_sha1_hashes,file_sha512_hashes,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,>
+cornucopia_async::Params<'a, UpsertModrinthVersionParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertModrinthVersionStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertModrinthVersionParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.project_id,&params.name,&params.version_number,&params.version_type,&params.loaders,&params.game_versions,&params.date_published,&params.downloads,&params.file_sha1_hashes,&params.file_sha512_hashes,)) }
+}pub fn get_modrinth_versions() -> GetModrinthVersionsStmt
+{ GetModrinthVersionsStmt(cornucopia_async::private::Stmt::new("SELECT * FROM modrinth_version")) } pub struct
+GetModrinthVersionsStmt(cornucopia_async::private::Stmt); impl GetModrinthVersionsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> ModrinthVersionEntityQuery<'a,C, ModrinthVersionEntity,
+0>
+{
+    ModrinthVersionEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { ModrinthVersionEntityBorrowed { id: row.get(0),project_id: row.get(1),name: row.get(2),version_number: row.get(3),version_type: row.get(4),loaders: row.get(5),game_versions: row.get(6),date_published: row.get(7),downloads: row.get(8),file_sha1_hashes: row.get(9),file_sha512_hashes: row.get(10),} }, mapper: |it| { <ModrinthVersionEntity>::from(it) },
+    }
+} }pub fn get_modrinth_version_by_id() -> GetModrinthVersionByIdStmt
+{ GetModrinthVersionByIdStmt(cornucopia_async::private::Stmt::new("SELECT * FROM modrinth_version
+WHERE id = $1")) } pub struct
+GetModrinthVersionByIdStmt(cornucopia_async::private::Stmt); impl GetModrinthVersionByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> ModrinthVersionEntityQuery<'a,C, ModrinthVersionEntity,
+1>
+{
+    ModrinthVersionEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { ModrinthVersionEntityBorrowed { id: row.get(0),project_id: row.get(1),name: row.get(2),version_number: row.get(3),version_type: row.get(4),loaders: row.get(5),game_versions: row.get(6),date_published: row.get(7),downloads: row.get(8),file_sha1_hashes: row.get(9),file_sha512_hashes: row.get(10),} }, mapper: |it| { <ModrinthVersionEntity>::from(it) },
+    }
+} }pub fn get_modrinth_versions_for_project() -> GetModrinthVersionsForProjectStmt
+{ GetModrinthVersionsForProjectStmt(cornucopia_async::private::Stmt::new("SELECT * FROM modrinth_version
+WHERE project_id = $1
+ORDER BY date_published DESC")) } pub struct
+GetModrinthVersionsForProjectStmt(cornucopia_async::private::Stmt); impl GetModrinthVersionsForProjectStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+project_id: &'a T1,) -> ModrinthVersionEntityQuery<'a,C, ModrinthVersionEntity,
+1>
+{
+    ModrinthVersionEntityQuery
+    {
+        client, params: [project_id,], stmt: &mut self.0, extractor:
+        |row| { ModrinthVersionEntityBorrowed { id: row.get(0),project_id: row.get(1),name: row.get(2),version_number: row.get(3),version_type: row.get(4),loaders: row.get(5),game_versions: row.get(6),date_published: row.get(7),downloads: row.get(8),file_sha1_hashes: row.get(9),file_sha512_hashes: row.get(10),} }, mapper: |it| { <ModrinthVersionEntity>::from(it) },
+    }
+} }}pub mod polymart_resource
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertPolymartResourceParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = i32>,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T13: cornucopia_async::StringSql,T14: cornucopia_async::ArraySql<Item = T13>,T15: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,> { pub id: i32,pub name: T1,pub description: T2,pub author: T3,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T4>,pub latest_minecraft_version_sort_key: Option<T5>,pub downloads: i32,pub premium: bool,pub icon_url: Option<T6>,pub version_name: Option<T7>,pub source_url: Option<T8>,pub source_repository_host: Option<T9>,pub source_repository_owner: Option<T10>,pub source_repository_name: Option<T11>,pub platforms: T12,pub minecraft_versions: T14,pub categories: T15,}#[derive( Debug, Clone, PartialEq,)] pub struct PolymartResourceEntity
+{ pub id : i32,pub name : String,pub description : String,pub author : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub latest_minecraft_version_sort_key : Option<Vec<i32>>,pub downloads : i32,pub premium : bool,pub icon_url : Option<String>,pub version_name : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : Vec<super::super::types::public::ServerPlatform>,pub minecraft_versions : Vec<String>,pub categories : Vec<super::super::types::public::ProjectCategory>,}pub struct PolymartResourceEntityBorrowed<'a> { pub id : i32,pub name : &'a str,pub description : &'a str,pub author : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub latest_minecraft_version_sort_key : Option<cornucopia_async::ArrayIterator<'a, i32>>,pub downloads : i32,pub premium : bool,pub icon_url : Option<&'a str>,pub version_name : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>,pub minecraft_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub categories : cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>,}
+impl<'a> From<PolymartResourceEntityBorrowed<'a>> for PolymartResourceEntity
+{
+    fn from(PolymartResourceEntityBorrowed { id,name,description,author,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,premium,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,date_last_seen,date_deleted,platforms,minecraft_versions,categories,}: PolymartResourceEntityBorrowed<'a>) -> Self
+    { Self { id,name: name.into(),description: description.into(),author: author.into(),date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),latest_minecraft_version_sort_key: latest_minecraft_version_sort_key.map(|v| v.map(|v| v).collect()),downloads,premium,icon_url: icon_url.map(|v| v.into()),version_name: version_name.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),date_last_seen,date_deleted,platforms: platforms.map(|v| v).collect(),minecraft_versions: minecraft_versions.map(|v| v.into()).collect(),categories: categories.map(|v| v).collect(),} }
+}pub struct PolymartResourceEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> PolymartResourceEntityBorrowed,
+    mapper: fn(PolymartResourceEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> PolymartResourceEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(PolymartResourceEntityBorrowed) -> R) ->
+    PolymartResourceEntityQuery<'a,C,R,N>
+    {
+        PolymartResourceEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub struct TimeOffsetDateTimeQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> time::OffsetDateTime,
+    mapper: fn(time::OffsetDateTime) -> T,
+} impl<'a, C, T:'a, const N: usize> TimeOffsetDateTimeQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(time::OffsetDateTime) -> R) ->
+    TimeOffsetDateTimeQuery<'a,C,R,N>
+    {
+        TimeOffsetDateTimeQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_polymart_resource() -> UpsertPolymartResourceStmt
+{ UpsertPolymartResourceStmt(cornucopia_async::private::Stmt::new("INSERT INTO polymart_resource (id, name, description, author, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, premium, icon_url, version_name, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, minecraft_versions, categories, date_last_seen)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, NOW())
+  ON CONFLICT (id)
+  DO UPDATE SET
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    author = EXCLUDED.author,
+    date_created = EXCLUDED.date_created,
+    date_updated = EXCLUDED.date_updated,
+    latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
+    downloads = EXCLUDED.downloads,
+    premium = EXCLUDED.premium,
+    icon_url = EXCLUDED.icon_url,
+    version_name = EXCLUDED.version_name,
+    source_url = EXCLUDED.source_url,
+    source_repository_host = EXCLUDED.source_repository_host,
+    source_repository_owner = EXCLUDED.source_repository_owner,
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    categories = EXCLUDED.categories,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL")) } pub struct
+UpsertPolymartResourceStmt(cornucopia_async::private::Stmt); impl UpsertPolymartResourceStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::ArraySql<Item = i32>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::StringSql,T12:
+cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T13:
+cornucopia_async::StringSql,T14:
+cornucopia_async::ArraySql<Item = T13>,T15:
+cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,>(&'a mut self, client: &'a  C,
+id: &'a i32,name: &'a T1,description: &'a T2,author: &'a T3,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T4>,latest_minecraft_version_sort_key: &'a Option<T5>,downloads: &'a i32,premium: &'a bool,icon_url: &'a Option<T6>,version_name: &'a Option<T7>,source_url: &'a Option<T8>,source_repository_host: &'a Option<T9>,source_repository_owner: &'a Option<T10>,source_repository_name: &'a Option<T11>,platforms: &'a T12,minecraft_versions: &'a T14,categories: &'a T15,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,name,description,author,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,premium,icon_url,version_name,source_url,source_repository_host,source_repository_owner,source_repository_name,platforms,minecraft_versions,categories,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = i32>,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T13: cornucopia_async::StringSql,T14: cornucopia_async::ArraySql<Item = T13>,T15: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,>
+cornucopia_async::Params<'a, UpsertPolymartResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertPolymartResourceStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertPolymartResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.name,&params.description,&params.author,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.latest_minecraft_version_sort_key,&params.downloads,&params.premium,&params.icon_url,&params.version_name,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,&params.platforms,&params.minecraft_versions,&params.categories,)) }
+}pub fn get_polymart_resources() -> GetPolymartResourcesStmt
+{ GetPolymartResourcesStmt(cornucopia_async::private::Stmt::new("SELECT * FROM polymart_resource
+WHERE date_deleted IS NULL")) } pub struct
+GetPolymartResourcesStmt(cornucopia_async::private::Stmt); impl GetPolymartResourcesStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> PolymartResourceEntityQuery<'a,C, PolymartResourceEntity,
+0>
+{
+    PolymartResourceEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { PolymartResourceEntityBorrowed { id: row.get(0),name: row.get(1),description: row.get(2),author: row.get(3),date_created: row.get(4),date_updated: row.get(5),latest_minecraft_version: row.get(6),latest_minecraft_version_sort_key: row.get(7),downloads: row.get(8),premium: row.get(9),icon_url: row.get(10),version_name: row.get(11),source_url: row.get(12),source_repository_host: row.get(13),source_repository_owner: row.get(14),source_repository_name: row.get(15),source_repository_id: row.get(16),date_last_seen: row.get(17),date_deleted: row.get(18),platforms: row.get(19),minecraft_versions: row.get(20),categories: row.get(21),} }, mapper: |it| { <PolymartResourceEntity>::from(it) },
+    }
+} }pub fn get_polymart_resource_by_id() -> GetPolymartResourceByIdStmt
+{ GetPolymartResourceByIdStmt(cornucopia_async::private::Stmt::new("SELECT * FROM polymart_resource
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetPolymartResourceByIdStmt(cornucopia_async::private::Stmt); impl GetPolymartResourceByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+id: &'a i32,) -> PolymartResourceEntityQuery<'a,C, PolymartResourceEntity,
+1>
+{
+    PolymartResourceEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { PolymartResourceEntityBorrowed { id: row.get(0),name: row.get(1),description: row.get(2),author: row.get(3),date_created: row.get(4),date_updated: row.get(5),latest_minecraft_version: row.get(6),latest_minecraft_version_sort_key: row.get(7),downloads: row.get(8),premium: row.get(9),icon_url: row.get(10),version_name: row.get(11),source_url: row.get(12),source_repository_host: row.get(13),source_repository_owner: row.get(14),source_repository_name: row.get(15),source_repository_id: row.get(16),date_last_seen: row.get(17),date_deleted: row.get(18),platforms: row.get(19),minecraft_versions: row.get(20),categories: row.get(21),} }, mapper: |it| { <PolymartResourceEntity>::from(it) },
+    }
+} }pub fn get_latest_polymart_resource_update_date() -> GetLatestPolymartResourceUpdateDateStmt
+{ GetLatestPolymartResourceUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM polymart_resource")) } pub struct
+GetLatestPolymartResourceUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestPolymartResourceUpdateDateStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
+{
+    TimeOffsetDateTimeQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }pub fn mark_unseen_polymart_resources_as_deleted() -> MarkUnseenPolymartResourcesAsDeletedStmt
+{ MarkUnseenPolymartResourcesAsDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE polymart_resource
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < $1)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Polymart' AND item = 'Resource' AND upstream_id = polymart_resource.id::text
+  )")) } pub struct
+MarkUnseenPolymartResourcesAsDeletedStmt(cornucopia_async::private::Stmt); impl MarkUnseenPolymartResourcesAsDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date_started: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date_started,]).await
+} }}pub mod project_description
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct ProjectDescriptionEntity
+{ pub name : String,pub description : String,pub full_description : Option<String>,}pub struct ProjectDescriptionEntityBorrowed<'a> { pub name : &'a str,pub description : &'a str,pub full_description : Option<&'a str>,}
+impl<'a> From<ProjectDescriptionEntityBorrowed<'a>> for ProjectDescriptionEntity
+{
+    fn from(ProjectDescriptionEntityBorrowed { name,description,full_description,}: ProjectDescriptionEntityBorrowed<'a>) -> Self
+    { Self { name: name.into(),description: description.into(),full_description: full_description.map(|v| v.into()),} }
+}pub struct ProjectDescriptionEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ProjectDescriptionEntityBorrowed,
+    mapper: fn(ProjectDescriptionEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ProjectDescriptionEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(ProjectDescriptionEntityBorrowed) -> R) ->
+    ProjectDescriptionEntityQuery<'a,C,R,N>
+    {
+        ProjectDescriptionEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn get_spigot_resource_description() -> GetSpigotResourceDescriptionStmt
+{ GetSpigotResourceDescriptionStmt(cornucopia_async::private::Stmt::new("SELECT COALESCE(parsed_name, name) AS name, description, full_description
+FROM spigot_resource
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetSpigotResourceDescriptionStmt(cornucopia_async::private::Stmt); impl GetSpigotResourceDescriptionStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+id: &'a i32,) -> ProjectDescriptionEntityQuery<'a,C, ProjectDescriptionEntity,
+1>
+{
+    ProjectDescriptionEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { ProjectDescriptionEntityBorrowed { name: row.get(0),description: row.get(1),full_description: row.get(2),} }, mapper: |it| { <ProjectDescriptionEntity>::from(it) },
+    }
+} }pub fn get_modrinth_project_description() -> GetModrinthProjectDescriptionStmt
+{ GetModrinthProjectDescriptionStmt(cornucopia_async::private::Stmt::new("SELECT name, description, full_description
+FROM modrinth_project
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetModrinthProjectDescriptionStmt(cornucopia_async::private::Stmt); impl GetModrinthProjectDescriptionStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> ProjectDescriptionEntityQuery<'a,C, ProjectDescriptionEntity,
+1>
+{
+    ProjectDescriptionEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { ProjectDescriptionEntityBorrowed { name: row.get(0),description: row.get(1),full_description: row.get(2),} }, mapper: |it| { <ProjectDescriptionEntity>::from(it) },
+    }
+} }pub fn get_hangar_project_description() -> GetHangarProjectDescriptionStmt
+{ GetHangarProjectDescriptionStmt(cornucopia_async::private::Stmt::new("SELECT name, description, full_description
+FROM hangar_project
+WHERE slug = $1 AND date_deleted IS NULL")) } pub struct
+GetHangarProjectDescriptionStmt(cornucopia_async::private::Stmt); impl GetHangarProjectDescriptionStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+slug: &'a T1,) -> ProjectDescriptionEntityQuery<'a,C, ProjectDescriptionEntity,
+1>
+{
+    ProjectDescriptionEntityQuery
+    {
+        client, params: [slug,], stmt: &mut self.0, extractor:
+        |row| { ProjectDescriptionEntityBorrowed { name: row.get(0),description: row.get(1),full_description: row.get(2),} }, mapper: |it| { <ProjectDescriptionEntity>::from(it) },
+    }
+} }}pub mod project_stats_snapshot
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct GetProjectStatsSnapshotsParams<T1: cornucopia_async::StringSql,> { pub repository: super::super::types::public::IngestLogRepository,pub project_id: T1,}#[derive( Debug)] pub struct InsertProjectStatsSnapshotParams<T1: cornucopia_async::StringSql,> { pub repository: super::super::types::public::IngestLogRepository,pub project_id: T1,pub date: time::Date,pub downloads: i32,pub likes: Option<i32>,pub follows: Option<i32>,pub stars: Option<i32>,pub watchers: Option<i32>,}#[derive( Debug, Clone, PartialEq,)] pub struct ProjectStatsSnapshotEntity
+{ pub repository : super::super::types::public::IngestLogRepository,pub project_id : String,pub date : time::Date,pub downloads : i32,pub likes : Option<i32>,pub follows : Option<i32>,pub stars : Option<i32>,pub watchers : Option<i32>,}pub struct ProjectStatsSnapshotEntityBorrowed<'a> { pub repository : super::super::types::public::IngestLogRepository,pub project_id : &'a str,pub date : time::Date,pub downloads : i32,pub likes : Option<i32>,pub follows : Option<i32>,pub stars : Option<i32>,pub watchers : Option<i32>,}
+impl<'a> From<ProjectStatsSnapshotEntityBorrowed<'a>> for ProjectStatsSnapshotEntity
+{
+    fn from(ProjectStatsSnapshotEntityBorrowed { repository,project_id,date,downloads,likes,follows,stars,watchers,}: ProjectStatsSnapshotEntityBorrowed<'a>) -> Self
+    { Self { repository,project_id: project_id.into(),date,downloads,likes,follows,stars,watchers,} }
+}pub struct ProjectStatsSnapshotEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ProjectStatsSnapshotEntityBorrowed,
+    mapper: fn(ProjectStatsSnapshotEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ProjectStatsSnapshotEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(ProjectStatsSnapshotEntityBorrowed) -> R) ->
+    ProjectStatsSnapshotEntityQuery<'a,C,R,N>
+    {
+        ProjectStatsSnapshotEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn insert_project_stats_snapshots() -> InsertProjectStatsSnapshotsStmt
+{ InsertProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt::new("INSERT INTO project_stats_snapshot (repository, project_id, date, downloads, likes, follows, stars, watchers)
+SELECT repository, project_id, $1, downloads, likes, follows, stars, watchers
+FROM (
+    SELECT 'Spigot'::ingest_log_repository AS repository, id::text AS project_id, downloads, likes, NULL::integer AS follows, NULL::integer AS stars, NULL::integer AS watchers
+    FROM spigot_resource
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Modrinth', id, downloads, NULL, follows, NULL, NULL
+    FROM modrinth_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Hangar', slug, downloads, NULL, NULL, stars, watchers
+    FROM hangar_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'CurseForge', id::text, downloads, likes, NULL, NULL, NULL
+    FROM curseforge_project
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'Polymart', id::text, downloads, NULL, NULL, NULL, NULL
+    FROM polymart_resource
+    WHERE date_deleted IS NULL
+  UNION ALL
+    SELECT 'BuiltByBit', id::text, downloads, NULL, NULL, NULL, NULL
+    FROM builtbybit_resource
+    WHERE date_deleted IS NULL
+) AS stats
+ON CONFLICT (repository, project_id, date)
+DO UPDATE SET
+  downloads = EXCLUDED.downloads,
+  likes = EXCLUDED.likes,
+  follows = EXCLUDED.follows,
+  stars = EXCLUDED.stars,
+  watchers = EXCLUDED.watchers")) } pub struct
+InsertProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt); impl InsertProjectStatsSnapshotsStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date: &'a time::Date,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date,]).await
+} }pub fn get_project_stats_snapshots() -> GetProjectStatsSnapshotsStmt
+{ GetProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt::new("SELECT *
+FROM project_stats_snapshot
+WHERE repository = $1 AND project_id = $2
+ORDER BY date")) } pub struct
+GetProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt); impl GetProjectStatsSnapshotsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,project_id: &'a T1,) -> ProjectStatsSnapshotEntityQuery<'a,C, ProjectStatsSnapshotEntity,
+2>
+{
+    ProjectStatsSnapshotEntityQuery
+    {
+        client, params: [repository,project_id,], stmt: &mut self.0, extractor:
+        |row| { ProjectStatsSnapshotEntityBorrowed { repository: row.get(0),project_id: row.get(1),date: row.get(2),downloads: row.get(3),likes: row.get(4),follows: row.get(5),stars: row.get(6),watchers: row.get(7),} }, mapper: |it| { <ProjectStatsSnapshotEntity>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+GetProjectStatsSnapshotsParams<T1,>, ProjectStatsSnapshotEntityQuery<'a, C, ProjectStatsSnapshotEntity,
+2>, C> for GetProjectStatsSnapshotsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GetProjectStatsSnapshotsParams<T1,>) -> ProjectStatsSnapshotEntityQuery<'a, C,
+    ProjectStatsSnapshotEntity, 2>
+    { self.bind(client, &params.repository,&params.project_id,) }
+}pub fn downsample_project_stats_snapshots() -> DownsampleProjectStatsSnapshotsStmt
+{ DownsampleProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt::new("DELETE FROM project_stats_snapshot s
+WHERE s.date < $1
+  AND EXISTS (
+    SELECT 1
+    FROM project_stats_snapshot t
+    WHERE t.repository = s.repository
+      AND t.project_id = s.project_id
+      AND date_trunc('week', t.date) = date_trunc('week', s.date)
+      AND t.date > s.date
+  )")) } pub struct
+DownsampleProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt); impl DownsampleProjectStatsSnapshotsStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+daily_cutoff: &'a time::Date,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[daily_cutoff,]).await
+} }pub fn delete_expired_project_stats_snapshots() -> DeleteExpiredProjectStatsSnapshotsStmt
+{ DeleteExpiredProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt::new("DELETE FROM project_stats_snapshot
+WHERE date < $1")) } pub struct
+DeleteExpiredProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt); impl DeleteExpiredProjectStatsSnapshotsStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+retention_cutoff: &'a time::Date,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[retention_cutoff,]).await
+} }pub fn get_all_project_stats_snapshots() -> GetAllProjectStatsSnapshotsStmt
+{ GetAllProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt::new("SELECT *
+FROM project_stats_snapshot
+ORDER BY repository, project_id, date")) } pub struct
+GetAllProjectStatsSnapshotsStmt(cornucopia_async::private::Stmt); impl GetAllProjectStatsSnapshotsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> ProjectStatsSnapshotEntityQuery<'a,C, ProjectStatsSnapshotEntity,
+0>
+{
+    ProjectStatsSnapshotEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { ProjectStatsSnapshotEntityBorrowed { repository: row.get(0),project_id: row.get(1),date: row.get(2),downloads: row.get(3),likes: row.get(4),follows: row.get(5),stars: row.get(6),watchers: row.get(7),} }, mapper: |it| { <ProjectStatsSnapshotEntity>::from(it) },
+    }
+} }pub fn insert_project_stats_snapshot() -> InsertProjectStatsSnapshotStmt
+{ InsertProjectStatsSnapshotStmt(cornucopia_async::private::Stmt::new("INSERT INTO project_stats_snapshot (repository, project_id, date, downloads, likes, follows, stars, watchers)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8)")) } pub struct
+InsertProjectStatsSnapshotStmt(cornucopia_async::private::Stmt); impl InsertProjectStatsSnapshotStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+repository: &'a super::super::types::public::IngestLogRepository,project_id: &'a T1,date: &'a time::Date,downloads: &'a i32,likes: &'a Option<i32>,follows: &'a Option<i32>,stars: &'a Option<i32>,watchers: &'a Option<i32>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[repository,project_id,date,downloads,likes,follows,stars,watchers,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, InsertProjectStatsSnapshotParams<T1,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InsertProjectStatsSnapshotStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InsertProjectStatsSnapshotParams<T1,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.repository,&params.project_id,&params.date,&params.downloads,&params.likes,&params.follows,&params.stars,&params.watchers,)) }
+}}pub mod search_result
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct SearchProjectsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T4: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,> { pub spigot: bool,pub modrinth: bool,pub hangar: bool,pub curseforge: bool,pub polymart: bool,pub builtbybit: bool,pub query: T1,pub text_query: T2,pub name: bool,pub description: bool,pub full_description: bool,pub author: bool,pub platforms: T3,pub categories: T4,pub minecraft_versions: T6,pub minecraft_version_family: Option<T7>,pub exclude_archived: bool,pub sort: T8,pub full_text_weight: f32,pub author_weight: f32,pub description_weight: f32,pub categories_weight: f32,pub name_weight: f32,pub trigram_weight: f32,pub limit: i64,pub offset: i64,}#[derive( Debug, Clone, PartialEq,)] pub struct SearchResultEntity
+{ pub full_count : i64,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub downloads : i32,pub likes_and_stars : i32,pub follows_and_watchers : i32,pub spigot_id : Option<i32>,pub spigot_slug : Option<String>,pub spigot_name : Option<String>,pub spigot_description : Option<String>,pub spigot_author : Option<String>,pub spigot_version : Option<String>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_hash : Option<String>,pub modrinth_id : Option<String>,pub modrinth_slug : Option<String>,pub modrinth_name : Option<String>,pub modrinth_description : Option<String>,pub modrinth_author : Option<String>,pub modrinth_version : Option<String>,pub modrinth_status : Option<String>,pub modrinth_icon_url : Option<String>,pub modrinth_icon_hash : Option<String>,pub hangar_slug : Option<String>,pub hangar_name : Option<String>,pub hangar_description : Option<String>,pub hangar_author : Option<String>,pub hangar_version : Option<String>,pub hangar_icon_url : Option<String>,pub hangar_icon_hash : Option<String>,pub hangar_paper_versions : Option<Vec<String>>,pub hangar_velocity_versions : Option<Vec<String>>,pub hangar_waterfall_versions : Option<Vec<String>>,pub curseforge_id : Option<i32>,pub curseforge_slug : Option<String>,pub curseforge_name : Option<String>,pub curseforge_description : Option<String>,pub curseforge_author : Option<String>,pub curseforge_version : Option<String>,pub curseforge_icon_url : Option<String>,pub curseforge_icon_hash : Option<String>,pub polymart_id : Option<i32>,pub polymart_name : Option<String>,pub polymart_description : Option<String>,pub polymart_author : Option<String>,pub polymart_version : Option<String>,pub polymart_premium : Option<bool>,pub polymart_icon_url : Option<String>,pub polymart_icon_hash : Option<String>,pub builtbybit_id : Option<i32>,pub builtbybit_name : Option<String>,pub builtbybit_description : Option<String>,pub builtbybit_author : Option<String>,pub builtbybit_version : Option<String>,pub builtbybit_price : Option<f64>,pub builtbybit_currency : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub release_tag_name : Option<String>,pub release_name : Option<String>,pub release_url : Option<String>,pub release_date_published : Option<time::OffsetDateTime>,pub repository_stars : Option<i32>,pub repository_archived : Option<bool>,pub repository_license : Option<String>,pub repository_open_issues : Option<i32>,pub repository_date_last_commit : Option<time::OffsetDateTime>,}pub struct SearchResultEntityBorrowed<'a> { pub full_count : i64,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub downloads : i32,pub likes_and_stars : i32,pub follows_and_watchers : i32,pub spigot_id : Option<i32>,pub spigot_slug : Option<&'a str>,pub spigot_name : Option<&'a str>,pub spigot_description : Option<&'a str>,pub spigot_author : Option<&'a str>,pub spigot_version : Option<&'a str>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_hash : Option<&'a str>,pub modrinth_id : Option<&'a str>,pub modrinth_slug : Option<&'a str>,pub modrinth_name : Option<&'a str>,pub modrinth_description : Option<&'a str>,pub modrinth_author : Option<&'a str>,pub modrinth_version : Option<&'a str>,pub modrinth_status : Option<&'a str>,pub modrinth_icon_url : Option<&'a str>,pub modrinth_icon_hash : Option<&'a str>,pub hangar_slug : Option<&'a str>,pub hangar_name : Option<&'a str>,pub hangar_description : Option<&'a str>,pub hangar_author : Option<&'a str>,pub hangar_version : Option<&'a str>,pub hangar_icon_url : Option<&'a str>,pub hangar_icon_hash : Option<&'a str>,pub hangar_paper_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub hangar_velocity_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub hangar_waterfall_versions : Option<cornucopia_async::ArrayIterator<'a, &'a str>>,pub curseforge_id : Option<i32>,pub curseforge_slug : Option<&'a str>,pub curseforge_name : Option<&'a str>,pub curseforge_description : Option<&'a str>,pub curseforge_author : Option<&'a str>,pub curseforge_version : Option<&'a str>,pub curseforge_icon_url : Option<&'a str>,pub curseforge_icon_hash : Option<&'a str>,pub polymart_id : Option<i32>,pub polymart_name : Option<&'a str>,pub polymart_description : Option<&'a str>,pub polymart_author : Option<&'a str>,pub polymart_version : Option<&'a str>,pub polymart_premium : Option<bool>,pub polymart_icon_url : Option<&'a str>,pub polymart_icon_hash : Option<&'a str>,pub builtbybit_id : Option<i32>,pub builtbybit_name : Option<&'a str>,pub builtbybit_description : Option<&'a str>,pub builtbybit_author : Option<&'a str>,pub builtbybit_version : Option<&'a str>,pub builtbybit_price : Option<f64>,pub builtbybit_currency : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub release_tag_name : Option<&'a str>,pub release_name : Option<&'a str>,pub release_url : Option<&'a str>,pub release_date_published : Option<time::OffsetDateTime>,pub repository_stars : Option<i32>,pub repository_archived : Option<bool>,pub repository_license : Option<&'a str>,pub repository_open_issues : Option<i32>,pub repository_date_last_commit : Option<time::OffsetDateTime>,}
+impl<'a> From<SearchResultEntityBorrowed<'a>> for SearchResultEntity
+{
+    fn from(SearchResultEntityBorrowed { full_count,date_created,date_updated,latest_minecraft_version,downloads,likes_and_stars,follows_and_watchers,spigot_id,spigot_slug,spigot_name,spigot_description,spigot_author,spigot_version,spigot_premium,spigot_abandoned,spigot_icon_hash,modrinth_id,modrinth_slug,modrinth_name,modrinth_description,modrinth_author,modrinth_version,modrinth_status,modrinth_icon_url,modrinth_icon_hash,hangar_slug,hangar_name,hangar_description,hangar_author,hangar_version,hangar_icon_url,hangar_icon_hash,hangar_paper_versions,hangar_velocity_versions,hangar_waterfall_versions,curseforge_id,curseforge_slug,curseforge_name,curseforge_description,curseforge_author,curseforge_version,curseforge_icon_url,curseforge_icon_hash,polymart_id,polymart_name,polymart_description,polymart_author,polymart_version,polymart_premium,polymart_icon_url,polymart_icon_hash,builtbybit_id,builtbybit_name,builtbybit_description,builtbybit_author,builtbybit_version,builtbybit_price,builtbybit_currency,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,release_tag_name,release_name,release_url,release_date_published,repository_stars,repository_archived,repository_license,repository_open_issues,repository_date_last_commit,}: SearchResultEntityBorrowed<'a>) -> Self
+    { Self { full_count,date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),downloads,likes_and_stars,follows_and_watchers,spigot_id,spigot_slug: spigot_slug.map(|v| v.into()),spigot_name: spigot_name.map(|v| v.into()),spigot_description: spigot_description.map(|v| v.into()),spigot_author: spigot_author.map(|v| v.into()),spigot_version: spigot_version.map(|v| v.into()),spigot_premium,spigot_abandoned,spigot_icon_hash: spigot_icon_hash.map(|v| v.into()),modrinth_id: modrinth_id.map(|v| v.into()),modrinth_slug: modrinth_slug.map(|v| v.into()),modrinth_name: modrinth_name.map(|v| v.into()),modrinth_description: modrinth_description.map(|v| v.into()),modrinth_author: modrinth_author.map(|v| v.into()),modrinth_version: modrinth_version.map(|v| v.into()),modrinth_status: modrinth_status.map(|v| v.into()),modrinth_icon_url: modrinth_icon_url.map(|v| v.into()),modrinth_icon_hash: modrinth_icon_hash.map(|v| v.into()),hangar_slug: hangar_slug.map(|v| v.into()),hangar_name: hangar_name.map(|v| v.into()),hangar_description: hangar_description.map(|v| v.into()),hangar_author: hangar_author.map(|v| v.into()),hangar_version: hangar_version.map(|v| v.into()),hangar_icon_url: hangar_icon_url.map(|v| v.into()),hangar_icon_hash: hangar_icon_hash.map(|v| v.into()),hangar_paper_versions: hangar_paper_versions.map(|v| v.map(|v| v.into()).collect()),hangar_velocity_versions: hangar_velocity_versions.map(|v| v.map(|v| v.into()).collect()),hangar_waterfall_versions: hangar_waterfall_versions.map(|v| v.map(|v| v.into()).collect()),curseforge_id,curseforge_slug: curseforge_slug.map(|v| v.into()),curseforge_name: curseforge_name.map(|v| v.into()),curseforge_description: curseforge_description.map(|v| v.into()),curseforge_author: curseforge_author.map(|v| v.into()),curseforge_version: curseforge_version.map(|v| v.into()),curseforge_icon_url: curseforge_icon_url.map(|v| v.into()),curseforge_icon_hash: curseforge_icon_hash.map(|v| v.into()),polymart_id,polymart_name: polymart_name.map(|v| v.into()),polymart_description: polymart_description.map(|v| v.into()),polymart_author: polymart_author.map(|v| v.into()),polymart_version: polymart_version.map(|v| v.into()),polymart_premium,polymart_icon_url: polymart_icon_url.map(|v| v.into()),polymart_icon_hash: polymart_icon_hash.map(|v| v.into()),builtbybit_id,builtbybit_name: builtbybit_name.map(|v| v.into()),builtbybit_description: builtbybit_description.map(|v| v.into()),builtbybit_author: builtbybit_author.map(|v| v.into()),builtbybit_version: builtbybit_version.map(|v| v.into()),builtbybit_price,builtbybit_currency: builtbybit_currency.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),release_tag_name: release_tag_name.map(|v| v.into()),release_name: release_name.map(|v| v.into()),release_url: release_url.map(|v| v.into()),release_date_published,repository_stars,repository_archived,repository_license: repository_license.map(|v| v.into()),repository_open_issues,repository_date_last_commit,} }
+}pub struct SearchResultEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> SearchResultEntityBorrowed,
+    mapper: fn(SearchResultEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> SearchResultEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(SearchResultEntityBorrowed) -> R) ->
+    SearchResultEntityQuery<'a,C,R,N>
+    {
+        SearchResultEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn search_projects() -> SearchProjectsStmt
+{ SearchProjectsStmt(cornucopia_async::private::Stmt::new("SELECT
+  COUNT(*) OVER() AS full_count,
+
+  GREATEST(
+    CASE WHEN $1 IS TRUE THEN spigot_date_created ELSE NULL END,
+    CASE WHEN $2 IS TRUE THEN modrinth_date_created ELSE NULL END,
+    CASE WHEN $3 IS TRUE THEN hangar_date_created ELSE NULL END,
+    CASE WHEN $4 IS TRUE THEN curseforge_date_created ELSE NULL END,
+    CASE WHEN $5 IS TRUE THEN polymart_date_created ELSE NULL END,
+    CASE WHEN $6 IS TRUE THEN builtbybit_date_created ELSE NULL END
+  ) AS date_created,
+
+  GREATEST(
+    CASE WHEN $1 IS TRUE THEN spigot_date_updated ELSE NULL END,
+    CASE WHEN $2 IS TRUE THEN modrinth_date_updated ELSE NULL END,
+    CASE WHEN $3 IS TRUE THEN hangar_date_updated ELSE NULL END,
+    CASE WHEN $4 IS TRUE THEN curseforge_date_updated ELSE NULL END,
+    CASE WHEN $5 IS TRUE THEN polymart_date_updated ELSE NULL END,
+    CASE WHEN $6 IS TRUE THEN builtbybit_date_updated ELSE NULL END
+  ) AS date_updated,
+
+  (
+    SELECT version
+    FROM (
+      VALUES
+        (CASE WHEN $1 IS TRUE THEN spigot_latest_minecraft_version ELSE NULL END, CASE WHEN $1 IS TRUE THEN spigot_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN $2 IS TRUE THEN modrinth_latest_minecraft_version ELSE NULL END, CASE WHEN $2 IS TRUE THEN modrinth_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN $3 IS TRUE THEN hangar_latest_minecraft_version ELSE NULL END, CASE WHEN $3 IS TRUE THEN hangar_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN $4 IS TRUE THEN curseforge_latest_minecraft_version ELSE NULL END, CASE WHEN $4 IS TRUE THEN curseforge_latest_minecraft_version_sort_key ELSE NULL END),
+        (CASE WHEN $5 IS TRUE THEN polymart_latest_minecraft_version ELSE NULL END, CASE WHEN $5 IS TRUE THEN polymart_latest_minecraft_version_sort_key ELSE NULL END)
+    ) AS latest(version, sort_key)
+    WHERE version IS NOT NULL
+    ORDER BY sort_key DESC NULLS LAST
+    LIMIT 1
+  ) AS latest_minecraft_version,
+
+  CASE WHEN $1 IS TRUE THEN COALESCE(spigot_downloads, 0) ELSE 0 END +
+  CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_downloads, 0) ELSE 0 END +
+  CASE WHEN $3 IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END +
+  CASE WHEN $4 IS TRUE THEN COALESCE(curseforge_downloads, 0) ELSE 0 END +
+  CASE WHEN $5 IS TRUE THEN COALESCE(polymart_downloads, 0) ELSE 0 END +
+  CASE WHEN $6 IS TRUE THEN COALESCE(builtbybit_downloads, 0) ELSE 0 END
+  AS downloads,
+
+  CASE WHEN $1 IS TRUE THEN COALESCE(spigot_likes, 0) ELSE 0 END +
+  CASE WHEN $3 IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END +
+  CASE WHEN $4 IS TRUE THEN COALESCE(curseforge_likes, 0) ELSE 0 END
+  AS likes_and_stars,
+
+  CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_follows, 0) ELSE 0 END +
+  CASE WHEN $3 IS TRUE THEN COALESCE(hangar_watchers, 0) ELSE 0 END
+  AS follows_and_watchers,
+
+  (CASE WHEN $1 IS TRUE THEN spigot_id ELSE NULL END) AS spigot_id,
+  (CASE WHEN $1 IS TRUE THEN spigot_slug ELSE NULL END) AS spigot_slug,
+  (CASE WHEN $1 IS TRUE THEN spigot_name ELSE NULL END) AS spigot_name,
+  (CASE WHEN $1 IS TRUE THEN spigot_description ELSE NULL END) AS spigot_description,
+  (CASE WHEN $1 IS TRUE THEN spigot_author ELSE NULL END) AS spigot_author,
+  (CASE WHEN $1 IS TRUE THEN spigot_version ELSE NULL END) AS spigot_version,
+  (CASE WHEN $1 IS TRUE THEN spigot_premium ELSE NULL END) AS spigot_premium,
+  (CASE WHEN $1 IS TRUE THEN spigot_abandoned ELSE NULL END) AS spigot_abandoned,
+  (CASE WHEN $1 IS TRUE THEN spigot_icon_hash ELSE NULL END) AS spigot_icon_hash,
+
+  (CASE WHEN $2 IS TRUE THEN modrinth_id ELSE NULL END) AS modrinth_id,
+  (CASE WHEN $2 IS TRUE THEN modrinth_slug ELSE NULL END) AS modrinth_slug,
+  (CASE WHEN $2 IS TRUE THEN modrinth_name ELSE NULL END) AS modrinth_name,
+  (CASE WHEN $2 IS TRUE THEN modrinth_description ELSE NULL END) AS modrinth_description,
+  (CASE WHEN $2 IS TRUE THEN modrinth_author ELSE NULL END) AS modrinth_author,
+  (CASE WHEN $2 IS TRUE THEN modrinth_version ELSE NULL END) AS modrinth_version,
+  (CASE WHEN $2 IS TRUE THEN modrinth_status ELSE NULL END) AS modrinth_status,
+  (CASE WHEN $2 IS TRUE THEN modrinth_icon_url ELSE NULL END) AS modrinth_icon_url,
+  (CASE WHEN $2 IS TRUE THEN modrinth_icon_hash ELSE NULL END) AS modrinth_icon_hash,
+
+  (CASE WHEN $3 IS TRUE THEN hangar_slug ELSE NULL END) AS hangar_slug,
+  (CASE WHEN $3 IS TRUE THEN hangar_name ELSE NULL END) AS hangar_name,
+  (CASE WHEN $3 IS TRUE THEN hangar_description ELSE NULL END) AS hangar_description,
+  (CASE WHEN $3 IS TRUE THEN hangar_author ELSE NULL END) AS hangar_author,
+  (CASE WHEN $3 IS TRUE THEN hangar_version ELSE NULL END) AS hangar_version,
+  (CASE WHEN $3 IS TRUE THEN hangar_icon_url ELSE NULL END) AS hangar_icon_url,
+  (CASE WHEN $3 IS TRUE THEN hangar_icon_hash ELSE NULL END) AS hangar_icon_hash,
+  (CASE WHEN $3 IS TRUE THEN hangar_paper_versions ELSE NULL END) AS hangar_paper_versions,
+  (CASE WHEN $3 IS TRUE THEN hangar_velocity_versions ELSE NULL END) AS hangar_velocity_versions,
+  (CASE WHEN $3 IS TRUE THEN hangar_waterfall_versions ELSE NULL END) AS hangar_waterfall_versions,
+
+  (CASE WHEN $4 IS TRUE THEN curseforge_id ELSE NULL END) AS curseforge_id,
+  (CASE WHEN $4 IS TRUE THEN curseforge_slug ELSE NULL END) AS curseforge_slug,
+  (CASE WHEN $4 IS TRUE THEN curseforge_name ELSE NULL END) AS curseforge_name,
+  (CASE WHEN $4 IS TRUE THEN curseforge_description ELSE NULL END) AS curseforge_description,
+  (CASE WHEN $4 IS TRUE THEN curseforge_author ELSE NULL END) AS curseforge_author,
+  (CASE WHEN $4 IS TRUE THEN curseforge_version ELSE NULL END) AS curseforge_version,
+  (CASE WHEN $4 IS TRUE THEN curseforge_icon_url ELSE NULL END) AS curseforge_icon_url,
+  (CASE WHEN $4 IS TRUE THEN curseforge_icon_hash ELSE NULL END) AS curseforge_icon_hash,
+
+  (CASE WHEN $5 IS TRUE THEN polymart_id ELSE NULL END) AS polymart_id,
+  (CASE WHEN $5 IS TRUE THEN polymart_name ELSE NULL END) AS polymart_name,
+  (CASE WHEN $5 IS TRUE THEN polymart_description ELSE NULL END) AS polymart_description,
+  (CASE WHEN $5 IS TRUE THEN polymart_author ELSE NULL END) AS polymart_author,
+  (CASE WHEN $5 IS TRUE THEN polymart_version ELSE NULL END) AS polymart_version,
+  (CASE WHEN $5 IS TRUE THEN polymart_premium ELSE NULL END) AS polymart_premium,
+  (CASE WHEN $5 IS TRUE THEN polymart_icon_url ELSE NULL END) AS polymart_icon_url,
+  (CASE WHEN $5 IS TRUE THEN polymart_icon_hash ELSE NULL END) AS polymart_icon_hash,
+
+  (CASE WHEN $6 IS TRUE THEN builtbybit_id ELSE NULL END) AS builtbybit_id,
+  (CASE WHEN $6 IS TRUE THEN builtbybit_name ELSE NULL END) AS builtbybit_name,
+  (CASE WHEN $6 IS TRUE THEN builtbybit_description ELSE NULL END) AS builtbybit_description,
+  (CASE WHEN $6 IS TRUE THEN builtbybit_author ELSE NULL END) AS builtbybit_author,
+  (CASE WHEN $6 IS TRUE THEN builtbybit_version ELSE NULL END) AS builtbybit_version,
+  (CASE WHEN $6 IS TRUE THEN builtbybit_price ELSE NULL END) AS builtbybit_price,
+  (CASE WHEN $6 IS TRUE THEN builtbybit_currency ELSE NULL END) AS builtbybit_currency,
+
+  source_repository_host,
+  source_repository_owner,
+  source_repository_name,
+  source_repository_id,
+
+  r.tag_name AS release_tag_name,
+  r.release_name AS release_name,
+  r.url AS release_url,
+  r.date_published AS release_date_published,
+
+  meta.stars AS repository_stars,
+  meta.archived AS repository_archived,
+  meta.license AS repository_license,
+  meta.open_issues AS repository_open_issues,
+  meta.date_last_commit AS repository_date_last_commit
+FROM
+  common_project
+  LEFT JOIN source_repository_release r
+  ON  LOWER(source_repository_host) = r.host
+  AND LOWER(source_repository_owner) = r.owner
+  AND LOWER(source_repository_name) = r.name
+  LEFT JOIN source_repository_metadata meta
+  ON  LOWER(source_repository_host) = meta.host
+  AND LOWER(source_repository_owner) = meta.owner
+  AND LOWER(source_repository_name) = meta.name
+WHERE
+  (
+    CASE $1 IS TRUE AND $7 = ''
+      WHEN TRUE THEN spigot_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $1 IS TRUE AND $8 != ''
+      WHEN TRUE THEN spigot_search_vector @@ to_tsquery('english', $8)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $1 IS TRUE AND $9 IS TRUE
+      WHEN TRUE THEN $7 <% spigot_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $1 IS TRUE AND $10 IS TRUE
+      WHEN TRUE THEN $7 <% spigot_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $1 IS TRUE AND $11 IS TRUE
+      WHEN TRUE THEN $7 <% spigot_full_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $1 IS TRUE AND $12 IS TRUE
+      WHEN TRUE THEN $7 <% spigot_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $2 IS TRUE AND $7 = ''
+      WHEN TRUE THEN modrinth_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $2 IS TRUE AND $8 != ''
+      WHEN TRUE THEN modrinth_search_vector @@ to_tsquery('english', $8)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $2 IS TRUE AND $9 IS TRUE
+      WHEN TRUE THEN $7 <% modrinth_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $2 IS TRUE AND $10 IS TRUE
+      WHEN TRUE THEN $7 <% modrinth_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $2 IS TRUE AND $11 IS TRUE
+      WHEN TRUE THEN $7 <% modrinth_full_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $2 IS TRUE AND $12 IS TRUE
+      WHEN TRUE THEN $7 <% modrinth_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $3 IS TRUE AND $7 = ''
+      WHEN TRUE THEN hangar_slug IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $3 IS TRUE AND $8 != ''
+      WHEN TRUE THEN hangar_search_vector @@ to_tsquery('english', $8)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $3 IS TRUE AND $9 IS TRUE
+      WHEN TRUE THEN $7 <% hangar_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $3 IS TRUE AND $10 IS TRUE
+      WHEN TRUE THEN $7 <% hangar_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $3 IS TRUE AND $11 IS TRUE
+      WHEN TRUE THEN $7 <% hangar_full_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $3 IS TRUE AND $12 IS TRUE
+      WHEN TRUE THEN $7 <% hangar_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $4 IS TRUE AND $7 = ''
+      WHEN TRUE THEN curseforge_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $4 IS TRUE AND $8 != ''
+      WHEN TRUE THEN curseforge_search_vector @@ to_tsquery('english', $8)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $4 IS TRUE AND $9 IS TRUE
+      WHEN TRUE THEN $7 <% curseforge_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $4 IS TRUE AND $10 IS TRUE
+      WHEN TRUE THEN $7 <% curseforge_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $4 IS TRUE AND $12 IS TRUE
+      WHEN TRUE THEN $7 <% curseforge_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $5 IS TRUE AND $7 = ''
+      WHEN TRUE THEN polymart_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $5 IS TRUE AND $8 != ''
+      WHEN TRUE THEN polymart_search_vector @@ to_tsquery('english', $8)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $5 IS TRUE AND $9 IS TRUE
+      WHEN TRUE THEN $7 <% polymart_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $5 IS TRUE AND $10 IS TRUE
+      WHEN TRUE THEN $7 <% polymart_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $5 IS TRUE AND $12 IS TRUE
+      WHEN TRUE THEN $7 <% polymart_author
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $6 IS TRUE AND $7 = ''
+      WHEN TRUE THEN builtbybit_id IS NOT NULL
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $6 IS TRUE AND $8 != ''
+      WHEN TRUE THEN builtbybit_search_vector @@ to_tsquery('english', $8)
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $6 IS TRUE AND $9 IS TRUE
+      WHEN TRUE THEN $7 <% builtbybit_name
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $6 IS TRUE AND $10 IS TRUE
+      WHEN TRUE THEN $7 <% builtbybit_description
+      ELSE FALSE
+    END
+
+    OR
+
+    CASE $6 IS TRUE AND $12 IS TRUE
+      WHEN TRUE THEN $7 <% builtbybit_author
+      ELSE FALSE
+    END
+  )
+
+  AND
+
+  -- Only include projects that support at least one of the given platforms, if any are given
+  (
+    cardinality($13::server_platform[]) = 0
+    OR ($1 IS TRUE AND spigot_platforms && $13)
+    OR ($2 IS TRUE AND modrinth_platforms && $13)
+    OR ($3 IS TRUE AND hangar_platforms && $13)
+    OR ($4 IS TRUE AND curseforge_platforms && $13)
+    OR ($5 IS TRUE AND polymart_platforms && $13)
+  )
+
+  AND
+
+  -- Only include projects in at least one of the given categories, if any are given
+  (
+    cardinality($14::project_category[]) = 0
+    OR ($1 IS TRUE AND spigot_categories && $14)
+    OR ($2 IS TRUE AND modrinth_categories && $14)
+    OR ($3 IS TRUE AND hangar_categories && $14)
+    OR ($4 IS TRUE AND curseforge_categories && $14)
+    OR ($5 IS TRUE AND polymart_categories && $14)
+  )
+
+  AND
+
+  -- Only include projects that support the given Minecraft version, if one is given.
+  -- The version is given in each of its spellings, such as \"1.21\" and \"1.21.0\", since upstream versions are stored as listed.
+  -- Spigot only lists major versions such as \"1.20\", which are assumed to cover all versions in that family such as \"1.20.4\".
+  (
+    cardinality($15::text[]) = 0
+    OR ($1 IS TRUE AND spigot_minecraft_versions && ($15 || $16::text))
+    OR ($2 IS TRUE AND modrinth_minecraft_versions && $15)
+    OR ($3 IS TRUE AND hangar_minecraft_versions && $15)
+    OR ($4 IS TRUE AND curseforge_minecraft_versions && $15)
+    OR ($5 IS TRUE AND polymart_minecraft_versions && $15)
+  )
+
+  AND
+
+  -- Exclude projects whose source repository has been archived, if specified
+  (
+    $17 IS FALSE
+    OR meta.archived IS NOT TRUE
+  )
+
+  ORDER BY
+    -- Sorts on 'real' type
+    -- Relevance combines the weighted full-text rank of each project with the trigram word similarity of its best matching field.
+    CASE
+      WHEN $18 = 'relevance' AND $7 != '' THEN
+        GREATEST(
+          CASE WHEN $1 IS TRUE THEN
+            CASE WHEN $8 != '' AND spigot_search_vector @@ to_tsquery('english', $8)
+              THEN $19::real * ts_rank(ARRAY[$20::real, $21::real, $22::real, $23::real], spigot_search_vector, to_tsquery('english', $8))
+              ELSE 0
+            END +
+            $24::real * COALESCE(1 - LEAST(
+                CASE WHEN $9 IS TRUE THEN $7 <<-> spigot_name ELSE NULL END,
+                CASE WHEN $10 IS TRUE THEN $7 <<-> spigot_description ELSE NULL END,
+                CASE WHEN $11 IS TRUE THEN $7 <<-> spigot_full_description ELSE NULL END,
+                CASE WHEN $12 IS TRUE THEN $7 <<-> spigot_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN $2 IS TRUE THEN
+            CASE WHEN $8 != '' AND modrinth_search_vector @@ to_tsquery('english', $8)
+              THEN $19::real * ts_rank(ARRAY[$20::real, $21::real, $22::real, $23::real], modrinth_search_vector, to_tsquery('english', $8))
+              ELSE 0
+            END +
+            $24::real * COALESCE(1 - LEAST(
+                CASE WHEN $9 IS TRUE THEN $7 <<-> modrinth_name ELSE NULL END,
+                CASE WHEN $10 IS TRUE THEN $7 <<-> modrinth_description ELSE NULL END,
+                CASE WHEN $11 IS TRUE THEN $7 <<-> modrinth_full_description ELSE NULL END,
+                CASE WHEN $12 IS TRUE THEN $7 <<-> modrinth_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN $3 IS TRUE THEN
+            CASE WHEN $8 != '' AND hangar_search_vector @@ to_tsquery('english', $8)
+              THEN $19::real * ts_rank(ARRAY[$20::real, $21::real, $22::real, $23::real], hangar_search_vector, to_tsquery('english', $8))
+              ELSE 0
+            END +
+            $24::real * COALESCE(1 - LEAST(
+                CASE WHEN $9 IS TRUE THEN $7 <<-> hangar_name ELSE NULL END,
+                CASE WHEN $10 IS TRUE THEN $7 <<-> hangar_description ELSE NULL END,
+                CASE WHEN $11 IS TRUE THEN $7 <<-> hangar_full_description ELSE NULL END,
+                CASE WHEN $12 IS TRUE THEN $7 <<-> hangar_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN $4 IS TRUE THEN
+            CASE WHEN $8 != '' AND curseforge_search_vector @@ to_tsquery('english', $8)
+              THEN $19::real * ts_rank(ARRAY[$20::real, $21::real, $22::real, $23::real], curseforge_search_vector, to_tsquery('english', $8))
+              ELSE 0
+            END +
+            $24::real * COALESCE(1 - LEAST(
+                CASE WHEN $9 IS TRUE THEN $7 <<-> curseforge_name ELSE NULL END,
+                CASE WHEN $10 IS TRUE THEN $7 <<-> curseforge_description ELSE NULL END,
+                CASE WHEN $12 IS TRUE THEN $7 <<-> curseforge_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN $5 IS TRUE THEN
+            CASE WHEN $8 != '' AND polymart_search_vector @@ to_tsquery('english', $8)
+              THEN $19::real * ts_rank(ARRAY[$20::real, $21::real, $22::real, $23::real], polymart_search_vector, to_tsquery('english', $8))
+              ELSE 0
+            END +
+            $24::real * COALESCE(1 - LEAST(
+                CASE WHEN $9 IS TRUE THEN $7 <<-> polymart_name ELSE NULL END,
+                CASE WHEN $10 IS TRUE THEN $7 <<-> polymart_description ELSE NULL END,
+                CASE WHEN $12 IS TRUE THEN $7 <<-> polymart_author ELSE NULL END
+            ), 0)
+          ELSE NULL END,
+          CASE WHEN $6 IS TRUE THEN
+            CASE WHEN $8 != '' AND builtbybit_search_vector @@ to_tsquery('english', $8)
+              THEN $19::real * ts_rank(ARRAY[$20::real, $21::real, $22::real, $23::real], builtbybit_search_vector, to_tsquery('english', $8))
+              ELSE 0
+            END +
+            $24::real * COALESCE(1 - LEAST(
+                CASE WHEN $9 IS TRUE THEN $7 <<-> builtbybit_name ELSE NULL END,
+                CASE WHEN $10 IS TRUE THEN $7 <<-> builtbybit_description ELSE NULL END,
+                CASE WHEN $12 IS TRUE THEN $7 <<-> builtbybit_author ELSE NULL END
+            ), 0)
+          ELSE NULL END
+        )
+    END DESC NULLS LAST,
+
+    -- Sorts on 'timestamptz' type
+    CASE
+      WHEN $18 = 'date_created' THEN
+        GREATEST(
+          CASE WHEN $1 IS TRUE THEN spigot_date_created ELSE NULL END,
+          CASE WHEN $2 IS TRUE THEN modrinth_date_created ELSE NULL END,
+          CASE WHEN $3 IS TRUE THEN hangar_date_created ELSE NULL END,
+          CASE WHEN $4 IS TRUE THEN curseforge_date_created ELSE NULL END,
+          CASE WHEN $5 IS TRUE THEN polymart_date_created ELSE NULL END,
+          CASE WHEN $6 IS TRUE THEN builtbybit_date_created ELSE NULL END
+        )
+
+      WHEN $18 = 'date_updated' THEN
+        GREATEST(
+          CASE WHEN $1 IS TRUE THEN spigot_date_updated ELSE NULL END,
+          CASE WHEN $2 IS TRUE THEN modrinth_date_updated ELSE NULL END,
+          CASE WHEN $3 IS TRUE THEN hangar_date_updated ELSE NULL END,
+          CASE WHEN $4 IS TRUE THEN curseforge_date_updated ELSE NULL END,
+          CASE WHEN $5 IS TRUE THEN polymart_date_updated ELSE NULL END,
+          CASE WHEN $6 IS TRUE THEN builtbybit_date_updated ELSE NULL END
+        )
+
+      WHEN $18 = 'repository_activity' THEN
+        meta.date_last_commit
+    END DESC NULLS LAST,
+
+    -- Sorts on 'integer[]' type
+    CASE
+      WHEN $18 = 'latest_minecraft_version' THEN
+        GREATEST(
+          CASE WHEN $1 IS TRUE THEN spigot_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN $2 IS TRUE THEN modrinth_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN $3 IS TRUE THEN hangar_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN $4 IS TRUE THEN curseforge_latest_minecraft_version_sort_key ELSE NULL END,
+          CASE WHEN $5 IS TRUE THEN polymart_latest_minecraft_version_sort_key ELSE NULL END
+        )
+    END DESC NULLS LAST,
+
+    -- Sorts on 'integer' type
+    CASE
+      WHEN $18 = 'likes_and_stars' THEN
+        CASE WHEN $1 IS TRUE THEN COALESCE(spigot_likes, 0) ELSE 0 END +
+        CASE WHEN $3 IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END +
+        CASE WHEN $4 IS TRUE THEN COALESCE(curseforge_likes, 0) ELSE 0 END
+
+      WHEN $18 = 'follows_and_watchers' THEN
+        CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_follows, 0) ELSE 0 END +
+        CASE WHEN $3 IS TRUE THEN COALESCE(hangar_watchers, 0) ELSE 0 END
+
+      WHEN $18 = 'trending' THEN
+        CASE WHEN $1 IS TRUE THEN COALESCE(spigot_trending_score, 0) ELSE 0 END +
+        CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_trending_score, 0) ELSE 0 END +
+        CASE WHEN $3 IS TRUE THEN COALESCE(hangar_trending_score, 0) ELSE 0 END +
+        CASE WHEN $4 IS TRUE THEN COALESCE(curseforge_trending_score, 0) ELSE 0 END +
+        CASE WHEN $5 IS TRUE THEN COALESCE(polymart_trending_score, 0) ELSE 0 END +
+        CASE WHEN $6 IS TRUE THEN COALESCE(builtbybit_trending_score, 0) ELSE 0 END
+    END DESC NULLS LAST,
+
+    -- Fallback to sort by downloads when no sort is specified or as a secondary sort
+    CASE WHEN $1 IS TRUE THEN COALESCE(spigot_downloads, 0) ELSE 0 END +
+    CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_downloads, 0) ELSE 0 END +
+    CASE WHEN $3 IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END +
+    CASE WHEN $4 IS TRUE THEN COALESCE(curseforge_downloads, 0) ELSE 0 END +
+    CASE WHEN $5 IS TRUE THEN COALESCE(polymart_downloads, 0) ELSE 0 END +
+    CASE WHEN $6 IS TRUE THEN COALESCE(builtbybit_downloads, 0) ELSE 0 END
+    DESC NULLS LAST
+
+LIMIT $25
+OFFSET $26")) } pub struct
+SearchProjectsStmt(cornucopia_async::private::Stmt); impl SearchProjectsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T4:
+cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = T5>,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+spigot: &'a bool,modrinth: &'a bool,hangar: &'a bool,curseforge: &'a bool,polymart: &'a bool,builtbybit: &'a bool,query: &'a T1,text_query: &'a T2,name: &'a bool,description: &'a bool,full_description: &'a bool,author: &'a bool,platforms: &'a T3,categories: &'a T4,minecraft_versions: &'a T6,minecraft_version_family: &'a Option<T7>,exclude_archived: &'a bool,sort: &'a T8,full_text_weight: &'a f32,author_weight: &'a f32,description_weight: &'a f32,categories_weight: &'a f32,name_weight: &'a f32,trigram_weight: &'a f32,limit: &'a i64,offset: &'a i64,) -> SearchResultEntityQuery<'a,C, SearchResultEntity,
+26>
+{
+    SearchResultEntityQuery
+    {
+        client, params: [spigot,modrinth,hangar,curseforge,polymart,builtbybit,query,text_query,name,description,full_description,author,platforms,categories,minecraft_versions,minecraft_version_family,exclude_archived,sort,full_text_weight,author_weight,description_weight,categories_weight,name_weight,trigram_weight,limit,offset,], stmt: &mut self.0, extractor:
+        |row| { SearchResultEntityBorrowed { full_count: row.get(0),date_created: row.get(1),date_updated: row.get(2),latest_minecraft_version: row.get(3),downloads: row.get(4),likes_and_stars: row.get(5),follows_and_watchers: row.get(6),spigot_id: row.get(7),spigot_slug: row.get(8),spigot_name: row.get(9),spigot_description: row.get(10),spigot_author: row.get(11),spigot_version: row.get(12),spigot_premium: row.get(13),spigot_abandoned: row.get(14),spigot_icon_hash: row.get(15),modrinth_id: row.get(16),modrinth_slug: row.get(17),modrinth_name: row.get(18),modrinth_description: row.get(19),modrinth_author: row.get(20),modrinth_version: row.get(21),modrinth_status: row.get(22),modrinth_icon_url: row.get(23),modrinth_icon_hash: row.get(24),hangar_slug: row.get(25),hangar_name: row.get(26),hangar_description: row.get(27),hangar_author: row.get(28),hangar_version: row.get(29),hangar_icon_url: row.get(30),hangar_icon_hash: row.get(31),hangar_paper_versions: row.get(32),hangar_velocity_versions: row.get(33),hangar_waterfall_versions: row.get(34),curseforge_id: row.get(35),curseforge_slug: row.get(36),curseforge_name: row.get(37),curseforge_description: row.get(38),curseforge_author: row.get(39),curseforge_version: row.get(40),curseforge_icon_url: row.get(41),curseforge_icon_hash: row.get(42),polymart_id: row.get(43),polymart_name: row.get(44),polymart_description: row.get(45),polymart_author: row.get(46),polymart_version: row.get(47),polymart_premium: row.get(48),polymart_icon_url: row.get(49),polymart_icon_hash: row.get(50),builtbybit_id: row.get(51),builtbybit_name: row.get(52),builtbybit_description: row.get(53),builtbybit_author: row.get(54),builtbybit_version: row.get(55),builtbybit_price: row.get(56),builtbybit_currency: row.get(57),source_repository_host: row.get(58),source_repository_owner: row.get(59),source_repository_name: row.get(60),source_repository_id: row.get(61),release_tag_name: row.get(62),release_name: row.get(63),release_url: row.get(64),release_date_published: row.get(65),repository_stars: row.get(66),repository_archived: row.get(67),repository_license: row.get(68),repository_open_issues: row.get(69),repository_date_last_commit: row.get(70),} }, mapper: |it| { <SearchResultEntity>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T4: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+SearchProjectsParams<T1,T2,T3,T4,T5,T6,T7,T8,>, SearchResultEntityQuery<'a, C, SearchResultEntity,
+26>, C> for SearchProjectsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    SearchProjectsParams<T1,T2,T3,T4,T5,T6,T7,T8,>) -> SearchResultEntityQuery<'a, C,
+    SearchResultEntity, 26>
+    { self.bind(client, &params.spigot,&params.modrinth,&params.hangar,&params.curseforge,&params.polymart,&params.builtbybit,&params.query,&params.text_query,&params.name,&params.description,&params.full_description,&params.author,&params.platforms,&params.categories,&params.minecraft_versions,&params.minecraft_version_family,&params.exclude_archived,&params.sort,&params.full_text_weight,&params.author_weight,&params.description_weight,&params.categories_weight,&params.name_weight,&params.trigram_weight,&params.limit,&params.offset,) }
+}}pub mod snapshot
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> & str,
+    mapper: fn(& str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(& str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn get_populated_snapshot_tables() -> GetPopulatedSnapshotTablesStmt
+{ GetPopulatedSnapshotTablesStmt(cornucopia_async::private::Stmt::new("SELECT table_name
+FROM (
+  VALUES
+    ('spigot_author', EXISTS (SELECT 1 FROM spigot_author)),
+    ('spigot_resource', EXISTS (SELECT 1 FROM spigot_resource)),
+    ('modrinth_project', EXISTS (SELECT 1 FROM modrinth_project)),
+    ('modrinth_version', EXISTS (SELECT 1 FROM modrinth_version)),
+    ('hangar_project', EXISTS (SELECT 1 FROM hangar_project)),
+    ('curseforge_project', EXISTS (SELECT 1 FROM curseforge_project)),
+    ('polymart_resource', EXISTS (SELECT 1 FROM polymart_resource)),
+    ('builtbybit_resource', EXISTS (SELECT 1 FROM builtbybit_resource)),
+    ('source_repository_release', EXISTS (SELECT 1 FROM source_repository_release)),
+    ('source_repository_metadata', EXISTS (SELECT 1 FROM source_repository_metadata)),
+    ('project_stats_snapshot', EXISTS (SELECT 1 FROM project_stats_snapshot)),
+    ('ingest_log', EXISTS (SELECT 1 FROM ingest_log))
+) AS snapshot_table (table_name, populated)
+WHERE populated")) } pub struct
+GetPopulatedSnapshotTablesStmt(cornucopia_async::private::Stmt); impl GetPopulatedSnapshotTablesStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> StringQuery<'a,C, String,
+0>
+{
+    StringQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod source_repository_metadata
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertSourceRepositoryMetadataParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,> { pub host: T1,pub owner: T2,pub name: T3,pub stars: i32,pub archived: bool,pub license: Option<T4>,pub open_issues: Option<i32>,pub date_last_commit: Option<time::OffsetDateTime>,}#[derive( Debug)] pub struct GetSourceRepositoryMetadataByRepositoryParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub host: T1,pub owner: T2,pub name: T3,}#[derive( Debug, Clone, PartialEq,)] pub struct SourceRepositoryMetadataEntity
+{ pub host : String,pub owner : String,pub name : String,pub stars : i32,pub archived : bool,pub license : Option<String>,pub open_issues : Option<i32>,pub date_last_commit : Option<time::OffsetDateTime>,pub date_last_checked : time::OffsetDateTime,}pub struct SourceRepositoryMetadataEntityBorrowed<'a> { pub host : &'a str,pub owner : &'a str,pub name : &'a str,pub stars : i32,pub archived : bool,pub license : Option<&'a str>,pub open_issues : Option<i32>,pub date_last_commit : Option<time::OffsetDateTime>,pub date_last_checked : time::OffsetDateTime,}
+impl<'a> From<SourceRepositoryMetadataEntityBorrowed<'a>> for SourceRepositoryMetadataEntity
+{
+    fn from(SourceRepositoryMetadataEntityBorrowed { host,owner,name,stars,archived,license,open_issues,date_last_commit,date_last_checked,}: SourceRepositoryMetadataEntityBorrowed<'a>) -> Self
+    { Self { host: host.into(),owner: owner.into(),name: name.into(),stars,archived,license: license.map(|v| v.into()),open_issues,date_last_commit,date_last_checked,} }
+}pub struct SourceRepositoryMetadataEntityQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> SourceRepositoryMetadataEntityBorrowed,
+    mapper: fn(SourceRepositoryMetadataEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> SourceRepositoryMetadataEntityQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(SourceRepositoryMetadataEntityBorrowed) -> R) ->
+    SourceRepositoryMetadataEntityQuery<'a,C,R,N>
+    {
+        SourceRepositoryMetadataEntityQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn upsert_source_repository_metadata() -> UpsertSourceRepositoryMetadataStmt
+{ UpsertSourceRepositoryMetadataStmt(cornucopia_async::private::Stmt::new("INSERT INTO source_repository_metadata (host, owner, name, stars, archived, license, open_issues, date_last_commit, date_last_checked)
+  VALUES (LOWER($1), LOWER($2), LOWER($3), $4, $5, $6, $7, $8, NOW())
+  ON CONFLICT (host, owner, name)
+  DO UPDATE SET
+    stars = EXCLUDED.stars,
+    archived = EXCLUDED.archived,
+    license = EXCLUDED.license,
+    open_issues = EXCLUDED.open_issues,
+    date_last_commit = EXCLUDED.date_last_commit,
+    date_last_checked = EXCLUDED.date_last_checked")) } pub struct
+UpsertSourceRepositoryMetadataStmt(cornucopia_async::private::Stmt); impl UpsertSourceRepositoryMetadataStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+host: &'a T1,owner: &'a T2,name: &'a T3,stars: &'a i32,archived: &'a bool,license: &'a Option<T4>,open_issues: &'a Option<i32>,date_last_commit: &'a Option<time::OffsetDateTime>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[host,owner,name,stars,archived,license,open_issues,date_last_commit,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertSourceRepositoryMetadataParams<T1,T2,T3,T4,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertSourceRepositoryMetadataStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    UpsertSourceRepositoryMetadataParams<T1,T2,T3,T4,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.host,&params.owner,&params.name,&params.stars,&params.archived,&params.license,&params.open_issues,&params.date_last_commit,)) }
+}pub fn get_source_repository_metadata() -> GetSourceRepositoryMetadataStmt
+{ GetSourceRepositoryMetadataStmt(cornucopia_async::private::Stmt::new("SELECT * FROM source_repository_metadata")) } pub struct
+GetSourceRepositoryMetadataStmt(cornucopia_async::private::Stmt); impl GetSourceRepositoryMetadataStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> SourceRepositoryMetadataEntityQuery<'a,C, SourceRepositoryMetadataEntity,
+0>
+{
+    SourceRepositoryMetadataEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { SourceRepositoryMetadataEntityBorrowed { host: row.get(0),owner: row.get(1),name: row.get(2),stars: row.get(3),archived: row.get(4),license: row.get(5),open_issues: row.get(6),date_last_commit: row.get(7),date_last_checked: row.get(8),} }, mapper: |it| { <SourceRepositoryMetadataEntity>::from(it) },
+    }
+} }pub fn get_source_repository_metadata_by_repository() -> GetSourceRepositoryMetadataByRepositoryStmt
+{ GetSourceRepositoryMetadataByRepositoryStmt(cornucopia_async::private::Stmt::new("SELECT * FROM source_repository_metadata
+WHERE host = $1 AND owner = $2 AND name = $3")) } pub struct
+GetSourceRepositoryMetadataByRepositoryStmt(cornucopia_async::private::Stmt); impl GetSourceRepositoryMetadataByRepositoryStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+host: &'a T1,owner: &'a T2,name: &'a T3,) -> SourceRepositoryMetadataEntityQuery<'a,C, SourceRepositoryMetadataEntity,
+3>
+{
+    SourceRepositoryMetadataEntityQuery
+    {
+        client, params: [host,owner,name,], stmt: &mut self.0, extractor:
+        |row| { SourceRepositoryMetadataEntityBorrowed { host: row.get(0),owner: row.get(1),name: row.get(2),stars: row.get(3),archived: row.get(4),license: row.get(5),open_issues: row.get(6),date_last_commit: row.get(7),date_last_checked: row.get(8),} }, mapper: |it| { <SourceRepositoryMetadataEntity>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+GetSourceRepositoryMetadataByRepositoryParams<T1,T2,T3,>, SourceRepositoryMetadataEntityQuery<'a, C, SourceRepositoryMetadataEntity,
+3>, C> for GetSourceRepositoryMetadataByRepositoryStmt
 {
-    fn from(ModrinthProjectEntityBorrowed { id,slug,name,description,author,date_created,date_updated,latest_minecraft_version,downloads,follows,version_id,version_name,status,icon_url,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,}: ModrinthProjectEntityBorrowed<'a>) ->
-    Self { Self { id: id.into(),slug: slug.into(),name: name.into(),description: description.into(),author: author.into(),date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),downloads,follows,version_id: version_id.map(|v| v.into()),version_name: version_name.map(|v| v.into()),status: status.into(),icon_url: icon_url.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),} }
-}pub struct ModrinthProjectEntityQuery<'a, C: GenericClient, T, const N: usize>
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GetSourceRepositoryMetadataByRepositoryParams<T1,T2,T3,>) -> SourceRepositoryMetadataEntityQuery<'a, C,
+    SourceRepositoryMetadataEntity, 3>
+    { self.bind(client, &params.host,&params.owner,&params.name,) }
+}}pub mod source_repository_release
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertSourceRepositoryReleaseParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,> { pub host: T1,pub owner: T2,pub name: T3,pub tag_name: T4,pub release_name: Option<T5>,pub url: Option<T6>,pub date_published: time::OffsetDateTime,}#[derive( Debug)] pub struct GetSourceRepositoryReleaseByRepositoryParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub host: T1,pub owner: T2,pub name: T3,}#[derive( Debug, Clone, PartialEq,)] pub struct SourceRepositoryReleaseEntity
+{ pub host : String,pub owner : String,pub name : String,pub tag_name : String,pub release_name : Option<String>,pub url : Option<String>,pub date_published : time::OffsetDateTime,pub date_last_checked : time::OffsetDateTime,}pub struct SourceRepositoryReleaseEntityBorrowed<'a> { pub host : &'a str,pub owner : &'a str,pub name : &'a str,pub tag_name : &'a str,pub release_name : Option<&'a str>,pub url : Option<&'a str>,pub date_published : time::OffsetDateTime,pub date_last_checked : time::OffsetDateTime,}
+impl<'a> From<SourceRepositoryReleaseEntityBorrowed<'a>> for SourceRepositoryReleaseEntity
+{
+    fn from(SourceRepositoryReleaseEntityBorrowed { host,owner,name,tag_name,release_name,url,date_published,date_last_checked,}: SourceRepositoryReleaseEntityBorrowed<'a>) -> Self
+    { Self { host: host.into(),owner: owner.into(),name: name.into(),tag_name: tag_name.into(),release_name: release_name.map(|v| v.into()),url: url.map(|v| v.into()),date_published,date_last_checked,} }
+}pub struct SourceRepositoryReleaseEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ModrinthProjectEntityBorrowed,
-    mapper: fn(ModrinthProjectEntityBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> ModrinthProjectEntityQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> SourceRepositoryReleaseEntityBorrowed,
+    mapper: fn(SourceRepositoryReleaseEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> SourceRepositoryReleaseEntityQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(ModrinthProjectEntityBorrowed) -> R) ->
-    ModrinthProjectEntityQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(SourceRepositoryReleaseEntityBorrowed) -> R) ->
+    SourceRepositoryReleaseEntityQuery<'a,C,R,N>
     {
-        ModrinthProjectEntityQuery
+        SourceRepositoryReleaseEntityQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -670,19 +3640,25 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub struct TimeOffsetDateTimeQuery<'a, C: GenericClient, T, const N: usize>
+}#[derive( Debug, Clone, PartialEq,)] pub struct SourceRepositoryEntity
+{ pub host : String,pub owner : String,pub name : String,}pub struct SourceRepositoryEntityBorrowed<'a> { pub host : &'a str,pub owner : &'a str,pub name : &'a str,}
+impl<'a> From<SourceRepositoryEntityBorrowed<'a>> for SourceRepositoryEntity
+{
+    fn from(SourceRepositoryEntityBorrowed { host,owner,name,}: SourceRepositoryEntityBorrowed<'a>) -> Self
+    { Self { host: host.into(),owner: owner.into(),name: name.into(),} }
+}pub struct SourceRepositoryEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> time::OffsetDateTime,
-    mapper: fn(time::OffsetDateTime) -> T,
-} impl<'a, C, T:'a, const N: usize> TimeOffsetDateTimeQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> SourceRepositoryEntityBorrowed,
+    mapper: fn(SourceRepositoryEntityBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> SourceRepositoryEntityQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(time::OffsetDateTime) -> R) ->
-    TimeOffsetDateTimeQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(SourceRepositoryEntityBorrowed) -> R) ->
+    SourceRepositoryEntityQuery<'a,C,R,N>
     {
-        TimeOffsetDateTimeQuery
+        SourceRepositoryEntityQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -709,30 +3685,17 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn upsert_modrinth_project() -> UpsertModrinthProjectStmt
-{ UpsertModrinthProjectStmt(cornucopia_async::private::Stmt::new("INSERT INTO modrinth_project (id, slug, name, description, author, date_created, date_updated, latest_minecraft_version, downloads, follows, version_id, version_name, status, icon_url, source_url, source_repository_host, source_repository_owner, source_repository_name)
-  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
-  ON CONFLICT(id)
+}pub fn upsert_source_repository_release() -> UpsertSourceRepositoryReleaseStmt
+{ UpsertSourceRepositoryReleaseStmt(cornucopia_async::private::Stmt::new("INSERT INTO source_repository_release (host, owner, name, tag_name, release_name, url, date_published, date_last_checked)
+  VALUES (LOWER($1), LOWER($2), LOWER($3), $4, $5, $6, $7, NOW())
+  ON CONFLICT (host, owner, name)
   DO UPDATE SET
-    id = EXCLUDED.id,
-    slug = EXCLUDED.slug,
-    name = EXCLUDED.name,
-    description = EXCLUDED.description,
-    author = EXCLUDED.author,
-    date_created = EXCLUDED.date_created,
-    date_updated = EXCLUDED.date_updated,
-    latest_minecraft_version = EXCLUDED.latest_minecraft_version,
-    downloads = EXCLUDED.downloads,
-    follows = EXCLUDED.follows,
-    version_id = EXCLUDED.version_id,
-    version_name = EXCLUDED.version_name,
-    status = EXCLUDED.status,
-    icon_url = EXCLUDED.icon_url,
-    source_url = EXCLUDED.source_url,
-    source_repository_host = EXCLUDED.source_repository_host,
-    source_repository_owner = EXCLUDED.source_repository_owner,
-    source_repository_name = EXCLUDED.source_repository_name")) } pub struct
-UpsertModrinthProjectStmt(cornucopia_async::private::Stmt); impl UpsertModrinthProjectStmt
+    tag_name = EXCLUDED.tag_name,
+    release_name = EXCLUDED.release_name,
+    url = EXCLUDED.url,
+    date_published = EXCLUDED.date_published,
+    date_last_checked = EXCLUDED.date_last_checked")) } pub struct
+UpsertSourceRepositoryReleaseStmt(cornucopia_async::private::Stmt); impl UpsertSourceRepositoryReleaseStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
@@ -740,354 +3703,87 @@ cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
 cornucopia_async::StringSql,T5:
 cornucopia_async::StringSql,T6:
-cornucopia_async::StringSql,T7:
-cornucopia_async::StringSql,T8:
-cornucopia_async::StringSql,T9:
-cornucopia_async::StringSql,T10:
-cornucopia_async::StringSql,T11:
-cornucopia_async::StringSql,T12:
-cornucopia_async::StringSql,T13:
-cornucopia_async::StringSql,T14:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,slug: &'a T2,name: &'a T3,description: &'a T4,author: &'a T5,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T6>,downloads: &'a i32,follows: &'a i32,version_id: &'a Option<T7>,version_name: &'a Option<T8>,status: &'a T9,icon_url: &'a Option<T10>,source_url: &'a Option<T11>,source_repository_host: &'a Option<T12>,source_repository_owner: &'a Option<T13>,source_repository_name: &'a Option<T14>,) -> Result<u64, tokio_postgres::Error>
+host: &'a T1,owner: &'a T2,name: &'a T3,tag_name: &'a T4,release_name: &'a Option<T5>,url: &'a Option<T6>,date_published: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[id,slug,name,description,author,date_created,date_updated,latest_minecraft_version,downloads,follows,version_id,version_name,status,icon_url,source_url,source_repository_host,source_repository_owner,source_repository_name,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, UpsertModrinthProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for UpsertModrinthProjectStmt
+    client.execute(stmt, &[host,owner,name,tag_name,release_name,url,date_published,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertSourceRepositoryReleaseParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for UpsertSourceRepositoryReleaseStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    UpsertModrinthProjectParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    UpsertSourceRepositoryReleaseParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.id,&params.slug,&params.name,&params.description,&params.author,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.downloads,&params.follows,&params.version_id,&params.version_name,&params.status,&params.icon_url,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,)) }
-}pub fn get_modrinth_projects() -> GetModrinthProjectsStmt
-{ GetModrinthProjectsStmt(cornucopia_async::private::Stmt::new("SELECT * FROM modrinth_project")) } pub struct
-GetModrinthProjectsStmt(cornucopia_async::private::Stmt); impl GetModrinthProjectsStmt
-{ pub fn bind<'a, C:
-GenericClient,>(&'a mut self, client: &'a  C,
-) -> ModrinthProjectEntityQuery<'a,C,
-ModrinthProjectEntity, 0>
-{
-    ModrinthProjectEntityQuery
-    {
-        client, params: [], stmt: &mut self.0, extractor:
-        |row| { ModrinthProjectEntityBorrowed { id: row.get(0),slug: row.get(1),name: row.get(2),description: row.get(3),author: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),downloads: row.get(8),follows: row.get(9),version_id: row.get(10),version_name: row.get(11),status: row.get(12),icon_url: row.get(13),source_url: row.get(14),source_repository_host: row.get(15),source_repository_owner: row.get(16),source_repository_name: row.get(17),source_repository_id: row.get(18),} }, mapper: |it| { <ModrinthProjectEntity>::from(it) },
-    }
-} }pub fn get_latest_modrinth_project_update_date() -> GetLatestModrinthProjectUpdateDateStmt
-{ GetLatestModrinthProjectUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM modrinth_project")) } pub struct
-GetLatestModrinthProjectUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestModrinthProjectUpdateDateStmt
+    { Box::pin(self.bind(client, &params.host,&params.owner,&params.name,&params.tag_name,&params.release_name,&params.url,&params.date_published,)) }
+}pub fn get_source_repository_releases() -> GetSourceRepositoryReleasesStmt
+{ GetSourceRepositoryReleasesStmt(cornucopia_async::private::Stmt::new("SELECT * FROM source_repository_release")) } pub struct
+GetSourceRepositoryReleasesStmt(cornucopia_async::private::Stmt); impl GetSourceRepositoryReleasesStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> TimeOffsetDateTimeQuery<'a,C,
-time::OffsetDateTime, 0>
+) -> SourceRepositoryReleaseEntityQuery<'a,C, SourceRepositoryReleaseEntity,
+0>
 {
-    TimeOffsetDateTimeQuery
+    SourceRepositoryReleaseEntityQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
-        |row| { row.get(0) }, mapper: |it| { it },
-    }
-} }}pub mod search_result
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct SearchProjectsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub spigot: bool,pub modrinth: bool,pub hangar: bool,pub query: T1,pub name: bool,pub description: bool,pub author: bool,pub sort: T2,pub limit: i64,pub offset: i64,}#[derive( Debug, Clone, PartialEq,)] pub struct SearchResultEntity
-{ pub full_count : i64,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub downloads : i32,pub likes_and_stars : i32,pub follows_and_watchers : i32,pub spigot_id : Option<i32>,pub spigot_slug : Option<String>,pub spigot_name : Option<String>,pub spigot_description : Option<String>,pub spigot_author : Option<String>,pub spigot_version : Option<String>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_data : Option<String>,pub modrinth_id : Option<String>,pub modrinth_slug : Option<String>,pub modrinth_name : Option<String>,pub modrinth_description : Option<String>,pub modrinth_author : Option<String>,pub modrinth_version : Option<String>,pub modrinth_status : Option<String>,pub modrinth_icon_url : Option<String>,pub hangar_slug : Option<String>,pub hangar_name : Option<String>,pub hangar_description : Option<String>,pub hangar_author : Option<String>,pub hangar_version : Option<String>,pub hangar_icon_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,}pub struct SearchResultEntityBorrowed<'a> { pub full_count : i64,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub downloads : i32,pub likes_and_stars : i32,pub follows_and_watchers : i32,pub spigot_id : Option<i32>,pub spigot_slug : Option<&'a str>,pub spigot_name : Option<&'a str>,pub spigot_description : Option<&'a str>,pub spigot_author : Option<&'a str>,pub spigot_version : Option<&'a str>,pub spigot_premium : Option<bool>,pub spigot_abandoned : Option<bool>,pub spigot_icon_data : Option<&'a str>,pub modrinth_id : Option<&'a str>,pub modrinth_slug : Option<&'a str>,pub modrinth_name : Option<&'a str>,pub modrinth_description : Option<&'a str>,pub modrinth_author : Option<&'a str>,pub modrinth_version : Option<&'a str>,pub modrinth_status : Option<&'a str>,pub modrinth_icon_url : Option<&'a str>,pub hangar_slug : Option<&'a str>,pub hangar_name : Option<&'a str>,pub hangar_description : Option<&'a str>,pub hangar_author : Option<&'a str>,pub hangar_version : Option<&'a str>,pub hangar_icon_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,}
-impl<'a> From<SearchResultEntityBorrowed<'a>> for SearchResultEntity
-{
-    fn from(SearchResultEntityBorrowed { full_count,date_created,date_updated,latest_minecraft_version,downloads,likes_and_stars,follows_and_watchers,spigot_id,spigot_slug,spigot_name,spigot_description,spigot_author,spigot_version,spigot_premium,spigot_abandoned,spigot_icon_data,modrinth_id,modrinth_slug,modrinth_name,modrinth_description,modrinth_author,modrinth_version,modrinth_status,modrinth_icon_url,hangar_slug,hangar_name,hangar_description,hangar_author,hangar_version,hangar_icon_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,}: SearchResultEntityBorrowed<'a>) ->
-    Self { Self { full_count,date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),downloads,likes_and_stars,follows_and_watchers,spigot_id,spigot_slug: spigot_slug.map(|v| v.into()),spigot_name: spigot_name.map(|v| v.into()),spigot_description: spigot_description.map(|v| v.into()),spigot_author: spigot_author.map(|v| v.into()),spigot_version: spigot_version.map(|v| v.into()),spigot_premium,spigot_abandoned,spigot_icon_data: spigot_icon_data.map(|v| v.into()),modrinth_id: modrinth_id.map(|v| v.into()),modrinth_slug: modrinth_slug.map(|v| v.into()),modrinth_name: modrinth_name.map(|v| v.into()),modrinth_description: modrinth_description.map(|v| v.into()),modrinth_author: modrinth_author.map(|v| v.into()),modrinth_version: modrinth_version.map(|v| v.into()),modrinth_status: modrinth_status.map(|v| v.into()),modrinth_icon_url: modrinth_icon_url.map(|v| v.into()),hangar_slug: hangar_slug.map(|v| v.into()),hangar_name: hangar_name.map(|v| v.into()),hangar_description: hangar_description.map(|v| v.into()),hangar_author: hangar_author.map(|v| v.into()),hangar_version: hangar_version.map(|v| v.into()),hangar_icon_url: hangar_icon_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),} }
-}pub struct SearchResultEntityQuery<'a, C: GenericClient, T, const N: usize>
-{
-    client: &'a  C, params:
-    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> SearchResultEntityBorrowed,
-    mapper: fn(SearchResultEntityBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> SearchResultEntityQuery<'a, C, T, N> where C:
-GenericClient
-{
-    pub fn map<R>(self, mapper: fn(SearchResultEntityBorrowed) -> R) ->
-    SearchResultEntityQuery<'a,C,R,N>
-    {
-        SearchResultEntityQuery
-        {
-            client: self.client, params: self.params, stmt: self.stmt,
-            extractor: self.extractor, mapper,
-        }
-    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
-    {
-        let stmt = self.stmt.prepare(self.client).await?; let row =
-        self.client.query_one(stmt, &self.params).await?;
-        Ok((self.mapper)((self.extractor)(&row)))
-    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
-    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
-    Result<Option<T>, tokio_postgres::Error>
-    {
-        let stmt = self.stmt.prepare(self.client).await?;
-        Ok(self.client.query_opt(stmt, &self.params) .await?
-        .map(|row| (self.mapper)((self.extractor)(&row))))
-    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
-    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
-    {
-        let stmt = self.stmt.prepare(self.client).await?; let it =
-        self.client.query_raw(stmt,
-        cornucopia_async::private::slice_iter(&self.params)) .await?
-        .map(move |res|
-        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
-        Ok(it)
+        |row| { SourceRepositoryReleaseEntityBorrowed { host: row.get(0),owner: row.get(1),name: row.get(2),tag_name: row.get(3),release_name: row.get(4),url: row.get(5),date_published: row.get(6),date_last_checked: row.get(7),} }, mapper: |it| { <SourceRepositoryReleaseEntity>::from(it) },
     }
-}pub fn search_projects() -> SearchProjectsStmt
-{ SearchProjectsStmt(cornucopia_async::private::Stmt::new("SELECT
-  COUNT(*) OVER() AS full_count,
-
-  GREATEST(
-    CASE WHEN $1 IS TRUE THEN spigot_date_created ELSE NULL END,
-    CASE WHEN $2 IS TRUE THEN modrinth_date_created ELSE NULL END,
-    CASE WHEN $3 IS TRUE THEN hangar_date_created ELSE NULL END
-  ) AS date_created,
-
-  GREATEST(
-    CASE WHEN $1 IS TRUE THEN spigot_date_updated ELSE NULL END,
-    CASE WHEN $2 IS TRUE THEN modrinth_date_updated ELSE NULL END,
-    CASE WHEN $3 IS TRUE THEN hangar_date_updated ELSE NULL END
-  ) AS date_updated,
-
-  GREATEST(
-    CASE WHEN $1 IS TRUE THEN spigot_latest_minecraft_version ELSE NULL END,
-    CASE WHEN $2 IS TRUE THEN modrinth_latest_minecraft_version ELSE NULL END,
-    CASE WHEN $3 IS TRUE THEN hangar_latest_minecraft_version ELSE NULL END
-  ) AS latest_minecraft_version,
-
-  CASE WHEN $1 IS TRUE THEN COALESCE(spigot_downloads, 0) ELSE 0 END +
-  CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_downloads, 0) ELSE 0 END +
-  CASE WHEN $3 IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END
-  AS downloads,
-
-  CASE WHEN $1 IS TRUE THEN COALESCE(spigot_likes, 0) ELSE 0 END +
-  CASE WHEN $3 IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END
-  AS likes_and_stars,
-
-  CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_follows, 0) ELSE 0 END +
-  CASE WHEN $3 IS TRUE THEN COALESCE(hangar_watchers, 0) ELSE 0 END
-  AS follows_and_watchers,
-
-  (CASE WHEN $1 IS TRUE THEN spigot_id ELSE NULL END) AS spigot_id,
-  (CASE WHEN $1 IS TRUE THEN spigot_slug ELSE NULL END) AS spigot_slug,
-  (CASE WHEN $1 IS TRUE THEN spigot_name ELSE NULL END) AS spigot_name,
-  (CASE WHEN $1 IS TRUE THEN spigot_description ELSE NULL END) AS spigot_description,
-  (CASE WHEN $1 IS TRUE THEN spigot_author ELSE NULL END) AS spigot_author,
-  (CASE WHEN $1 IS TRUE THEN spigot_version ELSE NULL END) AS spigot_version,
-  (CASE WHEN $1 IS TRUE THEN spigot_premium ELSE NULL END) AS spigot_premium,
-  (CASE WHEN $1 IS TRUE THEN spigot_abandoned ELSE NULL END) AS spigot_abandoned,
-  (CASE WHEN $1 IS TRUE THEN spigot_icon_data ELSE NULL END) AS spigot_icon_data,
-
-  (CASE WHEN $2 IS TRUE THEN modrinth_id ELSE NULL END) AS modrinth_id,
-  (CASE WHEN $2 IS TRUE THEN modrinth_slug ELSE NULL END) AS modrinth_slug,
-  (CASE WHEN $2 IS TRUE THEN modrinth_name ELSE NULL END) AS modrinth_name,
-  (CASE WHEN $2 IS TRUE THEN modrinth_description ELSE NULL END) AS modrinth_description,
-  (CASE WHEN $2 IS TRUE THEN modrinth_author ELSE NULL END) AS modrinth_author,
-  (CASE WHEN $2 IS TRUE THEN modrinth_version ELSE NULL END) AS modrinth_version,
-  (CASE WHEN $2 IS TRUE THEN modrinth_status ELSE NULL END) AS modrinth_status,
-  (CASE WHEN $2 IS TRUE THEN modrinth_icon_url ELSE NULL END) AS modrinth_icon_url,
-
-  (CASE WHEN $3 IS TRUE THEN hangar_slug ELSE NULL END) AS hangar_slug,
-  (CASE WHEN $3 IS TRUE THEN hangar_name ELSE NULL END) AS hangar_name,
-  (CASE WHEN $3 IS TRUE THEN hangar_description ELSE NULL END) AS hangar_description,
-  (CASE WHEN $3 IS TRUE THEN hangar_author ELSE NULL END) AS hangar_author,
-  (CASE WHEN $3 IS TRUE THEN hangar_version ELSE NULL END) AS hangar_version,
-  (CASE WHEN $3 IS TRUE THEN hangar_icon_url ELSE NULL END) AS hangar_icon_url,
-
-  source_repository_host,
-  source_repository_owner,
-  source_repository_name,
-  source_repository_id
-FROM
-  common_project
-WHERE
-  CASE $1 IS TRUE AND $4 = ''
-    WHEN TRUE THEN spigot_id IS NOT NULL
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $1 IS TRUE AND $5 IS TRUE
-    WHEN TRUE THEN $4 <% spigot_name
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $1 IS TRUE AND $6 IS TRUE
-    WHEN TRUE THEN $4 <% spigot_description
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $1 IS TRUE AND $7 IS TRUE
-    WHEN TRUE THEN $4 <% spigot_author
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $2 IS TRUE AND $4 = ''
-    WHEN TRUE THEN modrinth_id IS NOT NULL
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $2 IS TRUE AND $5 IS TRUE
-    WHEN TRUE THEN $4 <% modrinth_name
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $2 IS TRUE AND $6 IS TRUE
-    WHEN TRUE THEN $4 <% modrinth_description
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $2 IS TRUE AND $7 IS TRUE
-    WHEN TRUE THEN $4 <% modrinth_author
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $3 IS TRUE AND $4 = ''
-    WHEN TRUE THEN hangar_slug IS NOT NULL
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $3 IS TRUE AND $5 IS TRUE
-    WHEN TRUE THEN $4 <% hangar_name
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $3 IS TRUE AND $6 IS TRUE
-    WHEN TRUE THEN $4 <% hangar_description
-    ELSE FALSE
-  END
-
-  OR
-
-  CASE $3 IS TRUE AND $7 IS TRUE
-    WHEN TRUE THEN $4 <% hangar_author
-    ELSE FALSE
-  END
-
-  ORDER BY
-    -- Sorts on 'real' type
-    CASE
-      WHEN $8 = 'relevance' AND $4 != '' THEN
-        GREATEST(
-          CASE WHEN $1 IS TRUE THEN
-            GREATEST(
-              CASE WHEN $5 IS TRUE THEN $4 <<-> spigot_name ELSE NULL END,
-              CASE WHEN $6 IS TRUE THEN $4 <<-> spigot_description ELSE NULL END,
-              CASE WHEN $7 IS TRUE THEN $4 <<-> spigot_author ELSE NULL END
-            )
-          ELSE NULL END,
-          CASE WHEN $2 IS TRUE THEN
-            GREATEST(
-              CASE WHEN $5 IS TRUE THEN $4 <<-> modrinth_name ELSE NULL END,
-              CASE WHEN $6 IS TRUE THEN $4 <<-> modrinth_description ELSE NULL END,
-              CASE WHEN $7 IS TRUE THEN $4 <<-> modrinth_author ELSE NULL END
-            )
-          ELSE NULL END,
-          CASE WHEN $3 IS TRUE THEN
-            GREATEST(
-              CASE WHEN $5 IS TRUE THEN $4 <<-> hangar_name ELSE NULL END,
-              CASE WHEN $6 IS TRUE THEN $4 <<-> hangar_description ELSE NULL END,
-              CASE WHEN $7 IS TRUE THEN $4 <<-> hangar_author ELSE NULL END
-            )
-          ELSE NULL END
-        )
-    END ASC NULLS LAST,
-
-    -- Sorts on 'timestamptz' type
-    CASE
-      WHEN $8 = 'date_created' THEN
-        GREATEST(
-          CASE WHEN $1 IS TRUE THEN spigot_date_created ELSE NULL END,
-          CASE WHEN $2 IS TRUE THEN modrinth_date_created ELSE NULL END,
-          CASE WHEN $3 IS TRUE THEN hangar_date_created ELSE NULL END
-        )
-
-      WHEN $8 = 'date_updated' THEN
-        GREATEST(
-          CASE WHEN $1 IS TRUE THEN spigot_date_updated ELSE NULL END,
-          CASE WHEN $2 IS TRUE THEN modrinth_date_updated ELSE NULL END,
-          CASE WHEN $3 IS TRUE THEN hangar_date_updated ELSE NULL END
-        )
-    END DESC NULLS LAST,
-
-    -- Sorts on 'text' type
-    CASE
-      WHEN $8 = 'latest_minecraft_version' THEN
-        GREATEST(
-          CASE WHEN $1 IS TRUE THEN spigot_latest_minecraft_version ELSE NULL END,
-          CASE WHEN $2 IS TRUE THEN modrinth_latest_minecraft_version ELSE NULL END,
-          CASE WHEN $3 IS TRUE THEN hangar_latest_minecraft_version ELSE NULL END
-        )
-    END DESC NULLS LAST,
-
-    -- Sorts on 'integer' type
-    CASE
-      WHEN $8 = 'likes_and_stars' THEN
-        CASE WHEN $1 IS TRUE THEN COALESCE(spigot_likes, 0) ELSE 0 END +
-        CASE WHEN $3 IS TRUE THEN COALESCE(hangar_stars, 0) ELSE 0 END
-
-      WHEN $8 = 'follows_and_watchers' THEN
-        CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_follows, 0) ELSE 0 END +
-        CASE WHEN $3 IS TRUE THEN COALESCE(hangar_watchers, 0) ELSE 0 END
-    END DESC NULLS LAST,
-
-    -- Fallback to sort by downloads when no sort is specified or as a secondary sort
-    CASE WHEN $1 IS TRUE THEN COALESCE(spigot_downloads, 0) ELSE 0 END +
-    CASE WHEN $2 IS TRUE THEN COALESCE(modrinth_downloads, 0) ELSE 0 END +
-    CASE WHEN $3 IS TRUE THEN COALESCE(hangar_downloads, 0) ELSE 0 END
-    DESC NULLS LAST
-
-LIMIT $9
-OFFSET $10")) } pub struct
-SearchProjectsStmt(cornucopia_async::private::Stmt); impl SearchProjectsStmt
+} }pub fn get_source_repository_release_by_repository() -> GetSourceRepositoryReleaseByRepositoryStmt
+{ GetSourceRepositoryReleaseByRepositoryStmt(cornucopia_async::private::Stmt::new("SELECT * FROM source_repository_release
+WHERE host = $1 AND owner = $2 AND name = $3")) } pub struct
+GetSourceRepositoryReleaseByRepositoryStmt(cornucopia_async::private::Stmt); impl GetSourceRepositoryReleaseByRepositoryStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-spigot: &'a bool,modrinth: &'a bool,hangar: &'a bool,query: &'a T1,name: &'a bool,description: &'a bool,author: &'a bool,sort: &'a T2,limit: &'a i64,offset: &'a i64,) -> SearchResultEntityQuery<'a,C,
-SearchResultEntity, 10>
+host: &'a T1,owner: &'a T2,name: &'a T3,) -> SourceRepositoryReleaseEntityQuery<'a,C, SourceRepositoryReleaseEntity,
+3>
 {
-    SearchResultEntityQuery
+    SourceRepositoryReleaseEntityQuery
     {
-        client, params: [spigot,modrinth,hangar,query,name,description,author,sort,limit,offset,], stmt: &mut self.0, extractor:
-        |row| { SearchResultEntityBorrowed { full_count: row.get(0),date_created: row.get(1),date_updated: row.get(2),latest_minecraft_version: row.get(3),downloads: row.get(4),likes_and_stars: row.get(5),follows_and_watchers: row.get(6),spigot_id: row.get(7),spigot_slug: row.get(8),spigot_name: row.get(9),spigot_description: row.get(10),spigot_author: row.get(11),spigot_version: row.get(12),spigot_premium: row.get(13),spigot_abandoned: row.get(14),spigot_icon_data: row.get(15),modrinth_id: row.get(16),modrinth_slug: row.get(17),modrinth_name: row.get(18),modrinth_description: row.get(19),modrinth_author: row.get(20),modrinth_version: row.get(21),modrinth_status: row.get(22),modrinth_icon_url: row.get(23),hangar_slug: row.get(24),hangar_name: row.get(25),hangar_description: row.get(26),hangar_author: row.get(27),hangar_version: row.get(28),hangar_icon_url: row.get(29),source_repository_host: row.get(30),source_repository_owner: row.get(31),source_repository_name: row.get(32),source_repository_id: row.get(33),} }, mapper: |it| { <SearchResultEntity>::from(it) },
+        client, params: [host,owner,name,], stmt: &mut self.0, extractor:
+        |row| { SourceRepositoryReleaseEntityBorrowed { host: row.get(0),owner: row.get(1),name: row.get(2),tag_name: row.get(3),release_name: row.get(4),url: row.get(5),date_published: row.get(6),date_last_checked: row.get(7),} }, mapper: |it| { <SourceRepositoryReleaseEntity>::from(it) },
     }
-} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
-SearchProjectsParams<T1,T2,>, SearchResultEntityQuery<'a, C,
-SearchResultEntity, 10>, C> for SearchProjectsStmt
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+GetSourceRepositoryReleaseByRepositoryParams<T1,T2,T3,>, SourceRepositoryReleaseEntityQuery<'a, C, SourceRepositoryReleaseEntity,
+3>, C> for GetSourceRepositoryReleaseByRepositoryStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    SearchProjectsParams<T1,T2,>) -> SearchResultEntityQuery<'a, C,
-    SearchResultEntity, 10>
-    { self.bind(client, &params.spigot,&params.modrinth,&params.hangar,&params.query,&params.name,&params.description,&params.author,&params.sort,&params.limit,&params.offset,) }
-}}pub mod spigot_author
+    GetSourceRepositoryReleaseByRepositoryParams<T1,T2,T3,>) -> SourceRepositoryReleaseEntityQuery<'a, C,
+    SourceRepositoryReleaseEntity, 3>
+    { self.bind(client, &params.host,&params.owner,&params.name,) }
+}pub fn get_distinct_source_repositories() -> GetDistinctSourceRepositoriesStmt
+{ GetDistinctSourceRepositoriesStmt(cornucopia_async::private::Stmt::new("SELECT DISTINCT
+  LOWER(source_repository_host) AS host,
+  LOWER(source_repository_owner) AS owner,
+  LOWER(source_repository_name) AS name
+FROM common_project
+WHERE source_repository_host IS NOT NULL
+  AND source_repository_owner IS NOT NULL
+  AND source_repository_name IS NOT NULL
+ORDER BY host, owner, name")) } pub struct
+GetDistinctSourceRepositoriesStmt(cornucopia_async::private::Stmt); impl GetDistinctSourceRepositoriesStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> SourceRepositoryEntityQuery<'a,C, SourceRepositoryEntity,
+0>
+{
+    SourceRepositoryEntityQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { SourceRepositoryEntityBorrowed { host: row.get(0),owner: row.get(1),name: row.get(2),} }, mapper: |it| { <SourceRepositoryEntity>::from(it) },
+    }
+} }}pub mod spigot_author
 { use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InsertSpigotAuthorParams<T1: cornucopia_async::StringSql,> { pub id: i32,pub name: T1,}#[derive( Debug, Clone, PartialEq,)] pub struct SpigotAuthorEntity
 { pub id : i32,pub name : String,}pub struct SpigotAuthorEntityBorrowed<'a> { pub id : i32,pub name : &'a str,}
 impl<'a> From<SpigotAuthorEntityBorrowed<'a>> for SpigotAuthorEntity
 {
-    fn from(SpigotAuthorEntityBorrowed { id,name,}: SpigotAuthorEntityBorrowed<'a>) ->
-    Self { Self { id,name: name.into(),} }
+    fn from(SpigotAuthorEntityBorrowed { id,name,}: SpigotAuthorEntityBorrowed<'a>) -> Self
+    { Self { id,name: name.into(),} }
 }pub struct SpigotAuthorEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
@@ -1153,21 +3849,35 @@ tokio_postgres::Error>> + Send + 'a>>, C> for InsertSpigotAuthorStmt
 GetSpigotAuthorsStmt(cornucopia_async::private::Stmt); impl GetSpigotAuthorsStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> SpigotAuthorEntityQuery<'a,C,
-SpigotAuthorEntity, 0>
+) -> SpigotAuthorEntityQuery<'a,C, SpigotAuthorEntity,
+0>
 {
     SpigotAuthorEntityQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
         |row| { SpigotAuthorEntityBorrowed { id: row.get(0),name: row.get(1),} }, mapper: |it| { <SpigotAuthorEntity>::from(it) },
     }
+} }pub fn get_spigot_author_by_id() -> GetSpigotAuthorByIdStmt
+{ GetSpigotAuthorByIdStmt(cornucopia_async::private::Stmt::new("SELECT id, name FROM spigot_author
+WHERE id = $1")) } pub struct
+GetSpigotAuthorByIdStmt(cornucopia_async::private::Stmt); impl GetSpigotAuthorByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+id: &'a i32,) -> SpigotAuthorEntityQuery<'a,C, SpigotAuthorEntity,
+1>
+{
+    SpigotAuthorEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { SpigotAuthorEntityBorrowed { id: row.get(0),name: row.get(1),} }, mapper: |it| { <SpigotAuthorEntity>::from(it) },
+    }
 } }}pub mod spigot_resource
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertSpigotResourceParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,> { pub id: i32,pub name: T1,pub parsed_name: Option<T2>,pub description: T3,pub slug: T4,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T5>,pub downloads: i32,pub likes: i32,pub author_id: i32,pub version_id: i32,pub version_name: Option<T6>,pub premium: bool,pub abandoned: bool,pub icon_url: Option<T7>,pub icon_data: Option<T8>,pub source_url: Option<T9>,pub source_repository_host: Option<T10>,pub source_repository_owner: Option<T11>,pub source_repository_name: Option<T12>,}#[derive( Debug, Clone, PartialEq,)] pub struct SpigotResourceEntity
-{ pub id : i32,pub name : String,pub parsed_name : Option<String>,pub description : String,pub slug : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub downloads : i32,pub likes : i32,pub author_id : i32,pub version_id : i32,pub version_name : Option<String>,pub premium : bool,pub abandoned : bool,pub icon_url : Option<String>,pub icon_data : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,}pub struct SpigotResourceEntityBorrowed<'a> { pub id : i32,pub name : &'a str,pub parsed_name : Option<&'a str>,pub description : &'a str,pub slug : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub downloads : i32,pub likes : i32,pub author_id : i32,pub version_id : i32,pub version_name : Option<&'a str>,pub premium : bool,pub abandoned : bool,pub icon_url : Option<&'a str>,pub icon_data : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,}
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct UpsertSpigotResourceParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = i32>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T15: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T16: cornucopia_async::StringSql,T17: cornucopia_async::ArraySql<Item = T16>,T18: cornucopia_async::StringSql,> { pub id: i32,pub name: T1,pub parsed_name: Option<T2>,pub description: T3,pub slug: T4,pub date_created: time::OffsetDateTime,pub date_updated: time::OffsetDateTime,pub latest_minecraft_version: Option<T5>,pub latest_minecraft_version_sort_key: Option<T6>,pub downloads: i32,pub likes: i32,pub author_id: i32,pub version_id: i32,pub version_name: Option<T7>,pub premium: bool,pub abandoned: bool,pub icon_url: Option<T8>,pub icon_data: Option<T9>,pub source_url: Option<T10>,pub source_repository_host: Option<T11>,pub source_repository_owner: Option<T12>,pub source_repository_name: Option<T13>,pub platforms: T14,pub categories: T15,pub minecraft_versions: T17,pub full_description: Option<T18>,}#[derive( Debug, Clone, PartialEq,)] pub struct SpigotResourceEntity
+{ pub id : i32,pub name : String,pub parsed_name : Option<String>,pub description : String,pub slug : String,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<String>,pub downloads : i32,pub likes : i32,pub author_id : i32,pub version_id : i32,pub version_name : Option<String>,pub premium : bool,pub abandoned : bool,pub icon_url : Option<String>,pub icon_data : Option<String>,pub source_url : Option<String>,pub source_repository_host : Option<String>,pub source_repository_owner : Option<String>,pub source_repository_name : Option<String>,pub source_repository_id : Option<String>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : Vec<super::super::types::public::ServerPlatform>,pub minecraft_versions : Vec<String>,pub latest_minecraft_version_sort_key : Option<Vec<i32>>,pub categories : Vec<super::super::types::public::ProjectCategory>,pub full_description : Option<String>,}pub struct SpigotResourceEntityBorrowed<'a> { pub id : i32,pub name : &'a str,pub parsed_name : Option<&'a str>,pub description : &'a str,pub slug : &'a str,pub date_created : time::OffsetDateTime,pub date_updated : time::OffsetDateTime,pub latest_minecraft_version : Option<&'a str>,pub downloads : i32,pub likes : i32,pub author_id : i32,pub version_id : i32,pub version_name : Option<&'a str>,pub premium : bool,pub abandoned : bool,pub icon_url : Option<&'a str>,pub icon_data : Option<&'a str>,pub source_url : Option<&'a str>,pub source_repository_host : Option<&'a str>,pub source_repository_owner : Option<&'a str>,pub source_repository_name : Option<&'a str>,pub source_repository_id : Option<&'a str>,pub date_last_seen : Option<time::OffsetDateTime>,pub date_deleted : Option<time::OffsetDateTime>,pub platforms : cornucopia_async::ArrayIterator<'a, super::super::types::public::ServerPlatform>,pub minecraft_versions : cornucopia_async::ArrayIterator<'a, &'a str>,pub latest_minecraft_version_sort_key : Option<cornucopia_async::ArrayIterator<'a, i32>>,pub categories : cornucopia_async::ArrayIterator<'a, super::super::types::public::ProjectCategory>,pub full_description : Option<&'a str>,}
 impl<'a> From<SpigotResourceEntityBorrowed<'a>> for SpigotResourceEntity
 {
-    fn from(SpigotResourceEntityBorrowed { id,name,parsed_name,description,slug,date_created,date_updated,latest_minecraft_version,downloads,likes,author_id,version_id,version_name,premium,abandoned,icon_url,icon_data,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,}: SpigotResourceEntityBorrowed<'a>) ->
-    Self { Self { id,name: name.into(),parsed_name: parsed_name.map(|v| v.into()),description: description.into(),slug: slug.into(),date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),downloads,likes,author_id,version_id,version_name: version_name.map(|v| v.into()),premium,abandoned,icon_url: icon_url.map(|v| v.into()),icon_data: icon_data.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),} }
+    fn from(SpigotResourceEntityBorrowed { id,name,parsed_name,description,slug,date_created,date_updated,latest_minecraft_version,downloads,likes,author_id,version_id,version_name,premium,abandoned,icon_url,icon_data,source_url,source_repository_host,source_repository_owner,source_repository_name,source_repository_id,date_last_seen,date_deleted,platforms,minecraft_versions,latest_minecraft_version_sort_key,categories,full_description,}: SpigotResourceEntityBorrowed<'a>) -> Self
+    { Self { id,name: name.into(),parsed_name: parsed_name.map(|v| v.into()),description: description.into(),slug: slug.into(),date_created,date_updated,latest_minecraft_version: latest_minecraft_version.map(|v| v.into()),downloads,likes,author_id,version_id,version_name: version_name.map(|v| v.into()),premium,abandoned,icon_url: icon_url.map(|v| v.into()),icon_data: icon_data.map(|v| v.into()),source_url: source_url.map(|v| v.into()),source_repository_host: source_repository_host.map(|v| v.into()),source_repository_owner: source_repository_owner.map(|v| v.into()),source_repository_name: source_repository_name.map(|v| v.into()),source_repository_id: source_repository_id.map(|v| v.into()),date_last_seen,date_deleted,platforms: platforms.map(|v| v).collect(),minecraft_versions: minecraft_versions.map(|v| v.into()).collect(),latest_minecraft_version_sort_key: latest_minecraft_version_sort_key.map(|v| v.map(|v| v).collect()),categories: categories.map(|v| v).collect(),full_description: full_description.map(|v| v.into()),} }
 }pub struct SpigotResourceEntityQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
@@ -1247,8 +3957,8 @@ GenericClient
         Ok(it)
     }
 }pub fn upsert_spigot_resource() -> UpsertSpigotResourceStmt
-{ UpsertSpigotResourceStmt(cornucopia_async::private::Stmt::new("INSERT INTO spigot_resource (id, name, parsed_name, description, slug, date_created, date_updated, latest_minecraft_version, downloads, likes, author_id, version_id, version_name, premium, abandoned, icon_url, icon_data, source_url, source_repository_host, source_repository_owner, source_repository_name)
-  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
+{ UpsertSpigotResourceStmt(cornucopia_async::private::Stmt::new("INSERT INTO spigot_resource (id, name, parsed_name, description, slug, date_created, date_updated, latest_minecraft_version, latest_minecraft_version_sort_key, downloads, likes, author_id, version_id, version_name, premium, abandoned, icon_url, icon_data, source_url, source_repository_host, source_repository_owner, source_repository_name, platforms, categories, minecraft_versions, full_description, date_last_seen)
+  VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, NOW())
   ON CONFLICT (id)
   DO UPDATE SET
     name = EXCLUDED.name,
@@ -1258,6 +3968,7 @@ GenericClient
     date_created = EXCLUDED.date_created,
     date_updated = EXCLUDED.date_updated,
     latest_minecraft_version = EXCLUDED.latest_minecraft_version,
+    latest_minecraft_version_sort_key = EXCLUDED.latest_minecraft_version_sort_key,
     downloads = EXCLUDED.downloads,
     likes = EXCLUDED.likes,
     author_id = EXCLUDED.author_id,
@@ -1270,7 +3981,13 @@ GenericClient
     source_url = EXCLUDED.source_url,
     source_repository_host = EXCLUDED.source_repository_host,
     source_repository_owner = EXCLUDED.source_repository_owner,
-    source_repository_name = EXCLUDED.source_repository_name")) } pub struct
+    source_repository_name = EXCLUDED.source_repository_name,
+    platforms = EXCLUDED.platforms,
+    categories = EXCLUDED.categories,
+    minecraft_versions = EXCLUDED.minecraft_versions,
+    full_description = EXCLUDED.full_description,
+    date_last_seen = EXCLUDED.date_last_seen,
+    date_deleted = NULL")) } pub struct
 UpsertSpigotResourceStmt(cornucopia_async::private::Stmt); impl UpsertSpigotResourceStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
@@ -1279,50 +3996,87 @@ cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
 cornucopia_async::StringSql,T5:
 cornucopia_async::StringSql,T6:
-cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = i32>,T7:
 cornucopia_async::StringSql,T8:
 cornucopia_async::StringSql,T9:
 cornucopia_async::StringSql,T10:
 cornucopia_async::StringSql,T11:
 cornucopia_async::StringSql,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::StringSql,T14:
+cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T15:
+cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T16:
+cornucopia_async::StringSql,T17:
+cornucopia_async::ArraySql<Item = T16>,T18:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a i32,name: &'a T1,parsed_name: &'a Option<T2>,description: &'a T3,slug: &'a T4,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T5>,downloads: &'a i32,likes: &'a i32,author_id: &'a i32,version_id: &'a i32,version_name: &'a Option<T6>,premium: &'a bool,abandoned: &'a bool,icon_url: &'a Option<T7>,icon_data: &'a Option<T8>,source_url: &'a Option<T9>,source_repository_host: &'a Option<T10>,source_repository_owner: &'a Option<T11>,source_repository_name: &'a Option<T12>,) -> Result<u64, tokio_postgres::Error>
+id: &'a i32,name: &'a T1,parsed_name: &'a Option<T2>,description: &'a T3,slug: &'a T4,date_created: &'a time::OffsetDateTime,date_updated: &'a time::OffsetDateTime,latest_minecraft_version: &'a Option<T5>,latest_minecraft_version_sort_key: &'a Option<T6>,downloads: &'a i32,likes: &'a i32,author_id: &'a i32,version_id: &'a i32,version_name: &'a Option<T7>,premium: &'a bool,abandoned: &'a bool,icon_url: &'a Option<T8>,icon_data: &'a Option<T9>,source_url: &'a Option<T10>,source_repository_host: &'a Option<T11>,source_repository_owner: &'a Option<T12>,source_repository_name: &'a Option<T13>,platforms: &'a T14,categories: &'a T15,minecraft_versions: &'a T17,full_description: &'a Option<T18>,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[id,name,parsed_name,description,slug,date_created,date_updated,latest_minecraft_version,downloads,likes,author_id,version_id,version_name,premium,abandoned,icon_url,icon_data,source_url,source_repository_host,source_repository_owner,source_repository_name,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, UpsertSpigotResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    client.execute(stmt, &[id,name,parsed_name,description,slug,date_created,date_updated,latest_minecraft_version,latest_minecraft_version_sort_key,downloads,likes,author_id,version_id,version_name,premium,abandoned,icon_url,icon_data,source_url,source_repository_host,source_repository_owner,source_repository_name,platforms,categories,minecraft_versions,full_description,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = i32>,T7: cornucopia_async::StringSql,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::StringSql,T12: cornucopia_async::StringSql,T13: cornucopia_async::StringSql,T14: cornucopia_async::ArraySql<Item = super::super::types::public::ServerPlatform>,T15: cornucopia_async::ArraySql<Item = super::super::types::public::ProjectCategory>,T16: cornucopia_async::StringSql,T17: cornucopia_async::ArraySql<Item = T16>,T18: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, UpsertSpigotResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
 tokio_postgres::Error>> + Send + 'a>>, C> for UpsertSpigotResourceStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    UpsertSpigotResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    UpsertSpigotResourceParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.id,&params.name,&params.parsed_name,&params.description,&params.slug,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.downloads,&params.likes,&params.author_id,&params.version_id,&params.version_name,&params.premium,&params.abandoned,&params.icon_url,&params.icon_data,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,)) }
+    { Box::pin(self.bind(client, &params.id,&params.name,&params.parsed_name,&params.description,&params.slug,&params.date_created,&params.date_updated,&params.latest_minecraft_version,&params.latest_minecraft_version_sort_key,&params.downloads,&params.likes,&params.author_id,&params.version_id,&params.version_name,&params.premium,&params.abandoned,&params.icon_url,&params.icon_data,&params.source_url,&params.source_repository_host,&params.source_repository_owner,&params.source_repository_name,&params.platforms,&params.categories,&params.minecraft_versions,&params.full_description,)) }
 }pub fn get_spigot_resources() -> GetSpigotResourcesStmt
-{ GetSpigotResourcesStmt(cornucopia_async::private::Stmt::new("SELECT * FROM spigot_resource")) } pub struct
+{ GetSpigotResourcesStmt(cornucopia_async::private::Stmt::new("SELECT * FROM spigot_resource
+WHERE date_deleted IS NULL")) } pub struct
 GetSpigotResourcesStmt(cornucopia_async::private::Stmt); impl GetSpigotResourcesStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> SpigotResourceEntityQuery<'a,C,
-SpigotResourceEntity, 0>
+) -> SpigotResourceEntityQuery<'a,C, SpigotResourceEntity,
+0>
 {
     SpigotResourceEntityQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
-        |row| { SpigotResourceEntityBorrowed { id: row.get(0),name: row.get(1),parsed_name: row.get(2),description: row.get(3),slug: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),downloads: row.get(8),likes: row.get(9),author_id: row.get(10),version_id: row.get(11),version_name: row.get(12),premium: row.get(13),abandoned: row.get(14),icon_url: row.get(15),icon_data: row.get(16),source_url: row.get(17),source_repository_host: row.get(18),source_repository_owner: row.get(19),source_repository_name: row.get(20),source_repository_id: row.get(21),} }, mapper: |it| { <SpigotResourceEntity>::from(it) },
+        |row| { SpigotResourceEntityBorrowed { id: row.get(0),name: row.get(1),parsed_name: row.get(2),description: row.get(3),slug: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),downloads: row.get(8),likes: row.get(9),author_id: row.get(10),version_id: row.get(11),version_name: row.get(12),premium: row.get(13),abandoned: row.get(14),icon_url: row.get(15),icon_data: row.get(16),source_url: row.get(17),source_repository_host: row.get(18),source_repository_owner: row.get(19),source_repository_name: row.get(20),source_repository_id: row.get(21),date_last_seen: row.get(22),date_deleted: row.get(23),platforms: row.get(24),minecraft_versions: row.get(25),latest_minecraft_version_sort_key: row.get(26),categories: row.get(27),full_description: row.get(28),} }, mapper: |it| { <SpigotResourceEntity>::from(it) },
+    }
+} }pub fn get_spigot_resource_by_id() -> GetSpigotResourceByIdStmt
+{ GetSpigotResourceByIdStmt(cornucopia_async::private::Stmt::new("SELECT * FROM spigot_resource
+WHERE id = $1 AND date_deleted IS NULL")) } pub struct
+GetSpigotResourceByIdStmt(cornucopia_async::private::Stmt); impl GetSpigotResourceByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+id: &'a i32,) -> SpigotResourceEntityQuery<'a,C, SpigotResourceEntity,
+1>
+{
+    SpigotResourceEntityQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { SpigotResourceEntityBorrowed { id: row.get(0),name: row.get(1),parsed_name: row.get(2),description: row.get(3),slug: row.get(4),date_created: row.get(5),date_updated: row.get(6),latest_minecraft_version: row.get(7),downloads: row.get(8),likes: row.get(9),author_id: row.get(10),version_id: row.get(11),version_name: row.get(12),premium: row.get(13),abandoned: row.get(14),icon_url: row.get(15),icon_data: row.get(16),source_url: row.get(17),source_repository_host: row.get(18),source_repository_owner: row.get(19),source_repository_name: row.get(20),source_repository_id: row.get(21),date_last_seen: row.get(22),date_deleted: row.get(23),platforms: row.get(24),minecraft_versions: row.get(25),latest_minecraft_version_sort_key: row.get(26),categories: row.get(27),full_description: row.get(28),} }, mapper: |it| { <SpigotResourceEntity>::from(it) },
     }
 } }pub fn get_latest_spigot_resource_update_date() -> GetLatestSpigotResourceUpdateDateStmt
 { GetLatestSpigotResourceUpdateDateStmt(cornucopia_async::private::Stmt::new("SELECT max(date_updated) FROM spigot_resource")) } pub struct
 GetLatestSpigotResourceUpdateDateStmt(cornucopia_async::private::Stmt); impl GetLatestSpigotResourceUpdateDateStmt
 { pub fn bind<'a, C:
 GenericClient,>(&'a mut self, client: &'a  C,
-) -> TimeOffsetDateTimeQuery<'a,C,
-time::OffsetDateTime, 0>
+) -> TimeOffsetDateTimeQuery<'a,C, time::OffsetDateTime,
+0>
 {
     TimeOffsetDateTimeQuery
     {
         client, params: [], stmt: &mut self.0, extractor:
         |row| { row.get(0) }, mapper: |it| { it },
     }
+} }pub fn mark_unseen_spigot_resources_as_deleted() -> MarkUnseenSpigotResourcesAsDeletedStmt
+{ MarkUnseenSpigotResourcesAsDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE spigot_resource
+SET date_deleted = NOW()
+WHERE date_deleted IS NULL
+  AND (date_last_seen IS NULL OR date_last_seen < $1)
+  AND NOT EXISTS (
+    SELECT FROM ingest_failure
+    WHERE repository = 'Spigot' AND item = 'Resource' AND upstream_id = spigot_resource.id::text
+  )")) } pub struct
+MarkUnseenSpigotResourcesAsDeletedStmt(cornucopia_async::private::Stmt); impl MarkUnseenSpigotResourcesAsDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+date_started: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[date_started,]).await
 } }}}
\ No newline at end of file
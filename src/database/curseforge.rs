@@ -0,0 +1 @@
+pub mod project;
\ No newline at end of file
@@ -0,0 +1,420 @@
+use crate::database::project_category::{ProjectCategory, to_cornucopia_project_categories, from_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, to_cornucopia_server_platforms, from_cornucopia_server_platforms};
+use crate::database::source_repository::SourceRepository;
+use crate::database::cornucopia::queries::curseforge_project::{self, CurseForgeProjectEntity, UpsertCurseforgeProjectParams};
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+use crate::minecraft_version::get_minecraft_version_sort_key;
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CurseForgeProject {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_updated: OffsetDateTime,
+    pub latest_minecraft_version: Option<String>,
+    pub downloads: i32,
+    pub likes: i32,
+    pub icon_url: Option<String>,
+    pub version_name: Option<String>,
+    pub source_url: Option<String>,
+    pub source_repository: Option<SourceRepository>,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
+}
+
+impl From<CurseForgeProject> for UpsertCurseforgeProjectParams<String, String, String, String, String, Vec<i32>, String, String, String, String, String, String, Vec<CornucopiaServerPlatform>, Vec<CornucopiaProjectCategory>, String, Vec<String>> {
+    fn from(project: CurseForgeProject) -> Self {
+        let mut source_repository_host = None;
+        let mut source_repository_owner = None;
+        let mut source_repository_name = None;
+
+        if let Some(repo) = project.source_repository {
+            source_repository_host = Some(repo.host);
+            source_repository_owner = Some(repo.owner);
+            source_repository_name = Some(repo.name);
+        }
+
+        UpsertCurseforgeProjectParams {
+            id: project.id,
+            slug: project.slug,
+            name: project.name,
+            description: project.description,
+            author: project.author,
+            date_created: project.date_created,
+            date_updated: project.date_updated,
+            latest_minecraft_version_sort_key: get_minecraft_version_sort_key(project.latest_minecraft_version.as_deref()),
+            latest_minecraft_version: project.latest_minecraft_version,
+            downloads: project.downloads,
+            likes: project.likes,
+            icon_url: project.icon_url,
+            version_name: project.version_name,
+            source_url: project.source_url,
+            source_repository_host,
+            source_repository_owner,
+            source_repository_name,
+            platforms: to_cornucopia_server_platforms(project.platforms),
+            categories: to_cornucopia_project_categories(project.categories),
+            minecraft_versions: project.minecraft_versions
+        }
+    }
+}
+
+impl From<CurseForgeProjectEntity> for CurseForgeProject {
+    fn from(entity: CurseForgeProjectEntity) -> Self {
+        let mut source_repository = None;
+
+        if let (Some(host), Some(owner), Some(name)) = (entity.source_repository_host, entity.source_repository_owner, entity.source_repository_name) {
+            source_repository = Some(SourceRepository {
+                host,
+                owner,
+                name,
+                id: entity.source_repository_id
+            })
+        }
+
+        CurseForgeProject {
+            id: entity.id,
+            slug: entity.slug,
+            name: entity.name,
+            description: entity.description,
+            author: entity.author,
+            date_created: entity.date_created,
+            date_updated: entity.date_updated,
+            latest_minecraft_version: entity.latest_minecraft_version,
+            downloads: entity.downloads,
+            likes: entity.likes,
+            icon_url: entity.icon_url,
+            version_name: entity.version_name,
+            source_url: entity.source_url,
+            source_repository,
+            platforms: from_cornucopia_server_platforms(entity.platforms),
+            categories: from_cornucopia_project_categories(entity.categories),
+            minecraft_versions: entity.minecraft_versions
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum CurseForgeProjectError {
+    #[error("Skipping project {id}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        id: i32,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_curseforge_project(db_pool: &Pool, project: &CurseForgeProject) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = curseforge_project::upsert_curseforge_project()
+        .params(&db_client, &project.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            CurseForgeProjectError::DatabaseQueryFailed {
+                id: project.id,
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+pub async fn get_curseforge_projects(db_pool: &Pool) -> Result<Vec<CurseForgeProject>> {
+    let db_client = db_pool.get().await?;
+
+    let projects = curseforge_project::get_curseforge_projects()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(projects)
+}
+
+/// Returns the project with the given ID, or None if it does not exist or is deleted.
+pub async fn get_curseforge_project_by_id(db_pool: &Pool, id: i32) -> Result<Option<CurseForgeProject>> {
+    let db_client = db_pool.get().await?;
+
+    let project = curseforge_project::get_curseforge_project_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(project)
+}
+
+pub async fn get_latest_curseforge_project_update_date(db_pool: &Pool) -> Result<OffsetDateTime> {
+    let db_client = db_pool.get().await?;
+
+    let date = curseforge_project::get_latest_curseforge_project_update_date()
+        .bind(&db_client)
+        .one()
+        .await?;
+
+    Ok(date)
+}
+
+/// Marks projects that have not been seen upstream since the given date as deleted, returning the number of projects marked.
+/// Deleted projects are excluded from common projects, and are restored the next time they are upserted.
+/// Quarantined projects are left alone, since they may have been seen but failed to process.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn mark_unseen_curseforge_projects_as_deleted(db_pool: &Pool, date_started: OffsetDateTime) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = curseforge_project::mark_unseen_curseforge_projects_as_deleted()
+        .bind(&db_client, &date_started)
+        .await?;
+
+    Ok(count)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_curseforge_project() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let project = &create_test_curseforge_projects()[0];
+
+        // Act
+        upsert_curseforge_project(&context.pool, project).await?;
+
+        // Assert
+        let retrieved_projects = get_curseforge_projects(&context.pool).await?;
+        let retrieved_project = &retrieved_projects[0];
+
+        assert_that(&retrieved_projects).has_length(1);
+        assert_that(&retrieved_project).is_equal_to(project);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_curseforge_project() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _project = populate_test_curseforge_project(&context.pool).await?;
+
+        let updated_project = CurseForgeProject {
+            id: 1,
+            slug: "foo".to_string(),
+            name: "foo-updated".to_string(),
+            description: "foo-description-updated".to_string(),
+            author: "Frumple".to_string(),
+            date_created: datetime!(2020-01-01 0:00 UTC),
+            date_updated: datetime!(2021-07-01 0:00 UTC),
+            latest_minecraft_version: Some("1.22".to_string()),
+            downloads: 100,
+            likes: 200,
+            icon_url: Some("https://media.forgecdn.net/avatars/thumbnails/1/1/64/64/1.png".to_string()),
+            version_name: Some("foo-2.3.4.jar".to_string()),
+            source_url: Some("https://github.com/alice/foo-updated".to_string()),
+            source_repository: Some(SourceRepository {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo-updated".to_string(),
+                id: None
+            }),
+            platforms: vec![ServerPlatform::Bukkit],
+            categories: vec![ProjectCategory::AdminTools],
+            minecraft_versions: vec!["1.21.4".to_string(), "1.22".to_string()]
+        };
+
+        // Act
+        upsert_curseforge_project(&context.pool, &updated_project).await?;
+
+        // Assert
+        let retrieved_projects = get_curseforge_projects(&context.pool).await?;
+        let retrieved_project = &retrieved_projects[0];
+
+        assert_that(&retrieved_projects).has_length(1);
+        assert_that(&retrieved_project).is_equal_to(&updated_project);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_latest_curseforge_project_update_date() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = create_test_curseforge_projects();
+        for project in projects {
+            upsert_curseforge_project(&context.pool, &project).await?;
+        }
+
+        // Act
+        let latest_update_date = get_latest_curseforge_project_update_date(&context.pool).await?;
+
+        // Assert
+        assert_that(&latest_update_date).is_equal_to(datetime!(2019-02-03 0:00 UTC));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_mark_unseen_curseforge_projects_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_curseforge_projects(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_curseforge_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(projects.len() as u64);
+
+        let retrieved_projects = get_curseforge_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    pub async fn populate_test_curseforge_project(db_pool: &Pool) -> Result<CurseForgeProject> {
+        let project = &create_test_curseforge_projects()[0];
+        upsert_curseforge_project(db_pool, project).await?;
+        Ok(project.clone())
+    }
+
+    pub async fn populate_test_curseforge_projects(db_pool: &Pool) -> Result<Vec<CurseForgeProject>> {
+        let projects = create_test_curseforge_projects();
+        for project in &projects {
+            upsert_curseforge_project(db_pool, project).await?
+        }
+        Ok(projects)
+    }
+
+    fn create_test_curseforge_projects() -> Vec<CurseForgeProject> {
+        vec![
+            CurseForgeProject {
+                id: 1,
+                slug: "foo".to_string(),
+                name: "foo-curseforge".to_string(),
+                description: "foo-curseforge-description".to_string(),
+                author: "alice".to_string(),
+                date_created: datetime!(2019-01-01 0:00 UTC),
+                date_updated: datetime!(2019-02-03 0:00 UTC),
+                latest_minecraft_version: Some("1.21.4".to_string()),
+                downloads: 100,
+                likes: 200,
+                icon_url: Some("https://media.forgecdn.net/avatars/thumbnails/1/1/64/64/1.png".to_string()),
+                version_name: Some("foo-1.2.3.jar".to_string()),
+                source_url: Some("https://github.com/alice/foo".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "github.com".to_string(),
+                    owner: "alice".to_string(),
+                    name: "foo".to_string(),
+                    id: None
+                }),
+                platforms: vec![ServerPlatform::Bukkit],
+                categories: vec![ProjectCategory::Chat],
+                minecraft_versions: vec!["1.21.3".to_string(), "1.21.4".to_string()]
+            },
+            CurseForgeProject {
+                id: 2,
+                slug: "bar".to_string(),
+                name: "bar-curseforge".to_string(),
+                description: "bar-curseforge-description".to_string(),
+                author: "bob".to_string(),
+                date_created: datetime!(2019-01-02 0:00 UTC),
+                date_updated: datetime!(2019-02-02 0:00 UTC),
+                latest_minecraft_version: Some("1.8".to_string()),
+                downloads: 300,
+                likes: 100,
+                icon_url: None,
+                version_name: Some("bar-1.2.3.jar".to_string()),
+                source_url: Some("https://gitlab.com/bob/bar".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "gitlab.com".to_string(),
+                    owner: "bob".to_string(),
+                    name: "bar".to_string(),
+                    id: None
+                }),
+                platforms: vec![ServerPlatform::Bukkit],
+                categories: vec![ProjectCategory::Protection],
+                minecraft_versions: vec!["1.8".to_string()]
+            },
+            CurseForgeProject {
+                id: 3,
+                slug: "baz".to_string(),
+                name: "baz-curseforge".to_string(),
+                description: "baz-curseforge-description".to_string(),
+                author: "eve".to_string(),
+                date_created: datetime!(2019-01-03 0:00 UTC),
+                date_updated: datetime!(2019-02-01 0:00 UTC),
+                latest_minecraft_version: Some("1.16.5".to_string()),
+                downloads: 200,
+                likes: 300,
+                icon_url: Some("https://media.forgecdn.net/avatars/thumbnails/3/3/64/64/3.png".to_string()),
+                version_name: None,
+                source_url: Some("https://bitbucket.org/eve/baz".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "bitbucket.org".to_string(),
+                    owner: "eve".to_string(),
+                    name: "baz".to_string(),
+                    id: None
+                }),
+                platforms: vec![ServerPlatform::Bukkit],
+                categories: vec![ProjectCategory::Economy],
+                minecraft_versions: vec!["1.16.5".to_string()]
+            }
+        ]
+    }
+}
\ No newline at end of file
@@ -1,20 +1,28 @@
+use crate::database::project_category::{ProjectCategory, to_cornucopia_project_categories, from_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, to_cornucopia_server_platforms, from_cornucopia_server_platforms};
 use crate::database::source_repository::SourceRepository;
 use crate::database::cornucopia::queries::hangar_project::{self, HangarProjectEntity, UpsertHangarProjectParams};
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+use crate::minecraft_version::get_minecraft_version_sort_key;
 
 use anyhow::Result;
 use cornucopia_async::Params;
 use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use time::OffsetDateTime;
 use tracing::instrument;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HangarProject {
     pub slug: String,
     pub author: String,
     pub name: String,
     pub description: String,
+    #[serde(with = "time::serde::rfc3339")]
     pub date_created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
     pub date_updated: OffsetDateTime,
     pub latest_minecraft_version: Option<String>,
     pub downloads: i32,
@@ -24,10 +32,17 @@ pub struct HangarProject {
     pub icon_url: String,
     pub version_name: Option<String>,
     pub source_url: Option<String>,
-    pub source_repository: Option<SourceRepository>
+    pub source_repository: Option<SourceRepository>,
+    pub paper_versions: Vec<String>,
+    pub velocity_versions: Vec<String>,
+    pub waterfall_versions: Vec<String>,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>,
+    pub full_description: Option<String>
 }
 
-impl From<HangarProject> for UpsertHangarProjectParams<String, String, String, String, String, String, String, String, String, String, String, String> {
+impl From<HangarProject> for UpsertHangarProjectParams<String, String, String, String, String, Vec<i32>, String, String, String, String, String, String, String, String, Vec<String>, String, Vec<String>, String, Vec<String>, Vec<CornucopiaServerPlatform>, Vec<CornucopiaProjectCategory>, String, Vec<String>, String> {
     fn from(project: HangarProject) -> Self {
         let mut source_repository_host = None;
         let mut source_repository_owner = None;
@@ -46,6 +61,7 @@ impl From<HangarProject> for UpsertHangarProjectParams<String, String, String, S
             description: project.description,
             date_created: project.date_created,
             date_updated: project.date_updated,
+            latest_minecraft_version_sort_key: get_minecraft_version_sort_key(project.latest_minecraft_version.as_deref()),
             latest_minecraft_version: project.latest_minecraft_version,
             downloads: project.downloads,
             stars: project.stars,
@@ -56,7 +72,14 @@ impl From<HangarProject> for UpsertHangarProjectParams<String, String, String, S
             source_url: project.source_url,
             source_repository_host,
             source_repository_owner,
-            source_repository_name
+            source_repository_name,
+            paper_versions: project.paper_versions,
+            velocity_versions: project.velocity_versions,
+            waterfall_versions: project.waterfall_versions,
+            platforms: to_cornucopia_server_platforms(project.platforms),
+            categories: to_cornucopia_project_categories(project.categories),
+            minecraft_versions: project.minecraft_versions,
+            full_description: project.full_description
         }
     }
 }
@@ -91,7 +114,14 @@ impl From<HangarProjectEntity> for HangarProject {
             icon_url: entity.icon_url,
             version_name: entity.version_name,
             source_url: entity.source_url,
-            source_repository
+            source_repository,
+            paper_versions: entity.paper_versions,
+            velocity_versions: entity.velocity_versions,
+            waterfall_versions: entity.waterfall_versions,
+            platforms: from_cornucopia_server_platforms(entity.platforms),
+            categories: from_cornucopia_project_categories(entity.categories),
+            minecraft_versions: entity.minecraft_versions,
+            full_description: entity.full_description
         }
     }
 }
@@ -141,6 +171,19 @@ pub async fn get_hangar_projects(db_pool: &Pool) -> Result<Vec<HangarProject>> {
     Ok(projects)
 }
 
+/// Returns the project with the given slug, or None if it does not exist or is deleted.
+pub async fn get_hangar_project_by_slug(db_pool: &Pool, slug: &str) -> Result<Option<HangarProject>> {
+    let db_client = db_pool.get().await?;
+
+    let project = hangar_project::get_hangar_project_by_slug()
+        .bind(&db_client, &slug)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(project)
+}
+
 pub async fn get_latest_hangar_project_update_date(db_pool: &Pool) -> Result<OffsetDateTime> {
     let db_client = db_pool.get().await?;
 
@@ -152,6 +195,23 @@ pub async fn get_latest_hangar_project_update_date(db_pool: &Pool) -> Result<Off
     Ok(date)
 }
 
+/// Marks projects that have not been seen upstream since the given date as deleted, returning the number of projects marked.
+/// Deleted projects are excluded from common projects, and are restored the next time they are upserted.
+/// Quarantined projects are left alone, since they may have been seen but failed to process.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn mark_unseen_hangar_projects_as_deleted(db_pool: &Pool, date_started: OffsetDateTime) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = hangar_project::mark_unseen_hangar_projects_as_deleted()
+        .bind(&db_client, &date_started)
+        .await?;
+
+    Ok(count)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -215,7 +275,14 @@ pub mod test {
                 owner: "alice".to_string(),
                 name: "foo-updated".to_string(),
                 id: None
-            })
+            }),
+            paper_versions: vec!["1.21.4".to_string(), "1.22".to_string()],
+            velocity_versions: vec!["3.4".to_string()],
+            waterfall_versions: vec![],
+            platforms: vec![ServerPlatform::Paper, ServerPlatform::Velocity],
+            categories: vec![ProjectCategory::AdminTools],
+            minecraft_versions: vec!["1.21.4".to_string(), "1.22".to_string()],
+            full_description: Some("<p>foo-full-description-updated</p>".to_string())
         };
 
         // Act
@@ -258,6 +325,78 @@ pub mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_mark_unseen_hangar_projects_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_hangar_projects(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_hangar_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(projects.len() as u64);
+
+        let retrieved_projects = get_hangar_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_mark_seen_hangar_projects_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_hangar_projects(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_hangar_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() - time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(0);
+
+        let retrieved_projects = get_hangar_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).is_equal_to(&projects);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_restore_deleted_hangar_project_when_seen_again() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_hangar_projects(&context.pool).await?;
+        mark_unseen_hangar_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Act
+        upsert_hangar_project(&context.pool, &projects[0]).await?;
+
+        // Assert
+        let retrieved_projects = get_hangar_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).has_length(1);
+        assert_that(&retrieved_projects[0]).is_equal_to(&projects[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     pub async fn populate_test_hangar_project(db_pool: &Pool) -> Result<HangarProject> {
         let project = &create_test_hangar_projects()[0];
         upsert_hangar_project(db_pool, project).await?;
@@ -294,7 +433,14 @@ pub mod test {
                     owner: "alice".to_string(),
                     name: "foo".to_string(),
                     id: None
-                })
+                }),
+                paper_versions: vec!["1.21.3".to_string(), "1.21.4".to_string()],
+                velocity_versions: vec!["3.3".to_string(), "3.4".to_string()],
+                waterfall_versions: vec![],
+                platforms: vec![ServerPlatform::Paper, ServerPlatform::Folia, ServerPlatform::Velocity],
+                categories: vec![ProjectCategory::Chat],
+                minecraft_versions: vec!["1.21.3".to_string(), "1.21.4".to_string()],
+                full_description: Some("<p>foo-hangar-full-description</p>".to_string())
             },
             HangarProject {
                 slug: "bar".to_string(),
@@ -316,7 +462,14 @@ pub mod test {
                     owner: "bob".to_string(),
                     name: "bar".to_string(),
                     id: None
-                })
+                }),
+                paper_versions: vec!["1.8".to_string()],
+                velocity_versions: vec![],
+                waterfall_versions: vec!["1.8".to_string()],
+                platforms: vec![ServerPlatform::Paper, ServerPlatform::Waterfall],
+                categories: vec![ProjectCategory::Protection],
+                minecraft_versions: vec!["1.8".to_string()],
+                full_description: Some("<p>bar-hangar-full-description</p>".to_string())
             },
             HangarProject {
                 slug: "baz".to_string(),
@@ -338,7 +491,14 @@ pub mod test {
                     owner: "eve".to_string(),
                     name: "baz".to_string(),
                     id: None
-                })
+                }),
+                paper_versions: vec!["1.16.5".to_string()],
+                velocity_versions: vec![],
+                waterfall_versions: vec![],
+                platforms: vec![ServerPlatform::Paper],
+                categories: vec![ProjectCategory::Economy],
+                minecraft_versions: vec!["1.16.5".to_string()],
+                full_description: Some("<p>baz-hangar-full-description</p>".to_string())
             }
         ]
     }
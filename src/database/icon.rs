@@ -0,0 +1,361 @@
+use crate::database::cornucopia::queries::icon::{self, IconEntity, ProjectIconSourceEntity, InsertIconParams, UpsertProjectIconParams};
+use crate::database::ingest_log::IngestLogRepository;
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use thiserror::Error;
+use tracing::instrument;
+
+/// A resized project icon, identified by the SHA-256 hash of its original image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Icon {
+    pub hash: String,
+    pub webp: Vec<u8>,
+    pub png: Vec<u8>
+}
+
+impl From<Icon> for InsertIconParams<String, Vec<u8>, Vec<u8>> {
+    fn from(icon: Icon) -> Self {
+        InsertIconParams {
+            hash: icon.hash,
+            webp: icon.webp,
+            png: icon.png
+        }
+    }
+}
+
+impl From<IconEntity> for Icon {
+    fn from(entity: IconEntity) -> Self {
+        Icon {
+            hash: entity.hash,
+            webp: entity.webp,
+            png: entity.png
+        }
+    }
+}
+
+/// The icon of an upstream project, along with the upstream icon URL it was created from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectIcon {
+    pub repository: IngestLogRepository,
+    /// The upstream ID as text, except for Hangar which uses the slug
+    pub project_id: String,
+    pub source_url: String,
+    pub hash: String
+}
+
+impl From<ProjectIcon> for UpsertProjectIconParams<String, String, String> {
+    fn from(project_icon: ProjectIcon) -> Self {
+        UpsertProjectIconParams {
+            repository: project_icon.repository.into(),
+            project_id: project_icon.project_id,
+            source_url: project_icon.source_url,
+            hash: project_icon.hash
+        }
+    }
+}
+
+/// An upstream project whose icon has not been cached yet, or whose icon URL has changed since it was cached.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectIconSource {
+    pub repository: IngestLogRepository,
+    /// The upstream ID as text, except for Hangar which uses the slug
+    pub project_id: String,
+    pub icon_url: String,
+    /// The base64-encoded icon, which is only provided for Spigot resources
+    pub icon_data: Option<String>
+}
+
+impl From<ProjectIconSourceEntity> for ProjectIconSource {
+    fn from(entity: ProjectIconSourceEntity) -> Self {
+        ProjectIconSource {
+            repository: entity.repository.into(),
+            project_id: entity.project_id,
+            icon_url: entity.icon_url,
+            icon_data: entity.icon_data
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum IconError {
+    #[error("Unable to save icon {hash}: Database query failed: {source}")]
+    IconDatabaseQueryFailed {
+        hash: String,
+        source: anyhow::Error
+    },
+    #[error("Unable to save icon for project {project_id}: Database query failed: {source}")]
+    ProjectIconDatabaseQueryFailed {
+        project_id: String,
+        source: anyhow::Error
+    }
+}
+
+/// Returns the upstream projects that are not deleted, and whose icon is not cached or was cached from a different URL.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_outdated_project_icon_sources(db_pool: &Pool) -> Result<Vec<ProjectIconSource>> {
+    let db_client = db_pool.get().await?;
+
+    let sources = icon::get_outdated_project_icon_sources()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(sources)
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn icon_exists(db_pool: &Pool, hash: &str) -> Result<bool> {
+    let db_client = db_pool.get().await?;
+
+    let exists = icon::icon_exists()
+        .bind(&db_client, &hash)
+        .one()
+        .await?;
+
+    Ok(exists)
+}
+
+/// Saves an icon, unless an icon with the same hash already exists.
+#[instrument(
+    level = "debug",
+    skip(db_pool, icon),
+    fields(hash = icon.hash)
+)]
+pub async fn insert_icon(db_pool: &Pool, icon: &Icon) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = icon::insert_icon()
+        .params(&db_client, &icon.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            IconError::IconDatabaseQueryFailed {
+                hash: icon.hash.clone(),
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_icon(db_pool: &Pool, hash: &str) -> Result<Option<Icon>> {
+    let db_client = db_pool.get().await?;
+
+    let icon = icon::get_icon()
+        .bind(&db_client, &hash)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(icon)
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_project_icon(db_pool: &Pool, project_icon: &ProjectIcon) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = icon::upsert_project_icon()
+        .params(&db_client, &project_icon.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            IconError::ProjectIconDatabaseQueryFailed {
+                project_id: project_icon.project_id.clone(),
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_project_icon_hash(db_pool: &Pool, repository: IngestLogRepository, project_id: &str) -> Result<Option<String>> {
+    let db_client = db_pool.get().await?;
+
+    let hash = icon::get_project_icon_hash()
+        .bind(&db_client, &repository.into(), &project_id)
+        .opt()
+        .await?;
+
+    Ok(hash)
+}
+
+/// Deletes icons that are no longer the icon of any project, returning the number of icons deleted.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn delete_unused_icons(db_pool: &Pool) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = icon::delete_unused_icons()
+        .bind(&db_client)
+        .await?;
+
+    Ok(count)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::database::modrinth::project::test::populate_test_modrinth_projects;
+    use crate::database::spigot::resource::test::populate_test_spigot_authors_and_resources;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_icon_into_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let icon = create_test_icon("aaaa");
+        let duplicate_icon = Icon {
+            webp: vec![9, 9, 9],
+            ..icon.clone()
+        };
+
+        // Act
+        insert_icon(&context.pool, &icon).await?;
+        insert_icon(&context.pool, &duplicate_icon).await?;
+
+        // Assert
+        let retrieved_icon = get_icon(&context.pool, "aaaa").await?;
+        assert_that(&retrieved_icon).is_equal_to(Some(icon));
+        assert_that(&icon_exists(&context.pool, "aaaa").await?).is_true();
+        assert_that(&icon_exists(&context.pool, "bbbb").await?).is_false();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_outdated_project_icon_sources_from_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+
+        insert_icon(&context.pool, &create_test_icon("aaaa")).await?;
+
+        // The first Spigot resource is up to date, while the first Modrinth project has changed its icon URL since it was cached.
+        upsert_project_icon(&context.pool, &ProjectIcon {
+            repository: IngestLogRepository::Spigot,
+            project_id: spigot_resources[0].id.to_string(),
+            source_url: spigot_resources[0].icon_url.clone().unwrap(),
+            hash: "aaaa".to_string()
+        }).await?;
+        upsert_project_icon(&context.pool, &ProjectIcon {
+            repository: IngestLogRepository::Modrinth,
+            project_id: modrinth_projects[0].id.clone(),
+            source_url: "https://cdn.modrinth.com/data/aaaaaaaa/old-icon.png".to_string(),
+            hash: "aaaa".to_string()
+        }).await?;
+
+        // Act
+        let sources = get_outdated_project_icon_sources(&context.pool).await?;
+
+        // Assert
+        let ids: Vec<(IngestLogRepository, String)> = sources.iter()
+            .map(|x| (x.repository.clone(), x.project_id.clone()))
+            .collect();
+
+        assert_that(&ids).is_equal_to(vec![
+            (IngestLogRepository::Spigot, spigot_resources[1].id.to_string()),
+            (IngestLogRepository::Spigot, spigot_resources[2].id.to_string()),
+            (IngestLogRepository::Modrinth, modrinth_projects[0].id.clone()),
+            (IngestLogRepository::Modrinth, modrinth_projects[1].id.clone()),
+            (IngestLogRepository::Modrinth, modrinth_projects[2].id.clone())
+        ]);
+
+        assert_that(&sources[0].icon_data).is_equal_to(&spigot_resources[1].icon_data);
+        assert_that(&sources[2].icon_url).is_equal_to(modrinth_projects[0].icon_url.clone().unwrap());
+        assert_that(&sources[2].icon_data).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_delete_unused_icons_from_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        insert_icon(&context.pool, &create_test_icon("aaaa")).await?;
+        insert_icon(&context.pool, &create_test_icon("bbbb")).await?;
+        insert_icon(&context.pool, &create_test_icon("cccc")).await?;
+
+        upsert_project_icon(&context.pool, &create_test_project_icon("1", "aaaa")).await?;
+        upsert_project_icon(&context.pool, &create_test_project_icon("2", "bbbb")).await?;
+
+        // Project 2 changes its icon, so that icon bbbb is no longer used
+        upsert_project_icon(&context.pool, &create_test_project_icon("2", "aaaa")).await?;
+
+        // Act
+        let count = delete_unused_icons(&context.pool).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(2);
+        assert_that(&icon_exists(&context.pool, "aaaa").await?).is_true();
+        assert_that(&icon_exists(&context.pool, "bbbb").await?).is_false();
+        assert_that(&icon_exists(&context.pool, "cccc").await?).is_false();
+        assert_that(&get_project_icon_hash(&context.pool, IngestLogRepository::Polymart, "2").await?).is_equal_to(Some("aaaa".to_string()));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    pub fn create_test_icon(hash: &str) -> Icon {
+        Icon {
+            hash: hash.to_string(),
+            webp: vec![1, 2, 3],
+            png: vec![4, 5, 6]
+        }
+    }
+
+    pub fn create_test_project_icon(project_id: &str, hash: &str) -> ProjectIcon {
+        ProjectIcon {
+            repository: IngestLogRepository::Polymart,
+            project_id: project_id.to_string(),
+            source_url: format!("https://polymart.org/icons/{}.png", project_id),
+            hash: hash.to_string()
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,215 @@
+use crate::database::cornucopia::queries::ingest_checkpoint::{self, UpsertIngestCheckpointParams, IngestCheckpointEntity};
+use crate::database::ingest_log::{IngestLogRepository, IngestLogItem};
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use time::OffsetDateTime;
+use thiserror::Error;
+use tracing::instrument;
+
+/// Records how far a populate operation has progressed, so that it can be resumed if interrupted.
+/// The position is the last completed page (Spigot) or offset (Modrinth and Hangar).
+#[derive(Clone, Debug, PartialEq)]
+pub struct IngestCheckpoint {
+    pub repository: IngestLogRepository,
+    pub item: IngestLogItem,
+    pub position: i32,
+    /// The start date of the original run, which is kept even if that run is resumed multiple times.
+    /// This ensures that items seen before an interruption are not considered unseen when reconciling.
+    pub date_started: OffsetDateTime,
+    pub date_updated: OffsetDateTime
+}
+
+impl From<IngestCheckpoint> for UpsertIngestCheckpointParams {
+    fn from(checkpoint: IngestCheckpoint) -> Self {
+        UpsertIngestCheckpointParams {
+            repository: checkpoint.repository.into(),
+            item: checkpoint.item.into(),
+            position: checkpoint.position,
+            date_started: checkpoint.date_started,
+            date_updated: checkpoint.date_updated
+        }
+    }
+}
+
+impl From<IngestCheckpointEntity> for IngestCheckpoint {
+    fn from(entity: IngestCheckpointEntity) -> Self {
+        IngestCheckpoint {
+            repository: entity.repository.into(),
+            item: entity.item.into(),
+            position: entity.position,
+            date_started: entity.date_started,
+            date_updated: entity.date_updated
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum IngestCheckpointError {
+    #[error("Unable to save ingest checkpoint: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_ingest_checkpoint(db_pool: &Pool, checkpoint: &IngestCheckpoint) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = ingest_checkpoint::upsert_ingest_checkpoint()
+        .params(&db_client, &checkpoint.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            IngestCheckpointError::DatabaseQueryFailed {
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_ingest_checkpoint(db_pool: &Pool, repository: IngestLogRepository, item: IngestLogItem) -> Result<Option<IngestCheckpoint>> {
+    let db_client = db_pool.get().await?;
+
+    let checkpoint = ingest_checkpoint::get_ingest_checkpoint()
+        .bind(&db_client, &repository.into(), &item.into())
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(checkpoint)
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn delete_ingest_checkpoint(db_pool: &Pool, repository: IngestLogRepository, item: IngestLogItem) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    ingest_checkpoint::delete_ingest_checkpoint()
+        .bind(&db_client, &repository.into(), &item.into())
+        .await?;
+
+    Ok(())
+}
+
+/// Gets the start date for a new checkpoint from the database clock.
+/// Reconciling compares this against each item's date_last_seen, which is also set by the database.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_checkpoint_start_date(db_pool: &Pool) -> Result<OffsetDateTime> {
+    let db_client = db_pool.get().await?;
+
+    let date_started = ingest_checkpoint::get_checkpoint_start_date()
+        .bind(&db_client)
+        .one()
+        .await?;
+
+    Ok(date_started)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_ingest_checkpoint_into_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let checkpoint = create_test_ingest_checkpoint();
+
+        // Act
+        upsert_ingest_checkpoint(&context.pool, &checkpoint).await?;
+
+        // Assert
+        let retrieved_checkpoint = get_ingest_checkpoint(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+        assert_that(&retrieved_checkpoint).is_some().is_equal_to(&checkpoint);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_ingest_checkpoint_in_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let checkpoint = create_test_ingest_checkpoint();
+        upsert_ingest_checkpoint(&context.pool, &checkpoint).await?;
+
+        // Act
+        let updated_checkpoint = IngestCheckpoint {
+            position: 4,
+            date_updated: datetime!(2020-01-01 0:04 UTC),
+            ..checkpoint
+        };
+        upsert_ingest_checkpoint(&context.pool, &updated_checkpoint).await?;
+
+        // Assert
+        let retrieved_checkpoint = get_ingest_checkpoint(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+        assert_that(&retrieved_checkpoint).is_some().is_equal_to(&updated_checkpoint);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_delete_ingest_checkpoint_from_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let checkpoint = create_test_ingest_checkpoint();
+        upsert_ingest_checkpoint(&context.pool, &checkpoint).await?;
+
+        // Act
+        delete_ingest_checkpoint(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+
+        // Assert
+        let retrieved_checkpoint = get_ingest_checkpoint(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+        assert_that(&retrieved_checkpoint).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    fn create_test_ingest_checkpoint() -> IngestCheckpoint {
+        IngestCheckpoint {
+            repository: IngestLogRepository::Spigot,
+            item: IngestLogItem::Resource,
+            position: 3,
+            date_started: datetime!(2020-01-01 0:00 UTC),
+            date_updated: datetime!(2020-01-01 0:03 UTC)
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::database::cornucopia::queries::ingest_failure::{self, IngestFailureEntity, UpsertIngestFailureParams};
+use crate::database::ingest_log::{IngestLogRepository, IngestLogItem};
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+/// An upstream item that could not be converted or written during a populate/update operation.
+/// Only the latest failure of each item is kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IngestFailure {
+    pub repository: IngestLogRepository,
+    pub item: IngestLogItem,
+    /// The upstream ID as text, except for Hangar which uses the slug
+    pub upstream_id: String,
+    /// The step at which the item failed: Fetch, Conversion, or Database
+    pub error_kind: String,
+    pub message: String,
+    /// The raw upstream item as JSON
+    pub payload: String,
+    pub date_failed: OffsetDateTime
+}
+
+impl From<IngestFailure> for UpsertIngestFailureParams<String, String, String, String> {
+    fn from(failure: IngestFailure) -> Self {
+        UpsertIngestFailureParams {
+            repository: failure.repository.into(),
+            item: failure.item.into(),
+            upstream_id: failure.upstream_id,
+            error_kind: failure.error_kind,
+            message: failure.message,
+            payload: failure.payload,
+            date_failed: failure.date_failed
+        }
+    }
+}
+
+impl From<IngestFailureEntity> for IngestFailure {
+    fn from(entity: IngestFailureEntity) -> Self {
+        IngestFailure {
+            repository: entity.repository.into(),
+            item: entity.item.into(),
+            upstream_id: entity.upstream_id,
+            error_kind: entity.error_kind,
+            message: entity.message,
+            payload: entity.payload,
+            date_failed: entity.date_failed
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum IngestFailureError {
+    #[error("Unable to save ingest failure for {upstream_id}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        upstream_id: String,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_ingest_failure(db_pool: &Pool, failure: &IngestFailure) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = ingest_failure::upsert_ingest_failure()
+        .params(&db_client, &failure.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            IngestFailureError::DatabaseQueryFailed {
+                upstream_id: failure.upstream_id.clone(),
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+/// Returns the failures of an item type in a repository, ordered from oldest to newest.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_ingest_failures(db_pool: &Pool, repository: IngestLogRepository, item: IngestLogItem) -> Result<Vec<IngestFailure>> {
+    let db_client = db_pool.get().await?;
+
+    let failures = ingest_failure::get_ingest_failures()
+        .bind(&db_client, &repository.into(), &item.into())
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(failures)
+}
+
+/// Returns the upstream IDs of the failures of an item type in a repository, without their payloads.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_ingest_failure_upstream_ids(db_pool: &Pool, repository: IngestLogRepository, item: IngestLogItem) -> Result<Vec<String>> {
+    let db_client = db_pool.get().await?;
+
+    let upstream_ids = ingest_failure::get_ingest_failure_upstream_ids()
+        .bind(&db_client, &repository.into(), &item.into())
+        .all()
+        .await?;
+
+    Ok(upstream_ids)
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn delete_ingest_failure(db_pool: &Pool, repository: IngestLogRepository, item: IngestLogItem, upstream_id: &str) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    ingest_failure::delete_ingest_failure()
+        .bind(&db_client, &repository.into(), &item.into(), &upstream_id)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_upsert_ingest_failure_into_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let failure = create_test_ingest_failure("1", datetime!(2024-01-01 0:00 UTC));
+        let later_failure = IngestFailure {
+            error_kind: "Database".to_string(),
+            message: "Skipping resource ID 1: Database query failed: connection closed".to_string(),
+            date_failed: datetime!(2024-01-02 0:00 UTC),
+            ..failure.clone()
+        };
+
+        // Act
+        upsert_ingest_failure(&context.pool, &failure).await?;
+        upsert_ingest_failure(&context.pool, &later_failure).await?;
+
+        // Assert
+        let retrieved_failures = get_ingest_failures(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+        assert_that(&retrieved_failures).is_equal_to(vec![later_failure]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_ingest_failure_upstream_ids() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let failure = create_test_ingest_failure("1", datetime!(2024-01-01 0:00 UTC));
+        let other_failure = create_test_ingest_failure("2", datetime!(2024-01-02 0:00 UTC));
+        let other_repository_failure = IngestFailure {
+            repository: IngestLogRepository::Polymart,
+            ..create_test_ingest_failure("3", datetime!(2024-01-03 0:00 UTC))
+        };
+        upsert_ingest_failure(&context.pool, &failure).await?;
+        upsert_ingest_failure(&context.pool, &other_failure).await?;
+        upsert_ingest_failure(&context.pool, &other_repository_failure).await?;
+
+        // Act
+        let mut upstream_ids = get_ingest_failure_upstream_ids(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+
+        // Assert
+        upstream_ids.sort();
+        assert_that(&upstream_ids).is_equal_to(vec!["1".to_string(), "2".to_string()]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_delete_ingest_failure_from_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let failure = create_test_ingest_failure("1", datetime!(2024-01-01 0:00 UTC));
+        let other_failure = create_test_ingest_failure("2", datetime!(2024-01-02 0:00 UTC));
+        upsert_ingest_failure(&context.pool, &failure).await?;
+        upsert_ingest_failure(&context.pool, &other_failure).await?;
+
+        // Act
+        delete_ingest_failure(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource, "1").await?;
+
+        // Assert
+        let retrieved_failures = get_ingest_failures(&context.pool, IngestLogRepository::Spigot, IngestLogItem::Resource).await?;
+        assert_that(&retrieved_failures).is_equal_to(vec![other_failure]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    fn create_test_ingest_failure(upstream_id: &str, date_failed: OffsetDateTime) -> IngestFailure {
+        IngestFailure {
+            repository: IngestLogRepository::Spigot,
+            item: IngestLogItem::Resource,
+            upstream_id: upstream_id.to_string(),
+            error_kind: "Conversion".to_string(),
+            message: format!("Skipping resource ID {}: Invalid slug from URL: foo", upstream_id),
+            payload: format!("{{\"id\":{}}}", upstream_id),
+            date_failed
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,110 @@
+use crate::database::cornucopia::queries::ingest_lock;
+
+use anyhow::Result;
+use deadpool_postgres::{ClientWrapper, Object, Pool};
+use tracing::instrument;
+
+/// Arbitrary key of the Postgres advisory lock that is held while ingesting, so that two ingest runs never overlap.
+const INGEST_LOCK_KEY: i64 = 0x4D43_5046;
+
+/// A session-level Postgres advisory lock held for the duration of an ingest run.
+/// The lock belongs to a connection that is detached from the pool, so dropping this also closes the connection and releases the lock.
+pub struct IngestLock {
+    db_client: ClientWrapper
+}
+
+impl std::fmt::Debug for IngestLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IngestLock").field("key", &INGEST_LOCK_KEY).finish()
+    }
+}
+
+impl IngestLock {
+    #[instrument(
+        level = "debug",
+        skip(self)
+    )]
+    pub async fn release(self) -> Result<()> {
+        ingest_lock::release_ingest_lock()
+            .bind(&*self.db_client, &INGEST_LOCK_KEY)
+            .one()
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Returns None if the lock is already held by another ingest run.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn try_acquire_ingest_lock(db_pool: &Pool) -> Result<Option<IngestLock>> {
+    let db_client = Object::take(db_pool.get().await?);
+
+    let acquired = ingest_lock::try_acquire_ingest_lock()
+        .bind(&*db_client, &INGEST_LOCK_KEY)
+        .one()
+        .await?;
+
+    if acquired {
+        Ok(Some(IngestLock { db_client }))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_acquire_ingest_lock_while_it_is_held() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let lock = try_acquire_ingest_lock(&context.pool).await?;
+        assert_that(&lock).is_some();
+
+        // Act
+        let second_lock = try_acquire_ingest_lock(&context.pool).await?;
+
+        // Assert
+        assert_that(&second_lock).is_none();
+
+        // Teardown
+        drop(lock);
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_acquire_ingest_lock_after_it_is_released() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let lock = try_acquire_ingest_lock(&context.pool).await?.expect("lock should be acquired");
+        lock.release().await?;
+
+        // Act
+        let second_lock = try_acquire_ingest_lock(&context.pool).await?;
+
+        // Assert
+        assert_that(&second_lock).is_some();
+
+        // Teardown
+        drop(second_lock);
+        context.drop().await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
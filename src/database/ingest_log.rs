@@ -6,11 +6,13 @@ use crate::database::cornucopia::types::public::IngestLogItem as CornucopiaInges
 use anyhow::Result;
 use cornucopia_async::Params;
 use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use thiserror::Error;
 use tracing::instrument;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum IngestLogAction {
     Populate,
     Update,
@@ -37,11 +39,16 @@ impl From<CornucopiaIngestLogAction> for IngestLogAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum IngestLogRepository {
     Spigot,
     Modrinth,
     Hangar,
+    CurseForge,
+    Polymart,
+    BuiltByBit,
+    SourceRepository,
     Common
 }
 
@@ -51,6 +58,10 @@ impl From<IngestLogRepository> for CornucopiaIngestLogRepository {
             IngestLogRepository::Spigot => CornucopiaIngestLogRepository::Spigot,
             IngestLogRepository::Modrinth => CornucopiaIngestLogRepository::Modrinth,
             IngestLogRepository::Hangar => CornucopiaIngestLogRepository::Hangar,
+            IngestLogRepository::CurseForge => CornucopiaIngestLogRepository::CurseForge,
+            IngestLogRepository::Polymart => CornucopiaIngestLogRepository::Polymart,
+            IngestLogRepository::BuiltByBit => CornucopiaIngestLogRepository::BuiltByBit,
+            IngestLogRepository::SourceRepository => CornucopiaIngestLogRepository::SourceRepository,
             IngestLogRepository::Common => CornucopiaIngestLogRepository::Common
         }
     }
@@ -62,17 +73,24 @@ impl From<CornucopiaIngestLogRepository> for IngestLogRepository {
             CornucopiaIngestLogRepository::Spigot => IngestLogRepository::Spigot,
             CornucopiaIngestLogRepository::Modrinth => IngestLogRepository::Modrinth,
             CornucopiaIngestLogRepository::Hangar => IngestLogRepository::Hangar,
+            CornucopiaIngestLogRepository::CurseForge => IngestLogRepository::CurseForge,
+            CornucopiaIngestLogRepository::Polymart => IngestLogRepository::Polymart,
+            CornucopiaIngestLogRepository::BuiltByBit => IngestLogRepository::BuiltByBit,
+            CornucopiaIngestLogRepository::SourceRepository => IngestLogRepository::SourceRepository,
             CornucopiaIngestLogRepository::Common => IngestLogRepository::Common
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum IngestLogItem {
     Author,
     Resource,
     Project,
-    Version
+    Version,
+    Release,
+    Metadata
 }
 
 impl From<IngestLogItem> for CornucopiaIngestLogItem {
@@ -81,7 +99,9 @@ impl From<IngestLogItem> for CornucopiaIngestLogItem {
             IngestLogItem::Author => CornucopiaIngestLogItem::Author,
             IngestLogItem::Resource => CornucopiaIngestLogItem::Resource,
             IngestLogItem::Project => CornucopiaIngestLogItem::Project,
-            IngestLogItem::Version => CornucopiaIngestLogItem::Version
+            IngestLogItem::Version => CornucopiaIngestLogItem::Version,
+            IngestLogItem::Release => CornucopiaIngestLogItem::Release,
+            IngestLogItem::Metadata => CornucopiaIngestLogItem::Metadata
         }
     }
 }
@@ -92,17 +112,21 @@ impl From<CornucopiaIngestLogItem> for IngestLogItem {
             CornucopiaIngestLogItem::Author => IngestLogItem::Author,
             CornucopiaIngestLogItem::Resource => IngestLogItem::Resource,
             CornucopiaIngestLogItem::Project => IngestLogItem::Project,
-            CornucopiaIngestLogItem::Version => IngestLogItem::Version
+            CornucopiaIngestLogItem::Version => IngestLogItem::Version,
+            CornucopiaIngestLogItem::Release => IngestLogItem::Release,
+            CornucopiaIngestLogItem::Metadata => IngestLogItem::Metadata
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IngestLog {
   pub action: IngestLogAction,
   pub repository: IngestLogRepository,
   pub item: IngestLogItem,
+  #[serde(with = "time::serde::rfc3339")]
   pub date_started: OffsetDateTime,
+  #[serde(with = "time::serde::rfc3339")]
   pub date_finished: OffsetDateTime,
   pub items_processed: i32,
   pub success: bool
@@ -1 +1 @@
-pub mod project;
\ No newline at end of file
+pub mod project;pub mod version;
@@ -1,21 +1,29 @@
+use crate::database::project_category::{ProjectCategory, to_cornucopia_project_categories, from_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, to_cornucopia_server_platforms, from_cornucopia_server_platforms};
 use crate::database::source_repository::SourceRepository;
 use crate::database::cornucopia::queries::modrinth_project::{self, ModrinthProjectEntity, UpsertModrinthProjectParams};
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+use crate::minecraft_version::get_minecraft_version_sort_key;
 
 use anyhow::Result;
 use cornucopia_async::Params;
 use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use time::OffsetDateTime;
 use tracing::instrument;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ModrinthProject {
     pub id: String,
     pub slug: String,
     pub name: String,
     pub description: String,
     pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
     pub date_created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
     pub date_updated: OffsetDateTime,
     pub latest_minecraft_version: Option<String>,
     pub downloads: i32,
@@ -25,10 +33,14 @@ pub struct ModrinthProject {
     pub status: String,
     pub icon_url: Option<String>,
     pub source_url: Option<String>,
-    pub source_repository: Option<SourceRepository>
+    pub source_repository: Option<SourceRepository>,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>,
+    pub full_description: Option<String>
 }
 
-impl From<ModrinthProject> for UpsertModrinthProjectParams<String, String, String, String, String, String, String, String, String, String, String, String, String, String> {
+impl From<ModrinthProject> for UpsertModrinthProjectParams<String, String, String, String, String, String, Vec<i32>, String, String, String, String, String, String, String, String, Vec<CornucopiaServerPlatform>, Vec<CornucopiaProjectCategory>, String, Vec<String>, String> {
     fn from(project: ModrinthProject) -> Self {
         let mut source_repository_host = None;
         let mut source_repository_owner = None;
@@ -48,6 +60,7 @@ impl From<ModrinthProject> for UpsertModrinthProjectParams<String, String, Strin
             author: project.author,
             date_created: project.date_created,
             date_updated: project.date_updated,
+            latest_minecraft_version_sort_key: get_minecraft_version_sort_key(project.latest_minecraft_version.as_deref()),
             latest_minecraft_version: project.latest_minecraft_version,
             downloads: project.downloads,
             follows: project.follows,
@@ -58,7 +71,11 @@ impl From<ModrinthProject> for UpsertModrinthProjectParams<String, String, Strin
             source_url: project.source_url,
             source_repository_host,
             source_repository_owner,
-            source_repository_name
+            source_repository_name,
+            platforms: to_cornucopia_server_platforms(project.platforms),
+            categories: to_cornucopia_project_categories(project.categories),
+            minecraft_versions: project.minecraft_versions,
+            full_description: project.full_description
         }
     }
 }
@@ -94,7 +111,11 @@ impl From<ModrinthProjectEntity> for ModrinthProject {
             status: entity.status,
             icon_url: entity.icon_url,
             source_url: entity.source_url,
-            source_repository
+            source_repository,
+            platforms: from_cornucopia_server_platforms(entity.platforms),
+            categories: from_cornucopia_project_categories(entity.categories),
+            minecraft_versions: entity.minecraft_versions,
+            full_description: entity.full_description
         }
     }
 }
@@ -144,6 +165,19 @@ pub async fn get_modrinth_projects(db_pool: &Pool) -> Result<Vec<ModrinthProject
     Ok(projects)
 }
 
+/// Returns the project with the given ID, or None if it does not exist or is deleted.
+pub async fn get_modrinth_project_by_id(db_pool: &Pool, id: &str) -> Result<Option<ModrinthProject>> {
+    let db_client = db_pool.get().await?;
+
+    let project = modrinth_project::get_modrinth_project_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(project)
+}
+
 pub async fn get_latest_modrinth_project_update_date(db_pool: &Pool) -> Result<OffsetDateTime> {
     let db_client = db_pool.get().await?;
 
@@ -155,6 +189,23 @@ pub async fn get_latest_modrinth_project_update_date(db_pool: &Pool) -> Result<O
     Ok(date)
 }
 
+/// Marks projects that have not been seen upstream since the given date as deleted, returning the number of projects marked.
+/// Deleted projects are excluded from common projects, and are restored the next time they are upserted.
+/// Quarantined projects are left alone, since they may have been seen but failed to process.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn mark_unseen_modrinth_projects_as_deleted(db_pool: &Pool, date_started: OffsetDateTime) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = modrinth_project::mark_unseen_modrinth_projects_as_deleted()
+        .bind(&db_client, &date_started)
+        .await?;
+
+    Ok(count)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -219,7 +270,11 @@ pub mod test {
                 owner: "alice".to_string(),
                 name: "foo-updated".to_string(),
                 id: None
-            })
+            }),
+            platforms: vec![ServerPlatform::Paper, ServerPlatform::Folia],
+            categories: vec![ProjectCategory::Chat, ProjectCategory::Utility],
+            minecraft_versions: vec!["1.21".to_string(), "1.22".to_string()],
+            full_description: Some("<p>foo-full-description-updated</p>".to_string())
         };
 
         // Act
@@ -262,6 +317,78 @@ pub mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_mark_unseen_modrinth_projects_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_modrinth_projects(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_modrinth_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(projects.len() as u64);
+
+        let retrieved_projects = get_modrinth_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_mark_seen_modrinth_projects_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_modrinth_projects(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_modrinth_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() - time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(0);
+
+        let retrieved_projects = get_modrinth_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).is_equal_to(&projects);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_restore_deleted_modrinth_project_when_seen_again() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let projects = populate_test_modrinth_projects(&context.pool).await?;
+        mark_unseen_modrinth_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Act
+        upsert_modrinth_project(&context.pool, &projects[0]).await?;
+
+        // Assert
+        let retrieved_projects = get_modrinth_projects(&context.pool).await?;
+        assert_that(&retrieved_projects).has_length(1);
+        assert_that(&retrieved_projects[0]).is_equal_to(&projects[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     pub async fn populate_test_modrinth_project(db_pool: &Pool) -> Result<ModrinthProject> {
         let project = &create_test_modrinth_projects()[0];
         upsert_modrinth_project(db_pool, project).await?;
@@ -299,7 +426,11 @@ pub mod test {
                     owner: "alice".to_string(),
                     name: "foo".to_string(),
                     id: None
-                })
+                }),
+                platforms: vec![ServerPlatform::Paper, ServerPlatform::Folia],
+                categories: vec![ProjectCategory::Chat],
+                minecraft_versions: vec!["1.20.4".to_string(), "1.20.6".to_string(), "1.21".to_string()],
+                full_description: Some("<p>foo-modrinth-full-description</p>".to_string())
             },
             ModrinthProject {
                 id: "bbbbbbbb".to_string(),
@@ -322,7 +453,11 @@ pub mod test {
                     owner: "bob".to_string(),
                     name: "bar".to_string(),
                     id: None
-                })
+                }),
+                platforms: vec![ServerPlatform::Bukkit, ServerPlatform::Spigot, ServerPlatform::Paper],
+                categories: vec![ProjectCategory::Library],
+                minecraft_versions: vec!["1.8".to_string(), "1.8.8".to_string()],
+                full_description: Some("<p>bar-modrinth-full-description</p>".to_string())
             },
             ModrinthProject {
                 id: "cccccccc".to_string(),
@@ -345,7 +480,11 @@ pub mod test {
                     owner: "eve".to_string(),
                     name: "baz".to_string(),
                     id: None
-                })
+                }),
+                platforms: vec![ServerPlatform::Velocity],
+                categories: vec![ProjectCategory::Economy, ProjectCategory::Gameplay],
+                minecraft_versions: vec!["1.16".to_string(), "1.16.5".to_string()],
+                full_description: Some("<p>baz-modrinth-full-description</p>".to_string())
             },
         ]
     }
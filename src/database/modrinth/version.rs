@@ -0,0 +1,295 @@
+use crate::database::cornucopia::queries::modrinth_version::{self, ModrinthVersionEntity, UpsertModrinthVersionParams};
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModrinthVersion {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub version_number: String,
+    pub version_type: String,
+    pub loaders: Vec<String>,
+    pub game_versions: Vec<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_published: OffsetDateTime,
+    pub downloads: i32,
+    pub file_sha1_hashes: Vec<String>,
+    pub file_sha512_hashes: Vec<String>
+}
+
+impl From<ModrinthVersion> for UpsertModrinthVersionParams<String, String, String, String, String, String, Vec<String>, String, Vec<String>, String, Vec<String>, String, Vec<String>> {
+    fn from(version: ModrinthVersion) -> Self {
+        UpsertModrinthVersionParams {
+            id: version.id,
+            project_id: version.project_id,
+            name: version.name,
+            version_number: version.version_number,
+            version_type: version.version_type,
+            loaders: version.loaders,
+            game_versions: version.game_versions,
+            date_published: version.date_published,
+            downloads: version.downloads,
+            file_sha1_hashes: version.file_sha1_hashes,
+            file_sha512_hashes: version.file_sha512_hashes
+        }
+    }
+}
+
+impl From<ModrinthVersionEntity> for ModrinthVersion {
+    fn from(entity: ModrinthVersionEntity) -> Self {
+        ModrinthVersion {
+            id: entity.id,
+            project_id: entity.project_id,
+            name: entity.name,
+            version_number: entity.version_number,
+            version_type: entity.version_type,
+            loaders: entity.loaders,
+            game_versions: entity.game_versions,
+            date_published: entity.date_published,
+            downloads: entity.downloads,
+            file_sha1_hashes: entity.file_sha1_hashes,
+            file_sha512_hashes: entity.file_sha512_hashes
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum ModrinthVersionError {
+    #[error("Skipping version {version_id}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        version_id: String,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_modrinth_version(db_pool: &Pool, version: &ModrinthVersion) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = modrinth_version::upsert_modrinth_version()
+        .params(&db_client, &version.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            ModrinthVersionError::DatabaseQueryFailed {
+                version_id: version.id.clone(),
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+pub async fn get_modrinth_versions(db_pool: &Pool) -> Result<Vec<ModrinthVersion>> {
+    let db_client = db_pool.get().await?;
+
+    let versions = modrinth_version::get_modrinth_versions()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(versions)
+}
+
+pub async fn get_modrinth_version_by_id(db_pool: &Pool, id: &str) -> Result<Option<ModrinthVersion>> {
+    let db_client = db_pool.get().await?;
+
+    let version = modrinth_version::get_modrinth_version_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(version)
+}
+
+/// Gets all versions of the given project, from most recently published to least recently published.
+pub async fn get_modrinth_versions_for_project(db_pool: &Pool, project_id: &str) -> Result<Vec<ModrinthVersion>> {
+    let db_client = db_pool.get().await?;
+
+    let versions = modrinth_version::get_modrinth_versions_for_project()
+        .bind(&db_client, &project_id)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::modrinth::project::test::populate_test_modrinth_projects;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_modrinth_version() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _projects = populate_test_modrinth_projects(&context.pool).await?;
+        let version = &create_test_modrinth_versions()[0];
+
+        // Act
+        upsert_modrinth_version(&context.pool, version).await?;
+
+        // Assert
+        let retrieved_versions = get_modrinth_versions(&context.pool).await?;
+
+        assert_that(&retrieved_versions).has_length(1);
+        assert_that(&retrieved_versions[0]).is_equal_to(version);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_modrinth_version() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _projects = populate_test_modrinth_projects(&context.pool).await?;
+
+        let version = &create_test_modrinth_versions()[0];
+        upsert_modrinth_version(&context.pool, version).await?;
+
+        let updated_version = ModrinthVersion {
+            downloads: 1000,
+            game_versions: vec!["1.20.6".to_string(), "1.21".to_string(), "1.21.1".to_string()],
+            ..version.clone()
+        };
+
+        // Act
+        upsert_modrinth_version(&context.pool, &updated_version).await?;
+
+        // Assert
+        let retrieved_versions = get_modrinth_versions(&context.pool).await?;
+
+        assert_that(&retrieved_versions).has_length(1);
+        assert_that(&retrieved_versions[0]).is_equal_to(&updated_version);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_insert_version_with_nonexistent_project() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let version = &create_test_modrinth_versions()[0];
+
+        // Act
+        let result = upsert_modrinth_version(&context.pool, version).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_modrinth_versions_for_project() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _projects = populate_test_modrinth_projects(&context.pool).await?;
+
+        let versions = create_test_modrinth_versions();
+        for version in &versions {
+            upsert_modrinth_version(&context.pool, version).await?;
+        }
+
+        // Act
+        let retrieved_versions = get_modrinth_versions_for_project(&context.pool, "aaaaaaaa").await?;
+
+        // Assert
+        assert_that(&retrieved_versions).has_length(2);
+        assert_that(&retrieved_versions[0]).is_equal_to(&versions[1]);
+        assert_that(&retrieved_versions[1]).is_equal_to(&versions[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    fn create_test_modrinth_versions() -> Vec<ModrinthVersion> {
+        vec![
+            ModrinthVersion {
+                id: "aaaa0000".to_string(),
+                project_id: "aaaaaaaa".to_string(),
+                name: "foo v1.2.2".to_string(),
+                version_number: "v1.2.2".to_string(),
+                version_type: "beta".to_string(),
+                loaders: vec!["paper".to_string(), "spigot".to_string()],
+                game_versions: vec!["1.20.6".to_string(), "1.21".to_string()],
+                date_published: datetime!(2021-01-15 0:00 UTC),
+                downloads: 40,
+                file_sha1_hashes: vec!["aaaa0000sha1".to_string()],
+                file_sha512_hashes: vec!["aaaa0000sha512".to_string()]
+            },
+            ModrinthVersion {
+                id: "aaaa1111".to_string(),
+                project_id: "aaaaaaaa".to_string(),
+                name: "foo v1.2.3".to_string(),
+                version_number: "v1.2.3".to_string(),
+                version_type: "release".to_string(),
+                loaders: vec!["paper".to_string(), "spigot".to_string()],
+                game_versions: vec!["1.21".to_string()],
+                date_published: datetime!(2021-02-03 0:00 UTC),
+                downloads: 60,
+                file_sha1_hashes: vec!["aaaa1111sha1".to_string()],
+                file_sha512_hashes: vec!["aaaa1111sha512".to_string()]
+            },
+            ModrinthVersion {
+                id: "bbbb1111".to_string(),
+                project_id: "bbbbbbbb".to_string(),
+                name: "bar v1.2.3".to_string(),
+                version_number: "v1.2.3".to_string(),
+                version_type: "alpha".to_string(),
+                loaders: vec!["bukkit".to_string()],
+                game_versions: vec!["1.8".to_string()],
+                date_published: datetime!(2021-02-02 0:00 UTC),
+                downloads: 300,
+                file_sha1_hashes: vec!["bbbb1111sha1".to_string(), "bbbb2222sha1".to_string()],
+                file_sha512_hashes: vec!["bbbb1111sha512".to_string(), "bbbb2222sha512".to_string()]
+            }
+        ]
+    }
+}
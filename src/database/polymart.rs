@@ -0,0 +1 @@
+pub mod resource;
\ No newline at end of file
@@ -0,0 +1,413 @@
+use crate::database::project_category::{ProjectCategory, to_cornucopia_project_categories, from_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, to_cornucopia_server_platforms, from_cornucopia_server_platforms};
+use crate::database::source_repository::SourceRepository;
+use crate::database::cornucopia::queries::polymart_resource::{self, PolymartResourceEntity, UpsertPolymartResourceParams};
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+use crate::minecraft_version::get_minecraft_version_sort_key;
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PolymartResource {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_updated: OffsetDateTime,
+    pub latest_minecraft_version: Option<String>,
+    pub downloads: i32,
+    pub premium: bool,
+    pub icon_url: Option<String>,
+    pub version_name: Option<String>,
+    pub source_url: Option<String>,
+    pub source_repository: Option<SourceRepository>,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>
+}
+
+impl From<PolymartResource> for UpsertPolymartResourceParams<String, String, String, String, Vec<i32>, String, String, String, String, String, String, Vec<CornucopiaServerPlatform>, String, Vec<String>, Vec<CornucopiaProjectCategory>> {
+    fn from(resource: PolymartResource) -> Self {
+        let mut source_repository_host = None;
+        let mut source_repository_owner = None;
+        let mut source_repository_name = None;
+
+        if let Some(repo) = resource.source_repository {
+            source_repository_host = Some(repo.host);
+            source_repository_owner = Some(repo.owner);
+            source_repository_name = Some(repo.name);
+        }
+
+        UpsertPolymartResourceParams {
+            id: resource.id,
+            name: resource.name,
+            description: resource.description,
+            author: resource.author,
+            date_created: resource.date_created,
+            date_updated: resource.date_updated,
+            latest_minecraft_version_sort_key: get_minecraft_version_sort_key(resource.latest_minecraft_version.as_deref()),
+            latest_minecraft_version: resource.latest_minecraft_version,
+            downloads: resource.downloads,
+            premium: resource.premium,
+            icon_url: resource.icon_url,
+            version_name: resource.version_name,
+            source_url: resource.source_url,
+            source_repository_host,
+            source_repository_owner,
+            source_repository_name,
+            platforms: to_cornucopia_server_platforms(resource.platforms),
+            categories: to_cornucopia_project_categories(resource.categories),
+            minecraft_versions: resource.minecraft_versions
+        }
+    }
+}
+
+impl From<PolymartResourceEntity> for PolymartResource {
+    fn from(entity: PolymartResourceEntity) -> Self {
+        let mut source_repository = None;
+
+        if let (Some(host), Some(owner), Some(name)) = (entity.source_repository_host, entity.source_repository_owner, entity.source_repository_name) {
+            source_repository = Some(SourceRepository {
+                host,
+                owner,
+                name,
+                id: entity.source_repository_id
+            })
+        }
+
+        PolymartResource {
+            id: entity.id,
+            name: entity.name,
+            description: entity.description,
+            author: entity.author,
+            date_created: entity.date_created,
+            date_updated: entity.date_updated,
+            latest_minecraft_version: entity.latest_minecraft_version,
+            downloads: entity.downloads,
+            premium: entity.premium,
+            icon_url: entity.icon_url,
+            version_name: entity.version_name,
+            source_url: entity.source_url,
+            source_repository,
+            platforms: from_cornucopia_server_platforms(entity.platforms),
+            categories: from_cornucopia_project_categories(entity.categories),
+            minecraft_versions: entity.minecraft_versions
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum PolymartResourceError {
+    #[error("Skipping resource ID {resource_id}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        resource_id: i32,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_polymart_resource(db_pool: &Pool, resource: &PolymartResource) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = polymart_resource::upsert_polymart_resource()
+        .params(&db_client, &resource.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            PolymartResourceError::DatabaseQueryFailed {
+                resource_id: resource.id,
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+pub async fn get_polymart_resources(db_pool: &Pool) -> Result<Vec<PolymartResource>> {
+    let db_client = db_pool.get().await?;
+
+    let resources = polymart_resource::get_polymart_resources()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(resources)
+}
+
+/// Returns the resource with the given ID, or None if it does not exist or is deleted.
+pub async fn get_polymart_resource_by_id(db_pool: &Pool, id: i32) -> Result<Option<PolymartResource>> {
+    let db_client = db_pool.get().await?;
+
+    let resource = polymart_resource::get_polymart_resource_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(resource)
+}
+
+pub async fn get_latest_polymart_resource_update_date(db_pool: &Pool) -> Result<OffsetDateTime> {
+    let db_client = db_pool.get().await?;
+
+    let date = polymart_resource::get_latest_polymart_resource_update_date()
+        .bind(&db_client)
+        .one()
+        .await?;
+
+    Ok(date)
+}
+
+/// Marks resources that have not been seen upstream since the given date as deleted, returning the number of resources marked.
+/// Deleted resources are excluded from common projects, and are restored the next time they are upserted.
+/// Quarantined resources are left alone, since they may have been seen but failed to process.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn mark_unseen_polymart_resources_as_deleted(db_pool: &Pool, date_started: OffsetDateTime) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = polymart_resource::mark_unseen_polymart_resources_as_deleted()
+        .bind(&db_client, &date_started)
+        .await?;
+
+    Ok(count)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_polymart_resource() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let resource = &create_test_polymart_resources()[0];
+
+        // Act
+        upsert_polymart_resource(&context.pool, resource).await?;
+
+        // Assert
+        let retrieved_resources = get_polymart_resources(&context.pool).await?;
+        let retrieved_resource = &retrieved_resources[0];
+
+        assert_that(&retrieved_resources).has_length(1);
+        assert_that(&retrieved_resource).is_equal_to(resource);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_polymart_resource() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _resource = populate_test_polymart_resource(&context.pool).await?;
+
+        let updated_resource = PolymartResource {
+            id: 1,
+            name: "foo-updated".to_string(),
+            description: "foo-description-updated".to_string(),
+            author: "Frumple".to_string(),
+            date_created: datetime!(2020-01-01 0:00 UTC),
+            date_updated: datetime!(2021-07-01 0:00 UTC),
+            latest_minecraft_version: Some("1.22".to_string()),
+            downloads: 100,
+            premium: true,
+            icon_url: Some("https://polymart.org/resource/1/thumbnail-updated.png".to_string()),
+            version_name: Some("2.3.4".to_string()),
+            source_url: Some("https://github.com/alice/foo-updated".to_string()),
+            source_repository: Some(SourceRepository {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo-updated".to_string(),
+                id: None
+            }),
+            platforms: vec![ServerPlatform::Paper],
+            categories: vec![ProjectCategory::Economy],
+            minecraft_versions: vec!["1.21.4".to_string(), "1.22".to_string()]
+        };
+
+        // Act
+        upsert_polymart_resource(&context.pool, &updated_resource).await?;
+
+        // Assert
+        let retrieved_resources = get_polymart_resources(&context.pool).await?;
+        let retrieved_resource = &retrieved_resources[0];
+
+        assert_that(&retrieved_resources).has_length(1);
+        assert_that(&retrieved_resource).is_equal_to(&updated_resource);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_latest_polymart_resource_update_date() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let resources = create_test_polymart_resources();
+        for resource in resources {
+            upsert_polymart_resource(&context.pool, &resource).await?;
+        }
+
+        // Act
+        let latest_update_date = get_latest_polymart_resource_update_date(&context.pool).await?;
+
+        // Assert
+        assert_that(&latest_update_date).is_equal_to(datetime!(2018-02-03 0:00 UTC));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_mark_unseen_polymart_resources_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let resources = populate_test_polymart_resources(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_polymart_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(resources.len() as u64);
+
+        let retrieved_resources = get_polymart_resources(&context.pool).await?;
+        assert_that(&retrieved_resources).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    pub async fn populate_test_polymart_resource(db_pool: &Pool) -> Result<PolymartResource> {
+        let resource = &create_test_polymart_resources()[0];
+        upsert_polymart_resource(db_pool, resource).await?;
+        Ok(resource.clone())
+    }
+
+    pub async fn populate_test_polymart_resources(db_pool: &Pool) -> Result<Vec<PolymartResource>> {
+        let resources = create_test_polymart_resources();
+        for resource in &resources {
+            upsert_polymart_resource(db_pool, resource).await?
+        }
+        Ok(resources)
+    }
+
+    fn create_test_polymart_resources() -> Vec<PolymartResource> {
+        vec![
+            PolymartResource {
+                id: 1,
+                name: "foo-polymart".to_string(),
+                description: "foo-polymart-description".to_string(),
+                author: "alice".to_string(),
+                date_created: datetime!(2018-01-01 0:00 UTC),
+                date_updated: datetime!(2018-02-03 0:00 UTC),
+                latest_minecraft_version: Some("1.21.4".to_string()),
+                downloads: 100,
+                premium: false,
+                icon_url: Some("https://polymart.org/resource/1/thumbnail.png".to_string()),
+                version_name: Some("1.2.3".to_string()),
+                source_url: Some("https://github.com/alice/foo".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "github.com".to_string(),
+                    owner: "alice".to_string(),
+                    name: "foo".to_string(),
+                    id: None
+                }),
+                platforms: vec![ServerPlatform::Spigot, ServerPlatform::Paper],
+                categories: vec![ProjectCategory::Chat],
+                minecraft_versions: vec!["1.21.3".to_string(), "1.21.4".to_string()]
+            },
+            PolymartResource {
+                id: 2,
+                name: "bar-polymart".to_string(),
+                description: "bar-polymart-description".to_string(),
+                author: "bob".to_string(),
+                date_created: datetime!(2018-01-02 0:00 UTC),
+                date_updated: datetime!(2018-02-02 0:00 UTC),
+                latest_minecraft_version: Some("1.8".to_string()),
+                downloads: 300,
+                premium: true,
+                icon_url: None,
+                version_name: Some("1.2.3".to_string()),
+                source_url: Some("https://gitlab.com/bob/bar".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "gitlab.com".to_string(),
+                    owner: "bob".to_string(),
+                    name: "bar".to_string(),
+                    id: None
+                }),
+                platforms: vec![ServerPlatform::Spigot],
+                categories: vec![ProjectCategory::Economy, ProjectCategory::Utility],
+                minecraft_versions: vec!["1.8".to_string()]
+            },
+            PolymartResource {
+                id: 3,
+                name: "baz-polymart".to_string(),
+                description: "baz-polymart-description".to_string(),
+                author: "eve".to_string(),
+                date_created: datetime!(2018-01-03 0:00 UTC),
+                date_updated: datetime!(2018-02-01 0:00 UTC),
+                latest_minecraft_version: Some("1.16.5".to_string()),
+                downloads: 200,
+                premium: false,
+                icon_url: Some("https://polymart.org/resource/3/thumbnail.png".to_string()),
+                version_name: None,
+                source_url: Some("https://bitbucket.org/eve/baz".to_string()),
+                source_repository: Some(SourceRepository {
+                    host: "bitbucket.org".to_string(),
+                    owner: "eve".to_string(),
+                    name: "baz".to_string(),
+                    id: None
+                }),
+                platforms: vec![ServerPlatform::BungeeCord],
+                categories: vec![],
+                minecraft_versions: vec!["1.16.5".to_string()]
+            }
+        ]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+
+use serde::{Deserialize, Serialize};
+
+/// Category that a plugin belongs to, normalized across all upstream repositories.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProjectCategory {
+    AdminTools,
+    Chat,
+    Economy,
+    Gameplay,
+    Library,
+    Minigames,
+    Miscellaneous,
+    Optimization,
+    Protection,
+    RolePlaying,
+    Transportation,
+    Utility,
+    WorldManagement
+}
+
+impl From<ProjectCategory> for CornucopiaProjectCategory {
+    fn from(category: ProjectCategory) -> Self {
+        match category {
+            ProjectCategory::AdminTools => CornucopiaProjectCategory::AdminTools,
+            ProjectCategory::Chat => CornucopiaProjectCategory::Chat,
+            ProjectCategory::Economy => CornucopiaProjectCategory::Economy,
+            ProjectCategory::Gameplay => CornucopiaProjectCategory::Gameplay,
+            ProjectCategory::Library => CornucopiaProjectCategory::Library,
+            ProjectCategory::Minigames => CornucopiaProjectCategory::Minigames,
+            ProjectCategory::Miscellaneous => CornucopiaProjectCategory::Miscellaneous,
+            ProjectCategory::Optimization => CornucopiaProjectCategory::Optimization,
+            ProjectCategory::Protection => CornucopiaProjectCategory::Protection,
+            ProjectCategory::RolePlaying => CornucopiaProjectCategory::RolePlaying,
+            ProjectCategory::Transportation => CornucopiaProjectCategory::Transportation,
+            ProjectCategory::Utility => CornucopiaProjectCategory::Utility,
+            ProjectCategory::WorldManagement => CornucopiaProjectCategory::WorldManagement
+        }
+    }
+}
+
+impl From<CornucopiaProjectCategory> for ProjectCategory {
+    fn from(category: CornucopiaProjectCategory) -> Self {
+        match category {
+            CornucopiaProjectCategory::AdminTools => ProjectCategory::AdminTools,
+            CornucopiaProjectCategory::Chat => ProjectCategory::Chat,
+            CornucopiaProjectCategory::Economy => ProjectCategory::Economy,
+            CornucopiaProjectCategory::Gameplay => ProjectCategory::Gameplay,
+            CornucopiaProjectCategory::Library => ProjectCategory::Library,
+            CornucopiaProjectCategory::Minigames => ProjectCategory::Minigames,
+            CornucopiaProjectCategory::Miscellaneous => ProjectCategory::Miscellaneous,
+            CornucopiaProjectCategory::Optimization => ProjectCategory::Optimization,
+            CornucopiaProjectCategory::Protection => ProjectCategory::Protection,
+            CornucopiaProjectCategory::RolePlaying => ProjectCategory::RolePlaying,
+            CornucopiaProjectCategory::Transportation => ProjectCategory::Transportation,
+            CornucopiaProjectCategory::Utility => ProjectCategory::Utility,
+            CornucopiaProjectCategory::WorldManagement => ProjectCategory::WorldManagement
+        }
+    }
+}
+
+pub(crate) fn to_cornucopia_project_categories(categories: Vec<ProjectCategory>) -> Vec<CornucopiaProjectCategory> {
+    categories.into_iter().map(|x| x.into()).collect()
+}
+
+pub(crate) fn from_cornucopia_project_categories(categories: Vec<CornucopiaProjectCategory>) -> Vec<ProjectCategory> {
+    categories.into_iter().map(|x| x.into()).collect()
+}
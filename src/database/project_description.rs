@@ -0,0 +1,137 @@
+use crate::database::cornucopia::queries::project_description::{self, ProjectDescriptionEntity};
+use crate::database::ingest_log::IngestLogRepository;
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use tracing::instrument;
+
+/// The name, short description, and full description of an upstream project.
+/// The full description is sanitized HTML, and is only available for Spigot resources, Modrinth projects, and Hangar projects.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectDescription {
+    pub repository: IngestLogRepository,
+    /// The upstream ID as text, except for Hangar which uses the slug
+    pub project_id: String,
+    pub name: String,
+    pub description: String,
+    pub full_description: Option<String>
+}
+
+impl ProjectDescription {
+    fn from_entity(repository: IngestLogRepository, project_id: &str, entity: ProjectDescriptionEntity) -> Self {
+        ProjectDescription {
+            repository,
+            project_id: project_id.to_string(),
+            name: entity.name,
+            description: entity.description,
+            full_description: entity.full_description
+        }
+    }
+}
+
+/// Returns the description of a project that is not deleted, or None if the project does not exist or its repository has no full descriptions.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn get_project_description(db_pool: &Pool, repository: IngestLogRepository, project_id: &str) -> Result<Option<ProjectDescription>> {
+    let db_client = db_pool.get().await?;
+
+    let entity = match repository {
+        IngestLogRepository::Spigot => {
+            let Ok(id) = project_id.parse::<i32>() else {
+                return Ok(None)
+            };
+
+            project_description::get_spigot_resource_description()
+                .bind(&db_client, &id)
+                .opt()
+                .await?
+        }
+        IngestLogRepository::Modrinth => {
+            project_description::get_modrinth_project_description()
+                .bind(&db_client, &project_id)
+                .opt()
+                .await?
+        }
+        IngestLogRepository::Hangar => {
+            project_description::get_hangar_project_description()
+                .bind(&db_client, &project_id)
+                .opt()
+                .await?
+        }
+        _ => None
+    };
+
+    Ok(entity.map(|x| ProjectDescription::from_entity(repository, project_id, x)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::hangar::project::test::populate_test_hangar_projects;
+    use crate::database::modrinth::project::test::populate_test_modrinth_projects;
+    use crate::database::spigot::resource::test::populate_test_spigot_authors_and_resources;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_project_descriptions_from_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        let modrinth_projects = populate_test_modrinth_projects(&context.pool).await?;
+        let hangar_projects = populate_test_hangar_projects(&context.pool).await?;
+
+        // Act
+        let spigot_description = get_project_description(&context.pool, IngestLogRepository::Spigot, &spigot_resources[0].id.to_string()).await?;
+        let modrinth_description = get_project_description(&context.pool, IngestLogRepository::Modrinth, &modrinth_projects[1].id).await?;
+        let hangar_description = get_project_description(&context.pool, IngestLogRepository::Hangar, &hangar_projects[2].slug).await?;
+
+        // Assert
+        assert_that(&spigot_description).is_equal_to(Some(ProjectDescription {
+            repository: IngestLogRepository::Spigot,
+            project_id: spigot_resources[0].id.to_string(),
+            name: spigot_resources[0].parsed_name.clone().unwrap(),
+            description: spigot_resources[0].description.clone(),
+            full_description: spigot_resources[0].full_description.clone()
+        }));
+        assert_that(&modrinth_description.and_then(|x| x.full_description)).is_equal_to(modrinth_projects[1].full_description.clone());
+        assert_that(&hangar_description.and_then(|x| x.full_description)).is_equal_to(hangar_projects[2].full_description.clone());
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_get_description_of_missing_project_from_db() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let _spigot_resources = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        // Act
+        let missing_description = get_project_description(&context.pool, IngestLogRepository::Spigot, "999").await?;
+        let invalid_id_description = get_project_description(&context.pool, IngestLogRepository::Spigot, "foo").await?;
+        let unsupported_repository_description = get_project_description(&context.pool, IngestLogRepository::CurseForge, "1").await?;
+
+        // Assert
+        assert_that(&missing_description).is_none();
+        assert_that(&invalid_id_description).is_none();
+        assert_that(&unsupported_repository_description).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
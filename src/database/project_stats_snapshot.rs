@@ -0,0 +1,256 @@
+use crate::database::cornucopia::queries::project_stats_snapshot::{self, InsertProjectStatsSnapshotParams, ProjectStatsSnapshotEntity};
+use crate::database::ingest_log::IngestLogRepository;
+
+use anyhow::Result;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use time::{Date, Duration};
+use tracing::instrument;
+
+/// Snapshots newer than this are kept for every day. Older snapshots are downsampled to the last one of each week.
+pub const PROJECT_STATS_DAILY_RETENTION_DAYS: i64 = 90;
+
+/// Snapshots older than this are deleted entirely.
+pub const PROJECT_STATS_RETENTION_DAYS: i64 = 730;
+
+/// The popularity stats of a single upstream project on a given day.
+/// Stats that the upstream repository does not report are None.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectStatsSnapshot {
+    pub repository: IngestLogRepository,
+    pub project_id: String,
+    pub date: Date,
+    pub downloads: i32,
+    pub likes: Option<i32>,
+    pub follows: Option<i32>,
+    pub stars: Option<i32>,
+    pub watchers: Option<i32>
+}
+
+impl From<ProjectStatsSnapshot> for InsertProjectStatsSnapshotParams<String> {
+    fn from(snapshot: ProjectStatsSnapshot) -> Self {
+        InsertProjectStatsSnapshotParams {
+            repository: snapshot.repository.into(),
+            project_id: snapshot.project_id,
+            date: snapshot.date,
+            downloads: snapshot.downloads,
+            likes: snapshot.likes,
+            follows: snapshot.follows,
+            stars: snapshot.stars,
+            watchers: snapshot.watchers
+        }
+    }
+}
+
+impl From<ProjectStatsSnapshotEntity> for ProjectStatsSnapshot {
+    fn from(entity: ProjectStatsSnapshotEntity) -> Self {
+        ProjectStatsSnapshot {
+            repository: entity.repository.into(),
+            project_id: entity.project_id,
+            date: entity.date,
+            downloads: entity.downloads,
+            likes: entity.likes,
+            follows: entity.follows,
+            stars: entity.stars,
+            watchers: entity.watchers
+        }
+    }
+}
+
+/// Snapshots the current stats of every upstream project that is not deleted, returning the number of snapshots written.
+/// If a project already has a snapshot on the given date, it is overwritten with the current stats.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn insert_project_stats_snapshots(db_pool: &Pool, date: Date) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = project_stats_snapshot::insert_project_stats_snapshots()
+        .bind(&db_client, &date)
+        .await?;
+
+    Ok(count)
+}
+
+/// Returns the snapshots of a single project, ordered from oldest to newest.
+/// Project IDs are the upstream IDs as text, except for Hangar which uses the slug.
+pub async fn get_project_stats_snapshots(db_pool: &Pool, repository: IngestLogRepository, project_id: &str) -> Result<Vec<ProjectStatsSnapshot>> {
+    let db_client = db_pool.get().await?;
+
+    let snapshots = project_stats_snapshot::get_project_stats_snapshots()
+        .bind(&db_client, &repository.into(), &project_id)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(snapshots)
+}
+
+/// Returns the snapshots of every project, ordered by project and then from oldest to newest.
+pub async fn get_all_project_stats_snapshots(db_pool: &Pool) -> Result<Vec<ProjectStatsSnapshot>> {
+    let db_client = db_pool.get().await?;
+
+    let snapshots = project_stats_snapshot::get_all_project_stats_snapshots()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(snapshots)
+}
+
+/// Downsamples and deletes old snapshots according to the retention periods, returning the number of snapshots removed.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn prune_project_stats_snapshots(db_pool: &Pool, today: Date) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let daily_cutoff = today - Duration::days(PROJECT_STATS_DAILY_RETENTION_DAYS);
+    let retention_cutoff = today - Duration::days(PROJECT_STATS_RETENTION_DAYS);
+
+    let downsampled_count = project_stats_snapshot::downsample_project_stats_snapshots()
+        .bind(&db_client, &daily_cutoff)
+        .await?;
+
+    let expired_count = project_stats_snapshot::delete_expired_project_stats_snapshots()
+        .bind(&db_client, &retention_cutoff)
+        .await?;
+
+    Ok(downsampled_count + expired_count)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::hangar::project::{HangarProject, upsert_hangar_project};
+    use crate::database::hangar::project::test::populate_test_hangar_project;
+    use crate::database::modrinth::project::test::populate_test_modrinth_project;
+    use crate::database::spigot::resource::test::populate_test_spigot_author_and_resource;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::date;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_project_stats_snapshots() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_, spigot_resource) = populate_test_spigot_author_and_resource(&context.pool).await?;
+        let modrinth_project = populate_test_modrinth_project(&context.pool).await?;
+        let hangar_project = populate_test_hangar_project(&context.pool).await?;
+
+        // Act
+        let count = insert_project_stats_snapshots(&context.pool, date!(2024-01-01)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(3);
+
+        let spigot_snapshots = get_project_stats_snapshots(&context.pool, IngestLogRepository::Spigot, &spigot_resource.id.to_string()).await?;
+        assert_that(&spigot_snapshots).is_equal_to(vec![
+            ProjectStatsSnapshot {
+                repository: IngestLogRepository::Spigot,
+                project_id: spigot_resource.id.to_string(),
+                date: date!(2024-01-01),
+                downloads: spigot_resource.downloads,
+                likes: Some(spigot_resource.likes),
+                follows: None,
+                stars: None,
+                watchers: None
+            }
+        ]);
+
+        let modrinth_snapshots = get_project_stats_snapshots(&context.pool, IngestLogRepository::Modrinth, &modrinth_project.id).await?;
+        assert_that(&modrinth_snapshots).has_length(1);
+        assert_that(&modrinth_snapshots[0].follows).is_equal_to(Some(modrinth_project.follows));
+
+        let hangar_snapshots = get_project_stats_snapshots(&context.pool, IngestLogRepository::Hangar, &hangar_project.slug).await?;
+        assert_that(&hangar_snapshots).has_length(1);
+        assert_that(&hangar_snapshots[0].stars).is_equal_to(Some(hangar_project.stars));
+        assert_that(&hangar_snapshots[0].watchers).is_equal_to(Some(hangar_project.watchers));
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_overwrite_project_stats_snapshot_on_same_date() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let project = populate_test_hangar_project(&context.pool).await?;
+        insert_project_stats_snapshots(&context.pool, date!(2024-01-01)).await?;
+
+        let updated_project = HangarProject {
+            downloads: project.downloads + 50,
+            ..project.clone()
+        };
+        upsert_hangar_project(&context.pool, &updated_project).await?;
+
+        // Act
+        insert_project_stats_snapshots(&context.pool, date!(2024-01-01)).await?;
+        insert_project_stats_snapshots(&context.pool, date!(2024-01-02)).await?;
+
+        // Assert
+        let snapshots = get_project_stats_snapshots(&context.pool, IngestLogRepository::Hangar, &project.slug).await?;
+        let series: Vec<(Date, i32)> = snapshots.iter().map(|x| (x.date, x.downloads)).collect();
+        assert_that(&series).is_equal_to(vec![
+            (date!(2024-01-01), project.downloads + 50),
+            (date!(2024-01-02), project.downloads + 50)
+        ]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_prune_project_stats_snapshots() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let project = populate_test_hangar_project(&context.pool).await?;
+
+        // 2024-01-01 is a Monday, so the first week is 2024-01-01 to 2024-01-07.
+        for date in [date!(2022-01-03), date!(2024-01-01), date!(2024-01-03), date!(2024-01-07), date!(2024-01-08), date!(2024-05-01), date!(2024-05-02)] {
+            insert_project_stats_snapshots(&context.pool, date).await?;
+        }
+
+        // Act
+        let count = prune_project_stats_snapshots(&context.pool, date!(2024-05-10)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(3);
+
+        let snapshots = get_project_stats_snapshots(&context.pool, IngestLogRepository::Hangar, &project.slug).await?;
+        let dates: Vec<Date> = snapshots.iter().map(|x| x.date).collect();
+        assert_that(&dates).is_equal_to(vec![
+            date!(2024-01-07),
+            date!(2024-01-08),
+            date!(2024-05-01),
+            date!(2024-05-02)
+        ]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
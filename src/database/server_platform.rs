@@ -0,0 +1,58 @@
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+
+use serde::{Deserialize, Serialize};
+
+/// Server software that a plugin declares support for, normalized across all upstream repositories.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ServerPlatform {
+    Bukkit,
+    Spigot,
+    Paper,
+    Folia,
+    Purpur,
+    Velocity,
+    #[serde(rename = "bungeecord")]
+    #[strum(serialize = "bungeecord")]
+    BungeeCord,
+    Waterfall
+}
+
+impl From<ServerPlatform> for CornucopiaServerPlatform {
+    fn from(platform: ServerPlatform) -> Self {
+        match platform {
+            ServerPlatform::Bukkit => CornucopiaServerPlatform::Bukkit,
+            ServerPlatform::Spigot => CornucopiaServerPlatform::Spigot,
+            ServerPlatform::Paper => CornucopiaServerPlatform::Paper,
+            ServerPlatform::Folia => CornucopiaServerPlatform::Folia,
+            ServerPlatform::Purpur => CornucopiaServerPlatform::Purpur,
+            ServerPlatform::Velocity => CornucopiaServerPlatform::Velocity,
+            ServerPlatform::BungeeCord => CornucopiaServerPlatform::BungeeCord,
+            ServerPlatform::Waterfall => CornucopiaServerPlatform::Waterfall
+        }
+    }
+}
+
+impl From<CornucopiaServerPlatform> for ServerPlatform {
+    fn from(platform: CornucopiaServerPlatform) -> Self {
+        match platform {
+            CornucopiaServerPlatform::Bukkit => ServerPlatform::Bukkit,
+            CornucopiaServerPlatform::Spigot => ServerPlatform::Spigot,
+            CornucopiaServerPlatform::Paper => ServerPlatform::Paper,
+            CornucopiaServerPlatform::Folia => ServerPlatform::Folia,
+            CornucopiaServerPlatform::Purpur => ServerPlatform::Purpur,
+            CornucopiaServerPlatform::Velocity => ServerPlatform::Velocity,
+            CornucopiaServerPlatform::BungeeCord => ServerPlatform::BungeeCord,
+            CornucopiaServerPlatform::Waterfall => ServerPlatform::Waterfall
+        }
+    }
+}
+
+pub(crate) fn to_cornucopia_server_platforms(platforms: Vec<ServerPlatform>) -> Vec<CornucopiaServerPlatform> {
+    platforms.into_iter().map(|x| x.into()).collect()
+}
+
+pub(crate) fn from_cornucopia_server_platforms(platforms: Vec<CornucopiaServerPlatform>) -> Vec<ServerPlatform> {
+    platforms.into_iter().map(|x| x.into()).collect()
+}
@@ -0,0 +1,296 @@
+use crate::database::builtbybit::resource::BuiltByBitResource;
+use crate::database::cornucopia::queries::{builtbybit_resource, curseforge_project, hangar_project, ingest_log, modrinth_project, modrinth_version, polymart_resource, project_stats_snapshot, snapshot, source_repository_metadata, source_repository_release, spigot_author, spigot_resource};
+use crate::database::curseforge::project::CurseForgeProject;
+use crate::database::hangar::project::HangarProject;
+use crate::database::ingest_log::IngestLog;
+use crate::database::modrinth::project::ModrinthProject;
+use crate::database::modrinth::version::ModrinthVersion;
+use crate::database::polymart::resource::PolymartResource;
+use crate::database::project_stats_snapshot::ProjectStatsSnapshot;
+use crate::database::source_repository_metadata::SourceRepositoryMetadata;
+use crate::database::source_repository_release::SourceRepositoryRelease;
+use crate::database::spigot::author::SpigotAuthor;
+use crate::database::spigot::resource::SpigotResource;
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use thiserror::Error;
+use tracing::instrument;
+
+/// Every table that is exported to and imported from a snapshot.
+/// The icon cache, ingest checkpoints, and ingest failures are not included, since they are rebuilt by later ingest runs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    pub spigot_authors: Vec<SpigotAuthor>,
+    pub spigot_resources: Vec<SpigotResource>,
+    pub modrinth_projects: Vec<ModrinthProject>,
+    pub modrinth_versions: Vec<ModrinthVersion>,
+    pub hangar_projects: Vec<HangarProject>,
+    pub curseforge_projects: Vec<CurseForgeProject>,
+    pub polymart_resources: Vec<PolymartResource>,
+    pub builtbybit_resources: Vec<BuiltByBitResource>,
+    pub source_repository_releases: Vec<SourceRepositoryRelease>,
+    pub source_repository_metadata: Vec<SourceRepositoryMetadata>,
+    pub project_stats_snapshots: Vec<ProjectStatsSnapshot>,
+    pub ingest_logs: Vec<IngestLog>
+}
+
+#[derive(Debug, Error)]
+enum SnapshotError {
+    #[error("Snapshots can only be imported into an empty database, but it already contains rows in: {}", tables.join(", "))]
+    DatabaseNotEmpty {
+        tables: Vec<String>
+    }
+}
+
+/// Writes every item in the snapshot within a single transaction, so that a failed import leaves the database untouched.
+/// Fails if any of the snapshot tables already contain rows, including soft-deleted rows.
+#[instrument(
+    skip(db_pool, snapshot)
+)]
+pub async fn insert_snapshot(db_pool: &Pool, snapshot: &Snapshot) -> Result<()> {
+    let mut db_client = db_pool.get().await?;
+    let transaction = db_client.transaction().await?;
+
+    let populated_tables = snapshot::get_populated_snapshot_tables()
+        .bind(&transaction)
+        .all()
+        .await?;
+
+    if !populated_tables.is_empty() {
+        return Err(SnapshotError::DatabaseNotEmpty { tables: populated_tables }.into());
+    }
+
+    // Authors must be inserted before the resources that reference them, and projects before their versions.
+    for author in &snapshot.spigot_authors {
+        spigot_author::insert_spigot_author()
+            .params(&transaction, &author.clone().into())
+            .await?;
+    }
+    for resource in &snapshot.spigot_resources {
+        spigot_resource::upsert_spigot_resource()
+            .params(&transaction, &resource.clone().into())
+            .await?;
+    }
+    for project in &snapshot.modrinth_projects {
+        modrinth_project::upsert_modrinth_project()
+            .params(&transaction, &project.clone().into())
+            .await?;
+    }
+    for version in &snapshot.modrinth_versions {
+        modrinth_version::upsert_modrinth_version()
+            .params(&transaction, &version.clone().into())
+            .await?;
+    }
+    for project in &snapshot.hangar_projects {
+        hangar_project::upsert_hangar_project()
+            .params(&transaction, &project.clone().into())
+            .await?;
+    }
+    for project in &snapshot.curseforge_projects {
+        curseforge_project::upsert_curseforge_project()
+            .params(&transaction, &project.clone().into())
+            .await?;
+    }
+    for resource in &snapshot.polymart_resources {
+        polymart_resource::upsert_polymart_resource()
+            .params(&transaction, &resource.clone().into())
+            .await?;
+    }
+    for resource in &snapshot.builtbybit_resources {
+        builtbybit_resource::upsert_builtbybit_resource()
+            .params(&transaction, &resource.clone().into())
+            .await?;
+    }
+    for release in &snapshot.source_repository_releases {
+        source_repository_release::upsert_source_repository_release()
+            .params(&transaction, &release.clone().into())
+            .await?;
+    }
+    for metadata in &snapshot.source_repository_metadata {
+        source_repository_metadata::upsert_source_repository_metadata()
+            .params(&transaction, &metadata.clone().into())
+            .await?;
+    }
+    for stats in &snapshot.project_stats_snapshots {
+        project_stats_snapshot::insert_project_stats_snapshot()
+            .params(&transaction, &stats.clone().into())
+            .await?;
+    }
+    for log in &snapshot.ingest_logs {
+        ingest_log::insert_ingest_log()
+            .params(&transaction, &log.clone().into())
+            .await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::builtbybit::resource::get_builtbybit_resources;
+    use crate::database::builtbybit::resource::test::populate_test_builtbybit_resources;
+    use crate::database::curseforge::project::get_curseforge_projects;
+    use crate::database::curseforge::project::test::populate_test_curseforge_projects;
+    use crate::database::hangar::project::get_hangar_projects;
+    use crate::database::hangar::project::test::populate_test_hangar_projects;
+    use crate::database::ingest_log::{IngestLogAction, IngestLogItem, IngestLogRepository, get_ingest_logs};
+    use crate::database::modrinth::project::{get_modrinth_projects, mark_unseen_modrinth_projects_as_deleted};
+    use crate::database::modrinth::project::test::populate_test_modrinth_projects;
+    use crate::database::polymart::resource::get_polymart_resources;
+    use crate::database::polymart::resource::test::populate_test_polymart_resources;
+    use crate::database::project_stats_snapshot::{get_all_project_stats_snapshots, insert_project_stats_snapshots};
+    use crate::database::source_repository_metadata::get_source_repository_metadata;
+    use crate::database::source_repository_metadata::test::populate_test_source_repository_metadata;
+    use crate::database::source_repository_release::get_source_repository_releases;
+    use crate::database::source_repository_release::test::populate_test_source_repository_releases;
+    use crate::database::spigot::author::get_spigot_authors;
+    use crate::database::spigot::resource::get_spigot_resources;
+    use crate::database::spigot::resource::test::populate_test_spigot_authors_and_resources;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::OffsetDateTime;
+    use time::macros::{date, datetime};
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_snapshot() -> Result<()> {
+        // Setup
+        let source_context = DatabaseTestContext::new(&format!("{}_source", function_name!())).await;
+        let target_context = DatabaseTestContext::new(&format!("{}_target", function_name!())).await;
+
+        // Arrange
+        let (spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&source_context.pool).await?;
+        let modrinth_projects = populate_test_modrinth_projects(&source_context.pool).await?;
+        let hangar_projects = populate_test_hangar_projects(&source_context.pool).await?;
+        let curseforge_projects = populate_test_curseforge_projects(&source_context.pool).await?;
+        let polymart_resources = populate_test_polymart_resources(&source_context.pool).await?;
+        let builtbybit_resources = populate_test_builtbybit_resources(&source_context.pool).await?;
+        let source_repository_releases = populate_test_source_repository_releases(&source_context.pool).await?;
+        let source_repository_metadata = populate_test_source_repository_metadata(&source_context.pool).await?;
+        insert_project_stats_snapshots(&source_context.pool, date!(2024-01-01)).await?;
+
+        let snapshot = Snapshot {
+            spigot_authors,
+            spigot_resources,
+            modrinth_projects,
+            modrinth_versions: vec![],
+            hangar_projects,
+            curseforge_projects,
+            polymart_resources,
+            builtbybit_resources,
+            source_repository_releases,
+            source_repository_metadata,
+            project_stats_snapshots: get_all_project_stats_snapshots(&source_context.pool).await?,
+            ingest_logs: vec![create_test_ingest_log()]
+        };
+
+        // Act
+        insert_snapshot(&target_context.pool, &snapshot).await?;
+
+        // Assert
+        let pool = &target_context.pool;
+        assert_that(&get_spigot_authors(pool).await?).is_equal_to(&snapshot.spigot_authors);
+        assert_that(&get_spigot_resources(pool).await?).is_equal_to(&snapshot.spigot_resources);
+        assert_that(&get_modrinth_projects(pool).await?).is_equal_to(&snapshot.modrinth_projects);
+        assert_that(&get_hangar_projects(pool).await?).is_equal_to(&snapshot.hangar_projects);
+        assert_that(&get_curseforge_projects(pool).await?).is_equal_to(&snapshot.curseforge_projects);
+        assert_that(&get_polymart_resources(pool).await?).is_equal_to(&snapshot.polymart_resources);
+        assert_that(&get_builtbybit_resources(pool).await?).is_equal_to(&snapshot.builtbybit_resources);
+        assert_that(&get_source_repository_releases(pool).await?).is_equal_to(&snapshot.source_repository_releases);
+        assert_that(&get_source_repository_metadata(pool).await?).is_equal_to(&snapshot.source_repository_metadata);
+        assert_that(&get_all_project_stats_snapshots(pool).await?).is_equal_to(&snapshot.project_stats_snapshots);
+        assert_that(&get_ingest_logs(pool).await?).is_equal_to(&snapshot.ingest_logs);
+
+        // Teardown
+        source_context.drop().await?;
+        target_context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_insert_snapshot_if_database_only_has_deleted_rows() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        populate_test_modrinth_projects(&context.pool).await?;
+        mark_unseen_modrinth_projects_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        let snapshot = Snapshot {
+            ingest_logs: vec![create_test_ingest_log()],
+            ..Default::default()
+        };
+
+        // Act
+        let result = insert_snapshot(&context.pool, &snapshot).await;
+
+        // Assert
+        assert_that(&result).is_err();
+
+        let error = result.unwrap_err();
+        let downcast_error = error.downcast_ref::<SnapshotError>().unwrap();
+
+        let SnapshotError::DatabaseNotEmpty{tables} = downcast_error;
+        assert_that(tables).is_equal_to(vec!["modrinth_project".to_string()]);
+
+        assert_that(&get_ingest_logs(&context.pool).await?).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_insert_any_items_if_snapshot_fails_partway() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let source_context = DatabaseTestContext::new(&format!("{}_source", function_name!())).await;
+        let (spigot_authors, spigot_resources) = populate_test_spigot_authors_and_resources(&source_context.pool).await?;
+        source_context.drop().await?;
+
+        // The resources reference authors that are missing from the snapshot
+        let snapshot = Snapshot {
+            spigot_authors: spigot_authors[..1].to_vec(),
+            spigot_resources,
+            ..Default::default()
+        };
+
+        // Act
+        let result = insert_snapshot(&context.pool, &snapshot).await;
+
+        // Assert
+        assert_that(&result).is_err();
+        assert_that(&get_spigot_authors(&context.pool).await?).is_empty();
+        assert_that(&get_spigot_resources(&context.pool).await?).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    fn create_test_ingest_log() -> IngestLog {
+        IngestLog {
+            action: IngestLogAction::Populate,
+            repository: IngestLogRepository::Spigot,
+            item: IngestLogItem::Resource,
+            date_started: datetime!(2024-01-01 0:00 UTC),
+            date_finished: datetime!(2024-01-01 1:00 UTC),
+            items_processed: 100,
+            success: true
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::database::cornucopia::queries::source_repository_metadata::{self, SourceRepositoryMetadataEntity, UpsertSourceRepositoryMetadataParams};
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+/// Metadata of a source repository as reported by its forge (GitHub, GitLab, or Codeberg).
+/// Host, owner, and name are always stored in lowercase.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SourceRepositoryMetadata {
+    pub host: String,
+    pub owner: String,
+    pub name: String,
+    pub stars: i32,
+    /// True if the repository has been archived (or disabled) on its forge
+    pub archived: bool,
+    /// The license detected by the forge: an SPDX identifier for GitHub and Codeberg, or GitLab's own license key
+    pub license: Option<String>,
+    pub open_issues: Option<i32>,
+    /// The date of the latest commit on the default branch
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub date_last_commit: Option<OffsetDateTime>
+}
+
+impl From<SourceRepositoryMetadata> for UpsertSourceRepositoryMetadataParams<String, String, String, String> {
+    fn from(metadata: SourceRepositoryMetadata) -> Self {
+        UpsertSourceRepositoryMetadataParams {
+            host: metadata.host,
+            owner: metadata.owner,
+            name: metadata.name,
+            stars: metadata.stars,
+            archived: metadata.archived,
+            license: metadata.license,
+            open_issues: metadata.open_issues,
+            date_last_commit: metadata.date_last_commit
+        }
+    }
+}
+
+impl From<SourceRepositoryMetadataEntity> for SourceRepositoryMetadata {
+    fn from(entity: SourceRepositoryMetadataEntity) -> Self {
+        SourceRepositoryMetadata {
+            host: entity.host,
+            owner: entity.owner,
+            name: entity.name,
+            stars: entity.stars,
+            archived: entity.archived,
+            license: entity.license,
+            open_issues: entity.open_issues,
+            date_last_commit: entity.date_last_commit
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum SourceRepositoryMetadataError {
+    #[error("Skipping metadata for source repository {host}/{owner}/{name}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        host: String,
+        owner: String,
+        name: String,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_source_repository_metadata(db_pool: &Pool, metadata: &SourceRepositoryMetadata) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = source_repository_metadata::upsert_source_repository_metadata()
+        .params(&db_client, &metadata.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            SourceRepositoryMetadataError::DatabaseQueryFailed {
+                host: metadata.host.clone(),
+                owner: metadata.owner.clone(),
+                name: metadata.name.clone(),
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+pub async fn get_source_repository_metadata(db_pool: &Pool) -> Result<Vec<SourceRepositoryMetadata>> {
+    let db_client = db_pool.get().await?;
+
+    let metadata = source_repository_metadata::get_source_repository_metadata()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(metadata)
+}
+
+pub async fn get_source_repository_metadata_by_repository(db_pool: &Pool, host: &str, owner: &str, name: &str) -> Result<Option<SourceRepositoryMetadata>> {
+    let db_client = db_pool.get().await?;
+
+    let metadata = source_repository_metadata::get_source_repository_metadata_by_repository()
+        .bind(&db_client, &host, &owner, &name)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_source_repository_metadata() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let metadata = &create_test_source_repository_metadata()[0];
+
+        // Act
+        upsert_source_repository_metadata(&context.pool, metadata).await?;
+
+        // Assert
+        let retrieved_metadata = get_source_repository_metadata(&context.pool).await?;
+
+        assert_that(&retrieved_metadata).has_length(1);
+        assert_that(&retrieved_metadata[0]).is_equal_to(metadata);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_source_repository_metadata() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let metadata = &create_test_source_repository_metadata()[0];
+        upsert_source_repository_metadata(&context.pool, metadata).await?;
+
+        // The repository is matched case-insensitively, so this should replace the existing metadata.
+        let updated_metadata = SourceRepositoryMetadata {
+            host: "GitHub.com".to_string(),
+            owner: "Alice".to_string(),
+            name: "Foo".to_string(),
+            stars: 200,
+            archived: true,
+            license: None,
+            open_issues: Some(0),
+            date_last_commit: Some(datetime!(2024-06-01 0:00 UTC))
+        };
+
+        // Act
+        upsert_source_repository_metadata(&context.pool, &updated_metadata).await?;
+
+        // Assert
+        let retrieved_metadata = get_source_repository_metadata(&context.pool).await?;
+
+        assert_that(&retrieved_metadata).has_length(1);
+        assert_that(&retrieved_metadata[0]).is_equal_to(SourceRepositoryMetadata {
+            host: "github.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            ..updated_metadata
+        });
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    pub async fn populate_test_source_repository_metadata(db_pool: &Pool) -> Result<Vec<SourceRepositoryMetadata>> {
+        let metadata = create_test_source_repository_metadata();
+        for m in &metadata {
+            upsert_source_repository_metadata(db_pool, m).await?
+        }
+        Ok(metadata)
+    }
+
+    fn create_test_source_repository_metadata() -> Vec<SourceRepositoryMetadata> {
+        vec![
+            SourceRepositoryMetadata {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo".to_string(),
+                stars: 100,
+                archived: false,
+                license: Some("MIT".to_string()),
+                open_issues: Some(5),
+                date_last_commit: Some(datetime!(2023-01-01 0:00 UTC))
+            },
+            SourceRepositoryMetadata {
+                host: "gitlab.com".to_string(),
+                owner: "bob".to_string(),
+                name: "bar".to_string(),
+                stars: 10,
+                archived: true,
+                license: None,
+                open_issues: None,
+                date_last_commit: Some(datetime!(2018-01-01 0:00 UTC))
+            },
+            SourceRepositoryMetadata {
+                host: "bitbucket.org".to_string(),
+                owner: "eve".to_string(),
+                name: "baz".to_string(),
+                stars: 0,
+                archived: false,
+                license: None,
+                open_issues: None,
+                date_last_commit: Some(datetime!(2024-01-01 0:00 UTC))
+            }
+        ]
+    }
+}
\ No newline at end of file
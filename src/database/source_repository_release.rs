@@ -0,0 +1,302 @@
+use crate::database::cornucopia::queries::source_repository_release::{self, SourceRepositoryEntity, SourceRepositoryReleaseEntity, UpsertSourceRepositoryReleaseParams};
+use crate::database::source_repository::SourceRepository;
+
+use anyhow::Result;
+use cornucopia_async::Params;
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+/// The latest release published to a source repository on its forge (GitHub, GitLab, or Codeberg).
+/// Host, owner, and name are always stored in lowercase.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SourceRepositoryRelease {
+    pub host: String,
+    pub owner: String,
+    pub name: String,
+    pub tag_name: String,
+    pub release_name: Option<String>,
+    pub url: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_published: OffsetDateTime
+}
+
+impl From<SourceRepositoryRelease> for UpsertSourceRepositoryReleaseParams<String, String, String, String, String, String> {
+    fn from(release: SourceRepositoryRelease) -> Self {
+        UpsertSourceRepositoryReleaseParams {
+            host: release.host,
+            owner: release.owner,
+            name: release.name,
+            tag_name: release.tag_name,
+            release_name: release.release_name,
+            url: release.url,
+            date_published: release.date_published
+        }
+    }
+}
+
+impl From<SourceRepositoryReleaseEntity> for SourceRepositoryRelease {
+    fn from(entity: SourceRepositoryReleaseEntity) -> Self {
+        SourceRepositoryRelease {
+            host: entity.host,
+            owner: entity.owner,
+            name: entity.name,
+            tag_name: entity.tag_name,
+            release_name: entity.release_name,
+            url: entity.url,
+            date_published: entity.date_published
+        }
+    }
+}
+
+impl From<SourceRepositoryEntity> for SourceRepository {
+    fn from(entity: SourceRepositoryEntity) -> Self {
+        SourceRepository {
+            host: entity.host,
+            owner: entity.owner,
+            name: entity.name,
+            id: None
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+enum SourceRepositoryReleaseError {
+    #[error("Skipping release for source repository {host}/{owner}/{name}: Database query failed: {source}")]
+    DatabaseQueryFailed {
+        host: String,
+        owner: String,
+        name: String,
+        source: anyhow::Error
+    }
+}
+
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn upsert_source_repository_release(db_pool: &Pool, release: &SourceRepositoryRelease) -> Result<()> {
+    let db_client = db_pool.get().await?;
+
+    let db_result = source_repository_release::upsert_source_repository_release()
+        .params(&db_client, &release.clone().into())
+        .await;
+
+    match db_result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(
+            SourceRepositoryReleaseError::DatabaseQueryFailed {
+                host: release.host.clone(),
+                owner: release.owner.clone(),
+                name: release.name.clone(),
+                source: err.into()
+            }.into()
+        )
+    }
+}
+
+pub async fn get_source_repository_releases(db_pool: &Pool) -> Result<Vec<SourceRepositoryRelease>> {
+    let db_client = db_pool.get().await?;
+
+    let releases = source_repository_release::get_source_repository_releases()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(releases)
+}
+
+pub async fn get_source_repository_release_by_repository(db_pool: &Pool, host: &str, owner: &str, name: &str) -> Result<Option<SourceRepositoryRelease>> {
+    let db_client = db_pool.get().await?;
+
+    let release = source_repository_release::get_source_repository_release_by_repository()
+        .bind(&db_client, &host, &owner, &name)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(release)
+}
+
+/// Gets every distinct source repository linked from a common project, in lowercase.
+pub async fn get_distinct_source_repositories(db_pool: &Pool) -> Result<Vec<SourceRepository>> {
+    let db_client = db_pool.get().await?;
+
+    let repositories = source_repository_release::get_distinct_source_repositories()
+        .bind(&db_client)
+        .all()
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+
+    Ok(repositories)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::database::common::project::refresh_common_projects;
+    use crate::database::modrinth::project::test::populate_test_modrinth_projects;
+    use crate::database::spigot::resource::test::populate_test_spigot_authors_and_resources;
+    use crate::database::test::DatabaseTestContext;
+
+    use ::function_name::named;
+    use speculoos::prelude::*;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    #[named]
+    async fn should_insert_source_repository_release() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let release = &create_test_source_repository_releases()[0];
+
+        // Act
+        upsert_source_repository_release(&context.pool, release).await?;
+
+        // Assert
+        let retrieved_releases = get_source_repository_releases(&context.pool).await?;
+        let retrieved_release = &retrieved_releases[0];
+
+        assert_that(&retrieved_releases).has_length(1);
+        assert_that(&retrieved_release).is_equal_to(release);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_update_source_repository_release() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let release = &create_test_source_repository_releases()[0];
+        upsert_source_repository_release(&context.pool, release).await?;
+
+        // The repository is matched case-insensitively, so this should replace the existing release.
+        let updated_release = SourceRepositoryRelease {
+            host: "GitHub.com".to_string(),
+            owner: "Alice".to_string(),
+            name: "Foo".to_string(),
+            tag_name: "v2.0.0".to_string(),
+            release_name: Some("Version 2".to_string()),
+            url: Some("https://github.com/alice/foo/releases/tag/v2.0.0".to_string()),
+            date_published: datetime!(2024-03-01 0:00 UTC)
+        };
+
+        // Act
+        upsert_source_repository_release(&context.pool, &updated_release).await?;
+
+        // Assert
+        let retrieved_releases = get_source_repository_releases(&context.pool).await?;
+        let retrieved_release = &retrieved_releases[0];
+
+        assert_that(&retrieved_releases).has_length(1);
+        assert_that(retrieved_release).is_equal_to(SourceRepositoryRelease {
+            host: "github.com".to_string(),
+            owner: "alice".to_string(),
+            name: "foo".to_string(),
+            ..updated_release
+        });
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_source_repository_release_by_repository() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let releases = populate_test_source_repository_releases(&context.pool).await?;
+        let release = &releases[0];
+
+        // Act
+        let retrieved_release = get_source_repository_release_by_repository(&context.pool, &release.host, &release.owner, &release.name).await?;
+        let missing_release = get_source_repository_release_by_repository(&context.pool, &release.host, &release.owner, "missing").await?;
+
+        // Assert
+        assert_that(&retrieved_release).is_some().is_equal_to(release);
+        assert_that(&missing_release).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_get_distinct_source_repositories() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        populate_test_spigot_authors_and_resources(&context.pool).await?;
+        populate_test_modrinth_projects(&context.pool).await?;
+        refresh_common_projects(&context.pool).await?;
+
+        // Act
+        let repositories = get_distinct_source_repositories(&context.pool).await?;
+
+        // Assert
+        let names: Vec<String> = repositories.iter().map(|x| x.url()).collect();
+        assert_that(&names).is_equal_to(vec![
+            "https://bitbucket.org/eve/baz".to_string(),
+            "https://github.com/alice/foo".to_string(),
+            "https://gitlab.com/bob/bar".to_string()
+        ]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    pub async fn populate_test_source_repository_releases(db_pool: &Pool) -> Result<Vec<SourceRepositoryRelease>> {
+        let releases = create_test_source_repository_releases();
+        for release in &releases {
+            upsert_source_repository_release(db_pool, release).await?
+        }
+        Ok(releases)
+    }
+
+    fn create_test_source_repository_releases() -> Vec<SourceRepositoryRelease> {
+        vec![
+            SourceRepositoryRelease {
+                host: "github.com".to_string(),
+                owner: "alice".to_string(),
+                name: "foo".to_string(),
+                tag_name: "v1.2.3".to_string(),
+                release_name: Some("Version 1.2.3".to_string()),
+                url: Some("https://github.com/alice/foo/releases/tag/v1.2.3".to_string()),
+                date_published: datetime!(2024-01-01 0:00 UTC)
+            },
+            SourceRepositoryRelease {
+                host: "gitlab.com".to_string(),
+                owner: "bob".to_string(),
+                name: "bar".to_string(),
+                tag_name: "1.0.0".to_string(),
+                release_name: None,
+                url: None,
+                date_published: datetime!(2015-01-01 0:00 UTC)
+            }
+        ]
+    }
+}
\ No newline at end of file
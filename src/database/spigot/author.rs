@@ -3,10 +3,11 @@ use crate::database::cornucopia::queries::spigot_author::{self, InsertSpigotAuth
 use anyhow::Result;
 use cornucopia_async::Params;
 use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::instrument;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SpigotAuthor {
     pub id: i32,
     pub name: String
@@ -75,6 +76,18 @@ pub async fn get_spigot_authors(db_pool: &Pool) -> Result<Vec<SpigotAuthor>> {
     Ok(authors)
 }
 
+pub async fn get_spigot_author_by_id(db_pool: &Pool, id: i32) -> Result<Option<SpigotAuthor>> {
+    let db_client = db_pool.get().await?;
+
+    let author = spigot_author::get_spigot_author_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(author)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
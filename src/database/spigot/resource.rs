@@ -1,21 +1,29 @@
+use crate::database::project_category::{ProjectCategory, to_cornucopia_project_categories, from_cornucopia_project_categories};
+use crate::database::server_platform::{ServerPlatform, to_cornucopia_server_platforms, from_cornucopia_server_platforms};
 use crate::database::source_repository::SourceRepository;
 use crate::database::cornucopia::queries::spigot_resource::{self, SpigotResourceEntity, UpsertSpigotResourceParams};
+use crate::database::cornucopia::types::public::ProjectCategory as CornucopiaProjectCategory;
+use crate::database::cornucopia::types::public::ServerPlatform as CornucopiaServerPlatform;
+use crate::minecraft_version::get_minecraft_version_sort_key;
 
 use anyhow::Result;
 use cornucopia_async::Params;
 use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use time::OffsetDateTime;
 use tracing::instrument;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SpigotResource {
     pub id: i32,
     pub name: String,
     pub parsed_name: Option<String>,
     pub description: String,
     pub slug: String,
+    #[serde(with = "time::serde::rfc3339")]
     pub date_created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
     pub date_updated: OffsetDateTime,
     pub latest_minecraft_version: Option<String>,
     pub downloads: i32,
@@ -28,10 +36,14 @@ pub struct SpigotResource {
     pub icon_url: Option<String>,
     pub icon_data: Option<String>,
     pub source_url: Option<String>,
-    pub source_repository: Option<SourceRepository>
+    pub source_repository: Option<SourceRepository>,
+    pub platforms: Vec<ServerPlatform>,
+    pub categories: Vec<ProjectCategory>,
+    pub minecraft_versions: Vec<String>,
+    pub full_description: Option<String>
 }
 
-impl From<SpigotResource> for UpsertSpigotResourceParams<String, String, String, String, String, String, String, String, String, String, String, String> {
+impl From<SpigotResource> for UpsertSpigotResourceParams<String, String, String, String, String, Vec<i32>, String, String, String, String, String, String, String, Vec<CornucopiaServerPlatform>, Vec<CornucopiaProjectCategory>, String, Vec<String>, String> {
     fn from(resource: SpigotResource) -> Self {
         let mut source_repository_host = None;
         let mut source_repository_owner = None;
@@ -53,6 +65,7 @@ impl From<SpigotResource> for UpsertSpigotResourceParams<String, String, String,
             icon_data: resource.icon_data,
             date_created: resource.date_created,
             date_updated: resource.date_updated,
+            latest_minecraft_version_sort_key: get_minecraft_version_sort_key(resource.latest_minecraft_version.as_deref()),
             latest_minecraft_version: resource.latest_minecraft_version,
             downloads: resource.downloads,
             likes: resource.likes,
@@ -64,7 +77,11 @@ impl From<SpigotResource> for UpsertSpigotResourceParams<String, String, String,
             source_url: resource.source_url,
             source_repository_host,
             source_repository_owner,
-            source_repository_name
+            source_repository_name,
+            platforms: to_cornucopia_server_platforms(resource.platforms),
+            categories: to_cornucopia_project_categories(resource.categories),
+            minecraft_versions: resource.minecraft_versions,
+            full_description: resource.full_description
         }
     }
 }
@@ -103,7 +120,11 @@ impl From<SpigotResourceEntity> for SpigotResource {
             icon_url: entity.icon_url,
             icon_data: entity.icon_data,
             source_url: entity.source_url,
-            source_repository
+            source_repository,
+            platforms: from_cornucopia_server_platforms(entity.platforms),
+            categories: from_cornucopia_project_categories(entity.categories),
+            minecraft_versions: entity.minecraft_versions,
+            full_description: entity.full_description
         }
     }
 }
@@ -153,6 +174,19 @@ pub async fn get_spigot_resources(db_pool: &Pool) -> Result<Vec<SpigotResource>>
     Ok(resources)
 }
 
+/// Returns the resource with the given ID, or None if it does not exist or is deleted.
+pub async fn get_spigot_resource_by_id(db_pool: &Pool, id: i32) -> Result<Option<SpigotResource>> {
+    let db_client = db_pool.get().await?;
+
+    let resource = spigot_resource::get_spigot_resource_by_id()
+        .bind(&db_client, &id)
+        .opt()
+        .await?
+        .map(|x| x.into());
+
+    Ok(resource)
+}
+
 pub async fn get_latest_spigot_resource_update_date(db_pool: &Pool) -> Result<OffsetDateTime> {
     let db_client = db_pool.get().await?;
 
@@ -164,9 +198,28 @@ pub async fn get_latest_spigot_resource_update_date(db_pool: &Pool) -> Result<Of
     Ok(date)
 }
 
+/// Marks resources that have not been seen upstream since the given date as deleted, returning the number of resources marked.
+/// Deleted resources are excluded from common projects, and are restored the next time they are upserted.
+/// Quarantined resources are left alone, since they may have been seen but failed to process.
+#[instrument(
+    level = "debug",
+    skip(db_pool)
+)]
+pub async fn mark_unseen_spigot_resources_as_deleted(db_pool: &Pool, date_started: OffsetDateTime) -> Result<u64> {
+    let db_client = db_pool.get().await?;
+
+    let count = spigot_resource::mark_unseen_spigot_resources_as_deleted()
+        .bind(&db_client, &date_started)
+        .await?;
+
+    Ok(count)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::database::ingest_failure::{IngestFailure, upsert_ingest_failure};
+    use crate::database::ingest_log::{IngestLogRepository, IngestLogItem};
     use crate::database::spigot::author::SpigotAuthor;
     use crate::database::spigot::author::test::{populate_test_spigot_author, populate_test_spigot_authors};
     use crate::database::spigot::test::SPIGOT_BASE64_TEST_ICON_DATA;
@@ -238,7 +291,11 @@ pub mod test {
                 owner: "alice".to_string(),
                 name: "foo-updated".to_string(),
                 id: None
-            })
+            }),
+            platforms: vec![ServerPlatform::Spigot],
+            categories: vec![ProjectCategory::Utility],
+            minecraft_versions: vec!["1.21".to_string(), "1.22".to_string()],
+            full_description: Some("<p>foo-full-description-updated</p>".to_string())
         };
 
         // Act
@@ -288,6 +345,51 @@ pub mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_get_spigot_resource_by_id() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_authors, resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        // Act
+        let retrieved_resource = get_spigot_resource_by_id(&context.pool, resources[1].id).await?;
+        let missing_resource = get_spigot_resource_by_id(&context.pool, 0).await?;
+
+        // Assert
+        assert_that(&retrieved_resource).is_some().is_equal_to(&resources[1]);
+        assert_that(&missing_resource).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_get_deleted_spigot_resource_by_id() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_authors, resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        mark_unseen_spigot_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Act
+        let retrieved_resource = get_spigot_resource_by_id(&context.pool, resources[0].id).await?;
+
+        // Assert
+        assert_that(&retrieved_resource).is_none();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[named]
     async fn should_get_latest_spigot_resource_update_date() -> Result<()> {
@@ -314,6 +416,113 @@ pub mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[named]
+    async fn should_mark_unseen_spigot_resources_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_authors, resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_spigot_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(resources.len() as u64);
+
+        let retrieved_resources = get_spigot_resources(&context.pool).await?;
+        assert_that(&retrieved_resources).is_empty();
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_mark_seen_spigot_resources_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_authors, resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        // Act
+        let count = mark_unseen_spigot_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() - time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(0);
+
+        let retrieved_resources = get_spigot_resources(&context.pool).await?;
+        assert_that(&retrieved_resources).is_equal_to(&resources);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_not_mark_quarantined_spigot_resources_as_deleted() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_authors, resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+
+        let failure = IngestFailure {
+            repository: IngestLogRepository::Spigot,
+            item: IngestLogItem::Resource,
+            upstream_id: resources[0].id.to_string(),
+            error_kind: "Database".to_string(),
+            message: "Database query failed".to_string(),
+            payload: "{}".to_string(),
+            date_failed: OffsetDateTime::now_utc()
+        };
+        upsert_ingest_failure(&context.pool, &failure).await?;
+
+        // Act
+        let count = mark_unseen_spigot_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Assert
+        assert_that(&count).is_equal_to(resources.len() as u64 - 1);
+
+        let retrieved_resources = get_spigot_resources(&context.pool).await?;
+        assert_that(&retrieved_resources).is_equal_to(vec![resources[0].clone()]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[named]
+    async fn should_restore_deleted_spigot_resource_when_seen_again() -> Result<()> {
+        // Setup
+        let context = DatabaseTestContext::new(function_name!()).await;
+
+        // Arrange
+        let (_authors, resources) = populate_test_spigot_authors_and_resources(&context.pool).await?;
+        mark_unseen_spigot_resources_as_deleted(&context.pool, OffsetDateTime::now_utc() + time::Duration::hours(1)).await?;
+
+        // Act
+        upsert_spigot_resource(&context.pool, &resources[0]).await?;
+
+        // Assert
+        let retrieved_resources = get_spigot_resources(&context.pool).await?;
+        assert_that(&retrieved_resources).has_length(1);
+        assert_that(&retrieved_resources[0]).is_equal_to(&resources[0]);
+
+        // Teardown
+        context.drop().await?;
+
+        Ok(())
+    }
+
     pub async fn populate_test_spigot_author_and_resource(db_pool: &Pool) -> Result<(SpigotAuthor, SpigotResource)> {
         let author = populate_test_spigot_author(db_pool).await?;
 
@@ -358,7 +567,11 @@ pub mod test {
                     owner: "alice".to_string(),
                     name: "foo".to_string(),
                     id: None
-                })
+                }),
+                platforms: vec![ServerPlatform::Spigot],
+                categories: vec![ProjectCategory::Chat],
+                minecraft_versions: vec!["1.20".to_string(), "1.21".to_string()],
+                full_description: Some("<p>foo-spigot-full-description</p>".to_string())
             },
             SpigotResource {
                 id: 2,
@@ -384,7 +597,11 @@ pub mod test {
                     owner: "bob".to_string(),
                     name: "bar".to_string(),
                     id: None
-                })
+                }),
+                platforms: vec![ServerPlatform::BungeeCord],
+                categories: vec![ProjectCategory::Utility],
+                minecraft_versions: vec!["1.7".to_string(), "1.8".to_string()],
+                full_description: Some("<p>bar-spigot-full-description</p>".to_string())
             },
             SpigotResource {
                 id: 3,
@@ -410,7 +627,11 @@ pub mod test {
                     owner: "eve".to_string(),
                     name: "baz".to_string(),
                     id: None
-                })
+                }),
+                platforms: vec![ServerPlatform::Spigot, ServerPlatform::BungeeCord],
+                categories: vec![ProjectCategory::Economy],
+                minecraft_versions: vec!["1.12".to_string(), "1.16".to_string()],
+                full_description: Some("<p>baz-spigot-full-description</p>".to_string())
             }
         ]
     }
@@ -1,2 +1,3 @@
 pub mod config;
-pub mod database;
\ No newline at end of file
+pub mod database;
+pub mod minecraft_version;
@@ -0,0 +1,397 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// The first weekly snapshot of each release cycle, used to determine which release a weekly snapshot (e.g. "24w46a") leads up to.
+/// Snapshots published after the last entry are assumed to lead up to that entry's release.
+///
+/// An entry is added by hand whenever a weekly snapshot starts a new release cycle, taken from the version history on the Minecraft Wiki.
+/// Weekly snapshots were replaced by year-based snapshots such as "26.1-snapshot-1" after 1.21.11, and those name their release directly,
+/// so this table is only expected to change if an existing entry turns out to be wrong.
+const WEEKLY_SNAPSHOT_RELEASES: [(u32, u32, ReleaseNumber); 30] = [
+    (13, 36, ReleaseNumber::new(1, 7, 0)),
+    (14, 2, ReleaseNumber::new(1, 8, 0)),
+    (15, 31, ReleaseNumber::new(1, 9, 0)),
+    (16, 20, ReleaseNumber::new(1, 10, 0)),
+    (16, 32, ReleaseNumber::new(1, 11, 0)),
+    (17, 6, ReleaseNumber::new(1, 12, 0)),
+    (17, 43, ReleaseNumber::new(1, 13, 0)),
+    (18, 30, ReleaseNumber::new(1, 13, 1)),
+    (18, 43, ReleaseNumber::new(1, 14, 0)),
+    (19, 34, ReleaseNumber::new(1, 15, 0)),
+    (20, 6, ReleaseNumber::new(1, 16, 0)),
+    (20, 27, ReleaseNumber::new(1, 16, 2)),
+    (20, 45, ReleaseNumber::new(1, 17, 0)),
+    (21, 37, ReleaseNumber::new(1, 18, 0)),
+    (22, 3, ReleaseNumber::new(1, 18, 2)),
+    (22, 11, ReleaseNumber::new(1, 19, 0)),
+    (22, 24, ReleaseNumber::new(1, 19, 1)),
+    (22, 42, ReleaseNumber::new(1, 19, 3)),
+    (23, 3, ReleaseNumber::new(1, 19, 4)),
+    (23, 12, ReleaseNumber::new(1, 20, 0)),
+    (23, 31, ReleaseNumber::new(1, 20, 2)),
+    (23, 40, ReleaseNumber::new(1, 20, 3)),
+    (23, 51, ReleaseNumber::new(1, 20, 5)),
+    (24, 18, ReleaseNumber::new(1, 21, 0)),
+    (24, 33, ReleaseNumber::new(1, 21, 2)),
+    (24, 44, ReleaseNumber::new(1, 21, 4)),
+    (25, 2, ReleaseNumber::new(1, 21, 5)),
+    (25, 15, ReleaseNumber::new(1, 21, 6)),
+    (25, 31, ReleaseNumber::new(1, 21, 9)),
+    (25, 41, ReleaseNumber::new(1, 21, 11))
+];
+
+/// The numeric part of a release, e.g. "1.21.4" or the year-based "26.1".
+/// A missing patch number is treated as zero, so "1.21" and "1.21.0" are equal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ReleaseNumber {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32
+}
+
+impl ReleaseNumber {
+    const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        ReleaseNumber { major, minor, patch }
+    }
+}
+
+impl FromStr for ReleaseNumber {
+    type Err = MinecraftVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('.').collect();
+
+        if !(2..=3).contains(&parts.len()) {
+            return Err(MinecraftVersionError::Invalid(s.to_string()));
+        }
+
+        let numbers = parts.iter()
+            .map(|part| parse_number(part).ok_or_else(|| MinecraftVersionError::Invalid(s.to_string())))
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        Ok(ReleaseNumber::new(numbers[0], numbers[1], numbers.get(2).copied().unwrap_or(0)))
+    }
+}
+
+/// A development version that leads up to a release.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SnapshotNumber {
+    /// A weekly snapshot such as "24w46a".
+    Weekly { year: u32, week: u32, letter: char },
+    /// A snapshot under the year-based scheme such as "26.1-snapshot-1".
+    Numbered(u32)
+}
+
+/// The stage of a version within a release cycle, ordered from earliest to latest.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MinecraftVersionStage {
+    Snapshot(SnapshotNumber),
+    PreRelease(u32),
+    ReleaseCandidate(u32),
+    Release
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum MinecraftVersionError {
+    #[error("Invalid Minecraft version: '{0}'")]
+    Invalid(String)
+}
+
+/// A Minecraft version, as listed by the upstream repositories.
+///
+/// Supports releases ("1.21.4", "26.1"), weekly snapshots ("24w46a"), year-based snapshots ("26.1-snapshot-1"),
+/// pre-releases ("1.21.4-pre3", "26.1-pre-1") and release candidates ("1.21.4-rc3", "26.1-rc-1").
+///
+/// Versions are ordered by the release they belong to, followed by their stage, so "24w46a" < "1.21.4-pre1" < "1.21.4-rc1" < "1.21.4".
+#[derive(Clone, Debug)]
+pub struct MinecraftVersion {
+    pub release: ReleaseNumber,
+    pub stage: MinecraftVersionStage,
+    text: String
+}
+
+impl MinecraftVersion {
+    /// The major version family, e.g. "1.21" for "1.21.4" or "24w46a". Spigot only lists versions at this level.
+    pub fn family(&self) -> String {
+        format!("{}.{}", self.release.major, self.release.minor)
+    }
+
+    /// Every way of writing this version that parses to an equal version, such as "1.21" and "1.21.0".
+    /// Upstream versions are stored as listed, so a version filter has to match any of these.
+    pub fn spellings(&self) -> Vec<String> {
+        let ReleaseNumber { major, minor, patch } = self.release;
+
+        let releases = if patch == 0 {
+            vec![format!("{}.{}", major, minor), format!("{}.{}.0", major, minor)]
+        } else {
+            vec![format!("{}.{}.{}", major, minor, patch)]
+        };
+
+        match self.stage {
+            MinecraftVersionStage::Snapshot(SnapshotNumber::Weekly { .. }) => vec![self.text.clone()],
+            MinecraftVersionStage::Snapshot(SnapshotNumber::Numbered(number)) => with_stage_suffix(&releases, "snapshot", number),
+            MinecraftVersionStage::PreRelease(number) => with_stage_suffix(&releases, "pre", number),
+            MinecraftVersionStage::ReleaseCandidate(number) => with_stage_suffix(&releases, "rc", number),
+            MinecraftVersionStage::Release => releases
+        }
+    }
+
+    /// An array that sorts in the same order as this version when compared element by element, for use in the database.
+    pub fn sort_key(&self) -> Vec<i32> {
+        let (stage, first, second) = match self.stage {
+            MinecraftVersionStage::Snapshot(SnapshotNumber::Weekly { year, week, letter }) => (0, year * 100 + week, letter as u32),
+            MinecraftVersionStage::Snapshot(SnapshotNumber::Numbered(number)) => (0, number, 0),
+            MinecraftVersionStage::PreRelease(number) => (1, number, 0),
+            MinecraftVersionStage::ReleaseCandidate(number) => (2, number, 0),
+            MinecraftVersionStage::Release => (3, 0, 0)
+        };
+
+        [self.release.major, self.release.minor, self.release.patch, stage, first, second]
+            .into_iter()
+            .map(|x| i32::try_from(x).unwrap_or(i32::MAX))
+            .collect()
+    }
+}
+
+impl FromStr for MinecraftVersion {
+    type Err = MinecraftVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let invalid = || MinecraftVersionError::Invalid(s.to_string());
+
+        let (release, stage) = if let Some(snapshot) = parse_weekly_snapshot(text) {
+            (weekly_snapshot_release(&snapshot), MinecraftVersionStage::Snapshot(snapshot))
+        } else if let Some((release, suffix)) = text.split_once('-') {
+            let release = release.parse::<ReleaseNumber>()?;
+
+            let stage = if let Some(number) = strip_stage_prefix(suffix, "snapshot") {
+                MinecraftVersionStage::Snapshot(SnapshotNumber::Numbered(number))
+            } else if let Some(number) = strip_stage_prefix(suffix, "pre") {
+                MinecraftVersionStage::PreRelease(number)
+            } else if let Some(number) = strip_stage_prefix(suffix, "rc") {
+                MinecraftVersionStage::ReleaseCandidate(number)
+            } else {
+                return Err(invalid());
+            };
+
+            (release, stage)
+        } else {
+            (text.parse::<ReleaseNumber>()?, MinecraftVersionStage::Release)
+        };
+
+        Ok(MinecraftVersion {
+            release,
+            stage,
+            text: text.to_string()
+        })
+    }
+}
+
+impl fmt::Display for MinecraftVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl PartialEq for MinecraftVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MinecraftVersion {}
+
+impl PartialOrd for MinecraftVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MinecraftVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.release, self.stage).cmp(&(other.release, other.stage))
+    }
+}
+
+/// Returns the latest of the given versions, ignoring any that cannot be parsed.
+pub fn find_latest_minecraft_version<S: AsRef<str>>(versions: &[S]) -> Option<String> {
+    versions.iter()
+        .filter_map(|x| x.as_ref().parse::<MinecraftVersion>().ok())
+        .max()
+        .map(|x| x.to_string())
+}
+
+/// Returns the database sort key of the given version, or None if it cannot be parsed.
+pub fn get_minecraft_version_sort_key(version: Option<&str>) -> Option<Vec<i32>> {
+    version
+        .and_then(|x| x.parse::<MinecraftVersion>().ok())
+        .map(|x| x.sort_key())
+}
+
+fn parse_number(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+fn parse_weekly_snapshot(s: &str) -> Option<SnapshotNumber> {
+    let (year, rest) = s.split_once('w')?;
+    let letter = rest.chars().last()?;
+
+    if year.len() != 2 || rest.len() != 3 || !letter.is_ascii_lowercase() {
+        return None;
+    }
+
+    let year = parse_number(year)?;
+    let week = parse_number(&rest[..2])?;
+
+    Some(SnapshotNumber::Weekly { year, week, letter })
+}
+
+fn weekly_snapshot_release(snapshot: &SnapshotNumber) -> ReleaseNumber {
+    let SnapshotNumber::Weekly { year, week, .. } = *snapshot else {
+        unreachable!("only weekly snapshots need to be looked up");
+    };
+
+    WEEKLY_SNAPSHOT_RELEASES.iter()
+        .rev()
+        .find(|(first_year, first_week, _)| (*first_year, *first_week) <= (year, week))
+        .map(|(_, _, release)| *release)
+        .unwrap_or(ReleaseNumber::new(0, 0, 0))
+}
+
+// Both the legacy "pre3" and the year-based "pre-3" formats are accepted when parsing, so both are written out.
+fn with_stage_suffix(releases: &[String], prefix: &str, number: u32) -> Vec<String> {
+    releases.iter()
+        .flat_map(|release| [format!("{}-{}{}", release, prefix, number), format!("{}-{}-{}", release, prefix, number)])
+        .collect()
+}
+
+// Accepts both the legacy "pre3" and the year-based "pre-3" formats.
+fn strip_stage_prefix(suffix: &str, prefix: &str) -> Option<u32> {
+    let number = suffix.strip_prefix(prefix)?;
+    parse_number(number.strip_prefix('-').unwrap_or(number))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rstest::*;
+    use speculoos::prelude::*;
+
+    #[rstest]
+    #[case::release("1.21.4", ReleaseNumber::new(1, 21, 4), MinecraftVersionStage::Release)]
+    #[case::release_without_patch("1.21", ReleaseNumber::new(1, 21, 0), MinecraftVersionStage::Release)]
+    #[case::release_with_double_digit_patch("1.21.10", ReleaseNumber::new(1, 21, 10), MinecraftVersionStage::Release)]
+    #[case::year_based_release("26.1", ReleaseNumber::new(26, 1, 0), MinecraftVersionStage::Release)]
+    #[case::pre_release("1.21.4-pre3", ReleaseNumber::new(1, 21, 4), MinecraftVersionStage::PreRelease(3))]
+    #[case::year_based_pre_release("26.1-pre-2", ReleaseNumber::new(26, 1, 0), MinecraftVersionStage::PreRelease(2))]
+    #[case::release_candidate("1.21.4-rc1", ReleaseNumber::new(1, 21, 4), MinecraftVersionStage::ReleaseCandidate(1))]
+    #[case::year_based_release_candidate("26.1-rc-1", ReleaseNumber::new(26, 1, 0), MinecraftVersionStage::ReleaseCandidate(1))]
+    #[case::weekly_snapshot("24w46a", ReleaseNumber::new(1, 21, 4), MinecraftVersionStage::Snapshot(SnapshotNumber::Weekly { year: 24, week: 46, letter: 'a' }))]
+    #[case::year_based_snapshot("26.1-snapshot-5", ReleaseNumber::new(26, 1, 0), MinecraftVersionStage::Snapshot(SnapshotNumber::Numbered(5)))]
+    fn should_parse_minecraft_version(#[case] input: &str, #[case] expected_release: ReleaseNumber, #[case] expected_stage: MinecraftVersionStage) {
+        // Act
+        let version = input.parse::<MinecraftVersion>();
+
+        // Assert
+        assert_that(&version).is_ok();
+        let version = version.unwrap();
+        assert_that(&version.release).is_equal_to(expected_release);
+        assert_that(&version.stage).is_equal_to(expected_stage);
+        assert_that(&version.to_string()).is_equal_to(input.to_string());
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::single_number("1")]
+    #[case::too_many_numbers("1.21.4.1")]
+    #[case::beta("b1.7.3")]
+    #[case::unknown_suffix("1.21.4-beta1")]
+    #[case::wildcard("1.21.x")]
+    #[case::invalid_snapshot("24w4a")]
+    fn should_not_parse_invalid_minecraft_version(#[case] input: &str) {
+        // Act
+        let version = input.parse::<MinecraftVersion>();
+
+        // Assert
+        assert_that(&version).is_err();
+    }
+
+    #[rstest]
+    #[case::patch_versions("1.21.3", "1.21.4")]
+    #[case::double_digit_minor_versions("1.9", "1.10")]
+    #[case::double_digit_patch_versions("1.21.9", "1.21.10")]
+    #[case::year_based_after_legacy("1.21.11", "26.1")]
+    #[case::snapshot_after_previous_release("1.21.3", "24w46a")]
+    #[case::snapshot_before_pre_release("24w46a", "1.21.4-pre1")]
+    #[case::weekly_snapshots("24w44a", "24w45a")]
+    #[case::weekly_snapshot_letters("23w51a", "23w51b")]
+    #[case::year_based_snapshots("26.1-snapshot-1", "26.1-snapshot-2")]
+    #[case::pre_releases("1.21.4-pre2", "1.21.4-pre10")]
+    #[case::pre_release_before_release_candidate("1.21.4-pre3", "1.21.4-rc1")]
+    #[case::release_candidate_before_release("1.21.4-rc3", "1.21.4")]
+    #[case::release_before_next_snapshot("1.21.4", "25w02a")]
+    fn should_order_minecraft_versions(#[case] lower: &str, #[case] higher: &str) {
+        // Arrange
+        let lower = lower.parse::<MinecraftVersion>().unwrap();
+        let higher = higher.parse::<MinecraftVersion>().unwrap();
+
+        // Assert
+        assert_that(&lower).is_less_than(&higher);
+        assert_that(&lower.sort_key()).is_less_than(higher.sort_key());
+    }
+
+    #[test]
+    fn should_treat_missing_patch_version_as_zero() {
+        // Arrange
+        let without_patch = "1.21".parse::<MinecraftVersion>().unwrap();
+        let with_patch = "1.21.0".parse::<MinecraftVersion>().unwrap();
+
+        // Assert
+        assert_that(&without_patch).is_equal_to(&with_patch);
+        assert_that(&without_patch.sort_key()).is_equal_to(with_patch.sort_key());
+    }
+
+    #[rstest]
+    #[case::release("1.21.4", vec!["1.21.4"])]
+    #[case::release_without_patch("1.21", vec!["1.21", "1.21.0"])]
+    #[case::release_with_zero_patch("1.21.0", vec!["1.21", "1.21.0"])]
+    #[case::pre_release("1.21.4-pre3", vec!["1.21.4-pre3", "1.21.4-pre-3"])]
+    #[case::year_based_release_candidate("26.1-rc-1", vec!["26.1-rc1", "26.1-rc-1", "26.1.0-rc1", "26.1.0-rc-1"])]
+    #[case::weekly_snapshot("24w46a", vec!["24w46a"])]
+    fn should_list_minecraft_version_spellings(#[case] input: &str, #[case] expected: Vec<&str>) {
+        // Arrange
+        let version = input.parse::<MinecraftVersion>().unwrap();
+
+        // Act
+        let spellings = version.spellings();
+
+        // Assert
+        assert_that(&spellings).is_equal_to(expected.iter().map(|x| x.to_string()).collect::<Vec<String>>());
+        for spelling in spellings {
+            assert_that(&spelling.parse::<MinecraftVersion>().unwrap()).is_equal_to(&version);
+        }
+    }
+
+    #[rstest]
+    #[case::releases(vec!["1.21.10", "1.21.9", "1.8"], Some("1.21.10"))]
+    #[case::with_snapshot(vec!["1.21.3", "24w46a"], Some("24w46a"))]
+    #[case::with_invalid_versions(vec!["b1.7.3", "1.20.6", "Legacy"], Some("1.20.6"))]
+    #[case::only_invalid_versions(vec!["b1.7.3"], None)]
+    #[case::empty(vec![], None)]
+    fn should_find_latest_minecraft_version(#[case] versions: Vec<&str>, #[case] expected: Option<&str>) {
+        // Act
+        let latest = find_latest_minecraft_version(&versions);
+
+        // Assert
+        assert_that(&latest).is_equal_to(expected.map(|x| x.to_string()));
+    }
+}
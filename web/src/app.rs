@@ -6,8 +6,8 @@ use leptos::either::Either;
 use leptos_meta::{provide_meta_context, Meta, MetaTags, Script, Stylesheet, Title};
 use leptos_router::components::{Form,Router, Routes, Route};
 use leptos_router::params::{Params, ParamsError};
-use leptos_router::hooks::use_query;
-use leptos_router::StaticSegment;
+use leptos_router::hooks::{use_params, use_query};
+use leptos_router::{ParamSegment, StaticSegment};
 
 use serde::{Serialize, Deserialize};
 use time::format_description::BorrowedFormatItem;
@@ -15,14 +15,17 @@ use time::OffsetDateTime;
 use time::macros::format_description;
 
 #[cfg(feature = "ssr")]
-use mc_plugin_finder::database::common::search_result::{SearchParams, SearchParamsSort, SearchResult, SearchResultSpigot, SearchResultModrinth, SearchResultHangar};
-
-// For Modrinth and Hangar project icons, attempt to retrieve a cached version from the image proxy first.
-// This reduces unnecessary load on the Modrinth and Hangar CDNs.
+use mc_plugin_finder::database::common::search_result::{SearchParams, SearchParamsSort, SearchWeights, SearchResult, SearchResultSpigot, SearchResultModrinth, SearchResultHangar, SearchResultCurseForge, SearchResultPolymart, SearchResultBuiltByBit};
+#[cfg(feature = "ssr")]
+use mc_plugin_finder::database::project_category::ProjectCategory;
+#[cfg(feature = "ssr")]
+use mc_plugin_finder::database::project_description::ProjectDescription;
+#[cfg(feature = "ssr")]
+use mc_plugin_finder::database::server_platform::ServerPlatform;
 
-// Set this to false to retrieve icons directly from the Modrinth and Hangar CDNs.
-const USE_IMAGEPROXY: bool = true;
-const IMAGEPROXY_URL_PREFIX: &str = "https://img.mcpluginfinder.com/75,fit";
+// Project icons are served from the icon cache populated by the ingest tool.
+// Icons that have not been cached yet are retrieved directly from the Modrinth, Hangar, CurseForge, and Polymart CDNs.
+const ICONS_URL_PREFIX: &str = "/icons";
 
 const NO_ICON_IMAGE_URL: &str = "images/no-icon.svg";
 const ABANDONED_IMAGE_URL: &str = "images/abandoned.svg";
@@ -39,9 +42,17 @@ pub struct WebSearchParams {
     pub spigot: Option<bool>,
     pub modrinth: Option<bool>,
     pub hangar: Option<bool>,
+    pub curseforge: Option<bool>,
+    pub polymart: Option<bool>,
+    pub builtbybit: Option<bool>,
     pub name: Option<bool>,
     pub description: Option<bool>,
+    pub full_description: Option<bool>,
     pub author: Option<bool>,
+    pub platform: Option<String>,
+    pub category: Option<String>,
+    pub minecraft_version: Option<String>,
+    pub exclude_archived: Option<bool>,
     pub sort: Option<String>,
     pub limit: Option<u32>,
     pub page: Option<u32>
@@ -69,7 +80,7 @@ impl WebSearchParams {
     fn form_url(&self) -> String {
         "?".to_string() +
         &serde_urlencoded::to_string(self).unwrap_or(
-            "query=&spigot=true&modrinth=true&hangar=true&name=true&sort=relevance&limit=25&page=1".to_string())
+            "query=&spigot=true&modrinth=true&hangar=true&curseforge=true&polymart=true&builtbybit=true&name=true&sort=relevance&limit=25&page=1".to_string())
     }
 
     fn first_url(&self) -> String {
@@ -104,9 +115,17 @@ impl Default for WebSearchParams {
             spigot: Some(false),
             modrinth: Some(false),
             hangar: Some(false),
+            curseforge: Some(false),
+            polymart: Some(false),
+            builtbybit: Some(false),
             name: Some(false),
             description: Some(false),
+            full_description: Some(false),
             author: Some(false),
+            platform: Some("".to_string()),
+            category: Some("".to_string()),
+            minecraft_version: Some("".to_string()),
+            exclude_archived: Some(false),
             sort: Some("relevance".to_string()),
             limit: Some(25),
             page: Some(1)
@@ -125,10 +144,19 @@ impl From<WebSearchParams> for SearchParams {
             spigot: params.spigot.unwrap_or_default(),
             modrinth: params.modrinth.unwrap_or_default(),
             hangar: params.hangar.unwrap_or_default(),
+            curseforge: params.curseforge.unwrap_or_default(),
+            polymart: params.polymart.unwrap_or_default(),
+            builtbybit: params.builtbybit.unwrap_or_default(),
             name: params.name.unwrap_or_default(),
             description: params.description.unwrap_or_default(),
+            full_description: params.full_description.unwrap_or_default(),
             author: params.author.unwrap_or_default(),
+            platforms: ServerPlatform::from_str(&params.platform.unwrap_or_default()).into_iter().collect(),
+            categories: ProjectCategory::from_str(&params.category.unwrap_or_default()).into_iter().collect(),
+            minecraft_version: params.minecraft_version.map(|version| version.trim().to_string()).filter(|version| !version.is_empty()),
+            exclude_archived: params.exclude_archived.unwrap_or_default(),
             sort: SearchParamsSort::from_str(&params.sort.unwrap_or_default()).unwrap_or_default(),
+            weights: SearchWeights::default(),
             limit: params.limit.unwrap_or(25).into(),
             offset: offset.unwrap_or_default().into()
         }
@@ -154,7 +182,12 @@ pub struct WebSearchResult {
     pub spigot: Option<WebSearchResultSpigot>,
     pub modrinth: Option<WebSearchResultModrinth>,
     pub hangar: Option<WebSearchResultHangar>,
-    pub source_repository: Option<WebSearchResultSourceRepository>
+    pub curseforge: Option<WebSearchResultCurseForge>,
+    pub polymart: Option<WebSearchResultPolymart>,
+    pub builtbybit: Option<WebSearchResultBuiltByBit>,
+    pub source_repository: Option<WebSearchResultSourceRepository>,
+    pub source_repository_release: Option<WebSearchResultSourceRepositoryRelease>,
+    pub source_repository_metadata: Option<WebSearchResultSourceRepositoryMetadata>
 }
 
 impl WebSearchResult {
@@ -182,6 +215,18 @@ impl WebSearchResult {
         if let Some(hangar) = &self.hangar {
             return Some(hangar.name.clone())
         }
+
+        if let Some(curseforge) = &self.curseforge {
+            return Some(curseforge.name.clone())
+        }
+
+        if let Some(polymart) = &self.polymart {
+            return Some(polymart.name.clone())
+        }
+
+        if let Some(builtbybit) = &self.builtbybit {
+            return Some(builtbybit.name.clone())
+        }
         None
     }
 }
@@ -189,10 +234,29 @@ impl WebSearchResult {
 #[cfg(feature = "ssr")]
 impl From<SearchResult> for WebSearchResult {
     fn from(search_result: SearchResult) -> Self {
+        let newer = search_result.has_newer_source_repository_release();
+
         let spigot = search_result.spigot.map(|s| s.into());
         let modrinth = search_result.modrinth.map(|m| m.into());
         let hangar = search_result.hangar.map(|h| h.into());
+        let curseforge = search_result.curseforge.map(|c| c.into());
+        let polymart = search_result.polymart.map(|p| p.into());
+        let builtbybit = search_result.builtbybit.map(|b| b.into());
         let source_repository = search_result.source_repository.map(|r| r.into());
+        let source_repository_release = search_result.source_repository_release.map(|r| WebSearchResultSourceRepositoryRelease {
+            tag_name: r.tag_name,
+            name: r.name,
+            url: r.url,
+            date_published: r.date_published,
+            newer
+        });
+        let source_repository_metadata = search_result.source_repository_metadata.map(|m| WebSearchResultSourceRepositoryMetadata {
+            stars: m.stars,
+            archived: m.archived,
+            license: m.license,
+            open_issues: m.open_issues,
+            date_last_commit: m.date_last_commit
+        });
 
         WebSearchResult {
             full_count: search_result.full_count,
@@ -207,7 +271,12 @@ impl From<SearchResult> for WebSearchResult {
             spigot,
             modrinth,
             hangar,
-            source_repository
+            curseforge,
+            polymart,
+            builtbybit,
+            source_repository,
+            source_repository_release,
+            source_repository_metadata
         }
     }
 }
@@ -222,7 +291,7 @@ pub struct WebSearchResultSpigot {
     pub version: Option<String>,
     pub premium: bool,
     pub abandoned: bool,
-    pub icon_data: Option<String>
+    pub icon_hash: Option<String>
 }
 
 impl WebSearchResultSpigot {
@@ -230,14 +299,13 @@ impl WebSearchResultSpigot {
         Some(format!("https://spigotmc.org/resources/{}", self.slug))
     }
 
-    fn icon_img_url(&self) -> String {
-        let data = self.icon_data.clone();
-
-        if data.as_ref().is_none_or(String::is_empty) {
-            return NO_ICON_IMAGE_URL.to_string();
-        }
+    fn details_url(&self) -> String {
+        format!("/project/spigot/{}", self.id)
+    }
 
-        format!("data:image/png;base64,{}", data.unwrap())
+    // Spigot icons are only available from the icon cache.
+    fn icon_img_url(&self) -> String {
+        project_icon_img_url(&self.icon_hash, None)
     }
 
     fn icon_alt_text(&self) -> Option<String> {
@@ -257,7 +325,7 @@ impl From<SearchResultSpigot> for WebSearchResultSpigot {
             version: s.version,
             premium: s.premium,
             abandoned: s.abandoned,
-            icon_data: s.icon_data,
+            icon_hash: s.icon_hash,
         }
     }
 }
@@ -271,7 +339,8 @@ pub struct WebSearchResultModrinth {
     pub author: String,
     pub version: Option<String>,
     pub status: String,
-    pub icon_url: Option<String>
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>
 }
 
 impl WebSearchResultModrinth {
@@ -279,18 +348,12 @@ impl WebSearchResultModrinth {
         Some(format!("https://modrinth.com/plugin/{}", self.slug))
     }
 
-    fn icon_img_url(&self) -> String {
-        let url = self.icon_url.clone();
-
-        if url.as_ref().is_none_or(String::is_empty) {
-            return NO_ICON_IMAGE_URL.to_string();
-        }
-
-        if USE_IMAGEPROXY {
-            return format!("{}/{}", IMAGEPROXY_URL_PREFIX, url.unwrap());
-        }
+    fn details_url(&self) -> String {
+        format!("/project/modrinth/{}", self.id)
+    }
 
-        url.unwrap()
+    fn icon_img_url(&self) -> String {
+        project_icon_img_url(&self.icon_hash, self.icon_url.as_deref())
     }
 
     fn icon_alt_text(&self) -> Option<String> {
@@ -314,6 +377,7 @@ impl From<SearchResultModrinth> for WebSearchResultModrinth {
             version: m.version,
             status: m.status,
             icon_url: m.icon_url,
+            icon_hash: m.icon_hash,
         }
     }
 }
@@ -325,7 +389,8 @@ pub struct WebSearchResultHangar {
     pub description: String,
     pub author: String,
     pub version: Option<String>,
-    pub icon_url: String
+    pub icon_url: String,
+    pub icon_hash: Option<String>
 }
 
 impl WebSearchResultHangar {
@@ -333,18 +398,12 @@ impl WebSearchResultHangar {
         Some(format!("https://hangar.papermc.io/{}/{}", self.author, self.slug))
     }
 
-    fn icon_img_url(&self) -> String {
-        let url = self.icon_url.clone();
-
-        if url.is_empty() {
-            return NO_ICON_IMAGE_URL.to_string();
-        }
-
-        if USE_IMAGEPROXY {
-            return format!("{}/{}", IMAGEPROXY_URL_PREFIX, url);
-        }
+    fn details_url(&self) -> String {
+        format!("/project/hangar/{}", self.slug)
+    }
 
-        url
+    fn icon_img_url(&self) -> String {
+        project_icon_img_url(&self.icon_hash, Some(&self.icon_url))
     }
 
     fn icon_alt_text(&self) -> Option<String> {
@@ -362,6 +421,143 @@ impl From<SearchResultHangar> for WebSearchResultHangar {
             author: h.author,
             version: h.version,
             icon_url: h.icon_url,
+            icon_hash: h.icon_hash,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebSearchResultCurseForge {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>
+}
+
+impl WebSearchResultCurseForge {
+    fn url(&self) -> Option<String> {
+        Some(format!("https://www.curseforge.com/minecraft/bukkit-plugins/{}", self.slug))
+    }
+
+    fn icon_img_url(&self) -> String {
+        project_icon_img_url(&self.icon_hash, self.icon_url.as_deref())
+    }
+
+    fn icon_alt_text(&self) -> Option<String> {
+        alt_text(&Some(self.name.clone()), "CurseForge")
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<SearchResultCurseForge> for WebSearchResultCurseForge {
+    fn from(c: SearchResultCurseForge) -> Self {
+        WebSearchResultCurseForge {
+            id: c.id,
+            slug: c.slug,
+            name: c.name,
+            description: c.description,
+            author: c.author,
+            version: c.version,
+            icon_url: c.icon_url,
+            icon_hash: c.icon_hash,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebSearchResultPolymart {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub premium: bool,
+    pub icon_url: Option<String>,
+    pub icon_hash: Option<String>
+}
+
+impl WebSearchResultPolymart {
+    fn url(&self) -> Option<String> {
+        Some(format!("https://polymart.org/resource/{}", self.id))
+    }
+
+    fn icon_img_url(&self) -> String {
+        project_icon_img_url(&self.icon_hash, self.icon_url.as_deref())
+    }
+
+    fn icon_alt_text(&self) -> Option<String> {
+        alt_text(&Some(self.name.clone()), "Polymart")
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<SearchResultPolymart> for WebSearchResultPolymart {
+    fn from(p: SearchResultPolymart) -> Self {
+        WebSearchResultPolymart {
+            id: p.id,
+            name: p.name,
+            description: p.description,
+            author: p.author,
+            version: p.version,
+            premium: p.premium,
+            icon_url: p.icon_url,
+            icon_hash: p.icon_hash,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebSearchResultBuiltByBit {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: Option<String>,
+    pub price: f64,
+    pub currency: Option<String>
+}
+
+impl WebSearchResultBuiltByBit {
+    fn url(&self) -> Option<String> {
+        Some(format!("https://builtbybit.com/resources/{}/", self.id))
+    }
+
+    // The BuiltByBit API does not provide resource icons.
+    fn icon_img_url(&self) -> String {
+        NO_ICON_IMAGE_URL.to_string()
+    }
+
+    fn icon_alt_text(&self) -> Option<String> {
+        alt_text(&Some(self.name.clone()), "BuiltByBit")
+    }
+
+    fn is_premium(&self) -> bool {
+        self.price > 0.0
+    }
+
+    fn price_text(&self) -> String {
+        match &self.currency {
+            Some(currency) => format!("{:.2} {}", self.price, currency),
+            None => format!("{:.2}", self.price)
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<SearchResultBuiltByBit> for WebSearchResultBuiltByBit {
+    fn from(b: SearchResultBuiltByBit) -> Self {
+        WebSearchResultBuiltByBit {
+            id: b.id,
+            name: b.name,
+            description: b.description,
+            author: b.author,
+            version: b.version,
+            price: b.price,
+            currency: b.currency,
         }
     }
 }
@@ -424,14 +620,94 @@ impl From<mc_plugin_finder::database::source_repository::SourceRepository> for W
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebSearchResultSourceRepositoryRelease {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub date_published: OffsetDateTime,
+    /// True if this release was published after every upload to the selected repositories
+    pub newer: bool
+}
+
+impl WebSearchResultSourceRepositoryRelease {
+    fn title(&self) -> String {
+        let name = self.name.clone().unwrap_or(self.tag_name.clone());
+
+        if self.newer {
+            format!("Latest release: {} (newer than every upload on the selected repositories)", name)
+        } else {
+            format!("Latest release: {}", name)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebSearchResultSourceRepositoryMetadata {
+    pub stars: i32,
+    pub archived: bool,
+    pub license: Option<String>,
+    pub open_issues: Option<i32>,
+    pub date_last_commit: Option<OffsetDateTime>
+}
+
+impl WebSearchResultSourceRepositoryMetadata {
+    fn stars_formatted(&self) -> String {
+        format_number(&self.stars)
+    }
+
+    fn title(&self) -> String {
+        let mut title = format!("Stars: {}", self.stars_formatted());
+
+        if let Some(open_issues) = &self.open_issues {
+            title += &format!("\nOpen issues: {}", format_number(open_issues));
+        }
+
+        if let Some(date_last_commit) = &self.date_last_commit {
+            if let Ok(date) = date_last_commit.format(&SEARCH_RESULT_DATE_FORMAT_DESCRIPTION) {
+                title += &format!("\nLast commit: {}", date);
+            }
+        }
+
+        title
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Params)]
+pub struct ProjectPageParams {
+    pub repository: Option<String>,
+    pub id: Option<String>
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebProjectDescription {
+    pub name: String,
+    pub description: String,
+    /// Sanitized HTML, which was cleaned by the ingest tool before being saved
+    pub full_description: Option<String>
+}
+
+#[cfg(feature = "ssr")]
+impl From<ProjectDescription> for WebProjectDescription {
+    fn from(d: ProjectDescription) -> Self {
+        WebProjectDescription {
+            name: d.name,
+            description: d.description,
+            full_description: d.full_description
+        }
+    }
+}
+
 #[cfg(feature = "ssr")]
 pub mod ssr {
     use deadpool_postgres::Pool;
     use leptos::context::use_context;
+    use mc_plugin_finder::database::common::search_result::SearchWeights;
 
     #[derive(Clone)]
     pub struct WebContext {
-        pub db_pool: Pool
+        pub db_pool: Pool,
+        pub search_weights: SearchWeights
     }
 
     pub async fn context() -> Option<WebContext> {
@@ -501,7 +777,7 @@ pub fn App() -> impl IntoView {
         </div>
 
         // Description shown in search engine results
-        <Meta name="description" content="Find thousands of Minecraft server plugins with MC Plugin Finder, a search aggregator for plugins hosted on Spigot, Modrinth, and Hangar." />
+        <Meta name="description" content="Find thousands of Minecraft server plugins with MC Plugin Finder, a search aggregator for plugins hosted on Spigot, Modrinth, Hangar, CurseForge, Polymart, and BuiltByBit." />
 
         <Router>
             <main>
@@ -514,6 +790,7 @@ pub fn App() -> impl IntoView {
                     .into_view()
                 }>
                     <Route path=StaticSegment("") view=HomePage/>
+                    <Route path=(StaticSegment("project"), ParamSegment("repository"), ParamSegment("id")) view=ProjectPage/>
                 </Routes>
             </main>
         </Router>
@@ -526,7 +803,11 @@ pub async fn search_projects(params: WebSearchParams) -> Result<WebSearchRespons
     use mc_plugin_finder::database::common::search_result::search_projects;
 
     if let Some(context) = context().await {
-        let common_projects = search_projects(&context.db_pool, &params.into()).await;
+        let search_params = SearchParams {
+            weights: context.search_weights,
+            ..params.into()
+        };
+        let common_projects = search_projects(&context.db_pool, &search_params).await;
 
         match common_projects {
             Ok(projects) => {
@@ -569,6 +850,39 @@ pub async fn get_last_successful_ingest_date() -> Result<String, ServerFnError>
     }
 }
 
+#[server(GetProjectDescription)]
+pub async fn get_project_description(repository: String, id: String) -> Result<Option<WebProjectDescription>, ServerFnError> {
+    use self::ssr::*;
+    use mc_plugin_finder::database::ingest_log::IngestLogRepository;
+    use mc_plugin_finder::database::project_description::get_project_description;
+
+    // Full descriptions are only ingested for Spigot, Modrinth, and Hangar.
+    let repository = match repository.as_str() {
+        "spigot" => IngestLogRepository::Spigot,
+        "modrinth" => IngestLogRepository::Modrinth,
+        "hangar" => IngestLogRepository::Hangar,
+        _ => return Ok(None)
+    };
+
+    if let Some(context) = context().await {
+        let project_description = get_project_description(&context.db_pool, repository, &id).await;
+
+        match project_description {
+            Ok(description) => Ok(description.map(|x| x.into())),
+            Err(error) => Err(ServerFnError::ServerError(error.to_string()))
+        }
+    } else {
+        Err(ServerFnError::ServerError("web context not found".to_string()))
+    }
+}
+
+async fn fetch_project_description(params_result: Result<ProjectPageParams, ParamsError>) -> Result<Option<WebProjectDescription>, ServerFnError> {
+    match params_result {
+        Ok(params) => get_project_description(params.repository.unwrap_or_default(), params.id.unwrap_or_default()).await,
+        Err(error) => Err(ServerFnError::ServerError(error.to_string()))
+    }
+}
+
 #[component]
 fn HomePage() -> impl IntoView {
     let params_memo_original = use_query::<WebSearchParams>();
@@ -581,9 +895,17 @@ fn HomePage() -> impl IntoView {
                params.spigot.is_none() &&
                params.modrinth.is_none() &&
                params.hangar.is_none() &&
+               params.curseforge.is_none() &&
+               params.polymart.is_none() &&
+               params.builtbybit.is_none() &&
                params.name.is_none() &&
                params.description.is_none() &&
+               params.full_description.is_none() &&
                params.author.is_none() &&
+               params.platform.is_none() &&
+               params.category.is_none() &&
+               params.minecraft_version.is_none() &&
+               params.exclude_archived.is_none() &&
                params.sort.is_none() &&
                params.limit.is_none() &&
                params.page.is_none() {
@@ -592,9 +914,17 @@ fn HomePage() -> impl IntoView {
                     spigot: Some(true),
                     modrinth: Some(true),
                     hangar: Some(true),
+                    curseforge: Some(true),
+                    polymart: Some(true),
+                    builtbybit: Some(true),
                     name: Some(true),
                     description: None,
+                    full_description: None,
                     author: None,
+                    platform: None,
+                    category: None,
+                    minecraft_version: None,
+                    exclude_archived: None,
                     sort: Some("relevance".to_string()),
                     limit: Some(25),
                     page: Some(1)
@@ -627,8 +957,14 @@ fn HomePage() -> impl IntoView {
           <a href="https://www.spigotmc.org/resources" target="_blank">Spigot</a>
           <span>", "</span>
           <a href="https://modrinth.com/plugins" target="_blank">Modrinth</a>
-          <span>", and "</span>
+          <span>", "</span>
           <a href="https://hangar.papermc.io" target="_blank">Hangar</a>
+          <span>", "</span>
+          <a href="https://www.curseforge.com/minecraft/bukkit-plugins" target="_blank">CurseForge</a>
+          <span>", "</span>
+          <a href="https://polymart.org/plugins" target="_blank">Polymart</a>
+          <span>", and "</span>
+          <a href="https://builtbybit.com/resources/minecraft/plugins" target="_blank">BuiltByBit</a>
           <span>"."</span>
         </h2>
 
@@ -650,7 +986,50 @@ fn HomePage() -> impl IntoView {
                 <span>" | "</span>
                 <a href="https://github.com/Frumple/mc-plugin-finder" aria-label="View source on GitHub" target="_blank">GitHub</a>
             </div>
-            <div class="home-page__footer-disclaimer">"MC Plugin Finder is not an official Minecraft service, and is not approved or associated with Mojang, Microsoft, SpigotMC, Modrinth, or PaperMC."</div>
+            <div class="home-page__footer-disclaimer">"MC Plugin Finder is not an official Minecraft service, and is not approved or associated with Mojang, Microsoft, SpigotMC, Modrinth, PaperMC, CurseForge, Polymart, or BuiltByBit."</div>
+        </div>
+    }
+}
+
+/// Shows the full description of a single Spigot, Modrinth, or Hangar project.
+#[component]
+fn ProjectPage() -> impl IntoView {
+    let params_memo = use_params::<ProjectPageParams>();
+
+    let description_resource = Resource::new(
+        move || params_memo.get(),
+        fetch_project_description
+    );
+
+    view! {
+        <div class="project-page__container">
+            <a class="project-page__back-link" href="/">"← Back to search"</a>
+            <Transition fallback=move || view! { <div class="project-page__loading">"Loading..."</div> }>
+                {move || {
+                    description_resource.get()
+                        .map(move |response| match response {
+                            Err(e) => {
+                                Either::Left(view! {<pre class="error">"Server Error: " {e.to_string()}</pre>}.into_view())
+                            }
+                            Ok(None) => {
+                                Either::Right(Either::Left(view! { <div class="project-page__not-found">"This project was not found."</div> }.into_view()))
+                            }
+                            Ok(Some(project)) => {
+                                let full_description = match project.full_description {
+                                    Some(html) => Either::Left(view! { <div class="project-page__description" inner_html=html />}),
+                                    None => Either::Right(view! { <div class="project-page__no-description">"No full description available."</div> })
+                                };
+
+                                Either::Right(Either::Right(view! {
+                                    <Title text=format!("{} - MC Plugin Finder", project.name)/>
+                                    <h1 class="project-page__name">{project.name}</h1>
+                                    <p class="project-page__summary">{project.description}</p>
+                                    {full_description}
+                                }.into_view()))
+                            }
+                        })
+                }}
+            </Transition>
         </div>
     }
 }
@@ -678,6 +1057,15 @@ fn SearchForm(
             <input id="hangar-checkbox" type="checkbox" name="hangar" class="search-form__hangar-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().hangar />
             <label for="hangar-checkbox" class="search-form__hangar-label">Hangar</label>
 
+            <input id="curseforge-checkbox" type="checkbox" name="curseforge" class="search-form__curseforge-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().curseforge />
+            <label for="curseforge-checkbox" class="search-form__curseforge-label">CurseForge</label>
+
+            <input id="polymart-checkbox" type="checkbox" name="polymart" class="search-form__polymart-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().polymart />
+            <label for="polymart-checkbox" class="search-form__polymart-label">Polymart</label>
+
+            <input id="builtbybit-checkbox" type="checkbox" name="builtbybit" class="search-form__builtbybit-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().builtbybit />
+            <label for="builtbybit-checkbox" class="search-form__builtbybit-label">BuiltByBit</label>
+
             <span class="search-form__fields-text">"Fields:"</span>
 
             <input id="name-checkbox" type="checkbox" name="name" class="search-form__name-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().name />
@@ -686,10 +1074,52 @@ fn SearchForm(
             <input id="description-checkbox" type="checkbox" name="description" class="search-form__description-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().description />
             <label for="description-checkbox" class="search-form__description-label">Description</label>
 
+            <input id="full-description-checkbox" type="checkbox" name="full_description" class="search-form__full-description-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().full_description />
+            <label for="full-description-checkbox" class="search-form__full-description-label">Full Description</label>
+
             <input id="author-checkbox" type="checkbox" name="author" class="search-form__author-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().author />
             <label for="author-checkbox" class="search-form__author-label">Author</label>
 
             <div class="search-form__sort-limit-container">
+                <label for="platform-select" class="search-form__platform-label">"Platform:"</label>
+                <select id="platform-select" name="platform" class="search-form__platform-select" onchange="this.form.requestSubmit()" prop:value=move || params().platform>
+                    <option value="">Any</option>
+                    <option value="bukkit">Bukkit</option>
+                    <option value="spigot">Spigot</option>
+                    <option value="paper">Paper</option>
+                    <option value="folia">Folia</option>
+                    <option value="purpur">Purpur</option>
+                    <option value="velocity">Velocity</option>
+                    <option value="bungeecord">BungeeCord</option>
+                    <option value="waterfall">Waterfall</option>
+                </select>
+
+                <label for="category-select" class="search-form__category-label">"Category:"</label>
+                <select id="category-select" name="category" class="search-form__category-select" onchange="this.form.requestSubmit()" prop:value=move || params().category>
+                    <option value="">Any</option>
+                    <option value="admin_tools">Admin Tools</option>
+                    <option value="chat">Chat</option>
+                    <option value="economy">Economy</option>
+                    <option value="gameplay">Gameplay</option>
+                    <option value="library">Library</option>
+                    <option value="minigames">Minigames</option>
+                    <option value="miscellaneous">Miscellaneous</option>
+                    <option value="optimization">Optimization</option>
+                    <option value="protection">Protection</option>
+                    <option value="role_playing">Role Playing</option>
+                    <option value="transportation">Transportation</option>
+                    <option value="utility">Utility</option>
+                    <option value="world_management">World Management</option>
+                </select>
+
+                <label for="minecraft-version-input" class="search-form__minecraft-version-label">"MC Version:"</label>
+                <input id="minecraft-version-input" type="text" name="minecraft_version" class="search-form__minecraft-version-input" oninput="submitFormDebounce(this.form)" placeholder="Any" value=move || params().minecraft_version />
+
+                <div class="search-form__exclude-archived-container">
+                    <input id="exclude-archived-checkbox" type="checkbox" name="exclude_archived" class="search-form__exclude-archived-checkbox" value="true" oninput="this.form.requestSubmit()" checked=move || params().exclude_archived />
+                    <label for="exclude-archived-checkbox" class="search-form__exclude-archived-label">Exclude archived</label>
+                </div>
+
                 <label for="sort-select" class="search-form__sort-label">"Sort by:"</label>
                 <select id="sort-select" name="sort" class="search-form__sort-select" onchange="this.form.requestSubmit()" prop:value=move || params().sort>
                     <option value="relevance">Relevance</option>
@@ -697,8 +1127,10 @@ fn SearchForm(
                     <option value="date_updated">Recently Updated</option>
                     <option value="latest_minecraft_version">Latest MC Version</option>
                     <option value="downloads">Downloads</option>
+                    <option value="trending">Trending</option>
                     <option value="likes_and_stars">Likes + Stars</option>
                     <option value="follows_and_watchers">Follows + Watchers</option>
+                    <option value="repository_activity">Repository Activity</option>
                 </select>
 
                 <label for="limit-select" class="search-form__limit-label">"Show per page:"</label>
@@ -733,6 +1165,9 @@ fn SearchResults(
                                     let show_spigot = params.spigot.unwrap_or(false);
                                     let show_modrinth = params.modrinth.unwrap_or(false);
                                     let show_hangar = params.hangar.unwrap_or(false);
+                                    let show_curseforge = params.curseforge.unwrap_or(false);
+                                    let show_polymart = params.polymart.unwrap_or(false);
+                                    let show_builtbybit = params.builtbybit.unwrap_or(false);
 
                                     view! {
                                         <div class="search-results__header-row">
@@ -751,6 +1186,15 @@ fn SearchResults(
                                             <Show when=move || { show_hangar }>
                                                 <h3 class="search-results__hangar-header">Hangar</h3>
                                             </Show>
+                                            <Show when=move || { show_curseforge }>
+                                                <h3 class="search-results__curseforge-header">CurseForge</h3>
+                                            </Show>
+                                            <Show when=move || { show_polymart }>
+                                                <h3 class="search-results__polymart-header">Polymart</h3>
+                                            </Show>
+                                            <Show when=move || { show_builtbybit }>
+                                                <h3 class="search-results__builtbybit-header">BuiltByBit</h3>
+                                            </Show>
                                             <h3 class="search-results__source-header">Source Code</h3>
                                         </div>
                                     }
@@ -875,7 +1319,10 @@ fn SearchRow(
 
     let show_spigot = move || params().as_ref().map(|p| p.spigot.unwrap_or(false)).unwrap_or(false);
     let show_modrinth = move || params().as_ref().map(|p| p.modrinth.unwrap_or(false)).unwrap_or(false);
-    let show_hangar = move || params().map(|p| p.hangar.unwrap_or(false)).unwrap_or(false);
+    let show_hangar = move || params().as_ref().map(|p| p.hangar.unwrap_or(false)).unwrap_or(false);
+    let show_curseforge = move || params().map(|p| p.curseforge.unwrap_or(false)).unwrap_or(false);
+    let show_polymart = move || params().map(|p| p.polymart.unwrap_or(false)).unwrap_or(false);
+    let show_builtbybit = move || params().map(|p| p.builtbybit.unwrap_or(false)).unwrap_or(false);
 
     let date_created = search_result.date_created.format(&SEARCH_RESULT_DATE_FORMAT_DESCRIPTION);
     let time_created = search_result.date_created.format(&SEARCH_RESULT_TIME_FORMAT_DESCRIPTION);
@@ -894,7 +1341,12 @@ fn SearchRow(
     let spigot = search_result.spigot;
     let modrinth = search_result.modrinth;
     let hangar = search_result.hangar;
+    let curseforge = search_result.curseforge;
+    let polymart = search_result.polymart;
+    let builtbybit = search_result.builtbybit;
     let source_repository = search_result.source_repository;
+    let source_repository_release = search_result.source_repository_release;
+    let source_repository_metadata = search_result.source_repository_metadata;
 
     let has_source = source_repository.is_some();
 
@@ -944,16 +1396,34 @@ fn SearchRow(
                 </div>
             </Show>
 
+            <Show when=move || { show_curseforge() }>
+                <div class="search-row__curseforge-cell">
+                    <CurseForgeProjectOuter curseforge=curseforge.clone() />
+                </div>
+            </Show>
+
+            <Show when=move || { show_polymart() }>
+                <div class="search-row__polymart-cell">
+                    <PolymartResourceOuter polymart=polymart.clone() />
+                </div>
+            </Show>
+
+            <Show when=move || { show_builtbybit() }>
+                <div class="search-row__builtbybit-cell">
+                    <BuiltByBitResourceOuter builtbybit=builtbybit.clone() />
+                </div>
+            </Show>
+
             <div class="search-row__source-cell">
                 <Show when=move || { has_source }>
-                    <SourceRepository repo=source_repository.clone().unwrap() project_name=project_name.clone() />
+                    <SourceRepository repo=source_repository.clone().unwrap() release=source_repository_release.clone() metadata=source_repository_metadata.clone() project_name=project_name.clone() />
                 </Show>
             </div>
         </li>
     }
 }
 
-// These outer components are necessary to ensure that the Spigot/Modrinth/Hangar objects are not moved out of their environment when using nested <Show>s.
+// These outer components are necessary to ensure that the Spigot/Modrinth/Hangar/CurseForge/Polymart/BuiltByBit objects are not moved out of their environment when using nested <Show>s.
 // TODO: Find a way to do this without needing outer components.
 
 #[component]
@@ -995,6 +1465,45 @@ fn HangarProjectOuter(
     }
 }
 
+#[component]
+fn CurseForgeProjectOuter(
+    curseforge: Option<WebSearchResultCurseForge>
+) -> impl IntoView {
+    let has_curseforge = curseforge.is_some();
+
+    view! {
+        <Show when=move || { has_curseforge }>
+            <CurseForgeProjectInner curseforge=curseforge.clone().unwrap() />
+        </Show>
+    }
+}
+
+#[component]
+fn PolymartResourceOuter(
+    polymart: Option<WebSearchResultPolymart>
+) -> impl IntoView {
+    let has_polymart = polymart.is_some();
+
+    view! {
+        <Show when=move || { has_polymart }>
+            <PolymartResourceInner polymart=polymart.clone().unwrap() />
+        </Show>
+    }
+}
+
+#[component]
+fn BuiltByBitResourceOuter(
+    builtbybit: Option<WebSearchResultBuiltByBit>
+) -> impl IntoView {
+    let has_builtbybit = builtbybit.is_some();
+
+    view! {
+        <Show when=move || { has_builtbybit }>
+            <BuiltByBitResourceInner builtbybit=builtbybit.clone().unwrap() />
+        </Show>
+    }
+}
+
 #[component]
 fn SpigotResourceInner(
     spigot: WebSearchResultSpigot
@@ -1004,6 +1513,7 @@ fn SpigotResourceInner(
 
     let name = spigot.name.clone();
     let url = spigot.url();
+    let details_url = spigot.details_url();
     let icon_img_url = spigot.icon_img_url();
     let icon_alt_text = spigot.icon_alt_text();
     let version = spigot.version;
@@ -1032,6 +1542,7 @@ fn SpigotResourceInner(
                 </div>
             </div>
         </a>
+        <a class="search-row__details-link" href=details_url>"Full description"</a>
     }
 }
 
@@ -1045,6 +1556,7 @@ fn ModrinthProjectInner(
 
     let name = modrinth.name.clone();
     let url = modrinth.url();
+    let details_url = modrinth.details_url();
     let icon_img_url = modrinth.icon_img_url();
     let icon_alt_text = modrinth.icon_alt_text();
     let version = modrinth.version;
@@ -1070,6 +1582,7 @@ fn ModrinthProjectInner(
                 </div>
             </div>
         </a>
+        <a class="search-row__details-link" href=details_url>"Full description"</a>
     }
 }
 
@@ -1081,6 +1594,7 @@ fn HangarProjectInner(
 ) -> impl IntoView {
     let name = hangar.name.clone();
     let url = hangar.url();
+    let details_url = hangar.details_url();
     let icon_img_url = hangar.icon_img_url();
     let icon_alt_text = hangar.icon_alt_text();
     let version = hangar.version;
@@ -1103,6 +1617,117 @@ fn HangarProjectInner(
                 </div>
             </div>
         </a>
+        <a class="search-row__details-link" href=details_url>"Full description"</a>
+    }
+}
+
+/// A project hosted on CurseForge
+#[component]
+fn CurseForgeProjectInner(
+    /// The CurseForge portion of the search result
+    curseforge: WebSearchResultCurseForge
+) -> impl IntoView {
+    let name = curseforge.name.clone();
+    let url = curseforge.url();
+    let icon_img_url = curseforge.icon_img_url();
+    let icon_alt_text = curseforge.icon_alt_text();
+    let version = curseforge.version;
+    let author = curseforge.author;
+    let description = curseforge.description;
+
+    view! {
+        <a class="search-row__curseforge-link" href=url.clone() target="_blank">
+            <img class="search-row__image" src=icon_img_url.clone() title=name.clone() alt=icon_alt_text.clone() width="75" height="75" loading="lazy" />
+            <div class="search-row__text-contents">
+                <div class="search-row__cell-title">
+                    <h3 class="search-row__plugin-name">{name.clone()}</h3>
+                    <span>" "</span>
+                    <span class="search-row__plugin-version">{version.clone()}</span>
+                    <span>" by "</span>
+                    <span class="search-row__plugin-author">{author.clone()}</span>
+                </div>
+                <div class="search-row__cell-description">
+                    {description.clone()}
+                </div>
+            </div>
+        </a>
+    }
+}
+
+/// A resource hosted on Polymart
+#[component]
+fn PolymartResourceInner(
+    /// The Polymart portion of the search result
+    polymart: WebSearchResultPolymart
+) -> impl IntoView {
+    let is_premium = polymart.premium;
+
+    let name = polymart.name.clone();
+    let url = polymart.url();
+    let icon_img_url = polymart.icon_img_url();
+    let icon_alt_text = polymart.icon_alt_text();
+    let version = polymart.version;
+    let author = polymart.author;
+    let description = polymart.description;
+
+    view! {
+        <a class="search-row__polymart-link" href=url.clone() target="_blank">
+            <img class="search-row__image" src=icon_img_url.clone() title=name.clone() alt=icon_alt_text.clone() width="75" height="75" loading="lazy" />
+            <div class="search-row__text-contents">
+                <div class="search-row__cell-title">
+                    <Show when=move || { is_premium }>
+                        <img class="search-row__plugin-premium" src=PREMIUM_IMAGE_URL title="Premium Plugin (requires Polymart login)" alt="Dollar sign icon indicating this plugin requires payment to download" width="24" height="24" loading="lazy" />
+                    </Show>
+                    <h3 class="search-row__plugin-name">{name.clone()}</h3>
+                    <span>" "</span>
+                    <span class="search-row__plugin-version">{version.clone()}</span>
+                    <span>" by "</span>
+                    <span class="search-row__plugin-author">{author.clone()}</span>
+                </div>
+                <div class="search-row__cell-description">
+                    {description.clone()}
+                </div>
+            </div>
+        </a>
+    }
+}
+
+/// A resource hosted on BuiltByBit
+#[component]
+fn BuiltByBitResourceInner(
+    /// The BuiltByBit portion of the search result
+    builtbybit: WebSearchResultBuiltByBit
+) -> impl IntoView {
+    let is_premium = builtbybit.is_premium();
+    let premium_title = format!("Premium Plugin: {} (requires BuiltByBit purchase)", builtbybit.price_text());
+
+    let name = builtbybit.name.clone();
+    let url = builtbybit.url();
+    let icon_img_url = builtbybit.icon_img_url();
+    let icon_alt_text = builtbybit.icon_alt_text();
+    let version = builtbybit.version;
+    let author = builtbybit.author;
+    let description = builtbybit.description;
+
+    view! {
+        <a class="search-row__builtbybit-link" href=url.clone() target="_blank">
+            <img class="search-row__image" src=icon_img_url.clone() title=name.clone() alt=icon_alt_text.clone() width="75" height="75" loading="lazy" />
+            <div class="search-row__text-contents">
+                <div class="search-row__cell-title">
+                    <Show when=move || { is_premium }>
+                        <img class="search-row__plugin-premium" src=PREMIUM_IMAGE_URL title=premium_title.clone() alt="Dollar sign icon indicating this plugin requires payment to download" width="24" height="24" loading="lazy" />
+                    </Show>
+                    <h3 class="search-row__plugin-name">{name.clone()}</h3>
+                    <span>" "</span>
+                    <span class="search-row__plugin-version">{version.clone()}</span>
+                    <span>" by "</span>
+                    <span class="search-row__plugin-author">{author.clone()}</span>
+                </div>
+                <div class="search-row__cell-description">
+                    {description.clone()}
+                </div>
+            </div>
+        </a>
     }
 }
 
@@ -1111,6 +1736,10 @@ fn HangarProjectInner(
 fn SourceRepository(
     /// The source repository portion of the search result
     repo: WebSearchResultSourceRepository,
+    /// The latest release of the source repository, if any
+    release: Option<WebSearchResultSourceRepositoryRelease>,
+    /// The forge metadata of the source repository, if any
+    metadata: Option<WebSearchResultSourceRepositoryMetadata>,
     /// The name of the project
     project_name: Option<String>
 ) -> impl IntoView {
@@ -1118,17 +1747,75 @@ fn SourceRepository(
     let url_wbr = repo.url_wbr();
     let img_attributes = repo.img_attributes(&project_name);
 
+    let has_release = release.is_some();
+    let has_metadata = metadata.is_some();
+
     view! {
         <a class="search-row__source-link" href=url.clone() target="_blank">
             <img class="search-row__image" src=img_attributes.src.clone() title=img_attributes.title.clone() alt=img_attributes.alt.clone() width="75" height="75" loading="lazy" />
             <div class="search-row__source-text-contents">
                 <div class="search-row__cell-title" inner_html=url_wbr.clone()>
                 </div>
+                <Show when=move || { has_release }>
+                    <SourceRepositoryRelease release=release.clone().unwrap() />
+                </Show>
+                <Show when=move || { has_metadata }>
+                    <SourceRepositoryMetadata metadata=metadata.clone().unwrap() />
+                </Show>
             </div>
         </a>
     }
 }
 
+/// The latest release of the source code repository
+#[component]
+fn SourceRepositoryRelease(
+    /// The latest release of the source repository
+    release: WebSearchResultSourceRepositoryRelease
+) -> impl IntoView {
+    let title = release.title();
+    let date_published = release.date_published.format(&SEARCH_RESULT_DATE_FORMAT_DESCRIPTION);
+
+    let class = if release.newer {
+        "search-row__source-release search-row__source-release--newer"
+    } else {
+        "search-row__source-release"
+    };
+
+    view! {
+        <div class=class title=title>
+            <span class="search-row__plugin-version">{release.tag_name}</span>
+            <span>" on "</span>
+            <span class="search-row__date">{date_published}</span>
+        </div>
+    }
+}
+
+/// The stars, license, and archived status of the source code repository
+#[component]
+fn SourceRepositoryMetadata(
+    /// The forge metadata of the source repository
+    metadata: WebSearchResultSourceRepositoryMetadata
+) -> impl IntoView {
+    let title = metadata.title();
+    let stars = metadata.stars_formatted();
+    let license = metadata.license.clone();
+    let has_license = license.is_some();
+    let archived = metadata.archived;
+
+    view! {
+        <div class="search-row__source-metadata" title=title>
+            <span>"★ "{stars}</span>
+            <Show when=move || { has_license }>
+                <span class="search-row__source-license">{license.clone()}</span>
+            </Show>
+            <Show when=move || { archived }>
+                <span class="search-row__source-archived">"Archived"</span>
+            </Show>
+        </div>
+    }
+}
+
 struct ImgAttributes {
     src: Option<String>,
     title: Option<String>,
@@ -1137,4 +1824,15 @@ struct ImgAttributes {
 
 fn alt_text(project_name: &Option<String>, repository_name: &str) -> Option<String> {
     Some(format!("Icon for {} on {}", project_name.clone()?, repository_name))
+}
+
+fn project_icon_img_url(icon_hash: &Option<String>, icon_url: Option<&str>) -> String {
+    if let Some(hash) = icon_hash {
+        return format!("{}/{}", ICONS_URL_PREFIX, hash);
+    }
+
+    match icon_url {
+        Some(url) if !url.is_empty() => url.to_string(),
+        _ => NO_ICON_IMAGE_URL.to_string()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+use mc_plugin_finder::database::icon::get_icon;
+
+use axum::Router;
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use deadpool_postgres::Pool;
+use tracing::error;
+
+// Icons are identified by the hash of their original image, so the icon at a given URL never changes and can be cached indefinitely.
+const ICON_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Serves cached project icons at /icons/{hash}, in WebP if the browser accepts it and PNG otherwise.
+pub fn icon_router<S>(db_pool: Pool) -> Router<S> {
+    Router::new()
+        .route("/icons/:hash", get(get_icon_image))
+        .with_state(db_pool)
+}
+
+async fn get_icon_image(State(db_pool): State<Pool>, Path(hash): Path<String>, headers: HeaderMap) -> Response {
+    let icon = match get_icon(&db_pool, &hash).await {
+        Ok(Some(icon)) => icon,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(err) => {
+            error!("Unable to get icon {}: {}", hash, err);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let accepts_webp = headers.get(header::ACCEPT)
+        .and_then(|x| x.to_str().ok())
+        .is_some_and(|x| x.contains("image/webp"));
+
+    let (content_type, body) = if accepts_webp {
+        ("image/webp", icon.webp)
+    } else {
+        ("image/png", icon.png)
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, ICON_CACHE_CONTROL),
+            (header::VARY, "Accept")
+        ],
+        body
+    ).into_response()
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
 pub mod app;
 pub mod error_template;
+#[cfg(feature = "ssr")]
+pub mod icon;
 pub mod util;
 
 #[cfg(feature = "hydrate")]
@@ -7,6 +7,7 @@ async fn main() {
     use leptos::prelude::*;
     use leptos::context::provide_context;
     use leptos_axum::{generate_route_list, LeptosRoutes};
+    use mc_plugin_finder::database::common::search_result::SearchWeights;
     use mc_plugin_finder::database::get_db;
     use tracing::{info, warn};
     use tracing_subscriber::prelude::*;
@@ -15,6 +16,7 @@ async fn main() {
     use tracing_subscriber::fmt::format::FmtSpan;
     use web::app::*;
     use web::app::ssr::WebContext;
+    use web::icon::icon_router;
 
     // Initialize tracing
     let appender = tracing_appender::rolling::daily("logs/web", "web.log");
@@ -56,7 +58,8 @@ async fn main() {
     let db_pool = db.create_pool().await.unwrap();
 
     let context = WebContext {
-        db_pool
+        db_pool: db_pool.clone(),
+        search_weights: SearchWeights::from_config()
     };
 
     // build our application with a route
@@ -69,6 +72,7 @@ async fn main() {
                 let leptos_options = leptos_options.clone();
                 move || shell(leptos_options.clone())
             })
+        .merge(icon_router(db_pool))
         .fallback(leptos_axum::file_and_error_handler(shell))
         .with_state(leptos_options);
 